@@ -8,6 +8,7 @@ import 'src/pages/debug_page.dart';
 import 'src/pages/iap_page.dart';
 import 'package:open_anki/src/rust/frb_generated.dart';
 import 'package:open_anki/src/rust/api/simple.manual.dart';
+import 'package:open_anki/src/rust/api/simple.dart' show initCollection, openCollection, closeCollection;
 import 'dart:async';
 import 'dart:io';
 import 'package:path_provider/path_provider.dart';
@@ -102,6 +103,12 @@ class _SplashPageWrapperState extends State<SplashPageWrapper> {
     try {
       await RustLib.init();
       await initRustLog();
+      // 所有牌组都在 anki_data 下的集合库里，旧版本每个牌组一个目录，启动时导入集合库
+      final appDocDir = await getApplicationDocumentsDirectory();
+      final migrated = await initCollection(baseDir: '${appDocDir.path}/anki_data');
+      if (migrated > 0) {
+        LogHelper.log('已将 $migrated 个旧牌组目录导入集合库');
+      }
     } catch (e, st) {
       LogHelper.log('init error: $e\n$st');
     }
@@ -146,7 +153,7 @@ class MyApp extends StatefulWidget {
   State<MyApp> createState() => _MyAppState();
 }
 
-class _MyAppState extends State<MyApp> {
+class _MyAppState extends State<MyApp> with WidgetsBindingObserver {
   int _selectedIndex = 0;
   Locale? _locale;
   final _pages = [
@@ -159,9 +166,26 @@ class _MyAppState extends State<MyApp> {
   @override
   void initState() {
     super.initState();
+    WidgetsBinding.instance.addObserver(this);
     _loadSavedLocale();
   }
 
+  @override
+  void dispose() {
+    WidgetsBinding.instance.removeObserver(this);
+    super.dispose();
+  }
+
+  @override
+  void didChangeAppLifecycleState(AppLifecycleState state) {
+    // 进入后台时关闭集合库连接，回到前台时重新打开
+    if (state == AppLifecycleState.paused || state == AppLifecycleState.detached) {
+      closeCollection();
+    } else if (state == AppLifecycleState.resumed) {
+      openCollection().catchError((e) => LogHelper.log('open collection error: $e'));
+    }
+  }
+
   Future<void> _loadSavedLocale() async {
     final prefs = await SharedPreferences.getInstance();
     final code = prefs.getString('locale');
@@ -3,8 +3,7 @@ import 'package:path/path.dart';
 import 'dart:convert'; // Added for jsonEncode and jsonDecode
 import 'dart:typed_data'; // Added for Uint8List
 import 'model.dart';
-import 'package:path_provider/path_provider.dart'; // Added for getApplicationDocumentsDirectory
-import 'dart:io'; // Added for File
+import 'package:open_anki/src/rust/api/simple.dart' show removeDeck;
 
 class AppDb {
   static Database? _db;
@@ -212,12 +211,30 @@ class AppDb {
       }
     });
 
-    // 删除题库文件
-    final appDocDir = await getApplicationDocumentsDirectory();
-    final deckDir = Directory('${appDocDir.path}/anki_data/$deckId');
-    if (await deckDir.exists()) {
-      await deckDir.delete(recursive: true);
-    }
+    // 从集合库删除题库的牌组、笔记和卡片
+    await removeDeck(deckId: deckId);
+  }
+
+  // 用新版本更新题库后同步卡片映射：新卡片登记调度，已删除卡片清掉调度和状态
+  static Future<void> applyDeckUpdate(String deckId, {required List<int> addedCardIds, required List<int> removedCardIds, int? cardCount}) async {
+    final dbClient = await db;
+    final now = DateTime.now().millisecondsSinceEpoch ~/ 1000;
+    await dbClient.transaction((txn) async {
+      for (final cardId in addedCardIds) {
+        await txn.insert('cards', {'card_id': cardId, 'deck_id': deckId}, conflictAlgorithm: ConflictAlgorithm.replace);
+        await txn.insert('card_scheduling', CardScheduling(cardId: cardId, stability: 0.0, difficulty: 5.0, due: now).toMap(),
+            conflictAlgorithm: ConflictAlgorithm.replace);
+      }
+      if (removedCardIds.isNotEmpty) {
+        String idList = '(${List.filled(removedCardIds.length, '?').join(',')})';
+        await txn.delete('cards', where: 'card_id IN $idList', whereArgs: removedCardIds);
+        await txn.delete('card_scheduling', where: 'card_id IN $idList', whereArgs: removedCardIds);
+        await txn.delete('card_states', where: 'card_id IN $idList', whereArgs: removedCardIds);
+      }
+      if (cardCount != null) {
+        await txn.update('decks', {'card_count': cardCount}, where: 'md5 = ?', whereArgs: [deckId]);
+      }
+    });
   }
 
   // 重命名题库
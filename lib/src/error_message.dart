@@ -0,0 +1,27 @@
+import 'package:flutter/widgets.dart';
+import 'package:flutter_gen/gen_l10n/app_localizations.dart';
+import 'package:open_anki/src/rust/api/error.dart';
+import 'log_helper.dart';
+
+// Rust 接口抛出的 AnkiError 按类别显示本地化的提示，原始信息写到日志里；其他异常原样显示
+String errorMessage(BuildContext context, Object error) {
+  final l10n = AppLocalizations.of(context);
+  if (error is! AnkiError || l10n == null) return error.toString();
+  LogHelper.log('[AnkiError] ${error.kind.name}: ${error.context} ${error.message} ${error.path ?? ''}');
+  final text = switch (error.kind) {
+    ErrorKind.notInitialized => l10n.errorNotInitialized,
+    ErrorKind.notFound => l10n.errorNotFound,
+    ErrorKind.invalidPackage => l10n.errorInvalidPackage,
+    ErrorKind.unsafeEntry => l10n.errorUnsafeEntry,
+    ErrorKind.tooLarge => l10n.errorTooLarge,
+    ErrorKind.diskFull => l10n.errorDiskFull,
+    ErrorKind.permissionDenied => l10n.errorPermissionDenied,
+    ErrorKind.io => l10n.errorIo,
+    ErrorKind.database => l10n.errorDatabase,
+    ErrorKind.decode => l10n.errorDecode,
+    ErrorKind.invalidArgument => l10n.errorInvalidArgument,
+    ErrorKind.scheduler => l10n.errorScheduler,
+    ErrorKind.cancelled => l10n.errorCancelled,
+  };
+  return error.path == null ? text : '$text: ${error.path}';
+}
@@ -23,9 +23,9 @@ import 'settings_page.dart';
 import 'package:flutter_gen/gen_l10n/app_localizations.dart';
 import 'package:flutter/foundation.dart';
 import 'package:open_anki/src/widgets/snack_bar.dart';
+import '../error_message.dart';
 
 const String kAutoMatchChoiceTemplate = '自动匹配-选择题模板';
-const String kSqliteDBFileName = 'collection.sqlite';
 
 // A set to keep track of deckIds for which the network warning has been shown.
 final _shownNetworkWarningForDecks = <String>{};
@@ -51,7 +51,6 @@ class _CardReviewPageState extends ConsumerState<CardReviewPage> {
   List<int> _noteIds = [];
   int _currentIndex = 0;
   String? _mediaDir;
-  String? _sqlitePath;
   String? _deckVersion;
   NoteExt? _currentNote;
   NotetypeExt? _currentNotetype;
@@ -231,14 +230,11 @@ class _CardReviewPageState extends ConsumerState<CardReviewPage> {
   Future<void> _loadDeck() async {
     setState(() { _loading = true; });
     try {
-      final appDocDir = await getApplicationDocumentsDirectory();
       final deck = await AppDb.getDeckById(widget.deckId);
       if (deck == null) throw Exception('题库未找到');
       
-      final deckDir = '${appDocDir.path}/anki_data/${deck.deckId}';
-      final sqlitePath = '$deckDir/$kSqliteDBFileName';
-      _sqlitePath = sqlitePath;
-      _mediaDir = '$deckDir/unarchived_media';
+      // 所有牌组共用集合库的媒体目录
+      _mediaDir = await getMediaDir();
       _deckVersion = deck.version ?? 'anki2';
       
       // 根据学习模式获取卡片
@@ -263,7 +259,7 @@ class _CardReviewPageState extends ConsumerState<CardReviewPage> {
             break;
           }
           // 通过 Rust FFI 获取新卡片 id
-          _noteIds = (await getNewNoteIds(sqlitePath: sqlitePath, limit: BigInt.from(newLimit), version: _deckVersion!)).map((e) => e.toInt()).toList();
+          _noteIds = (await getNewNoteIds(deckId: deck.deckId, limit: BigInt.from(newLimit))).map((e) => e.toInt()).toList();
           break;
           
         case StudyMode.review:
@@ -293,12 +289,12 @@ class _CardReviewPageState extends ConsumerState<CardReviewPage> {
           
         case StudyMode.preview:
           // 通过 Rust FFI 获取所有卡片 id
-          _noteIds = (await getAllNoteIds(sqlitePath: sqlitePath, version: _deckVersion!)).map((e) => e.toInt()).toList();
+          _noteIds = (await getAllNoteIds(deckId: deck.deckId)).map((e) => e.toInt()).toList();
           break;
           
         case StudyMode.custom:
           // 通过 Rust FFI 获取所有卡片 id
-          _noteIds = (await getAllNoteIds(sqlitePath: sqlitePath, version: _deckVersion!)).map((e) => e.toInt()).toList();
+          _noteIds = (await getAllNoteIds(deckId: deck.deckId)).map((e) => e.toInt()).toList();
           break;
       }
       
@@ -311,7 +307,7 @@ class _CardReviewPageState extends ConsumerState<CardReviewPage> {
 
     } catch (e) {
       if (mounted) {
-        ScaffoldMessenger.of(context).showSnackBar(SnackBar(content: Text(AppLocalizations.of(context)?.loadDeckFailed(e.toString()) ?? 'Load deck failed: ${e.toString()}')));
+        ScaffoldMessenger.of(context).showSnackBar(SnackBar(content: Text(AppLocalizations.of(context)?.loadDeckFailed(errorMessage(context, e)) ?? 'Load deck failed: ${e.toString()}')));
       }
     } finally {
       if (mounted) {
@@ -345,7 +341,7 @@ class _CardReviewPageState extends ConsumerState<CardReviewPage> {
     final indexToLoad = initialIndex ?? _currentIndex;
 
     LogHelper.log('[_loadCurrentCard] 开始加载卡片，索引: $indexToLoad');
-    if (_noteIds.isEmpty || _mediaDir == null || indexToLoad < 0 || indexToLoad >= _noteIds.length || _sqlitePath == null || _deckVersion == null) {
+    if (_noteIds.isEmpty || _mediaDir == null || indexToLoad < 0 || indexToLoad >= _noteIds.length || _deckVersion == null) {
       debugPrint('[_loadCurrentCard] 条件不足，无法加载卡片');
       if (!_cardLoadCompleter!.isCompleted) _cardLoadCompleter!.complete();
       return;
@@ -361,7 +357,7 @@ class _CardReviewPageState extends ConsumerState<CardReviewPage> {
         await AppDb.upsertCardScheduling(scheduling);
       }
       
-      final result = await getDeckNote(sqlitePath: _sqlitePath!, noteId: noteId, version: _deckVersion!);
+      final result = await getDeckNote(noteId: noteId);
       
       final fieldsForType = List<FieldExt>.from(result.fields)..sort((a, b) => a.ord.compareTo(b.ord));
       final fieldMap = <String, String>{};
@@ -407,7 +403,7 @@ class _CardReviewPageState extends ConsumerState<CardReviewPage> {
     } catch (e, s) {
       LogHelper.log('Error in _loadCurrentCard: $e\n$s');
       if (mounted) {
-        ScaffoldMessenger.of(context).showSnackBar(SnackBar(content: Text(AppLocalizations.of(context)?.loadCardFailed(e.toString()) ?? 'Load card failed: ${e.toString()}')));
+        ScaffoldMessenger.of(context).showSnackBar(SnackBar(content: Text(AppLocalizations.of(context)?.loadCardFailed(errorMessage(context, e)) ?? 'Load card failed: ${e.toString()}')));
       }
       if (_cardLoadCompleter != null && !_cardLoadCompleter!.isCompleted) {
         _cardLoadCompleter!.completeError(e, s);
@@ -486,36 +482,22 @@ class _CardReviewPageState extends ConsumerState<CardReviewPage> {
       style: ElevatedButton.styleFrom(minimumSize: const Size(64, 40)),
       onPressed: () async {
         if (_currentNote == null) return;
-        // 获取当前卡片的调度参数
         final now = DateTime.now().millisecondsSinceEpoch ~/ 1000; // 转换为秒
-        var scheduling = await AppDb.getCardScheduling(_currentNote!.id);
-        if (scheduling == null) {
-          // 如果没有调度参数，创建默认值
-          scheduling = CardScheduling(
-            cardId: _currentNote!.id,
-            stability: 0.0, // 新卡片从0开始
-            difficulty: 5.0,
-            due: now,
-          );
-          await AppDb.upsertCardScheduling(scheduling);
-        }
         
-        // 根据设置选择调度算法
+        // 根据设置选择调度算法，调度状态和学习步骤由 Rust 按牌组配置保存在集合里
         final prefs = await SharedPreferences.getInstance();
         final algorithm = prefs.getString('schedulingAlgorithm') ?? 'fsrs';
         
         final result = algorithm == 'simple' 
           ? await updateCardScheduleSimple(
-              stability: scheduling.stability,
-              difficulty: scheduling.difficulty,
-              lastReview: scheduling.due,
+              deckId: widget.deckId,
+              noteId: _currentNote!.id,
               rating: value,
               now: now,
             )
           : await updateCardSchedule(
-              stability: scheduling.stability,
-              difficulty: scheduling.difficulty,
-              lastReview: scheduling.due,
+              deckId: widget.deckId,
+              noteId: _currentNote!.id,
               rating: value,
               now: now,
             );
@@ -537,25 +519,21 @@ class _CardReviewPageState extends ConsumerState<CardReviewPage> {
         // 预览此选项的复习时间
         if (_currentNote == null) return;
         final now = DateTime.now().millisecondsSinceEpoch ~/ 1000;
-        final scheduling = await AppDb.getCardScheduling(_currentNote!.id);
-        if (scheduling == null) return;
 
-        // 根据设置选择调度算法
+        // 根据设置选择调度算法，只预览不保存
         final prefs = await SharedPreferences.getInstance();
         final algorithm = prefs.getString('schedulingAlgorithm') ?? 'fsrs';
         
         final result = algorithm == 'simple' 
-          ? await updateCardScheduleSimple(
-              stability: scheduling.stability,
-              difficulty: scheduling.difficulty,
-              lastReview: scheduling.due,
+          ? await previewCardScheduleSimple(
+              deckId: widget.deckId,
+              noteId: _currentNote!.id,
               rating: value, // 直接使用 value
               now: now,
             )
-          : await updateCardSchedule(
-              stability: scheduling.stability,
-              difficulty: scheduling.difficulty,
-              lastReview: scheduling.due,
+          : await previewCardSchedule(
+              deckId: widget.deckId,
+              noteId: _currentNote!.id,
               rating: value, // 直接使用 value
               now: now,
             );
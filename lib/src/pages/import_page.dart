@@ -18,6 +18,7 @@ import '../constants.dart';
 import 'package:sqflite/sqflite.dart';
 import 'package:flutter_gen/gen_l10n/app_localizations.dart';
 import '../widgets/snack_bar.dart';
+import '../error_message.dart';
 
 class ImportPage extends ConsumerStatefulWidget {
   const ImportPage({super.key});
@@ -79,6 +80,24 @@ class _ImportPageState extends ConsumerState<ImportPage> {
     );
   }
 
+  // 把解包好的牌组登记到本地数据库
+  Future<void> _registerDeck(String md5, String deckName, Map<String, String> mediaMap, String version) async {
+    int cardCount = await getCardCount(deckId: md5);
+    await AppDb.insertDeck(md5, deckName, md5, mediaMap: mediaMap, version: version, cardCount: cardCount);
+    final cardIds = (await getAllNoteIds(deckId: md5)).map((e) => e.toInt()).toList();
+    final now = DateTime.now().millisecondsSinceEpoch ~/ 1000;
+    for (final cardId in cardIds) {
+      await AppDb.upsertCardScheduling(CardScheduling(
+        cardId: cardId,
+        stability: 0.0,
+        difficulty: 5.0,
+        due: now,
+      ));
+      await AppDb.insertCardMapping(cardId, md5);
+    }
+    _deckColors[md5] = kMacaronColors[DateTime.now().millisecondsSinceEpoch % kMacaronColors.length];
+  }
+
   Future<void> importApkg() async {
     setState(() {
       error = null;
@@ -90,15 +109,24 @@ class _ImportPageState extends ConsumerState<ImportPage> {
         return;
       }
       setState(() { importing = true; });
-      final appDocDir = await getApplicationDocumentsDirectory();
       int successCount = 0;
       for (final file in picked.files) {
         String? path = file.path;
         if (path == null) continue;
         final fileName = p.basenameWithoutExtension(path);
+        // .colpkg 是整个集合的备份，拆成多个牌组分别登记，已经导入过的牌组跳过
+        if (p.extension(path).toLowerCase() == '.colpkg') {
+          final colpkg = await extractColpkg(colpkgPath: path);
+          for (final deck in colpkg.decks) {
+            if (await AppDb.getDeckById(deck.md5) != null) continue;
+            await _registerDeck(deck.md5, deck.name, deck.mediaMap, colpkg.version);
+            successCount++;
+          }
+          continue;
+        }
         String? deckName;
         deckName = fileName;
-        final result = await extractApkg(apkgPath: path, baseDir: p.join(appDocDir.path, 'anki_data'));
+        final result = await extractApkg(apkgPath: path);
         final existingDeck = await AppDb.getDeckById(result.md5);
         if (existingDeck != null) {
           if(mounted) {
@@ -111,21 +139,7 @@ class _ImportPageState extends ConsumerState<ImportPage> {
           }
           continue;
         }
-        int cardCount = (await getCardCountFromDeck(appDocDir: appDocDir.path, md5: result.md5)).toInt();
-        await AppDb.insertDeck(result.md5, deckName, result.md5, mediaMap: result.mediaMap, version: result.version, cardCount: cardCount);
-        final sqlitePath = p.join(appDocDir.path, 'anki_data', result.md5, 'collection.sqlite');
-        final cardIds = (await getAllNoteIds(sqlitePath: sqlitePath, version: result.version)).map((e) => e.toInt()).toList();
-        final now = DateTime.now().millisecondsSinceEpoch ~/ 1000;
-        for (final cardId in cardIds) {
-          await AppDb.upsertCardScheduling(CardScheduling(
-            cardId: cardId,
-            stability: 0.0,
-            difficulty: 5.0,
-            due: now,
-          ));
-          await AppDb.insertCardMapping(cardId, result.md5);
-        }
-        _deckColors[result.md5] = kMacaronColors[DateTime.now().millisecondsSinceEpoch % kMacaronColors.length];
+        await _registerDeck(result.md5, deckName, result.mediaMap, result.version);
         successCount++;
       }
       ref.invalidate(allDecksProvider);
@@ -141,7 +155,7 @@ class _ImportPageState extends ConsumerState<ImportPage> {
       }
     } catch (e) {
       setState(() {
-        error = e.toString();
+        error = errorMessage(context, e);
         importing = false;
       });
     }
@@ -315,14 +329,13 @@ class _ImportPageState extends ConsumerState<ImportPage> {
                         }
                         final fileName = 'anki21b';
                         final deckName = fileName;
-                        final result = await extractApkg(apkgPath: file.path, baseDir: p.join(appDocDir.path, 'anki_data'));
+                        final result = await extractApkg(apkgPath: file.path);
 
                         final existingDeck = await AppDb.getDeckById(result.md5);
                         if (existingDeck == null) {
-                          int cardCount = (await getCardCountFromDeck(appDocDir: appDocDir.path, md5: result.md5)).toInt();
+                          int cardCount = await getCardCount(deckId: result.md5);
                           await AppDb.insertDeck(result.md5, deckName, result.md5, mediaMap: result.mediaMap, version: result.version, cardCount: cardCount);
-                          final sqlitePath = p.join(appDocDir.path, 'anki_data', result.md5, 'collection.sqlite');
-                          final cardIds = (await getAllNoteIds(sqlitePath: sqlitePath, version: result.version)).map((e) => e.toInt()).toList();
+                          final cardIds = (await getAllNoteIds(deckId: result.md5)).map((e) => e.toInt()).toList();
                           final now = DateTime.now().millisecondsSinceEpoch ~/ 1000;
                           for (final cardId in cardIds) {
                             await AppDb.upsertCardScheduling(CardScheduling(
@@ -339,7 +352,7 @@ class _ImportPageState extends ConsumerState<ImportPage> {
                         setState(() { importing = false; });
                         if (!mounted) return;
                       } catch (e) {
-                        setState(() { error = e.toString(); importing = false; });
+                        setState(() { error = errorMessage(context, e); importing = false; });
                       }
                     },
                     child: const SizedBox(width: 1, height: 1), // 极小不可见
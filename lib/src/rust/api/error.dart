@@ -0,0 +1,55 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+/// 接口返回的错误
+class AnkiError implements FrbException {
+  final ErrorKind kind;
+  final String context;
+  final String message;
+  final String? path;
+
+  const AnkiError({
+    required this.kind,
+    required this.context,
+    required this.message,
+    this.path,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^ context.hashCode ^ message.hashCode ^ path.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AnkiError &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          context == other.context &&
+          message == other.message &&
+          path == other.path;
+}
+
+/// 错误类别
+enum ErrorKind {
+  notInitialized,
+  notFound,
+  invalidPackage,
+  unsafeEntry,
+  tooLarge,
+  diskFull,
+  permissionDenied,
+  io,
+  database,
+  decode,
+  invalidArgument,
+  scheduler,
+  cancelled,
+}
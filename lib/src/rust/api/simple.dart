@@ -4,11 +4,12 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `rust_log`, `table_has_columns`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ApkgParseResult`, `CardExt`, `DeckNotesResult`, `LOG_SINK`, `Note`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `deref`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`
+// These functions are ignored because they are not marked as `pub`: `answer_in_collection`, `answer_note`, `check_answerable`, `emit_leech`, `first_card_id`, `import_apkg`, `import_into_collection`, `load_card_render_data`, `load_cards_batch`, `load_deck_name`, `load_deck_note`, `load_note_cards`, `load_notetype_info`, `load_review_logs`, `next_card_state`, `prepare_collection`, `preview_note`, `register`, `review_page_config`, `run_import_task`, `rust_log`, `staged_media`, `update_card_states`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ApkgParseResult`, `CancelFlag`, `CardRenderData`, `DeckNotesResult`, `IMPORT_CANCEL_FLAGS`, `LEECH_SINK`, `LOG_SINK`, `Note`, `NotetypeInfo`, `TemplateInfo`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `deref`, `deref`, `deref`, `drop`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `initialize`, `initialize`, `initialize`
 
 String greet({required String name}) =>
     RustLib.instance.api.crateApiSimpleGreet(name: name);
@@ -16,107 +17,720 @@ String greet({required String name}) =>
 Stream<String> registerLogCallback() =>
     RustLib.instance.api.crateApiSimpleRegisterLogCallback();
 
-Future<ExtractResult> extractApkg({
+/// 初始化并打开集合库，base_dir 是应用的 anki_data 目录，其他接口都要在这之后调用。
+/// 以前每个牌组一个 sqlite 的目录会导入集合库，返回导入的牌组数
+Future<int> initCollection({required String baseDir}) =>
+    RustLib.instance.api.crateApiSimpleInitCollection(baseDir: baseDir);
+
+/// 重新打开 close_collection 关闭的集合库，应用回到前台时调用；已经打开时什么都不做
+Future<void> openCollection() =>
+    RustLib.instance.api.crateApiSimpleOpenCollection();
+
+/// 关闭集合库的连接，应用进入后台时调用；之后要先调用 open_collection 才能使用其他接口
+Future<void> closeCollection() =>
+    RustLib.instance.api.crateApiSimpleCloseCollection();
+
+/// 媒体文件所在的目录，所有牌组共用
+Future<String> getMediaDir() =>
+    RustLib.instance.api.crateApiSimpleGetMediaDir();
+
+Future<ExtractResult> extractApkg({required String apkgPath}) =>
+    RustLib.instance.api.crateApiSimpleExtractApkg(apkgPath: apkgPath);
+
+/// 带进度的导入，进度和最终结果都通过 progress 推送；导入进行中用同一个 task_id 调用 cancel_import 可以中途取消，
+/// 取消后集合库保持不变，解包的临时目录也会删掉
+Stream<ImportProgress> extractApkgWithProgress({
   required String apkgPath,
-  required String baseDir,
-}) => RustLib.instance.api.crateApiSimpleExtractApkg(
+  required String taskId,
+}) => RustLib.instance.api.crateApiSimpleExtractApkgWithProgress(
   apkgPath: apkgPath,
-  baseDir: baseDir,
+  taskId: taskId,
 );
 
-Future<SingleNoteResult> getDeckNote({
-  required String sqlitePath,
-  required PlatformInt64 noteId,
-  required String version,
-}) => RustLib.instance.api.crateApiSimpleGetDeckNote(
-  sqlitePath: sqlitePath,
-  noteId: noteId,
-  version: version,
+/// 取消正在进行的导入；task_id 不存在或导入已经结束时什么也不做
+void cancelImport({required String taskId}) =>
+    RustLib.instance.api.crateApiSimpleCancelImport(taskId: taskId);
+
+/// 用新版本的 apkg 更新已导入的牌组：笔记按 guid 对应，更新有变化的笔记、添加新笔记，
+/// remove_missing 为 true 时删除新版本里已经没有的笔记；已有卡片的学习进度保持不变
+Future<MergeResult> mergeApkg({
+  required String apkgPath,
+  required String deckId,
+  required bool removeMissing,
+}) => RustLib.instance.api.crateApiSimpleMergeApkg(
+  apkgPath: apkgPath,
+  deckId: deckId,
+  removeMissing: removeMissing,
+);
+
+/// 导入整个集合的备份（.colpkg），按牌组拆成多个牌组导入集合库，每个牌组保留自己的卡片调度和复习记录
+Future<ColpkgImportResult> extractColpkg({required String colpkgPath}) =>
+    RustLib.instance.api.crateApiSimpleExtractColpkg(colpkgPath: colpkgPath);
+
+Future<SingleNoteResult> getDeckNote({required PlatformInt64 noteId}) =>
+    RustLib.instance.api.crateApiSimpleGetDeckNote(noteId: noteId);
+
+/// 获取笔记的全部卡片，按 ord 排序。填空题每个填空序号对应一张卡片，导入时已经补齐
+Future<List<CardExt>> getNoteCards({required PlatformInt64 noteId}) =>
+    RustLib.instance.api.crateApiSimpleGetNoteCards(noteId: noteId);
+
+/// 按填空题笔记字段里的填空序号补齐缺失的卡片，新卡片排在所在牌组的新卡最后，返回新卡片的 id。
+/// 导入时已经补齐，修改过笔记字段后调用
+Future<Int64List> generateClozeCards({required PlatformInt64 noteId}) =>
+    RustLib.instance.api.crateApiSimpleGenerateClozeCards(noteId: noteId);
+
+/// 在 Rust 侧按 Anki 模板语法渲染一张卡片的正反面
+Future<RenderedCard> renderCard({required PlatformInt64 cardId}) =>
+    RustLib.instance.api.crateApiSimpleRenderCard(cardId: cardId);
+
+/// 一次取回多张卡片的渲染结果，复习界面预取后面的卡片时用，笔记类型的模板和样式按 id 共用
+Future<CardsBatch> getCardsBatch({
+  required String deckId,
+  required Int64List cardIds,
+}) => RustLib.instance.api.crateApiSimpleGetCardsBatch(
+  deckId: deckId,
+  cardIds: cardIds,
 );
 
-Future<BigInt> getCardCount({required String sqlitePath}) =>
-    RustLib.instance.api.crateApiSimpleGetCardCount(sqlitePath: sqlitePath);
+/// 牌组里的笔记数，卡片全部暂停的笔记不算
+Future<int> getCardCount({required String deckId}) =>
+    RustLib.instance.api.crateApiSimpleGetCardCount(deckId: deckId);
 
-Future<BigInt> getCardCountFromDeck({
-  required String appDocDir,
-  required String md5,
-}) => RustLib.instance.api.crateApiSimpleGetCardCountFromDeck(
-  appDocDir: appDocDir,
-  md5: md5,
+/// 删除牌组的卡片、笔记、复习记录和调度配置
+Future<void> removeDeck({required String deckId}) =>
+    RustLib.instance.api.crateApiSimpleRemoveDeck(deckId: deckId);
+
+/// 复习界面按笔记评分：按牌组的调度配置（算法、参数、学习步骤）调度笔记的第一张卡片，结果写回集合并记录 revlog。
+/// rating: 0-重来, 1-困难, 2-良好, 3-简单。
+/// 不兼容的改动：以前的参数是 (stability, difficulty, last_review, rating, now)，rating 0-2 表示困难/一般/简单，
+/// 由调用方保存调度状态；现在状态保存在集合里，调用方只传 (deck_id, note_id, rating, now)，rating 的含义也和以前不同
+Future<FsrsScheduleResult> updateCardSchedule({
+  required String deckId,
+  required PlatformInt64 noteId,
+  required int rating,
+  required PlatformInt64 now,
+}) => RustLib.instance.api.crateApiSimpleUpdateCardSchedule(
+  deckId: deckId,
+  noteId: noteId,
+  rating: rating,
+  now: now,
 );
 
+/// 和 update_card_schedule 一样，但用 SM-2 调度，学习和重学步骤按牌组配置。rating 同样是 0-3
 Future<FsrsScheduleResult> updateCardScheduleSimple({
-  required double stability,
-  required double difficulty,
-  required PlatformInt64 lastReview,
+  required String deckId,
+  required PlatformInt64 noteId,
   required int rating,
   required PlatformInt64 now,
 }) => RustLib.instance.api.crateApiSimpleUpdateCardScheduleSimple(
-  stability: stability,
-  difficulty: difficulty,
-  lastReview: lastReview,
+  deckId: deckId,
+  noteId: noteId,
   rating: rating,
   now: now,
 );
 
-Future<FsrsScheduleResult> updateCardSchedule({
-  required double stability,
-  required double difficulty,
-  required PlatformInt64 lastReview,
+/// 预览复习界面某个按钮的评分结果，和 update_card_schedule 的调度一样，但不写入集合
+Future<FsrsScheduleResult> previewCardSchedule({
+  required String deckId,
+  required PlatformInt64 noteId,
   required int rating,
   required PlatformInt64 now,
-}) => RustLib.instance.api.crateApiSimpleUpdateCardSchedule(
-  stability: stability,
-  difficulty: difficulty,
-  lastReview: lastReview,
+}) => RustLib.instance.api.crateApiSimplePreviewCardSchedule(
+  deckId: deckId,
+  noteId: noteId,
   rating: rating,
   now: now,
 );
 
-Future<Int64List> getAllNoteIds({
-  required String sqlitePath,
-  required String version,
-}) => RustLib.instance.api.crateApiSimpleGetAllNoteIds(
-  sqlitePath: sqlitePath,
-  version: version,
+/// 预览 update_card_schedule_simple 的评分结果，不写入集合
+Future<FsrsScheduleResult> previewCardScheduleSimple({
+  required String deckId,
+  required PlatformInt64 noteId,
+  required int rating,
+  required PlatformInt64 now,
+}) => RustLib.instance.api.crateApiSimplePreviewCardScheduleSimple(
+  deckId: deckId,
+  noteId: noteId,
+  rating: rating,
+  now: now,
 );
 
-Future<Int64List> getNewNoteIds({
-  required String sqlitePath,
-  required BigInt limit,
-  required String version,
-}) => RustLib.instance.api.crateApiSimpleGetNewNoteIds(
-  sqlitePath: sqlitePath,
-  limit: limit,
-  version: version,
+/// 新卡的初始状态
+CardMemoryState newCardMemoryState({required PlatformInt64 now}) =>
+    RustLib.instance.api.crateApiSimpleNewCardMemoryState(now: now);
+
+/// 读取牌组的调度配置，没有设置过时使用导入集合里的牌组选项，都没有时返回默认配置
+Future<SchedulerConfig> getSchedulerConfig({required String deckId}) =>
+    RustLib.instance.api.crateApiSimpleGetSchedulerConfig(deckId: deckId);
+
+/// 保存牌组的调度配置，参数超出范围时返回错误
+Future<void> setSchedulerConfig({
+  required String deckId,
+  required SchedulerConfig config,
+}) => RustLib.instance.api.crateApiSimpleSetSchedulerConfig(
+  deckId: deckId,
+  config: config,
 );
 
+/// 集合的换天时刻（本地时间 0-23 点），每天的上限和今天学过的卡片按它划分学习日，默认凌晨 4 点
+Future<int> getRolloverHour() =>
+    RustLib.instance.api.crateApiSimpleGetRolloverHour();
+
+/// 设置集合的换天时刻，超出 0-23 时返回错误
+Future<void> setRolloverHour({required int hour}) =>
+    RustLib.instance.api.crateApiSimpleSetRolloverHour(hour: hour);
+
+/// 四按钮调度，按配置选择 FSRS 或 SM-2，rating: 0-重来, 1-困难, 2-良好, 3-简单
+Future<CardMemoryState> scheduleCard({
+  required CardMemoryState card,
+  required int rating,
+  required PlatformInt64 now,
+  required SchedulerConfig config,
+}) => RustLib.instance.api.crateApiSimpleScheduleCard(
+  card: card,
+  rating: rating,
+  now: now,
+  config: config,
+);
+
+/// 一次返回四个评分按钮的下次复习时间和记忆状态，复习界面用来显示按钮上的间隔
+Future<List<SchedulePreview>> previewSchedule({
+  required CardMemoryState card,
+  required PlatformInt64 now,
+  required SchedulerConfig config,
+}) => RustLib.instance.api.crateApiSimplePreviewSchedule(
+  card: card,
+  now: now,
+  config: config,
+);
+
+/// 读取卡片当前的记忆状态，导入时没有恢复过的卡片按 SM-2 参数估算
+Future<CardMemoryState> getCardMemoryState({
+  required String deckId,
+  required PlatformInt64 cardId,
+  required PlatformInt64 now,
+}) => RustLib.instance.api.crateApiSimpleGetCardMemoryState(
+  deckId: deckId,
+  cardId: cardId,
+  now: now,
+);
+
+/// 对牌组里的一张卡片评分：按牌组配置调度，把结果写回 cards 表并记录 revlog，返回新的记忆状态。
+/// 卡片不在牌组里时返回 NotFound，已暂停或搁置时返回 InvalidArgument。
+/// 评分结果、难记卡片的处理和兄弟卡片的搁置在同一个事务里提交，提交后才发送难记卡片事件
+Future<CardMemoryState> answerCard({
+  required String deckId,
+  required PlatformInt64 cardId,
+  required int rating,
+  required PlatformInt64 now,
+}) => RustLib.instance.api.crateApiSimpleAnswerCard(
+  deckId: deckId,
+  cardId: cardId,
+  rating: rating,
+  now: now,
+);
+
+/// 注册难记卡片事件的接收端，answer_card 里卡片成为难记卡片时发送
+Stream<LeechEvent> registerLeechCallback() =>
+    RustLib.instance.api.crateApiSimpleRegisterLeechCallback();
+
+/// 读取当天的学习队列，learn_ahead 按牌组配置的提前学习时限计算
+Future<IntradayLearningQueue> getIntradayLearningQueue({
+  required String deckId,
+  required PlatformInt64 now,
+}) => RustLib.instance.api.crateApiSimpleGetIntradayLearningQueue(
+  deckId: deckId,
+  now: now,
+);
+
+/// 按牌组配置的每天上限、收集顺序和排序方式构建今天的新卡队列
+Future<NewCardQueue> getNewCardQueue({
+  required String deckId,
+  required PlatformInt64 now,
+}) => RustLib.instance.api.crateApiSimpleGetNewCardQueue(
+  deckId: deckId,
+  now: now,
+);
+
+/// 构建现在的学习队列：已到期的学习卡在最前，之后是按牌组配置排序、穿插了新卡的复习卡，
+/// 最后是提前学习时限内的学习卡。复习卡和新卡扣掉今天已经学过的数量后按每日上限截断
+Future<ReviewQueue> buildReviewQueue({
+  required String deckId,
+  required PlatformInt64 now,
+  required QueueLimits limits,
+}) => RustLib.instance.api.crateApiSimpleBuildReviewQueue(
+  deckId: deckId,
+  now: now,
+  limits: limits,
+);
+
+/// 恢复牌组里所有搁置的卡片（回答后自动搁置的和手动搁置的），返回恢复的卡片数
+Future<int> unburyDeck({required String deckId}) =>
+    RustLib.instance.api.crateApiSimpleUnburyDeck(deckId: deckId);
+
+/// 读取牌组里卡片的暂停、搁置、旗标和标记状态，不存在或不在牌组里的卡片不返回
+Future<List<CardStatus>> getCardStatus({
+  required String deckId,
+  required Int64List cardIds,
+}) => RustLib.instance.api.crateApiSimpleGetCardStatus(
+  deckId: deckId,
+  cardIds: cardIds,
+);
+
+/// 牌组里暂停的卡片 id，包括导入的集合里已经暂停的
+Future<Int64List> getSuspendedCardIds({required String deckId}) =>
+    RustLib.instance.api.crateApiSimpleGetSuspendedCardIds(deckId: deckId);
+
+/// 暂停牌组里的卡片，暂停后不会出现在任何队列里，返回状态有变化的卡片数
+Future<int> suspendCards({required String deckId, required Int64List cardIds}) =>
+    RustLib.instance.api.crateApiSimpleSuspendCards(
+      deckId: deckId,
+      cardIds: cardIds,
+    );
+
+/// 取消暂停，卡片回到暂停前所在的队列，返回状态有变化的卡片数
+Future<int> unsuspendCards({required String deckId, required Int64List cardIds}) =>
+    RustLib.instance.api.crateApiSimpleUnsuspendCards(
+      deckId: deckId,
+      cardIds: cardIds,
+    );
+
+/// 手动搁置牌组里的卡片，换天后自动恢复，暂停的卡片不变，返回状态有变化的卡片数
+Future<int> buryCards({required String deckId, required Int64List cardIds}) =>
+    RustLib.instance.api.crateApiSimpleBuryCards(
+      deckId: deckId,
+      cardIds: cardIds,
+    );
+
+/// 恢复搁置的卡片，返回状态有变化的卡片数
+Future<int> unburyCards({required String deckId, required Int64List cardIds}) =>
+    RustLib.instance.api.crateApiSimpleUnburyCards(
+      deckId: deckId,
+      cardIds: cardIds,
+    );
+
+/// 设置牌组里卡片的旗标，flag: 0-清除, 1-7 对应 Anki 的七种颜色，返回旗标有变化的卡片数
+Future<int> setCardFlag({
+  required String deckId,
+  required Int64List cardIds,
+  required int flag,
+}) => RustLib.instance.api.crateApiSimpleSetCardFlag(
+  deckId: deckId,
+  cardIds: cardIds,
+  flag: flag,
+);
+
+/// 切换笔记的 marked 标签，返回切换后是否标记
+Future<bool> toggleNoteMarked({required PlatformInt64 noteId}) =>
+    RustLib.instance.api.crateApiSimpleToggleNoteMarked(noteId: noteId);
+
+/// 读取牌组的 revlog，跳过手动改期等没有评分的记录
+Future<List<ReviewLogEntry>> getReviewLogs({required String deckId}) =>
+    RustLib.instance.api.crateApiSimpleGetReviewLogs(deckId: deckId);
+
+/// 用复习记录训练 FSRS 参数，训练进度和最终结果都通过 progress 推送。
+/// 复习间隔按集合的换天时刻划分学习日，需要先打开集合；rating 超出 0-3 时返回错误
+Stream<OptimizeProgress> optimizeFsrsParameters({
+  required List<ReviewLogEntry> reviewLog,
+}) => RustLib.instance.api.crateApiSimpleOptimizeFsrsParameters(
+  reviewLog: reviewLog,
+);
+
+/// 牌组里的全部笔记 id，按 id 排序，卡片全部暂停的笔记不算
+Future<Int64List> getAllNoteIds({required String deckId}) =>
+    RustLib.instance.api.crateApiSimpleGetAllNoteIds(deckId: deckId);
+
+Future<Int64List> getNewNoteIds({required String deckId, required BigInt limit}) =>
+    RustLib.instance.api.crateApiSimpleGetNewNoteIds(
+      deckId: deckId,
+      limit: limit,
+    );
+
+/// 批量取卡时的一张卡片，样式和模板到 notetypes 里按 notetype_id 查
+class BatchCard {
+  final PlatformInt64 cardId;
+  final PlatformInt64 noteId;
+  final PlatformInt64 notetypeId;
+  final PlatformInt64 ord;
+  final PlatformInt64 templateOrd;
+  final String question;
+  final String answer;
+  final List<String> diagnostics;
+
+  const BatchCard({
+    required this.cardId,
+    required this.noteId,
+    required this.notetypeId,
+    required this.ord,
+    required this.templateOrd,
+    required this.question,
+    required this.answer,
+    required this.diagnostics,
+  });
+
+  @override
+  int get hashCode =>
+      cardId.hashCode ^
+      noteId.hashCode ^
+      notetypeId.hashCode ^
+      ord.hashCode ^
+      templateOrd.hashCode ^
+      question.hashCode ^
+      answer.hashCode ^
+      diagnostics.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BatchCard &&
+          runtimeType == other.runtimeType &&
+          cardId == other.cardId &&
+          noteId == other.noteId &&
+          notetypeId == other.notetypeId &&
+          ord == other.ord &&
+          templateOrd == other.templateOrd &&
+          question == other.question &&
+          answer == other.answer &&
+          diagnostics == other.diagnostics;
+}
+
+/// 批量取卡时同一笔记类型的卡片共用的数据，卡片里只带 notetype_id
+class BatchNotetype {
+  final PlatformInt64 id;
+  final String name;
+  final bool isCloze;
+  final String css;
+  final List<String> fieldNames;
+  final List<BatchTemplate> templates;
+
+  const BatchNotetype({
+    required this.id,
+    required this.name,
+    required this.isCloze,
+    required this.css,
+    required this.fieldNames,
+    required this.templates,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      isCloze.hashCode ^
+      css.hashCode ^
+      fieldNames.hashCode ^
+      templates.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BatchNotetype &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          isCloze == other.isCloze &&
+          css == other.css &&
+          fieldNames == other.fieldNames &&
+          templates == other.templates;
+}
+
+/// 批量取卡时笔记类型的模板
+class BatchTemplate {
+  final PlatformInt64 ord;
+  final String name;
+  final String qfmt;
+  final String afmt;
+
+  const BatchTemplate({
+    required this.ord,
+    required this.name,
+    required this.qfmt,
+    required this.afmt,
+  });
+
+  @override
+  int get hashCode =>
+      ord.hashCode ^ name.hashCode ^ qfmt.hashCode ^ afmt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BatchTemplate &&
+          runtimeType == other.runtimeType &&
+          ord == other.ord &&
+          name == other.name &&
+          qfmt == other.qfmt &&
+          afmt == other.afmt;
+}
+
+class CardExt {
+  final PlatformInt64 id;
+  final PlatformInt64 nid;
+  final PlatformInt64 ord;
+  final PlatformInt64 type;
+  final PlatformInt64 queue;
+  final PlatformInt64 due;
+
+  const CardExt({
+    required this.id,
+    required this.nid,
+    required this.ord,
+    required this.type,
+    required this.queue,
+    required this.due,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      nid.hashCode ^
+      ord.hashCode ^
+      type.hashCode ^
+      queue.hashCode ^
+      due.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CardExt &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          nid == other.nid &&
+          ord == other.ord &&
+          type == other.type &&
+          queue == other.queue &&
+          due == other.due;
+}
+
+/// 卡片完整的记忆状态，调度时原样传入、原样保存返回值
+class CardMemoryState {
+  final CardState state;
+  final PlatformInt64 due;
+  final double stability;
+  final double difficulty;
+  final PlatformInt64 elapsedDays;
+  final PlatformInt64 scheduledDays;
+  final int reps;
+  final int lapses;
+  final int step;
+  final PlatformInt64? lastReview;
+  final double easeFactor;
+
+  const CardMemoryState({
+    required this.state,
+    required this.due,
+    required this.stability,
+    required this.difficulty,
+    required this.elapsedDays,
+    required this.scheduledDays,
+    required this.reps,
+    required this.lapses,
+    required this.step,
+    this.lastReview,
+    required this.easeFactor,
+  });
+
+  @override
+  int get hashCode =>
+      state.hashCode ^
+      due.hashCode ^
+      stability.hashCode ^
+      difficulty.hashCode ^
+      elapsedDays.hashCode ^
+      scheduledDays.hashCode ^
+      reps.hashCode ^
+      lapses.hashCode ^
+      step.hashCode ^
+      lastReview.hashCode ^
+      easeFactor.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CardMemoryState &&
+          runtimeType == other.runtimeType &&
+          state == other.state &&
+          due == other.due &&
+          stability == other.stability &&
+          difficulty == other.difficulty &&
+          elapsedDays == other.elapsedDays &&
+          scheduledDays == other.scheduledDays &&
+          reps == other.reps &&
+          lapses == other.lapses &&
+          step == other.step &&
+          lastReview == other.lastReview &&
+          easeFactor == other.easeFactor;
+}
+
+/// 卡片所处阶段，和 Anki/FSRS 的 New/Learning/Review/Relearning 对应
+enum CardState { new_, learning, review, relearning }
+
+/// 卡片的暂停、搁置、旗标和标记状态
+class CardStatus {
+  final PlatformInt64 cardId;
+  final PlatformInt64 noteId;
+  final bool suspended;
+  final bool buried;
+  final int flag;
+  final bool marked;
+
+  const CardStatus({
+    required this.cardId,
+    required this.noteId,
+    required this.suspended,
+    required this.buried,
+    required this.flag,
+    required this.marked,
+  });
+
+  @override
+  int get hashCode =>
+      cardId.hashCode ^
+      noteId.hashCode ^
+      suspended.hashCode ^
+      buried.hashCode ^
+      flag.hashCode ^
+      marked.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CardStatus &&
+          runtimeType == other.runtimeType &&
+          cardId == other.cardId &&
+          noteId == other.noteId &&
+          suspended == other.suspended &&
+          buried == other.buried &&
+          flag == other.flag &&
+          marked == other.marked;
+}
+
+class CardsBatch {
+  final List<BatchCard> cards;
+  final List<BatchNotetype> notetypes;
+  final Int64List missingCardIds;
+
+  const CardsBatch({
+    required this.cards,
+    required this.notetypes,
+    required this.missingCardIds,
+  });
+
+  @override
+  int get hashCode =>
+      cards.hashCode ^ notetypes.hashCode ^ missingCardIds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CardsBatch &&
+          runtimeType == other.runtimeType &&
+          cards == other.cards &&
+          notetypes == other.notetypes &&
+          missingCardIds == other.missingCardIds;
+}
+
+/// .colpkg 拆分出的一个牌组
+class ColpkgDeck {
+  final String md5;
+  final PlatformInt64 deckId;
+  final String name;
+  final String? parentName;
+  final Map<String, String> mediaMap;
+  final int cardCount;
+  final int scheduledCardCount;
+
+  const ColpkgDeck({
+    required this.md5,
+    required this.deckId,
+    required this.name,
+    this.parentName,
+    required this.mediaMap,
+    required this.cardCount,
+    required this.scheduledCardCount,
+  });
+
+  @override
+  int get hashCode =>
+      md5.hashCode ^
+      deckId.hashCode ^
+      name.hashCode ^
+      parentName.hashCode ^
+      mediaMap.hashCode ^
+      cardCount.hashCode ^
+      scheduledCardCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ColpkgDeck &&
+          runtimeType == other.runtimeType &&
+          md5 == other.md5 &&
+          deckId == other.deckId &&
+          name == other.name &&
+          parentName == other.parentName &&
+          mediaMap == other.mediaMap &&
+          cardCount == other.cardCount &&
+          scheduledCardCount == other.scheduledCardCount;
+}
+
+class ColpkgImportResult {
+  final String md5;
+  final String version;
+  final List<ColpkgDeck> decks;
+
+  const ColpkgImportResult({
+    required this.md5,
+    required this.version,
+    required this.decks,
+  });
+
+  @override
+  int get hashCode => md5.hashCode ^ version.hashCode ^ decks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ColpkgImportResult &&
+          runtimeType == other.runtimeType &&
+          md5 == other.md5 &&
+          version == other.version &&
+          decks == other.decks;
+}
+
 class ExtractResult {
-  final String dir;
   final String md5;
   final Map<String, String> mediaMap;
   final String version;
+  final int scheduledCardCount;
+  final MergeSummary summary;
 
   const ExtractResult({
-    required this.dir,
     required this.md5,
     required this.mediaMap,
     required this.version,
+    required this.scheduledCardCount,
+    required this.summary,
   });
 
   @override
   int get hashCode =>
-      dir.hashCode ^ md5.hashCode ^ mediaMap.hashCode ^ version.hashCode;
+      md5.hashCode ^
+      mediaMap.hashCode ^
+      version.hashCode ^
+      scheduledCardCount.hashCode ^
+      summary.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
       other is ExtractResult &&
           runtimeType == other.runtimeType &&
-          dir == other.dir &&
           md5 == other.md5 &&
           mediaMap == other.mediaMap &&
-          version == other.version;
+          version == other.version &&
+          scheduledCardCount == other.scheduledCardCount &&
+          summary == other.summary;
 }
 
 class FieldExt {
@@ -124,17 +738,32 @@ class FieldExt {
   final PlatformInt64 notetypeId;
   final String name;
   final PlatformInt64 ord;
+  final String fontName;
+  final PlatformInt64 fontSize;
+  final bool rtl;
+  final bool sticky;
 
   const FieldExt({
     required this.id,
     required this.notetypeId,
     required this.name,
     required this.ord,
+    required this.fontName,
+    required this.fontSize,
+    required this.rtl,
+    required this.sticky,
   });
 
   @override
   int get hashCode =>
-      id.hashCode ^ notetypeId.hashCode ^ name.hashCode ^ ord.hashCode;
+      id.hashCode ^
+      notetypeId.hashCode ^
+      name.hashCode ^
+      ord.hashCode ^
+      fontName.hashCode ^
+      fontSize.hashCode ^
+      rtl.hashCode ^
+      sticky.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -144,9 +773,14 @@ class FieldExt {
           id == other.id &&
           notetypeId == other.notetypeId &&
           name == other.name &&
-          ord == other.ord;
+          ord == other.ord &&
+          fontName == other.fontName &&
+          fontSize == other.fontSize &&
+          rtl == other.rtl &&
+          sticky == other.sticky;
 }
 
+/// 复习界面用的调度结果
 class FsrsScheduleResult {
   final PlatformInt64 due;
   final double stability;
@@ -171,6 +805,263 @@ class FsrsScheduleResult {
           difficulty == other.difficulty;
 }
 
+/// 导入阶段
+enum ImportPhase {
+  extracting,
+  hashing,
+  finalizing,
+  scheduling,
+  done,
+  cancelled,
+}
+
+/// 导入进度，最后一条消息（phase 为 Done）带上导入结果
+class ImportProgress {
+  final ImportPhase phase;
+  final PlatformInt64 bytesProcessed;
+  final PlatformInt64 totalBytes;
+  final int entriesDone;
+  final int entriesTotal;
+  final String? currentFile;
+  final ExtractResult? result;
+
+  const ImportProgress({
+    required this.phase,
+    required this.bytesProcessed,
+    required this.totalBytes,
+    required this.entriesDone,
+    required this.entriesTotal,
+    this.currentFile,
+    this.result,
+  });
+
+  @override
+  int get hashCode =>
+      phase.hashCode ^
+      bytesProcessed.hashCode ^
+      totalBytes.hashCode ^
+      entriesDone.hashCode ^
+      entriesTotal.hashCode ^
+      currentFile.hashCode ^
+      result.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportProgress &&
+          runtimeType == other.runtimeType &&
+          phase == other.phase &&
+          bytesProcessed == other.bytesProcessed &&
+          totalBytes == other.totalBytes &&
+          entriesDone == other.entriesDone &&
+          entriesTotal == other.entriesTotal &&
+          currentFile == other.currentFile &&
+          result == other.result;
+}
+
+/// 当天的学习队列（学习/重学步骤中、按秒调度的卡片）
+class IntradayLearningQueue {
+  final Int64List due;
+  final Int64List learnAhead;
+  final PlatformInt64? nextDue;
+
+  const IntradayLearningQueue({
+    required this.due,
+    required this.learnAhead,
+    this.nextDue,
+  });
+
+  @override
+  int get hashCode => due.hashCode ^ learnAhead.hashCode ^ nextDue.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IntradayLearningQueue &&
+          runtimeType == other.runtimeType &&
+          due == other.due &&
+          learnAhead == other.learnAhead &&
+          nextDue == other.nextDue;
+}
+
+/// 卡片成为难记卡片后的处理方式
+enum LeechAction { suspend, tagOnly }
+
+/// 卡片成为难记卡片时发给 Flutter 的事件
+class LeechEvent {
+  final String deckId;
+  final PlatformInt64 cardId;
+  final PlatformInt64 noteId;
+  final int lapses;
+  final bool suspended;
+
+  const LeechEvent({
+    required this.deckId,
+    required this.cardId,
+    required this.noteId,
+    required this.lapses,
+    required this.suspended,
+  });
+
+  @override
+  int get hashCode =>
+      deckId.hashCode ^
+      cardId.hashCode ^
+      noteId.hashCode ^
+      lapses.hashCode ^
+      suspended.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LeechEvent &&
+          runtimeType == other.runtimeType &&
+          deckId == other.deckId &&
+          cardId == other.cardId &&
+          noteId == other.noteId &&
+          lapses == other.lapses &&
+          suspended == other.suspended;
+}
+
+class MergeResult {
+  final String md5;
+  final String version;
+  final Map<String, String> mediaMap;
+  final MergeSummary summary;
+
+  const MergeResult({
+    required this.md5,
+    required this.version,
+    required this.mediaMap,
+    required this.summary,
+  });
+
+  @override
+  int get hashCode =>
+      md5.hashCode ^ version.hashCode ^ mediaMap.hashCode ^ summary.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MergeResult &&
+          runtimeType == other.runtimeType &&
+          md5 == other.md5 &&
+          version == other.version &&
+          mediaMap == other.mediaMap &&
+          summary == other.summary;
+}
+
+/// 合并导入的差异统计
+class MergeSummary {
+  final int addedNotes;
+  final int updatedNotes;
+  final int unchangedNotes;
+  final int removedNotes;
+  final int skippedNotes;
+  final int addedNotetypes;
+  final int updatedNotetypes;
+  final Int64List addedNoteIds;
+  final Int64List removedNoteIds;
+  final Int64List addedCardIds;
+  final Int64List removedCardIds;
+
+  const MergeSummary({
+    required this.addedNotes,
+    required this.updatedNotes,
+    required this.unchangedNotes,
+    required this.removedNotes,
+    required this.skippedNotes,
+    required this.addedNotetypes,
+    required this.updatedNotetypes,
+    required this.addedNoteIds,
+    required this.removedNoteIds,
+    required this.addedCardIds,
+    required this.removedCardIds,
+  });
+
+  static Future<MergeSummary> default_() =>
+      RustLib.instance.api.crateApiSimpleMergeSummaryDefault();
+
+  @override
+  int get hashCode =>
+      addedNotes.hashCode ^
+      updatedNotes.hashCode ^
+      unchangedNotes.hashCode ^
+      removedNotes.hashCode ^
+      skippedNotes.hashCode ^
+      addedNotetypes.hashCode ^
+      updatedNotetypes.hashCode ^
+      addedNoteIds.hashCode ^
+      removedNoteIds.hashCode ^
+      addedCardIds.hashCode ^
+      removedCardIds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MergeSummary &&
+          runtimeType == other.runtimeType &&
+          addedNotes == other.addedNotes &&
+          updatedNotes == other.updatedNotes &&
+          unchangedNotes == other.unchangedNotes &&
+          removedNotes == other.removedNotes &&
+          skippedNotes == other.skippedNotes &&
+          addedNotetypes == other.addedNotetypes &&
+          updatedNotetypes == other.updatedNotetypes &&
+          addedNoteIds == other.addedNoteIds &&
+          removedNoteIds == other.removedNoteIds &&
+          addedCardIds == other.addedCardIds &&
+          removedCardIds == other.removedCardIds;
+}
+
+/// 新卡片的收集顺序，决定每天的新卡上限从哪些卡片里取
+enum NewCardGatherPriority {
+  deck,
+  deckThenRandomNotes,
+  lowestPosition,
+  highestPosition,
+  randomNotes,
+  randomCards,
+}
+
+/// 今天的新卡队列
+class NewCardQueue {
+  final List<QueuedCard> cards;
+  final int introducedToday;
+  final int available;
+
+  const NewCardQueue({
+    required this.cards,
+    required this.introducedToday,
+    required this.available,
+  });
+
+  @override
+  int get hashCode =>
+      cards.hashCode ^ introducedToday.hashCode ^ available.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NewCardQueue &&
+          runtimeType == other.runtimeType &&
+          cards == other.cards &&
+          introducedToday == other.introducedToday &&
+          available == other.available;
+}
+
+/// 收集到的新卡片在队列里的排列方式
+enum NewCardSortOrder {
+  template,
+  noSort,
+  templateThenRandom,
+  randomNoteThenTemplate,
+  randomCard,
+}
+
+/// 新卡和复习卡的穿插方式
+enum NewReviewMix { mixWithReviews, reviewsFirst, newFirst }
+
 class NoteExt {
   final PlatformInt64 id;
   final String guid;
@@ -230,6 +1121,402 @@ class NotetypeExt {
           config == other.config;
 }
 
+/// 优化进度，最后一条消息带上优化结果
+class OptimizeProgress {
+  final int current;
+  final int total;
+  final OptimizeResult? result;
+
+  const OptimizeProgress({
+    required this.current,
+    required this.total,
+    this.result,
+  });
+
+  @override
+  int get hashCode => current.hashCode ^ total.hashCode ^ result.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OptimizeProgress &&
+          runtimeType == other.runtimeType &&
+          current == other.current &&
+          total == other.total &&
+          result == other.result;
+}
+
+class OptimizeResult {
+  final Float64List weights;
+  final double logLossBefore;
+  final double logLossAfter;
+  final double rmseBefore;
+  final double rmseAfter;
+  final int reviewCount;
+
+  const OptimizeResult({
+    required this.weights,
+    required this.logLossBefore,
+    required this.logLossAfter,
+    required this.rmseBefore,
+    required this.rmseAfter,
+    required this.reviewCount,
+  });
+
+  @override
+  int get hashCode =>
+      weights.hashCode ^
+      logLossBefore.hashCode ^
+      logLossAfter.hashCode ^
+      rmseBefore.hashCode ^
+      rmseAfter.hashCode ^
+      reviewCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OptimizeResult &&
+          runtimeType == other.runtimeType &&
+          weights == other.weights &&
+          logLossBefore == other.logLossBefore &&
+          logLossAfter == other.logLossAfter &&
+          rmseBefore == other.rmseBefore &&
+          rmseAfter == other.rmseAfter &&
+          reviewCount == other.reviewCount;
+}
+
+/// 卡片在队列里的类别
+enum QueueKind { new_, learning, review }
+
+/// 构建复习队列时的每日上限，为空时使用牌组配置里的上限
+class QueueLimits {
+  final int? newCards;
+  final int? reviews;
+
+  const QueueLimits({this.newCards, this.reviews});
+
+  static Future<QueueLimits> default_() =>
+      RustLib.instance.api.crateApiSimpleQueueLimitsDefault();
+
+  @override
+  int get hashCode => newCards.hashCode ^ reviews.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QueueLimits &&
+          runtimeType == other.runtimeType &&
+          newCards == other.newCards &&
+          reviews == other.reviews;
+}
+
+/// 队列里的一张卡片
+class QueuedCard {
+  final PlatformInt64 cardId;
+  final PlatformInt64 noteId;
+  final QueueKind kind;
+
+  const QueuedCard({
+    required this.cardId,
+    required this.noteId,
+    required this.kind,
+  });
+
+  @override
+  int get hashCode => cardId.hashCode ^ noteId.hashCode ^ kind.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QueuedCard &&
+          runtimeType == other.runtimeType &&
+          cardId == other.cardId &&
+          noteId == other.noteId &&
+          kind == other.kind;
+}
+
+class RenderedCard {
+  final PlatformInt64 cardId;
+  final PlatformInt64 noteId;
+  final PlatformInt64 ord;
+  final String question;
+  final String answer;
+  final String css;
+  final List<String> diagnostics;
+
+  const RenderedCard({
+    required this.cardId,
+    required this.noteId,
+    required this.ord,
+    required this.question,
+    required this.answer,
+    required this.css,
+    required this.diagnostics,
+  });
+
+  @override
+  int get hashCode =>
+      cardId.hashCode ^
+      noteId.hashCode ^
+      ord.hashCode ^
+      question.hashCode ^
+      answer.hashCode ^
+      css.hashCode ^
+      diagnostics.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RenderedCard &&
+          runtimeType == other.runtimeType &&
+          cardId == other.cardId &&
+          noteId == other.noteId &&
+          ord == other.ord &&
+          question == other.question &&
+          answer == other.answer &&
+          css == other.css &&
+          diagnostics == other.diagnostics;
+}
+
+/// 一条复习记录，可以来自导入的 Anki revlog，也可以是应用内记录的复习
+class ReviewLogEntry {
+  final PlatformInt64 cardId;
+  final PlatformInt64 reviewTime;
+  final int rating;
+
+  const ReviewLogEntry({
+    required this.cardId,
+    required this.reviewTime,
+    required this.rating,
+  });
+
+  @override
+  int get hashCode => cardId.hashCode ^ reviewTime.hashCode ^ rating.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReviewLogEntry &&
+          runtimeType == other.runtimeType &&
+          cardId == other.cardId &&
+          reviewTime == other.reviewTime &&
+          rating == other.rating;
+}
+
+/// 现在要学的卡片，按显示顺序排列
+class ReviewQueue {
+  final List<QueuedCard> cards;
+  final int newCount;
+  final int learningCount;
+  final int reviewCount;
+  final PlatformInt64? nextLearningDue;
+
+  const ReviewQueue({
+    required this.cards,
+    required this.newCount,
+    required this.learningCount,
+    required this.reviewCount,
+    this.nextLearningDue,
+  });
+
+  @override
+  int get hashCode =>
+      cards.hashCode ^
+      newCount.hashCode ^
+      learningCount.hashCode ^
+      reviewCount.hashCode ^
+      nextLearningDue.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReviewQueue &&
+          runtimeType == other.runtimeType &&
+          cards == other.cards &&
+          newCount == other.newCount &&
+          learningCount == other.learningCount &&
+          reviewCount == other.reviewCount &&
+          nextLearningDue == other.nextLearningDue;
+}
+
+/// 到期复习卡的排列方式
+enum ReviewSortOrder {
+  due,
+  retrievabilityAscending,
+  random,
+  relativeOverdueness,
+}
+
+/// 某个评分按钮对应的调度结果
+class SchedulePreview {
+  final int rating;
+  final PlatformInt64 due;
+  final PlatformInt64 intervalSeconds;
+  final String intervalText;
+  final CardMemoryState card;
+
+  const SchedulePreview({
+    required this.rating,
+    required this.due,
+    required this.intervalSeconds,
+    required this.intervalText,
+    required this.card,
+  });
+
+  @override
+  int get hashCode =>
+      rating.hashCode ^
+      due.hashCode ^
+      intervalSeconds.hashCode ^
+      intervalText.hashCode ^
+      card.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SchedulePreview &&
+          runtimeType == other.runtimeType &&
+          rating == other.rating &&
+          due == other.due &&
+          intervalSeconds == other.intervalSeconds &&
+          intervalText == other.intervalText &&
+          card == other.card;
+}
+
+/// 调度算法
+enum SchedulerAlgorithm { fsrs, sm2 }
+
+/// 牌组的调度配置，SM-2 相关的选项和 Anki 牌组选项里的同名设置一致
+class SchedulerConfig {
+  final SchedulerAlgorithm algorithm;
+  final Float64List weights;
+  final double requestRetention;
+  final int maximumInterval;
+  final bool enableFuzz;
+  final bool enableShortTerm;
+  final Float64List learnSteps;
+  final Float64List relearnSteps;
+  final int graduatingIntervalGood;
+  final int graduatingIntervalEasy;
+  final double initialEase;
+  final double easyMultiplier;
+  final double hardMultiplier;
+  final double lapseMultiplier;
+  final double intervalMultiplier;
+  final int minimumLapseInterval;
+  final int learnAheadSecs;
+  final int newPerDay;
+  final NewCardGatherPriority newCardGatherPriority;
+  final NewCardSortOrder newCardSortOrder;
+  final int reviewsPerDay;
+  final ReviewSortOrder reviewSortOrder;
+  final NewReviewMix newMix;
+  final bool buryNew;
+  final bool buryReviews;
+  final bool buryInterdayLearning;
+  final int leechThreshold;
+  final LeechAction leechAction;
+
+  const SchedulerConfig({
+    required this.algorithm,
+    required this.weights,
+    required this.requestRetention,
+    required this.maximumInterval,
+    required this.enableFuzz,
+    required this.enableShortTerm,
+    required this.learnSteps,
+    required this.relearnSteps,
+    required this.graduatingIntervalGood,
+    required this.graduatingIntervalEasy,
+    required this.initialEase,
+    required this.easyMultiplier,
+    required this.hardMultiplier,
+    required this.lapseMultiplier,
+    required this.intervalMultiplier,
+    required this.minimumLapseInterval,
+    required this.learnAheadSecs,
+    required this.newPerDay,
+    required this.newCardGatherPriority,
+    required this.newCardSortOrder,
+    required this.reviewsPerDay,
+    required this.reviewSortOrder,
+    required this.newMix,
+    required this.buryNew,
+    required this.buryReviews,
+    required this.buryInterdayLearning,
+    required this.leechThreshold,
+    required this.leechAction,
+  });
+
+  static Future<SchedulerConfig> default_() =>
+      RustLib.instance.api.crateApiSimpleSchedulerConfigDefault();
+
+  @override
+  int get hashCode =>
+      algorithm.hashCode ^
+      weights.hashCode ^
+      requestRetention.hashCode ^
+      maximumInterval.hashCode ^
+      enableFuzz.hashCode ^
+      enableShortTerm.hashCode ^
+      learnSteps.hashCode ^
+      relearnSteps.hashCode ^
+      graduatingIntervalGood.hashCode ^
+      graduatingIntervalEasy.hashCode ^
+      initialEase.hashCode ^
+      easyMultiplier.hashCode ^
+      hardMultiplier.hashCode ^
+      lapseMultiplier.hashCode ^
+      intervalMultiplier.hashCode ^
+      minimumLapseInterval.hashCode ^
+      learnAheadSecs.hashCode ^
+      newPerDay.hashCode ^
+      newCardGatherPriority.hashCode ^
+      newCardSortOrder.hashCode ^
+      reviewsPerDay.hashCode ^
+      reviewSortOrder.hashCode ^
+      newMix.hashCode ^
+      buryNew.hashCode ^
+      buryReviews.hashCode ^
+      buryInterdayLearning.hashCode ^
+      leechThreshold.hashCode ^
+      leechAction.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SchedulerConfig &&
+          runtimeType == other.runtimeType &&
+          algorithm == other.algorithm &&
+          weights == other.weights &&
+          requestRetention == other.requestRetention &&
+          maximumInterval == other.maximumInterval &&
+          enableFuzz == other.enableFuzz &&
+          enableShortTerm == other.enableShortTerm &&
+          learnSteps == other.learnSteps &&
+          relearnSteps == other.relearnSteps &&
+          graduatingIntervalGood == other.graduatingIntervalGood &&
+          graduatingIntervalEasy == other.graduatingIntervalEasy &&
+          initialEase == other.initialEase &&
+          easyMultiplier == other.easyMultiplier &&
+          hardMultiplier == other.hardMultiplier &&
+          lapseMultiplier == other.lapseMultiplier &&
+          intervalMultiplier == other.intervalMultiplier &&
+          minimumLapseInterval == other.minimumLapseInterval &&
+          learnAheadSecs == other.learnAheadSecs &&
+          newPerDay == other.newPerDay &&
+          newCardGatherPriority == other.newCardGatherPriority &&
+          newCardSortOrder == other.newCardSortOrder &&
+          reviewsPerDay == other.reviewsPerDay &&
+          reviewSortOrder == other.reviewSortOrder &&
+          newMix == other.newMix &&
+          buryNew == other.buryNew &&
+          buryReviews == other.buryReviews &&
+          buryInterdayLearning == other.buryInterdayLearning &&
+          leechThreshold == other.leechThreshold &&
+          leechAction == other.leechAction;
+}
+
 class SingleNoteResult {
   final NoteExt note;
   final NotetypeExt? notetype;
@@ -238,6 +1525,9 @@ class SingleNoteResult {
   final String front;
   final String back;
   final String css;
+  final String latexPre;
+  final String latexPost;
+  final PlatformInt64 sortFieldIdx;
 
   const SingleNoteResult({
     required this.note,
@@ -247,6 +1537,9 @@ class SingleNoteResult {
     required this.front,
     required this.back,
     required this.css,
+    required this.latexPre,
+    required this.latexPost,
+    required this.sortFieldIdx,
   });
 
   @override
@@ -257,7 +1550,10 @@ class SingleNoteResult {
       ord.hashCode ^
       front.hashCode ^
       back.hashCode ^
-      css.hashCode;
+      css.hashCode ^
+      latexPre.hashCode ^
+      latexPost.hashCode ^
+      sortFieldIdx.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -270,5 +1566,8 @@ class SingleNoteResult {
           ord == other.ord &&
           front == other.front &&
           back == other.back &&
-          css == other.css;
+          css == other.css &&
+          latexPre == other.latexPre &&
+          latexPost == other.latexPost &&
+          sortFieldIdx == other.sortFieldIdx;
 }
@@ -3,6 +3,7 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/error.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -66,7 +67,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1250749672;
+  int get rustContentHash => -847349441;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -77,53 +78,207 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
-  Future<ExtractResult> crateApiSimpleExtractApkg({
+  Future<CardMemoryState> crateApiSimpleAnswerCard({
+    required String deckId,
+    required PlatformInt64 cardId,
+    required int rating,
+    required PlatformInt64 now,
+  });
+
+  Future<ReviewQueue> crateApiSimpleBuildReviewQueue({
+    required String deckId,
+    required PlatformInt64 now,
+    required QueueLimits limits,
+  });
+
+  Future<int> crateApiSimpleBuryCards({
+    required String deckId,
+    required Int64List cardIds,
+  });
+
+  void crateApiSimpleCancelImport({required String taskId});
+
+  Future<void> crateApiSimpleCloseCollection();
+
+  Future<ExtractResult> crateApiSimpleExtractApkg({required String apkgPath});
+
+  Stream<ImportProgress> crateApiSimpleExtractApkgWithProgress({
     required String apkgPath,
-    required String baseDir,
+    required String taskId,
+  });
+
+  Future<ColpkgImportResult> crateApiSimpleExtractColpkg({
+    required String colpkgPath,
+  });
+
+  Future<Int64List> crateApiSimpleGenerateClozeCards({
+    required PlatformInt64 noteId,
   });
 
-  Future<Int64List> crateApiSimpleGetAllNoteIds({
-    required String sqlitePath,
-    required String version,
+  Future<Int64List> crateApiSimpleGetAllNoteIds({required String deckId});
+
+  Future<int> crateApiSimpleGetCardCount({required String deckId});
+
+  Future<CardMemoryState> crateApiSimpleGetCardMemoryState({
+    required String deckId,
+    required PlatformInt64 cardId,
+    required PlatformInt64 now,
   });
 
-  Future<BigInt> crateApiSimpleGetCardCount({required String sqlitePath});
+  Future<List<CardStatus>> crateApiSimpleGetCardStatus({
+    required String deckId,
+    required Int64List cardIds,
+  });
 
-  Future<BigInt> crateApiSimpleGetCardCountFromDeck({
-    required String appDocDir,
-    required String md5,
+  Future<CardsBatch> crateApiSimpleGetCardsBatch({
+    required String deckId,
+    required Int64List cardIds,
   });
 
   Future<SingleNoteResult> crateApiSimpleGetDeckNote({
-    required String sqlitePath,
     required PlatformInt64 noteId,
-    required String version,
+  });
+
+  Future<IntradayLearningQueue> crateApiSimpleGetIntradayLearningQueue({
+    required String deckId,
+    required PlatformInt64 now,
+  });
+
+  Future<String> crateApiSimpleGetMediaDir();
+
+  Future<NewCardQueue> crateApiSimpleGetNewCardQueue({
+    required String deckId,
+    required PlatformInt64 now,
   });
 
   Future<Int64List> crateApiSimpleGetNewNoteIds({
-    required String sqlitePath,
+    required String deckId,
     required BigInt limit,
-    required String version,
   });
 
+  Future<List<CardExt>> crateApiSimpleGetNoteCards({
+    required PlatformInt64 noteId,
+  });
+
+  Future<List<ReviewLogEntry>> crateApiSimpleGetReviewLogs({
+    required String deckId,
+  });
+
+  Future<int> crateApiSimpleGetRolloverHour();
+
+  Future<SchedulerConfig> crateApiSimpleGetSchedulerConfig({
+    required String deckId,
+  });
+
+  Future<Int64List> crateApiSimpleGetSuspendedCardIds({required String deckId});
+
   String crateApiSimpleGreet({required String name});
 
   Future<void> crateApiSimpleInitApp();
 
+  Future<int> crateApiSimpleInitCollection({required String baseDir});
+
+  Future<MergeResult> crateApiSimpleMergeApkg({
+    required String apkgPath,
+    required String deckId,
+    required bool removeMissing,
+  });
+
+  Future<MergeSummary> crateApiSimpleMergeSummaryDefault();
+
+  CardMemoryState crateApiSimpleNewCardMemoryState({
+    required PlatformInt64 now,
+  });
+
+  Future<void> crateApiSimpleOpenCollection();
+
+  Stream<OptimizeProgress> crateApiSimpleOptimizeFsrsParameters({
+    required List<ReviewLogEntry> reviewLog,
+  });
+
+  Future<FsrsScheduleResult> crateApiSimplePreviewCardSchedule({
+    required String deckId,
+    required PlatformInt64 noteId,
+    required int rating,
+    required PlatformInt64 now,
+  });
+
+  Future<FsrsScheduleResult> crateApiSimplePreviewCardScheduleSimple({
+    required String deckId,
+    required PlatformInt64 noteId,
+    required int rating,
+    required PlatformInt64 now,
+  });
+
+  Future<List<SchedulePreview>> crateApiSimplePreviewSchedule({
+    required CardMemoryState card,
+    required PlatformInt64 now,
+    required SchedulerConfig config,
+  });
+
+  Future<QueueLimits> crateApiSimpleQueueLimitsDefault();
+
+  Stream<LeechEvent> crateApiSimpleRegisterLeechCallback();
+
   Stream<String> crateApiSimpleRegisterLogCallback();
 
+  Future<void> crateApiSimpleRemoveDeck({required String deckId});
+
+  Future<RenderedCard> crateApiSimpleRenderCard({
+    required PlatformInt64 cardId,
+  });
+
+  Future<CardMemoryState> crateApiSimpleScheduleCard({
+    required CardMemoryState card,
+    required int rating,
+    required PlatformInt64 now,
+    required SchedulerConfig config,
+  });
+
+  Future<SchedulerConfig> crateApiSimpleSchedulerConfigDefault();
+
+  Future<int> crateApiSimpleSetCardFlag({
+    required String deckId,
+    required Int64List cardIds,
+    required int flag,
+  });
+
+  Future<void> crateApiSimpleSetRolloverHour({required int hour});
+
+  Future<void> crateApiSimpleSetSchedulerConfig({
+    required String deckId,
+    required SchedulerConfig config,
+  });
+
+  Future<int> crateApiSimpleSuspendCards({
+    required String deckId,
+    required Int64List cardIds,
+  });
+
+  Future<bool> crateApiSimpleToggleNoteMarked({required PlatformInt64 noteId});
+
+  Future<int> crateApiSimpleUnburyCards({
+    required String deckId,
+    required Int64List cardIds,
+  });
+
+  Future<int> crateApiSimpleUnburyDeck({required String deckId});
+
+  Future<int> crateApiSimpleUnsuspendCards({
+    required String deckId,
+    required Int64List cardIds,
+  });
+
   Future<FsrsScheduleResult> crateApiSimpleUpdateCardSchedule({
-    required double stability,
-    required double difficulty,
-    required PlatformInt64 lastReview,
+    required String deckId,
+    required PlatformInt64 noteId,
     required int rating,
     required PlatformInt64 now,
   });
 
   Future<FsrsScheduleResult> crateApiSimpleUpdateCardScheduleSimple({
-    required double stability,
-    required double difficulty,
-    required PlatformInt64 lastReview,
+    required String deckId,
+    required PlatformInt64 noteId,
     required int rating,
     required PlatformInt64 now,
   });
@@ -138,16 +293,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<ExtractResult> crateApiSimpleExtractApkg({
-    required String apkgPath,
-    required String baseDir,
+  Future<CardMemoryState> crateApiSimpleAnswerCard({
+    required String deckId,
+    required PlatformInt64 cardId,
+    required int rating,
+    required PlatformInt64 now,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(apkgPath, serializer);
-          sse_encode_String(baseDir, serializer);
+          sse_encode_String(deckId, serializer);
+          sse_encode_i_64(cardId, serializer);
+          sse_encode_u_8(rating, serializer);
+          sse_encode_i_64(now, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -156,32 +315,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_extract_result,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_card_memory_state,
+          decodeErrorData: sse_decode_anki_error,
         ),
-        constMeta: kCrateApiSimpleExtractApkgConstMeta,
-        argValues: [apkgPath, baseDir],
+        constMeta: kCrateApiSimpleAnswerCardConstMeta,
+        argValues: [deckId, cardId, rating, now],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleExtractApkgConstMeta => const TaskConstMeta(
-    debugName: "extract_apkg",
-    argNames: ["apkgPath", "baseDir"],
+  TaskConstMeta get kCrateApiSimpleAnswerCardConstMeta => const TaskConstMeta(
+    debugName: "answer_card",
+    argNames: ["deckId", "cardId", "rating", "now"],
   );
 
   @override
-  Future<Int64List> crateApiSimpleGetAllNoteIds({
-    required String sqlitePath,
-    required String version,
+  Future<ReviewQueue> crateApiSimpleBuildReviewQueue({
+    required String deckId,
+    required PlatformInt64 now,
+    required QueueLimits limits,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sqlitePath, serializer);
-          sse_encode_String(version, serializer);
+          sse_encode_String(deckId, serializer);
+          sse_encode_i_64(now, serializer);
+          sse_encode_box_autoadd_queue_limits(limits, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -190,29 +351,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_i_64_strict,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_review_queue,
+          decodeErrorData: sse_decode_anki_error,
         ),
-        constMeta: kCrateApiSimpleGetAllNoteIdsConstMeta,
-        argValues: [sqlitePath, version],
+        constMeta: kCrateApiSimpleBuildReviewQueueConstMeta,
+        argValues: [deckId, now, limits],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGetAllNoteIdsConstMeta =>
+  TaskConstMeta get kCrateApiSimpleBuildReviewQueueConstMeta =>
       const TaskConstMeta(
-        debugName: "get_all_note_ids",
-        argNames: ["sqlitePath", "version"],
+        debugName: "build_review_queue",
+        argNames: ["deckId", "now", "limits"],
       );
 
   @override
-  Future<BigInt> crateApiSimpleGetCardCount({required String sqlitePath}) {
+  Future<int> crateApiSimpleBuryCards({
+    required String deckId,
+    required Int64List cardIds,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sqlitePath, serializer);
+          sse_encode_String(deckId, serializer);
+          sse_encode_list_prim_i_64_strict(cardIds, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -221,69 +386,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_usize,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_anki_error,
         ),
-        constMeta: kCrateApiSimpleGetCardCountConstMeta,
-        argValues: [sqlitePath],
+        constMeta: kCrateApiSimpleBuryCardsConstMeta,
+        argValues: [deckId, cardIds],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGetCardCountConstMeta => const TaskConstMeta(
-    debugName: "get_card_count",
-    argNames: ["sqlitePath"],
+  TaskConstMeta get kCrateApiSimpleBuryCardsConstMeta => const TaskConstMeta(
+    debugName: "bury_cards",
+    argNames: ["deckId", "cardIds"],
   );
 
   @override
-  Future<BigInt> crateApiSimpleGetCardCountFromDeck({
-    required String appDocDir,
-    required String md5,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  void crateApiSimpleCancelImport({required String taskId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(appDocDir, serializer);
-          sse_encode_String(md5, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
+          sse_encode_String(taskId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_usize,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleGetCardCountFromDeckConstMeta,
-        argValues: [appDocDir, md5],
+        constMeta: kCrateApiSimpleCancelImportConstMeta,
+        argValues: [taskId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGetCardCountFromDeckConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_card_count_from_deck",
-        argNames: ["appDocDir", "md5"],
-      );
+  TaskConstMeta get kCrateApiSimpleCancelImportConstMeta =>
+      const TaskConstMeta(debugName: "cancel_import", argNames: ["taskId"]);
 
   @override
-  Future<SingleNoteResult> crateApiSimpleGetDeckNote({
-    required String sqlitePath,
-    required PlatformInt64 noteId,
-    required String version,
-  }) {
+  Future<void> crateApiSimpleCloseCollection() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sqlitePath, serializer);
-          sse_encode_i_64(noteId, serializer);
-          sse_encode_String(version, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -292,34 +438,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_single_note_result,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleGetDeckNoteConstMeta,
-        argValues: [sqlitePath, noteId, version],
+        constMeta: kCrateApiSimpleCloseCollectionConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGetDeckNoteConstMeta => const TaskConstMeta(
-    debugName: "get_deck_note",
-    argNames: ["sqlitePath", "noteId", "version"],
-  );
+  TaskConstMeta get kCrateApiSimpleCloseCollectionConstMeta =>
+      const TaskConstMeta(debugName: "close_collection", argNames: []);
 
   @override
-  Future<Int64List> crateApiSimpleGetNewNoteIds({
-    required String sqlitePath,
-    required BigInt limit,
-    required String version,
-  }) {
+  Future<ExtractResult> crateApiSimpleExtractApkg({required String apkgPath}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sqlitePath, serializer);
-          sse_encode_usize(limit, serializer);
-          sse_encode_String(version, serializer);
+          sse_encode_String(apkgPath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -328,51 +466,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_i_64_strict,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_extract_result,
+          decodeErrorData: sse_decode_anki_error,
         ),
-        constMeta: kCrateApiSimpleGetNewNoteIdsConstMeta,
-        argValues: [sqlitePath, limit, version],
+        constMeta: kCrateApiSimpleExtractApkgConstMeta,
+        argValues: [apkgPath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGetNewNoteIdsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_new_note_ids",
-        argNames: ["sqlitePath", "limit", "version"],
-      );
+  TaskConstMeta get kCrateApiSimpleExtractApkgConstMeta =>
+      const TaskConstMeta(debugName: "extract_apkg", argNames: ["apkgPath"]);
 
   @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+  Stream<ImportProgress> crateApiSimpleExtractApkgWithProgress({
+    required String apkgPath,
+    required String taskId,
+  }) {
+    final progress = RustStreamSink<ImportProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(apkgPath, serializer);
+            sse_encode_String(taskId, serializer);
+            sse_encode_StreamSink_import_progress_Sse(progress, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 7,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_anki_error,
+          ),
+          constMeta: kCrateApiSimpleExtractApkgWithProgressConstMeta,
+          argValues: [apkgPath, taskId, progress],
+          apiImpl: this,
         ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
       ),
     );
+    return progress.stream;
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiSimpleExtractApkgWithProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_apkg_with_progress",
+        argNames: ["apkgPath", "taskId", "progress"],
+      );
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<ColpkgImportResult> crateApiSimpleExtractColpkg({
+    required String colpkgPath,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(colpkgPath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -381,71 +536,62 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_colpkg_import_result,
+          decodeErrorData: sse_decode_anki_error,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSimpleExtractColpkgConstMeta,
+        argValues: [colpkgPath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiSimpleExtractColpkgConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_colpkg",
+        argNames: ["colpkgPath"],
+      );
 
   @override
-  Stream<String> crateApiSimpleRegisterLogCallback() {
-    final sink = RustStreamSink<String>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_String_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 9,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateApiSimpleRegisterLogCallbackConstMeta,
-          argValues: [sink],
-          apiImpl: this,
+  Future<Int64List> crateApiSimpleGenerateClozeCards({
+    required PlatformInt64 noteId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(noteId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_i_64_strict,
+          decodeErrorData: sse_decode_anki_error,
         ),
+        constMeta: kCrateApiSimpleGenerateClozeCardsConstMeta,
+        argValues: [noteId],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiSimpleRegisterLogCallbackConstMeta =>
+  TaskConstMeta get kCrateApiSimpleGenerateClozeCardsConstMeta =>
       const TaskConstMeta(
-        debugName: "register_log_callback",
-        argNames: ["sink"],
+        debugName: "generate_cloze_cards",
+        argNames: ["noteId"],
       );
 
   @override
-  Future<FsrsScheduleResult> crateApiSimpleUpdateCardSchedule({
-    required double stability,
-    required double difficulty,
-    required PlatformInt64 lastReview,
-    required int rating,
-    required PlatformInt64 now,
-  }) {
+  Future<Int64List> crateApiSimpleGetAllNoteIds({required String deckId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_f_64(stability, serializer);
-          sse_encode_f_64(difficulty, serializer);
-          sse_encode_i_64(lastReview, serializer);
-          sse_encode_u_8(rating, serializer);
-          sse_encode_i_64(now, serializer);
+          sse_encode_String(deckId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -454,39 +600,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_fsrs_schedule_result,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_prim_i_64_strict,
+          decodeErrorData: sse_decode_anki_error,
         ),
-        constMeta: kCrateApiSimpleUpdateCardScheduleConstMeta,
-        argValues: [stability, difficulty, lastReview, rating, now],
+        constMeta: kCrateApiSimpleGetAllNoteIdsConstMeta,
+        argValues: [deckId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleUpdateCardScheduleConstMeta =>
-      const TaskConstMeta(
-        debugName: "update_card_schedule",
-        argNames: ["stability", "difficulty", "lastReview", "rating", "now"],
-      );
+  TaskConstMeta get kCrateApiSimpleGetAllNoteIdsConstMeta =>
+      const TaskConstMeta(debugName: "get_all_note_ids", argNames: ["deckId"]);
 
   @override
-  Future<FsrsScheduleResult> crateApiSimpleUpdateCardScheduleSimple({
-    required double stability,
-    required double difficulty,
-    required PlatformInt64 lastReview,
-    required int rating,
-    required PlatformInt64 now,
-  }) {
+  Future<int> crateApiSimpleGetCardCount({required String deckId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_f_64(stability, serializer);
-          sse_encode_f_64(difficulty, serializer);
-          sse_encode_i_64(lastReview, serializer);
-          sse_encode_u_8(rating, serializer);
-          sse_encode_i_64(now, serializer);
+          sse_encode_String(deckId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -495,585 +628,3746 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_fsrs_schedule_result,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_anki_error,
         ),
-        constMeta: kCrateApiSimpleUpdateCardScheduleSimpleConstMeta,
-        argValues: [stability, difficulty, lastReview, rating, now],
+        constMeta: kCrateApiSimpleGetCardCountConstMeta,
+        argValues: [deckId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleUpdateCardScheduleSimpleConstMeta =>
-      const TaskConstMeta(
-        debugName: "update_card_schedule_simple",
-        argNames: ["stability", "difficulty", "lastReview", "rating", "now"],
-      );
+  TaskConstMeta get kCrateApiSimpleGetCardCountConstMeta =>
+      const TaskConstMeta(debugName: "get_card_count", argNames: ["deckId"]);
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+  @override
+  Future<CardMemoryState> crateApiSimpleGetCardMemoryState({
+    required String deckId,
+    required PlatformInt64 cardId,
+    required PlatformInt64 now,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_i_64(cardId, serializer);
+          sse_encode_i_64(now, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_card_memory_state,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleGetCardMemoryStateConstMeta,
+        argValues: [deckId, cardId, now],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetCardMemoryStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_card_memory_state",
+        argNames: ["deckId", "cardId", "now"],
+      );
+
+  @override
+  Future<List<CardStatus>> crateApiSimpleGetCardStatus({
+    required String deckId,
+    required Int64List cardIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_list_prim_i_64_strict(cardIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_card_status,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleGetCardStatusConstMeta,
+        argValues: [deckId, cardIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetCardStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_card_status",
+        argNames: ["deckId", "cardIds"],
+      );
+
+  @override
+  Future<CardsBatch> crateApiSimpleGetCardsBatch({
+    required String deckId,
+    required Int64List cardIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_list_prim_i_64_strict(cardIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cards_batch,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleGetCardsBatchConstMeta,
+        argValues: [deckId, cardIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetCardsBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cards_batch",
+        argNames: ["deckId", "cardIds"],
+      );
+
+  @override
+  Future<SingleNoteResult> crateApiSimpleGetDeckNote({
+    required PlatformInt64 noteId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(noteId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_single_note_result,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleGetDeckNoteConstMeta,
+        argValues: [noteId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetDeckNoteConstMeta =>
+      const TaskConstMeta(debugName: "get_deck_note", argNames: ["noteId"]);
+
+  @override
+  Future<IntradayLearningQueue> crateApiSimpleGetIntradayLearningQueue({
+    required String deckId,
+    required PlatformInt64 now,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_i_64(now, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_intraday_learning_queue,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleGetIntradayLearningQueueConstMeta,
+        argValues: [deckId, now],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetIntradayLearningQueueConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_intraday_learning_queue",
+        argNames: ["deckId", "now"],
+      );
+
+  @override
+  Future<String> crateApiSimpleGetMediaDir() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleGetMediaDirConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetMediaDirConstMeta =>
+      const TaskConstMeta(debugName: "get_media_dir", argNames: []);
+
+  @override
+  Future<NewCardQueue> crateApiSimpleGetNewCardQueue({
+    required String deckId,
+    required PlatformInt64 now,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_i_64(now, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_new_card_queue,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleGetNewCardQueueConstMeta,
+        argValues: [deckId, now],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetNewCardQueueConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_new_card_queue",
+        argNames: ["deckId", "now"],
+      );
+
+  @override
+  Future<Int64List> crateApiSimpleGetNewNoteIds({
+    required String deckId,
+    required BigInt limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_usize(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_i_64_strict,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleGetNewNoteIdsConstMeta,
+        argValues: [deckId, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetNewNoteIdsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_new_note_ids",
+        argNames: ["deckId", "limit"],
+      );
+
+  @override
+  Future<List<CardExt>> crateApiSimpleGetNoteCards({
+    required PlatformInt64 noteId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(noteId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_card_ext,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleGetNoteCardsConstMeta,
+        argValues: [noteId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetNoteCardsConstMeta =>
+      const TaskConstMeta(debugName: "get_note_cards", argNames: ["noteId"]);
+
+  @override
+  Future<List<ReviewLogEntry>> crateApiSimpleGetReviewLogs({
+    required String deckId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_review_log_entry,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleGetReviewLogsConstMeta,
+        argValues: [deckId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetReviewLogsConstMeta =>
+      const TaskConstMeta(debugName: "get_review_logs", argNames: ["deckId"]);
+
+  @override
+  Future<int> crateApiSimpleGetRolloverHour() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_8,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleGetRolloverHourConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetRolloverHourConstMeta =>
+      const TaskConstMeta(debugName: "get_rollover_hour", argNames: []);
+
+  @override
+  Future<SchedulerConfig> crateApiSimpleGetSchedulerConfig({
+    required String deckId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_scheduler_config,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleGetSchedulerConfigConstMeta,
+        argValues: [deckId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetSchedulerConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_scheduler_config",
+        argNames: ["deckId"],
+      );
+
+  @override
+  Future<Int64List> crateApiSimpleGetSuspendedCardIds({
+    required String deckId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_i_64_strict,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleGetSuspendedCardIdsConstMeta,
+        argValues: [deckId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGetSuspendedCardIdsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_suspended_card_ids",
+        argNames: ["deckId"],
+      );
+
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<int> crateApiSimpleInitCollection({required String baseDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(baseDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleInitCollectionConstMeta,
+        argValues: [baseDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitCollectionConstMeta =>
+      const TaskConstMeta(debugName: "init_collection", argNames: ["baseDir"]);
+
+  @override
+  Future<MergeResult> crateApiSimpleMergeApkg({
+    required String apkgPath,
+    required String deckId,
+    required bool removeMissing,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(apkgPath, serializer);
+          sse_encode_String(deckId, serializer);
+          sse_encode_bool(removeMissing, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_merge_result,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleMergeApkgConstMeta,
+        argValues: [apkgPath, deckId, removeMissing],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMergeApkgConstMeta => const TaskConstMeta(
+    debugName: "merge_apkg",
+    argNames: ["apkgPath", "deckId", "removeMissing"],
+  );
+
+  @override
+  Future<MergeSummary> crateApiSimpleMergeSummaryDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_merge_summary,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleMergeSummaryDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMergeSummaryDefaultConstMeta =>
+      const TaskConstMeta(debugName: "merge_summary_default", argNames: []);
+
+  @override
+  CardMemoryState crateApiSimpleNewCardMemoryState({
+    required PlatformInt64 now,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(now, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_card_memory_state,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleNewCardMemoryStateConstMeta,
+        argValues: [now],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleNewCardMemoryStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "new_card_memory_state",
+        argNames: ["now"],
+      );
+
+  @override
+  Future<void> crateApiSimpleOpenCollection() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleOpenCollectionConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleOpenCollectionConstMeta =>
+      const TaskConstMeta(debugName: "open_collection", argNames: []);
+
+  @override
+  Stream<OptimizeProgress> crateApiSimpleOptimizeFsrsParameters({
+    required List<ReviewLogEntry> reviewLog,
+  }) {
+    final progress = RustStreamSink<OptimizeProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_review_log_entry(reviewLog, serializer);
+            sse_encode_StreamSink_optimize_progress_Sse(progress, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 32,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_anki_error,
+          ),
+          constMeta: kCrateApiSimpleOptimizeFsrsParametersConstMeta,
+          argValues: [reviewLog, progress],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return progress.stream;
+  }
+
+  TaskConstMeta get kCrateApiSimpleOptimizeFsrsParametersConstMeta =>
+      const TaskConstMeta(
+        debugName: "optimize_fsrs_parameters",
+        argNames: ["reviewLog", "progress"],
+      );
+
+  @override
+  Future<FsrsScheduleResult> crateApiSimplePreviewCardSchedule({
+    required String deckId,
+    required PlatformInt64 noteId,
+    required int rating,
+    required PlatformInt64 now,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_i_64(noteId, serializer);
+          sse_encode_u_8(rating, serializer);
+          sse_encode_i_64(now, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_fsrs_schedule_result,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimplePreviewCardScheduleConstMeta,
+        argValues: [deckId, noteId, rating, now],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimplePreviewCardScheduleConstMeta =>
+      const TaskConstMeta(
+        debugName: "preview_card_schedule",
+        argNames: ["deckId", "noteId", "rating", "now"],
+      );
+
+  @override
+  Future<FsrsScheduleResult> crateApiSimplePreviewCardScheduleSimple({
+    required String deckId,
+    required PlatformInt64 noteId,
+    required int rating,
+    required PlatformInt64 now,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_i_64(noteId, serializer);
+          sse_encode_u_8(rating, serializer);
+          sse_encode_i_64(now, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_fsrs_schedule_result,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimplePreviewCardScheduleSimpleConstMeta,
+        argValues: [deckId, noteId, rating, now],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimplePreviewCardScheduleSimpleConstMeta =>
+      const TaskConstMeta(
+        debugName: "preview_card_schedule_simple",
+        argNames: ["deckId", "noteId", "rating", "now"],
+      );
+
+  @override
+  Future<List<SchedulePreview>> crateApiSimplePreviewSchedule({
+    required CardMemoryState card,
+    required PlatformInt64 now,
+    required SchedulerConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_card_memory_state(card, serializer);
+          sse_encode_i_64(now, serializer);
+          sse_encode_box_autoadd_scheduler_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_schedule_preview,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimplePreviewScheduleConstMeta,
+        argValues: [card, now, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimplePreviewScheduleConstMeta =>
+      const TaskConstMeta(
+        debugName: "preview_schedule",
+        argNames: ["card", "now", "config"],
+      );
+
+  @override
+  Future<QueueLimits> crateApiSimpleQueueLimitsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_queue_limits,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleQueueLimitsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleQueueLimitsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "queue_limits_default", argNames: []);
+
+  @override
+  Stream<LeechEvent> crateApiSimpleRegisterLeechCallback() {
+    final sink = RustStreamSink<LeechEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_leech_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 37,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiSimpleRegisterLeechCallbackConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiSimpleRegisterLeechCallbackConstMeta =>
+      const TaskConstMeta(
+        debugName: "register_leech_callback",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<String> crateApiSimpleRegisterLogCallback() {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 38,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiSimpleRegisterLogCallbackConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiSimpleRegisterLogCallbackConstMeta =>
+      const TaskConstMeta(
+        debugName: "register_log_callback",
+        argNames: ["sink"],
+      );
+
+  @override
+  Future<void> crateApiSimpleRemoveDeck({required String deckId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleRemoveDeckConstMeta,
+        argValues: [deckId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleRemoveDeckConstMeta =>
+      const TaskConstMeta(debugName: "remove_deck", argNames: ["deckId"]);
+
+  @override
+  Future<RenderedCard> crateApiSimpleRenderCard({
+    required PlatformInt64 cardId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(cardId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_rendered_card,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleRenderCardConstMeta,
+        argValues: [cardId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleRenderCardConstMeta =>
+      const TaskConstMeta(debugName: "render_card", argNames: ["cardId"]);
+
+  @override
+  Future<CardMemoryState> crateApiSimpleScheduleCard({
+    required CardMemoryState card,
+    required int rating,
+    required PlatformInt64 now,
+    required SchedulerConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_card_memory_state(card, serializer);
+          sse_encode_u_8(rating, serializer);
+          sse_encode_i_64(now, serializer);
+          sse_encode_box_autoadd_scheduler_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_card_memory_state,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleScheduleCardConstMeta,
+        argValues: [card, rating, now, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleScheduleCardConstMeta => const TaskConstMeta(
+    debugName: "schedule_card",
+    argNames: ["card", "rating", "now", "config"],
+  );
+
+  @override
+  Future<SchedulerConfig> crateApiSimpleSchedulerConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_scheduler_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleSchedulerConfigDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSchedulerConfigDefaultConstMeta =>
+      const TaskConstMeta(debugName: "scheduler_config_default", argNames: []);
+
+  @override
+  Future<int> crateApiSimpleSetCardFlag({
+    required String deckId,
+    required Int64List cardIds,
+    required int flag,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_list_prim_i_64_strict(cardIds, serializer);
+          sse_encode_u_8(flag, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleSetCardFlagConstMeta,
+        argValues: [deckId, cardIds, flag],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetCardFlagConstMeta => const TaskConstMeta(
+    debugName: "set_card_flag",
+    argNames: ["deckId", "cardIds", "flag"],
+  );
+
+  @override
+  Future<void> crateApiSimpleSetRolloverHour({required int hour}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_8(hour, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleSetRolloverHourConstMeta,
+        argValues: [hour],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetRolloverHourConstMeta =>
+      const TaskConstMeta(debugName: "set_rollover_hour", argNames: ["hour"]);
+
+  @override
+  Future<void> crateApiSimpleSetSchedulerConfig({
+    required String deckId,
+    required SchedulerConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_box_autoadd_scheduler_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleSetSchedulerConfigConstMeta,
+        argValues: [deckId, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetSchedulerConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_scheduler_config",
+        argNames: ["deckId", "config"],
+      );
+
+  @override
+  Future<int> crateApiSimpleSuspendCards({
+    required String deckId,
+    required Int64List cardIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_list_prim_i_64_strict(cardIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleSuspendCardsConstMeta,
+        argValues: [deckId, cardIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSuspendCardsConstMeta => const TaskConstMeta(
+    debugName: "suspend_cards",
+    argNames: ["deckId", "cardIds"],
+  );
+
+  @override
+  Future<bool> crateApiSimpleToggleNoteMarked({required PlatformInt64 noteId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(noteId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleToggleNoteMarkedConstMeta,
+        argValues: [noteId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleToggleNoteMarkedConstMeta =>
+      const TaskConstMeta(
+        debugName: "toggle_note_marked",
+        argNames: ["noteId"],
+      );
+
+  @override
+  Future<int> crateApiSimpleUnburyCards({
+    required String deckId,
+    required Int64List cardIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_list_prim_i_64_strict(cardIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleUnburyCardsConstMeta,
+        argValues: [deckId, cardIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleUnburyCardsConstMeta => const TaskConstMeta(
+    debugName: "unbury_cards",
+    argNames: ["deckId", "cardIds"],
+  );
+
+  @override
+  Future<int> crateApiSimpleUnburyDeck({required String deckId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleUnburyDeckConstMeta,
+        argValues: [deckId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleUnburyDeckConstMeta =>
+      const TaskConstMeta(debugName: "unbury_deck", argNames: ["deckId"]);
+
+  @override
+  Future<int> crateApiSimpleUnsuspendCards({
+    required String deckId,
+    required Int64List cardIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_list_prim_i_64_strict(cardIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleUnsuspendCardsConstMeta,
+        argValues: [deckId, cardIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleUnsuspendCardsConstMeta =>
+      const TaskConstMeta(
+        debugName: "unsuspend_cards",
+        argNames: ["deckId", "cardIds"],
+      );
+
+  @override
+  Future<FsrsScheduleResult> crateApiSimpleUpdateCardSchedule({
+    required String deckId,
+    required PlatformInt64 noteId,
+    required int rating,
+    required PlatformInt64 now,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_i_64(noteId, serializer);
+          sse_encode_u_8(rating, serializer);
+          sse_encode_i_64(now, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_fsrs_schedule_result,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleUpdateCardScheduleConstMeta,
+        argValues: [deckId, noteId, rating, now],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleUpdateCardScheduleConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_card_schedule",
+        argNames: ["deckId", "noteId", "rating", "now"],
+      );
+
+  @override
+  Future<FsrsScheduleResult> crateApiSimpleUpdateCardScheduleSimple({
+    required String deckId,
+    required PlatformInt64 noteId,
+    required int rating,
+    required PlatformInt64 now,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deckId, serializer);
+          sse_encode_i_64(noteId, serializer);
+          sse_encode_u_8(rating, serializer);
+          sse_encode_i_64(now, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_fsrs_schedule_result,
+          decodeErrorData: sse_decode_anki_error,
+        ),
+        constMeta: kCrateApiSimpleUpdateCardScheduleSimpleConstMeta,
+        argValues: [deckId, noteId, rating, now],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleUpdateCardScheduleSimpleConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_card_schedule_simple",
+        argNames: ["deckId", "noteId", "rating", "now"],
+      );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_string(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ImportProgress> dco_decode_StreamSink_import_progress_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<LeechEvent> dco_decode_StreamSink_leech_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<OptimizeProgress> dco_decode_StreamSink_optimize_progress_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AnkiError dco_decode_anki_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AnkiError(
+      kind: dco_decode_error_kind(arr[0]),
+      context: dco_decode_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      path: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  BatchCard dco_decode_batch_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return BatchCard(
+      cardId: dco_decode_i_64(arr[0]),
+      noteId: dco_decode_i_64(arr[1]),
+      notetypeId: dco_decode_i_64(arr[2]),
+      ord: dco_decode_i_64(arr[3]),
+      templateOrd: dco_decode_i_64(arr[4]),
+      question: dco_decode_String(arr[5]),
+      answer: dco_decode_String(arr[6]),
+      diagnostics: dco_decode_list_String(arr[7]),
+    );
+  }
+
+  @protected
+  BatchNotetype dco_decode_batch_notetype(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return BatchNotetype(
+      id: dco_decode_i_64(arr[0]),
+      name: dco_decode_String(arr[1]),
+      isCloze: dco_decode_bool(arr[2]),
+      css: dco_decode_String(arr[3]),
+      fieldNames: dco_decode_list_String(arr[4]),
+      templates: dco_decode_list_batch_template(arr[5]),
+    );
+  }
+
+  @protected
+  BatchTemplate dco_decode_batch_template(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BatchTemplate(
+      ord: dco_decode_i_64(arr[0]),
+      name: dco_decode_String(arr[1]),
+      qfmt: dco_decode_String(arr[2]),
+      afmt: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  CardMemoryState dco_decode_box_autoadd_card_memory_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_card_memory_state(raw);
+  }
+
+  @protected
+  ExtractResult dco_decode_box_autoadd_extract_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_extract_result(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  NotetypeExt dco_decode_box_autoadd_notetype_ext(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_notetype_ext(raw);
+  }
+
+  @protected
+  OptimizeResult dco_decode_box_autoadd_optimize_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_optimize_result(raw);
+  }
+
+  @protected
+  QueueLimits dco_decode_box_autoadd_queue_limits(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_queue_limits(raw);
+  }
+
+  @protected
+  SchedulerConfig dco_decode_box_autoadd_scheduler_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_scheduler_config(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  CardExt dco_decode_card_ext(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return CardExt(
+      id: dco_decode_i_64(arr[0]),
+      nid: dco_decode_i_64(arr[1]),
+      ord: dco_decode_i_64(arr[2]),
+      type: dco_decode_i_64(arr[3]),
+      queue: dco_decode_i_64(arr[4]),
+      due: dco_decode_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  CardMemoryState dco_decode_card_memory_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return CardMemoryState(
+      state: dco_decode_card_state(arr[0]),
+      due: dco_decode_i_64(arr[1]),
+      stability: dco_decode_f_64(arr[2]),
+      difficulty: dco_decode_f_64(arr[3]),
+      elapsedDays: dco_decode_i_64(arr[4]),
+      scheduledDays: dco_decode_i_64(arr[5]),
+      reps: dco_decode_i_32(arr[6]),
+      lapses: dco_decode_i_32(arr[7]),
+      step: dco_decode_i_32(arr[8]),
+      lastReview: dco_decode_opt_box_autoadd_i_64(arr[9]),
+      easeFactor: dco_decode_f_64(arr[10]),
+    );
+  }
+
+  @protected
+  CardState dco_decode_card_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CardState.values[raw as int];
+  }
+
+  @protected
+  CardStatus dco_decode_card_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return CardStatus(
+      cardId: dco_decode_i_64(arr[0]),
+      noteId: dco_decode_i_64(arr[1]),
+      suspended: dco_decode_bool(arr[2]),
+      buried: dco_decode_bool(arr[3]),
+      flag: dco_decode_u_8(arr[4]),
+      marked: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  CardsBatch dco_decode_cards_batch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CardsBatch(
+      cards: dco_decode_list_batch_card(arr[0]),
+      notetypes: dco_decode_list_batch_notetype(arr[1]),
+      missingCardIds: dco_decode_list_prim_i_64_strict(arr[2]),
+    );
+  }
+
+  @protected
+  ColpkgDeck dco_decode_colpkg_deck(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ColpkgDeck(
+      md5: dco_decode_String(arr[0]),
+      deckId: dco_decode_i_64(arr[1]),
+      name: dco_decode_String(arr[2]),
+      parentName: dco_decode_opt_String(arr[3]),
+      mediaMap: dco_decode_Map_String_String_None(arr[4]),
+      cardCount: dco_decode_u_32(arr[5]),
+      scheduledCardCount: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  ColpkgImportResult dco_decode_colpkg_import_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ColpkgImportResult(
+      md5: dco_decode_String(arr[0]),
+      version: dco_decode_String(arr[1]),
+      decks: dco_decode_list_colpkg_deck(arr[2]),
+    );
+  }
+
+  @protected
+  ErrorKind dco_decode_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ErrorKind.values[raw as int];
+  }
+
+  @protected
+  ExtractResult dco_decode_extract_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ExtractResult(
+      md5: dco_decode_String(arr[0]),
+      mediaMap: dco_decode_Map_String_String_None(arr[1]),
+      version: dco_decode_String(arr[2]),
+      scheduledCardCount: dco_decode_u_32(arr[3]),
+      summary: dco_decode_merge_summary(arr[4]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FieldExt dco_decode_field_ext(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return FieldExt(
+      id: dco_decode_i_64(arr[0]),
+      notetypeId: dco_decode_i_64(arr[1]),
+      name: dco_decode_String(arr[2]),
+      ord: dco_decode_i_64(arr[3]),
+      fontName: dco_decode_String(arr[4]),
+      fontSize: dco_decode_i_64(arr[5]),
+      rtl: dco_decode_bool(arr[6]),
+      sticky: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  FsrsScheduleResult dco_decode_fsrs_schedule_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FsrsScheduleResult(
+      due: dco_decode_i_64(arr[0]),
+      stability: dco_decode_f_64(arr[1]),
+      difficulty: dco_decode_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  ImportPhase dco_decode_import_phase(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ImportPhase.values[raw as int];
+  }
+
+  @protected
+  ImportProgress dco_decode_import_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ImportProgress(
+      phase: dco_decode_import_phase(arr[0]),
+      bytesProcessed: dco_decode_i_64(arr[1]),
+      totalBytes: dco_decode_i_64(arr[2]),
+      entriesDone: dco_decode_u_32(arr[3]),
+      entriesTotal: dco_decode_u_32(arr[4]),
+      currentFile: dco_decode_opt_String(arr[5]),
+      result: dco_decode_opt_box_autoadd_extract_result(arr[6]),
+    );
+  }
+
+  @protected
+  IntradayLearningQueue dco_decode_intraday_learning_queue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return IntradayLearningQueue(
+      due: dco_decode_list_prim_i_64_strict(arr[0]),
+      learnAhead: dco_decode_list_prim_i_64_strict(arr[1]),
+      nextDue: dco_decode_opt_box_autoadd_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  LeechAction dco_decode_leech_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LeechAction.values[raw as int];
+  }
+
+  @protected
+  LeechEvent dco_decode_leech_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LeechEvent(
+      deckId: dco_decode_String(arr[0]),
+      cardId: dco_decode_i_64(arr[1]),
+      noteId: dco_decode_i_64(arr[2]),
+      lapses: dco_decode_i_32(arr[3]),
+      suspended: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<BatchCard> dco_decode_list_batch_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_batch_card).toList();
+  }
+
+  @protected
+  List<BatchNotetype> dco_decode_list_batch_notetype(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_batch_notetype).toList();
+  }
+
+  @protected
+  List<BatchTemplate> dco_decode_list_batch_template(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_batch_template).toList();
+  }
+
+  @protected
+  List<CardExt> dco_decode_list_card_ext(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_card_ext).toList();
+  }
+
+  @protected
+  List<CardStatus> dco_decode_list_card_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_card_status).toList();
+  }
+
+  @protected
+  List<ColpkgDeck> dco_decode_list_colpkg_deck(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_colpkg_deck).toList();
+  }
+
+  @protected
+  List<FieldExt> dco_decode_list_field_ext(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_field_ext).toList();
+  }
+
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float64List;
+  }
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeInt64List(raw);
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<QueuedCard> dco_decode_list_queued_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_queued_card).toList();
+  }
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  }
+
+  @protected
+  List<ReviewLogEntry> dco_decode_list_review_log_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_review_log_entry).toList();
+  }
+
+  @protected
+  List<SchedulePreview> dco_decode_list_schedule_preview(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_schedule_preview).toList();
+  }
+
+  @protected
+  MergeResult dco_decode_merge_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MergeResult(
+      md5: dco_decode_String(arr[0]),
+      version: dco_decode_String(arr[1]),
+      mediaMap: dco_decode_Map_String_String_None(arr[2]),
+      summary: dco_decode_merge_summary(arr[3]),
+    );
+  }
+
+  @protected
+  MergeSummary dco_decode_merge_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return MergeSummary(
+      addedNotes: dco_decode_u_32(arr[0]),
+      updatedNotes: dco_decode_u_32(arr[1]),
+      unchangedNotes: dco_decode_u_32(arr[2]),
+      removedNotes: dco_decode_u_32(arr[3]),
+      skippedNotes: dco_decode_u_32(arr[4]),
+      addedNotetypes: dco_decode_u_32(arr[5]),
+      updatedNotetypes: dco_decode_u_32(arr[6]),
+      addedNoteIds: dco_decode_list_prim_i_64_strict(arr[7]),
+      removedNoteIds: dco_decode_list_prim_i_64_strict(arr[8]),
+      addedCardIds: dco_decode_list_prim_i_64_strict(arr[9]),
+      removedCardIds: dco_decode_list_prim_i_64_strict(arr[10]),
+    );
+  }
+
+  @protected
+  NewCardGatherPriority dco_decode_new_card_gather_priority(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NewCardGatherPriority.values[raw as int];
+  }
+
+  @protected
+  NewCardQueue dco_decode_new_card_queue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return NewCardQueue(
+      cards: dco_decode_list_queued_card(arr[0]),
+      introducedToday: dco_decode_u_32(arr[1]),
+      available: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  NewCardSortOrder dco_decode_new_card_sort_order(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NewCardSortOrder.values[raw as int];
+  }
+
+  @protected
+  NewReviewMix dco_decode_new_review_mix(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NewReviewMix.values[raw as int];
+  }
+
+  @protected
+  NoteExt dco_decode_note_ext(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return NoteExt(
+      id: dco_decode_i_64(arr[0]),
+      guid: dco_decode_String(arr[1]),
+      mid: dco_decode_i_64(arr[2]),
+      flds: dco_decode_list_String(arr[3]),
+      notetypeName: dco_decode_String(arr[4]),
+      fieldNames: dco_decode_list_String(arr[5]),
+    );
+  }
+
+  @protected
+  NotetypeExt dco_decode_notetype_ext(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return NotetypeExt(
+      id: dco_decode_i_64(arr[0]),
+      name: dco_decode_String(arr[1]),
+      config: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  ExtractResult? dco_decode_opt_box_autoadd_extract_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_extract_result(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  NotetypeExt? dco_decode_opt_box_autoadd_notetype_ext(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_notetype_ext(raw);
+  }
+
+  @protected
+  OptimizeResult? dco_decode_opt_box_autoadd_optimize_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_optimize_result(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  OptimizeProgress dco_decode_optimize_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return OptimizeProgress(
+      current: dco_decode_u_32(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+      result: dco_decode_opt_box_autoadd_optimize_result(arr[2]),
+    );
+  }
+
+  @protected
+  OptimizeResult dco_decode_optimize_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return OptimizeResult(
+      weights: dco_decode_list_prim_f_64_strict(arr[0]),
+      logLossBefore: dco_decode_f_64(arr[1]),
+      logLossAfter: dco_decode_f_64(arr[2]),
+      rmseBefore: dco_decode_f_64(arr[3]),
+      rmseAfter: dco_decode_f_64(arr[4]),
+      reviewCount: dco_decode_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  QueueKind dco_decode_queue_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QueueKind.values[raw as int];
+  }
+
+  @protected
+  QueueLimits dco_decode_queue_limits(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QueueLimits(
+      newCards: dco_decode_opt_box_autoadd_u_32(arr[0]),
+      reviews: dco_decode_opt_box_autoadd_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  QueuedCard dco_decode_queued_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return QueuedCard(
+      cardId: dco_decode_i_64(arr[0]),
+      noteId: dco_decode_i_64(arr[1]),
+      kind: dco_decode_queue_kind(arr[2]),
+    );
+  }
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  }
+
+  @protected
+  RenderedCard dco_decode_rendered_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return RenderedCard(
+      cardId: dco_decode_i_64(arr[0]),
+      noteId: dco_decode_i_64(arr[1]),
+      ord: dco_decode_i_64(arr[2]),
+      question: dco_decode_String(arr[3]),
+      answer: dco_decode_String(arr[4]),
+      css: dco_decode_String(arr[5]),
+      diagnostics: dco_decode_list_String(arr[6]),
+    );
+  }
+
+  @protected
+  ReviewLogEntry dco_decode_review_log_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ReviewLogEntry(
+      cardId: dco_decode_i_64(arr[0]),
+      reviewTime: dco_decode_i_64(arr[1]),
+      rating: dco_decode_u_8(arr[2]),
+    );
+  }
+
+  @protected
+  ReviewQueue dco_decode_review_queue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ReviewQueue(
+      cards: dco_decode_list_queued_card(arr[0]),
+      newCount: dco_decode_u_32(arr[1]),
+      learningCount: dco_decode_u_32(arr[2]),
+      reviewCount: dco_decode_u_32(arr[3]),
+      nextLearningDue: dco_decode_opt_box_autoadd_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  ReviewSortOrder dco_decode_review_sort_order(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ReviewSortOrder.values[raw as int];
+  }
+
+  @protected
+  SchedulePreview dco_decode_schedule_preview(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SchedulePreview(
+      rating: dco_decode_u_8(arr[0]),
+      due: dco_decode_i_64(arr[1]),
+      intervalSeconds: dco_decode_i_64(arr[2]),
+      intervalText: dco_decode_String(arr[3]),
+      card: dco_decode_card_memory_state(arr[4]),
+    );
+  }
+
+  @protected
+  SchedulerAlgorithm dco_decode_scheduler_algorithm(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SchedulerAlgorithm.values[raw as int];
+  }
+
+  @protected
+  SchedulerConfig dco_decode_scheduler_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 28)
+      throw Exception('unexpected arr length: expect 28 but see ${arr.length}');
+    return SchedulerConfig(
+      algorithm: dco_decode_scheduler_algorithm(arr[0]),
+      weights: dco_decode_list_prim_f_64_strict(arr[1]),
+      requestRetention: dco_decode_f_64(arr[2]),
+      maximumInterval: dco_decode_i_32(arr[3]),
+      enableFuzz: dco_decode_bool(arr[4]),
+      enableShortTerm: dco_decode_bool(arr[5]),
+      learnSteps: dco_decode_list_prim_f_64_strict(arr[6]),
+      relearnSteps: dco_decode_list_prim_f_64_strict(arr[7]),
+      graduatingIntervalGood: dco_decode_i_32(arr[8]),
+      graduatingIntervalEasy: dco_decode_i_32(arr[9]),
+      initialEase: dco_decode_f_64(arr[10]),
+      easyMultiplier: dco_decode_f_64(arr[11]),
+      hardMultiplier: dco_decode_f_64(arr[12]),
+      lapseMultiplier: dco_decode_f_64(arr[13]),
+      intervalMultiplier: dco_decode_f_64(arr[14]),
+      minimumLapseInterval: dco_decode_i_32(arr[15]),
+      learnAheadSecs: dco_decode_i_32(arr[16]),
+      newPerDay: dco_decode_u_32(arr[17]),
+      newCardGatherPriority: dco_decode_new_card_gather_priority(arr[18]),
+      newCardSortOrder: dco_decode_new_card_sort_order(arr[19]),
+      reviewsPerDay: dco_decode_u_32(arr[20]),
+      reviewSortOrder: dco_decode_review_sort_order(arr[21]),
+      newMix: dco_decode_new_review_mix(arr[22]),
+      buryNew: dco_decode_bool(arr[23]),
+      buryReviews: dco_decode_bool(arr[24]),
+      buryInterdayLearning: dco_decode_bool(arr[25]),
+      leechThreshold: dco_decode_u_32(arr[26]),
+      leechAction: dco_decode_leech_action(arr[27]),
+    );
+  }
+
+  @protected
+  SingleNoteResult dco_decode_single_note_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return SingleNoteResult(
+      note: dco_decode_note_ext(arr[0]),
+      notetype: dco_decode_opt_box_autoadd_notetype_ext(arr[1]),
+      fields: dco_decode_list_field_ext(arr[2]),
+      ord: dco_decode_i_64(arr[3]),
+      front: dco_decode_String(arr[4]),
+      back: dco_decode_String(arr[5]),
+      css: dco_decode_String(arr[6]),
+      latexPre: dco_decode_String(arr[7]),
+      latexPost: dco_decode_String(arr[8]),
+      sortFieldIdx: dco_decode_i_64(arr[9]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ImportProgress> sse_decode_StreamSink_import_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<LeechEvent> sse_decode_StreamSink_leech_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<OptimizeProgress> sse_decode_StreamSink_optimize_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AnkiError sse_decode_anki_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_error_kind(deserializer);
+    var var_context = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_path = sse_decode_opt_String(deserializer);
+    return AnkiError(
+      kind: var_kind,
+      context: var_context,
+      message: var_message,
+      path: var_path,
+    );
+  }
+
+  @protected
+  BatchCard sse_decode_batch_card(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cardId = sse_decode_i_64(deserializer);
+    var var_noteId = sse_decode_i_64(deserializer);
+    var var_notetypeId = sse_decode_i_64(deserializer);
+    var var_ord = sse_decode_i_64(deserializer);
+    var var_templateOrd = sse_decode_i_64(deserializer);
+    var var_question = sse_decode_String(deserializer);
+    var var_answer = sse_decode_String(deserializer);
+    var var_diagnostics = sse_decode_list_String(deserializer);
+    return BatchCard(
+      cardId: var_cardId,
+      noteId: var_noteId,
+      notetypeId: var_notetypeId,
+      ord: var_ord,
+      templateOrd: var_templateOrd,
+      question: var_question,
+      answer: var_answer,
+      diagnostics: var_diagnostics,
+    );
+  }
+
+  @protected
+  BatchNotetype sse_decode_batch_notetype(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_isCloze = sse_decode_bool(deserializer);
+    var var_css = sse_decode_String(deserializer);
+    var var_fieldNames = sse_decode_list_String(deserializer);
+    var var_templates = sse_decode_list_batch_template(deserializer);
+    return BatchNotetype(
+      id: var_id,
+      name: var_name,
+      isCloze: var_isCloze,
+      css: var_css,
+      fieldNames: var_fieldNames,
+      templates: var_templates,
+    );
+  }
+
+  @protected
+  BatchTemplate sse_decode_batch_template(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ord = sse_decode_i_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_qfmt = sse_decode_String(deserializer);
+    var var_afmt = sse_decode_String(deserializer);
+    return BatchTemplate(
+      ord: var_ord,
+      name: var_name,
+      qfmt: var_qfmt,
+      afmt: var_afmt,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  CardMemoryState sse_decode_box_autoadd_card_memory_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_card_memory_state(deserializer));
+  }
+
+  @protected
+  ExtractResult sse_decode_box_autoadd_extract_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_extract_result(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  NotetypeExt sse_decode_box_autoadd_notetype_ext(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_notetype_ext(deserializer));
+  }
+
+  @protected
+  OptimizeResult sse_decode_box_autoadd_optimize_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_optimize_result(deserializer));
+  }
+
+  @protected
+  QueueLimits sse_decode_box_autoadd_queue_limits(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_queue_limits(deserializer));
+  }
+
+  @protected
+  SchedulerConfig sse_decode_box_autoadd_scheduler_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_scheduler_config(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  CardExt sse_decode_card_ext(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_nid = sse_decode_i_64(deserializer);
+    var var_ord = sse_decode_i_64(deserializer);
+    var var_type = sse_decode_i_64(deserializer);
+    var var_queue = sse_decode_i_64(deserializer);
+    var var_due = sse_decode_i_64(deserializer);
+    return CardExt(
+      id: var_id,
+      nid: var_nid,
+      ord: var_ord,
+      type: var_type,
+      queue: var_queue,
+      due: var_due,
+    );
+  }
+
+  @protected
+  CardMemoryState sse_decode_card_memory_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_state = sse_decode_card_state(deserializer);
+    var var_due = sse_decode_i_64(deserializer);
+    var var_stability = sse_decode_f_64(deserializer);
+    var var_difficulty = sse_decode_f_64(deserializer);
+    var var_elapsedDays = sse_decode_i_64(deserializer);
+    var var_scheduledDays = sse_decode_i_64(deserializer);
+    var var_reps = sse_decode_i_32(deserializer);
+    var var_lapses = sse_decode_i_32(deserializer);
+    var var_step = sse_decode_i_32(deserializer);
+    var var_lastReview = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_easeFactor = sse_decode_f_64(deserializer);
+    return CardMemoryState(
+      state: var_state,
+      due: var_due,
+      stability: var_stability,
+      difficulty: var_difficulty,
+      elapsedDays: var_elapsedDays,
+      scheduledDays: var_scheduledDays,
+      reps: var_reps,
+      lapses: var_lapses,
+      step: var_step,
+      lastReview: var_lastReview,
+      easeFactor: var_easeFactor,
+    );
+  }
+
+  @protected
+  CardState sse_decode_card_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CardState.values[inner];
+  }
+
+  @protected
+  CardStatus sse_decode_card_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cardId = sse_decode_i_64(deserializer);
+    var var_noteId = sse_decode_i_64(deserializer);
+    var var_suspended = sse_decode_bool(deserializer);
+    var var_buried = sse_decode_bool(deserializer);
+    var var_flag = sse_decode_u_8(deserializer);
+    var var_marked = sse_decode_bool(deserializer);
+    return CardStatus(
+      cardId: var_cardId,
+      noteId: var_noteId,
+      suspended: var_suspended,
+      buried: var_buried,
+      flag: var_flag,
+      marked: var_marked,
+    );
+  }
+
+  @protected
+  CardsBatch sse_decode_cards_batch(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cards = sse_decode_list_batch_card(deserializer);
+    var var_notetypes = sse_decode_list_batch_notetype(deserializer);
+    var var_missingCardIds = sse_decode_list_prim_i_64_strict(deserializer);
+    return CardsBatch(
+      cards: var_cards,
+      notetypes: var_notetypes,
+      missingCardIds: var_missingCardIds,
+    );
+  }
+
+  @protected
+  ColpkgDeck sse_decode_colpkg_deck(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_md5 = sse_decode_String(deserializer);
+    var var_deckId = sse_decode_i_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_parentName = sse_decode_opt_String(deserializer);
+    var var_mediaMap = sse_decode_Map_String_String_None(deserializer);
+    var var_cardCount = sse_decode_u_32(deserializer);
+    var var_scheduledCardCount = sse_decode_u_32(deserializer);
+    return ColpkgDeck(
+      md5: var_md5,
+      deckId: var_deckId,
+      name: var_name,
+      parentName: var_parentName,
+      mediaMap: var_mediaMap,
+      cardCount: var_cardCount,
+      scheduledCardCount: var_scheduledCardCount,
+    );
+  }
+
+  @protected
+  ColpkgImportResult sse_decode_colpkg_import_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_md5 = sse_decode_String(deserializer);
+    var var_version = sse_decode_String(deserializer);
+    var var_decks = sse_decode_list_colpkg_deck(deserializer);
+    return ColpkgImportResult(
+      md5: var_md5,
+      version: var_version,
+      decks: var_decks,
+    );
+  }
+
+  @protected
+  ErrorKind sse_decode_error_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ErrorKind.values[inner];
+  }
+
+  @protected
+  ExtractResult sse_decode_extract_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_md5 = sse_decode_String(deserializer);
+    var var_mediaMap = sse_decode_Map_String_String_None(deserializer);
+    var var_version = sse_decode_String(deserializer);
+    var var_scheduledCardCount = sse_decode_u_32(deserializer);
+    var var_summary = sse_decode_merge_summary(deserializer);
+    return ExtractResult(
+      md5: var_md5,
+      mediaMap: var_mediaMap,
+      version: var_version,
+      scheduledCardCount: var_scheduledCardCount,
+      summary: var_summary,
+    );
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FieldExt sse_decode_field_ext(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_notetypeId = sse_decode_i_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_ord = sse_decode_i_64(deserializer);
+    var var_fontName = sse_decode_String(deserializer);
+    var var_fontSize = sse_decode_i_64(deserializer);
+    var var_rtl = sse_decode_bool(deserializer);
+    var var_sticky = sse_decode_bool(deserializer);
+    return FieldExt(
+      id: var_id,
+      notetypeId: var_notetypeId,
+      name: var_name,
+      ord: var_ord,
+      fontName: var_fontName,
+      fontSize: var_fontSize,
+      rtl: var_rtl,
+      sticky: var_sticky,
+    );
+  }
+
+  @protected
+  FsrsScheduleResult sse_decode_fsrs_schedule_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_due = sse_decode_i_64(deserializer);
+    var var_stability = sse_decode_f_64(deserializer);
+    var var_difficulty = sse_decode_f_64(deserializer);
+    return FsrsScheduleResult(
+      due: var_due,
+      stability: var_stability,
+      difficulty: var_difficulty,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  ImportPhase sse_decode_import_phase(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ImportPhase.values[inner];
+  }
+
+  @protected
+  ImportProgress sse_decode_import_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_phase = sse_decode_import_phase(deserializer);
+    var var_bytesProcessed = sse_decode_i_64(deserializer);
+    var var_totalBytes = sse_decode_i_64(deserializer);
+    var var_entriesDone = sse_decode_u_32(deserializer);
+    var var_entriesTotal = sse_decode_u_32(deserializer);
+    var var_currentFile = sse_decode_opt_String(deserializer);
+    var var_result = sse_decode_opt_box_autoadd_extract_result(deserializer);
+    return ImportProgress(
+      phase: var_phase,
+      bytesProcessed: var_bytesProcessed,
+      totalBytes: var_totalBytes,
+      entriesDone: var_entriesDone,
+      entriesTotal: var_entriesTotal,
+      currentFile: var_currentFile,
+      result: var_result,
+    );
+  }
+
+  @protected
+  IntradayLearningQueue sse_decode_intraday_learning_queue(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_due = sse_decode_list_prim_i_64_strict(deserializer);
+    var var_learnAhead = sse_decode_list_prim_i_64_strict(deserializer);
+    var var_nextDue = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return IntradayLearningQueue(
+      due: var_due,
+      learnAhead: var_learnAhead,
+      nextDue: var_nextDue,
+    );
+  }
+
+  @protected
+  LeechAction sse_decode_leech_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LeechAction.values[inner];
+  }
+
+  @protected
+  LeechEvent sse_decode_leech_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_deckId = sse_decode_String(deserializer);
+    var var_cardId = sse_decode_i_64(deserializer);
+    var var_noteId = sse_decode_i_64(deserializer);
+    var var_lapses = sse_decode_i_32(deserializer);
+    var var_suspended = sse_decode_bool(deserializer);
+    return LeechEvent(
+      deckId: var_deckId,
+      cardId: var_cardId,
+      noteId: var_noteId,
+      lapses: var_lapses,
+      suspended: var_suspended,
+    );
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BatchCard> sse_decode_list_batch_card(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BatchCard>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_batch_card(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BatchNotetype> sse_decode_list_batch_notetype(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BatchNotetype>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_batch_notetype(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BatchTemplate> sse_decode_list_batch_template(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BatchTemplate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_batch_template(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CardExt> sse_decode_list_card_ext(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CardExt>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_card_ext(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CardStatus> sse_decode_list_card_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CardStatus>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_card_status(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ColpkgDeck> sse_decode_list_colpkg_deck(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ColpkgDeck>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_colpkg_deck(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FieldExt> sse_decode_list_field_ext(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FieldExt>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_field_ext(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat64List(len_);
+  }
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt64List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<QueuedCard> sse_decode_list_queued_card(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <QueuedCard>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_queued_card(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, String)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_string(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ReviewLogEntry> sse_decode_list_review_log_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReviewLogEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_review_log_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SchedulePreview> sse_decode_list_schedule_preview(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SchedulePreview>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_schedule_preview(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MergeResult sse_decode_merge_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_md5 = sse_decode_String(deserializer);
+    var var_version = sse_decode_String(deserializer);
+    var var_mediaMap = sse_decode_Map_String_String_None(deserializer);
+    var var_summary = sse_decode_merge_summary(deserializer);
+    return MergeResult(
+      md5: var_md5,
+      version: var_version,
+      mediaMap: var_mediaMap,
+      summary: var_summary,
+    );
+  }
+
+  @protected
+  MergeSummary sse_decode_merge_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_addedNotes = sse_decode_u_32(deserializer);
+    var var_updatedNotes = sse_decode_u_32(deserializer);
+    var var_unchangedNotes = sse_decode_u_32(deserializer);
+    var var_removedNotes = sse_decode_u_32(deserializer);
+    var var_skippedNotes = sse_decode_u_32(deserializer);
+    var var_addedNotetypes = sse_decode_u_32(deserializer);
+    var var_updatedNotetypes = sse_decode_u_32(deserializer);
+    var var_addedNoteIds = sse_decode_list_prim_i_64_strict(deserializer);
+    var var_removedNoteIds = sse_decode_list_prim_i_64_strict(deserializer);
+    var var_addedCardIds = sse_decode_list_prim_i_64_strict(deserializer);
+    var var_removedCardIds = sse_decode_list_prim_i_64_strict(deserializer);
+    return MergeSummary(
+      addedNotes: var_addedNotes,
+      updatedNotes: var_updatedNotes,
+      unchangedNotes: var_unchangedNotes,
+      removedNotes: var_removedNotes,
+      skippedNotes: var_skippedNotes,
+      addedNotetypes: var_addedNotetypes,
+      updatedNotetypes: var_updatedNotetypes,
+      addedNoteIds: var_addedNoteIds,
+      removedNoteIds: var_removedNoteIds,
+      addedCardIds: var_addedCardIds,
+      removedCardIds: var_removedCardIds,
+    );
+  }
+
+  @protected
+  NewCardGatherPriority sse_decode_new_card_gather_priority(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NewCardGatherPriority.values[inner];
+  }
+
+  @protected
+  NewCardQueue sse_decode_new_card_queue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cards = sse_decode_list_queued_card(deserializer);
+    var var_introducedToday = sse_decode_u_32(deserializer);
+    var var_available = sse_decode_u_32(deserializer);
+    return NewCardQueue(
+      cards: var_cards,
+      introducedToday: var_introducedToday,
+      available: var_available,
+    );
+  }
+
+  @protected
+  NewCardSortOrder sse_decode_new_card_sort_order(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NewCardSortOrder.values[inner];
+  }
+
+  @protected
+  NewReviewMix sse_decode_new_review_mix(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NewReviewMix.values[inner];
+  }
+
+  @protected
+  NoteExt sse_decode_note_ext(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_guid = sse_decode_String(deserializer);
+    var var_mid = sse_decode_i_64(deserializer);
+    var var_flds = sse_decode_list_String(deserializer);
+    var var_notetypeName = sse_decode_String(deserializer);
+    var var_fieldNames = sse_decode_list_String(deserializer);
+    return NoteExt(
+      id: var_id,
+      guid: var_guid,
+      mid: var_mid,
+      flds: var_flds,
+      notetypeName: var_notetypeName,
+      fieldNames: var_fieldNames,
+    );
+  }
+
+  @protected
+  NotetypeExt sse_decode_notetype_ext(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_config = sse_decode_opt_String(deserializer);
+    return NotetypeExt(id: var_id, name: var_name, config: var_config);
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ExtractResult? sse_decode_opt_box_autoadd_extract_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_extract_result(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_string(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
+  NotetypeExt? sse_decode_opt_box_autoadd_notetype_ext(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_notetype_ext(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OptimizeResult? sse_decode_opt_box_autoadd_optimize_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_optimize_result(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OptimizeProgress sse_decode_optimize_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_current = sse_decode_u_32(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    var var_result = sse_decode_opt_box_autoadd_optimize_result(deserializer);
+    return OptimizeProgress(
+      current: var_current,
+      total: var_total,
+      result: var_result,
     );
   }
 
   @protected
-  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+  OptimizeResult sse_decode_optimize_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_weights = sse_decode_list_prim_f_64_strict(deserializer);
+    var var_logLossBefore = sse_decode_f_64(deserializer);
+    var var_logLossAfter = sse_decode_f_64(deserializer);
+    var var_rmseBefore = sse_decode_f_64(deserializer);
+    var var_rmseAfter = sse_decode_f_64(deserializer);
+    var var_reviewCount = sse_decode_u_32(deserializer);
+    return OptimizeResult(
+      weights: var_weights,
+      logLossBefore: var_logLossBefore,
+      logLossAfter: var_logLossAfter,
+      rmseBefore: var_rmseBefore,
+      rmseAfter: var_rmseAfter,
+      reviewCount: var_reviewCount,
+    );
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  QueueKind sse_decode_queue_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return QueueKind.values[inner];
   }
 
   @protected
-  NotetypeExt dco_decode_box_autoadd_notetype_ext(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_notetype_ext(raw);
+  QueueLimits sse_decode_queue_limits(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_newCards = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_reviews = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return QueueLimits(newCards: var_newCards, reviews: var_reviews);
   }
 
   @protected
-  ExtractResult dco_decode_extract_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return ExtractResult(
-      dir: dco_decode_String(arr[0]),
-      md5: dco_decode_String(arr[1]),
-      mediaMap: dco_decode_Map_String_String_None(arr[2]),
-      version: dco_decode_String(arr[3]),
+  QueuedCard sse_decode_queued_card(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cardId = sse_decode_i_64(deserializer);
+    var var_noteId = sse_decode_i_64(deserializer);
+    var var_kind = sse_decode_queue_kind(deserializer);
+    return QueuedCard(cardId: var_cardId, noteId: var_noteId, kind: var_kind);
+  }
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  RenderedCard sse_decode_rendered_card(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cardId = sse_decode_i_64(deserializer);
+    var var_noteId = sse_decode_i_64(deserializer);
+    var var_ord = sse_decode_i_64(deserializer);
+    var var_question = sse_decode_String(deserializer);
+    var var_answer = sse_decode_String(deserializer);
+    var var_css = sse_decode_String(deserializer);
+    var var_diagnostics = sse_decode_list_String(deserializer);
+    return RenderedCard(
+      cardId: var_cardId,
+      noteId: var_noteId,
+      ord: var_ord,
+      question: var_question,
+      answer: var_answer,
+      css: var_css,
+      diagnostics: var_diagnostics,
     );
   }
 
   @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  ReviewLogEntry sse_decode_review_log_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cardId = sse_decode_i_64(deserializer);
+    var var_reviewTime = sse_decode_i_64(deserializer);
+    var var_rating = sse_decode_u_8(deserializer);
+    return ReviewLogEntry(
+      cardId: var_cardId,
+      reviewTime: var_reviewTime,
+      rating: var_rating,
+    );
   }
 
   @protected
-  FieldExt dco_decode_field_ext(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return FieldExt(
-      id: dco_decode_i_64(arr[0]),
-      notetypeId: dco_decode_i_64(arr[1]),
-      name: dco_decode_String(arr[2]),
-      ord: dco_decode_i_64(arr[3]),
+  ReviewQueue sse_decode_review_queue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cards = sse_decode_list_queued_card(deserializer);
+    var var_newCount = sse_decode_u_32(deserializer);
+    var var_learningCount = sse_decode_u_32(deserializer);
+    var var_reviewCount = sse_decode_u_32(deserializer);
+    var var_nextLearningDue = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return ReviewQueue(
+      cards: var_cards,
+      newCount: var_newCount,
+      learningCount: var_learningCount,
+      reviewCount: var_reviewCount,
+      nextLearningDue: var_nextLearningDue,
     );
   }
 
   @protected
-  FsrsScheduleResult dco_decode_fsrs_schedule_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return FsrsScheduleResult(
-      due: dco_decode_i_64(arr[0]),
-      stability: dco_decode_f_64(arr[1]),
-      difficulty: dco_decode_f_64(arr[2]),
+  ReviewSortOrder sse_decode_review_sort_order(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ReviewSortOrder.values[inner];
+  }
+
+  @protected
+  SchedulePreview sse_decode_schedule_preview(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rating = sse_decode_u_8(deserializer);
+    var var_due = sse_decode_i_64(deserializer);
+    var var_intervalSeconds = sse_decode_i_64(deserializer);
+    var var_intervalText = sse_decode_String(deserializer);
+    var var_card = sse_decode_card_memory_state(deserializer);
+    return SchedulePreview(
+      rating: var_rating,
+      due: var_due,
+      intervalSeconds: var_intervalSeconds,
+      intervalText: var_intervalText,
+      card: var_card,
     );
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  SchedulerAlgorithm sse_decode_scheduler_algorithm(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SchedulerAlgorithm.values[inner];
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  SchedulerConfig sse_decode_scheduler_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_algorithm = sse_decode_scheduler_algorithm(deserializer);
+    var var_weights = sse_decode_list_prim_f_64_strict(deserializer);
+    var var_requestRetention = sse_decode_f_64(deserializer);
+    var var_maximumInterval = sse_decode_i_32(deserializer);
+    var var_enableFuzz = sse_decode_bool(deserializer);
+    var var_enableShortTerm = sse_decode_bool(deserializer);
+    var var_learnSteps = sse_decode_list_prim_f_64_strict(deserializer);
+    var var_relearnSteps = sse_decode_list_prim_f_64_strict(deserializer);
+    var var_graduatingIntervalGood = sse_decode_i_32(deserializer);
+    var var_graduatingIntervalEasy = sse_decode_i_32(deserializer);
+    var var_initialEase = sse_decode_f_64(deserializer);
+    var var_easyMultiplier = sse_decode_f_64(deserializer);
+    var var_hardMultiplier = sse_decode_f_64(deserializer);
+    var var_lapseMultiplier = sse_decode_f_64(deserializer);
+    var var_intervalMultiplier = sse_decode_f_64(deserializer);
+    var var_minimumLapseInterval = sse_decode_i_32(deserializer);
+    var var_learnAheadSecs = sse_decode_i_32(deserializer);
+    var var_newPerDay = sse_decode_u_32(deserializer);
+    var var_newCardGatherPriority =
+        sse_decode_new_card_gather_priority(deserializer);
+    var var_newCardSortOrder = sse_decode_new_card_sort_order(deserializer);
+    var var_reviewsPerDay = sse_decode_u_32(deserializer);
+    var var_reviewSortOrder = sse_decode_review_sort_order(deserializer);
+    var var_newMix = sse_decode_new_review_mix(deserializer);
+    var var_buryNew = sse_decode_bool(deserializer);
+    var var_buryReviews = sse_decode_bool(deserializer);
+    var var_buryInterdayLearning = sse_decode_bool(deserializer);
+    var var_leechThreshold = sse_decode_u_32(deserializer);
+    var var_leechAction = sse_decode_leech_action(deserializer);
+    return SchedulerConfig(
+      algorithm: var_algorithm,
+      weights: var_weights,
+      requestRetention: var_requestRetention,
+      maximumInterval: var_maximumInterval,
+      enableFuzz: var_enableFuzz,
+      enableShortTerm: var_enableShortTerm,
+      learnSteps: var_learnSteps,
+      relearnSteps: var_relearnSteps,
+      graduatingIntervalGood: var_graduatingIntervalGood,
+      graduatingIntervalEasy: var_graduatingIntervalEasy,
+      initialEase: var_initialEase,
+      easyMultiplier: var_easyMultiplier,
+      hardMultiplier: var_hardMultiplier,
+      lapseMultiplier: var_lapseMultiplier,
+      intervalMultiplier: var_intervalMultiplier,
+      minimumLapseInterval: var_minimumLapseInterval,
+      learnAheadSecs: var_learnAheadSecs,
+      newPerDay: var_newPerDay,
+      newCardGatherPriority: var_newCardGatherPriority,
+      newCardSortOrder: var_newCardSortOrder,
+      reviewsPerDay: var_reviewsPerDay,
+      reviewSortOrder: var_reviewSortOrder,
+      newMix: var_newMix,
+      buryNew: var_buryNew,
+      buryReviews: var_buryReviews,
+      buryInterdayLearning: var_buryInterdayLearning,
+      leechThreshold: var_leechThreshold,
+      leechAction: var_leechAction,
+    );
   }
 
   @protected
-  List<FieldExt> dco_decode_list_field_ext(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_field_ext).toList();
+  SingleNoteResult sse_decode_single_note_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_note = sse_decode_note_ext(deserializer);
+    var var_notetype = sse_decode_opt_box_autoadd_notetype_ext(deserializer);
+    var var_fields = sse_decode_list_field_ext(deserializer);
+    var var_ord = sse_decode_i_64(deserializer);
+    var var_front = sse_decode_String(deserializer);
+    var var_back = sse_decode_String(deserializer);
+    var var_css = sse_decode_String(deserializer);
+    var var_latexPre = sse_decode_String(deserializer);
+    var var_latexPost = sse_decode_String(deserializer);
+    var var_sortFieldIdx = sse_decode_i_64(deserializer);
+    return SingleNoteResult(
+      note: var_note,
+      notetype: var_notetype,
+      fields: var_fields,
+      ord: var_ord,
+      front: var_front,
+      back: var_back,
+      css: var_css,
+      latexPre: var_latexPre,
+      latexPost: var_latexPost,
+      sortFieldIdx: var_sortFieldIdx,
+    );
   }
 
   @protected
-  Int64List dco_decode_list_prim_i_64_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeInt64List(raw);
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
   @protected
-  NoteExt dco_decode_note_ext(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return NoteExt(
-      id: dco_decode_i_64(arr[0]),
-      guid: dco_decode_String(arr[1]),
-      mid: dco_decode_i_64(arr[2]),
-      flds: dco_decode_list_String(arr[3]),
-      notetypeName: dco_decode_String(arr[4]),
-      fieldNames: dco_decode_list_String(arr[5]),
-    );
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  NotetypeExt dco_decode_notetype_ext(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return NotetypeExt(
-      id: dco_decode_i_64(arr[0]),
-      name: dco_decode_String(arr[1]),
-      config: dco_decode_opt_String(arr[2]),
-    );
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_string(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
   }
 
   @protected
-  NotetypeExt? dco_decode_opt_box_autoadd_notetype_ext(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_notetype_ext(raw);
+  void sse_encode_StreamSink_String_Sse(
+    RustStreamSink<String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  (String, String) dco_decode_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  void sse_encode_StreamSink_import_progress_Sse(
+    RustStreamSink<ImportProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_import_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  SingleNoteResult dco_decode_single_note_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return SingleNoteResult(
-      note: dco_decode_note_ext(arr[0]),
-      notetype: dco_decode_opt_box_autoadd_notetype_ext(arr[1]),
-      fields: dco_decode_list_field_ext(arr[2]),
-      ord: dco_decode_i_64(arr[3]),
-      front: dco_decode_String(arr[4]),
-      back: dco_decode_String(arr[5]),
-      css: dco_decode_String(arr[6]),
+  void sse_encode_StreamSink_leech_event_Sse(
+    RustStreamSink<LeechEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_leech_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
     );
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_StreamSink_optimize_progress_Sse(
+    RustStreamSink<OptimizeProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_optimize_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_anki_error(AnkiError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_error_kind(self.kind, serializer);
+    sse_encode_String(self.context, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_String(self.path, serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_batch_card(BatchCard self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_i_64(self.cardId, serializer);
+    sse_encode_i_64(self.noteId, serializer);
+    sse_encode_i_64(self.notetypeId, serializer);
+    sse_encode_i_64(self.ord, serializer);
+    sse_encode_i_64(self.templateOrd, serializer);
+    sse_encode_String(self.question, serializer);
+    sse_encode_String(self.answer, serializer);
+    sse_encode_list_String(self.diagnostics, serializer);
   }
 
   @protected
-  Map<String, String> sse_decode_Map_String_String_None(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_batch_notetype(BatchNotetype self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_string(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_bool(self.isCloze, serializer);
+    sse_encode_String(self.css, serializer);
+    sse_encode_list_String(self.fieldNames, serializer);
+    sse_encode_list_batch_template(self.templates, serializer);
   }
 
   @protected
-  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_batch_template(BatchTemplate self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_i_64(self.ord, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.qfmt, serializer);
+    sse_encode_String(self.afmt, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  NotetypeExt sse_decode_box_autoadd_notetype_ext(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_card_memory_state(
+    CardMemoryState self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_notetype_ext(deserializer));
+    sse_encode_card_memory_state(self, serializer);
   }
 
   @protected
-  ExtractResult sse_decode_extract_result(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_extract_result(
+    ExtractResult self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_dir = sse_decode_String(deserializer);
-    var var_md5 = sse_decode_String(deserializer);
-    var var_mediaMap = sse_decode_Map_String_String_None(deserializer);
-    var var_version = sse_decode_String(deserializer);
-    return ExtractResult(
-      dir: var_dir,
-      md5: var_md5,
-      mediaMap: var_mediaMap,
-      version: var_version,
-    );
+    sse_encode_extract_result(self, serializer);
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+    sse_encode_i_64(self, serializer);
   }
 
   @protected
-  FieldExt sse_decode_field_ext(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_notetype_ext(
+    NotetypeExt self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_64(deserializer);
-    var var_notetypeId = sse_decode_i_64(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_ord = sse_decode_i_64(deserializer);
-    return FieldExt(
-      id: var_id,
-      notetypeId: var_notetypeId,
-      name: var_name,
-      ord: var_ord,
-    );
+    sse_encode_notetype_ext(self, serializer);
   }
 
   @protected
-  FsrsScheduleResult sse_decode_fsrs_schedule_result(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_optimize_result(
+    OptimizeResult self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_due = sse_decode_i_64(deserializer);
-    var var_stability = sse_decode_f_64(deserializer);
-    var var_difficulty = sse_decode_f_64(deserializer);
-    return FsrsScheduleResult(
-      due: var_due,
-      stability: var_stability,
-      difficulty: var_difficulty,
-    );
+    sse_encode_optimize_result(self, serializer);
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_queue_limits(
+    QueueLimits self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+    sse_encode_queue_limits(self, serializer);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_scheduler_config(
+    SchedulerConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
+    sse_encode_scheduler_config(self, serializer);
   }
 
   @protected
-  List<FieldExt> sse_decode_list_field_ext(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FieldExt>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_field_ext(deserializer));
-    }
-    return ans_;
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer) {
+  void sse_encode_card_ext(CardExt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getInt64List(len_);
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_i_64(self.nid, serializer);
+    sse_encode_i_64(self.ord, serializer);
+    sse_encode_i_64(self.type, serializer);
+    sse_encode_i_64(self.queue, serializer);
+    sse_encode_i_64(self.due, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_card_memory_state(
+    CardMemoryState self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_card_state(self.state, serializer);
+    sse_encode_i_64(self.due, serializer);
+    sse_encode_f_64(self.stability, serializer);
+    sse_encode_f_64(self.difficulty, serializer);
+    sse_encode_i_64(self.elapsedDays, serializer);
+    sse_encode_i_64(self.scheduledDays, serializer);
+    sse_encode_i_32(self.reps, serializer);
+    sse_encode_i_32(self.lapses, serializer);
+    sse_encode_i_32(self.step, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastReview, serializer);
+    sse_encode_f_64(self.easeFactor, serializer);
   }
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_card_state(CardState self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
-    }
-    return ans_;
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  NoteExt sse_decode_note_ext(SseDeserializer deserializer) {
+  void sse_encode_card_status(CardStatus self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_64(deserializer);
-    var var_guid = sse_decode_String(deserializer);
-    var var_mid = sse_decode_i_64(deserializer);
-    var var_flds = sse_decode_list_String(deserializer);
-    var var_notetypeName = sse_decode_String(deserializer);
-    var var_fieldNames = sse_decode_list_String(deserializer);
-    return NoteExt(
-      id: var_id,
-      guid: var_guid,
-      mid: var_mid,
-      flds: var_flds,
-      notetypeName: var_notetypeName,
-      fieldNames: var_fieldNames,
-    );
+    sse_encode_i_64(self.cardId, serializer);
+    sse_encode_i_64(self.noteId, serializer);
+    sse_encode_bool(self.suspended, serializer);
+    sse_encode_bool(self.buried, serializer);
+    sse_encode_u_8(self.flag, serializer);
+    sse_encode_bool(self.marked, serializer);
   }
 
   @protected
-  NotetypeExt sse_decode_notetype_ext(SseDeserializer deserializer) {
+  void sse_encode_cards_batch(CardsBatch self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_64(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_config = sse_decode_opt_String(deserializer);
-    return NotetypeExt(id: var_id, name: var_name, config: var_config);
+    sse_encode_list_batch_card(self.cards, serializer);
+    sse_encode_list_batch_notetype(self.notetypes, serializer);
+    sse_encode_list_prim_i_64_strict(self.missingCardIds, serializer);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_colpkg_deck(ColpkgDeck self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_String(self.md5, serializer);
+    sse_encode_i_64(self.deckId, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.parentName, serializer);
+    sse_encode_Map_String_String_None(self.mediaMap, serializer);
+    sse_encode_u_32(self.cardCount, serializer);
+    sse_encode_u_32(self.scheduledCardCount, serializer);
   }
 
   @protected
-  NotetypeExt? sse_decode_opt_box_autoadd_notetype_ext(
-    SseDeserializer deserializer,
+  void sse_encode_colpkg_import_result(
+    ColpkgImportResult self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_notetype_ext(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_String(self.md5, serializer);
+    sse_encode_String(self.version, serializer);
+    sse_encode_list_colpkg_deck(self.decks, serializer);
   }
 
   @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_error_kind(ErrorKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_String(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  SingleNoteResult sse_decode_single_note_result(SseDeserializer deserializer) {
+  void sse_encode_extract_result(ExtractResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_note = sse_decode_note_ext(deserializer);
-    var var_notetype = sse_decode_opt_box_autoadd_notetype_ext(deserializer);
-    var var_fields = sse_decode_list_field_ext(deserializer);
-    var var_ord = sse_decode_i_64(deserializer);
-    var var_front = sse_decode_String(deserializer);
-    var var_back = sse_decode_String(deserializer);
-    var var_css = sse_decode_String(deserializer);
-    return SingleNoteResult(
-      note: var_note,
-      notetype: var_notetype,
-      fields: var_fields,
-      ord: var_ord,
-      front: var_front,
-      back: var_back,
-      css: var_css,
-    );
+    sse_encode_String(self.md5, serializer);
+    sse_encode_Map_String_String_None(self.mediaMap, serializer);
+    sse_encode_String(self.version, serializer);
+    sse_encode_u_32(self.scheduledCardCount, serializer);
+    sse_encode_merge_summary(self.summary, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_field_ext(FieldExt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_i_64(self.notetypeId, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_i_64(self.ord, serializer);
+    sse_encode_String(self.fontName, serializer);
+    sse_encode_i_64(self.fontSize, serializer);
+    sse_encode_bool(self.rtl, serializer);
+    sse_encode_bool(self.sticky, serializer);
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_fsrs_schedule_result(
+    FsrsScheduleResult self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_64(self.due, serializer);
+    sse_encode_f_64(self.stability, serializer);
+    sse_encode_f_64(self.difficulty, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_import_phase(ImportPhase self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_Map_String_String_None(
-    Map<String, String> self,
+  void sse_encode_import_progress(
+    ImportProgress self,
     SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_string(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_import_phase(self.phase, serializer);
+    sse_encode_i_64(self.bytesProcessed, serializer);
+    sse_encode_i_64(self.totalBytes, serializer);
+    sse_encode_u_32(self.entriesDone, serializer);
+    sse_encode_u_32(self.entriesTotal, serializer);
+    sse_encode_opt_String(self.currentFile, serializer);
+    sse_encode_opt_box_autoadd_extract_result(self.result, serializer);
   }
 
   @protected
-  void sse_encode_StreamSink_String_Sse(
-    RustStreamSink<String> self,
+  void sse_encode_intraday_learning_queue(
+    IntradayLearningQueue self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+    sse_encode_list_prim_i_64_strict(self.due, serializer);
+    sse_encode_list_prim_i_64_strict(self.learnAhead, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.nextDue, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_leech_action(LeechAction self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_notetype_ext(
-    NotetypeExt self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_leech_event(LeechEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_notetype_ext(self, serializer);
+    sse_encode_String(self.deckId, serializer);
+    sse_encode_i_64(self.cardId, serializer);
+    sse_encode_i_64(self.noteId, serializer);
+    sse_encode_i_32(self.lapses, serializer);
+    sse_encode_bool(self.suspended, serializer);
   }
 
   @protected
-  void sse_encode_extract_result(ExtractResult self, SseSerializer serializer) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.dir, serializer);
-    sse_encode_String(self.md5, serializer);
-    sse_encode_Map_String_String_None(self.mediaMap, serializer);
-    sse_encode_String(self.version, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
+  void sse_encode_list_batch_card(
+    List<BatchCard> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_batch_card(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_field_ext(FieldExt self, SseSerializer serializer) {
+  void sse_encode_list_batch_notetype(
+    List<BatchNotetype> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.id, serializer);
-    sse_encode_i_64(self.notetypeId, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_i_64(self.ord, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_batch_notetype(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_fsrs_schedule_result(
-    FsrsScheduleResult self,
+  void sse_encode_list_batch_template(
+    List<BatchTemplate> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.due, serializer);
-    sse_encode_f_64(self.stability, serializer);
-    sse_encode_f_64(self.difficulty, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_batch_template(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_list_card_ext(List<CardExt> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_card_ext(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_list_card_status(
+    List<CardStatus> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_String(item, serializer);
+      sse_encode_card_status(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_colpkg_deck(
+    List<ColpkgDeck> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_colpkg_deck(item, serializer);
     }
   }
 
@@ -1089,6 +4383,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_prim_f_64_strict(
+    Float64List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat64List(self);
+  }
+
   @protected
   void sse_encode_list_prim_i_64_strict(
     Int64List self,
@@ -1109,6 +4413,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_list_queued_card(
+    List<QueuedCard> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_queued_card(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_record_string_string(
     List<(String, String)> self,
@@ -1121,6 +4437,87 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_review_log_entry(
+    List<ReviewLogEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_review_log_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_schedule_preview(
+    List<SchedulePreview> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_schedule_preview(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_merge_result(MergeResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.md5, serializer);
+    sse_encode_String(self.version, serializer);
+    sse_encode_Map_String_String_None(self.mediaMap, serializer);
+    sse_encode_merge_summary(self.summary, serializer);
+  }
+
+  @protected
+  void sse_encode_merge_summary(MergeSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.addedNotes, serializer);
+    sse_encode_u_32(self.updatedNotes, serializer);
+    sse_encode_u_32(self.unchangedNotes, serializer);
+    sse_encode_u_32(self.removedNotes, serializer);
+    sse_encode_u_32(self.skippedNotes, serializer);
+    sse_encode_u_32(self.addedNotetypes, serializer);
+    sse_encode_u_32(self.updatedNotetypes, serializer);
+    sse_encode_list_prim_i_64_strict(self.addedNoteIds, serializer);
+    sse_encode_list_prim_i_64_strict(self.removedNoteIds, serializer);
+    sse_encode_list_prim_i_64_strict(self.addedCardIds, serializer);
+    sse_encode_list_prim_i_64_strict(self.removedCardIds, serializer);
+  }
+
+  @protected
+  void sse_encode_new_card_gather_priority(
+    NewCardGatherPriority self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_new_card_queue(NewCardQueue self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_queued_card(self.cards, serializer);
+    sse_encode_u_32(self.introducedToday, serializer);
+    sse_encode_u_32(self.available, serializer);
+  }
+
+  @protected
+  void sse_encode_new_card_sort_order(
+    NewCardSortOrder self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_new_review_mix(NewReviewMix self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_note_ext(NoteExt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1150,6 +4547,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_extract_result(
+    ExtractResult? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_extract_result(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_notetype_ext(
     NotetypeExt? self,
@@ -1163,6 +4586,75 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_optimize_result(
+    OptimizeResult? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_optimize_result(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_optimize_progress(
+    OptimizeProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.current, serializer);
+    sse_encode_u_32(self.total, serializer);
+    sse_encode_opt_box_autoadd_optimize_result(self.result, serializer);
+  }
+
+  @protected
+  void sse_encode_optimize_result(
+    OptimizeResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_f_64_strict(self.weights, serializer);
+    sse_encode_f_64(self.logLossBefore, serializer);
+    sse_encode_f_64(self.logLossAfter, serializer);
+    sse_encode_f_64(self.rmseBefore, serializer);
+    sse_encode_f_64(self.rmseAfter, serializer);
+    sse_encode_u_32(self.reviewCount, serializer);
+  }
+
+  @protected
+  void sse_encode_queue_kind(QueueKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_queue_limits(QueueLimits self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_32(self.newCards, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.reviews, serializer);
+  }
+
+  @protected
+  void sse_encode_queued_card(QueuedCard self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.cardId, serializer);
+    sse_encode_i_64(self.noteId, serializer);
+    sse_encode_queue_kind(self.kind, serializer);
+  }
+
   @protected
   void sse_encode_record_string_string(
     (String, String) self,
@@ -1173,6 +4665,106 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_rendered_card(RenderedCard self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.cardId, serializer);
+    sse_encode_i_64(self.noteId, serializer);
+    sse_encode_i_64(self.ord, serializer);
+    sse_encode_String(self.question, serializer);
+    sse_encode_String(self.answer, serializer);
+    sse_encode_String(self.css, serializer);
+    sse_encode_list_String(self.diagnostics, serializer);
+  }
+
+  @protected
+  void sse_encode_review_log_entry(
+    ReviewLogEntry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.cardId, serializer);
+    sse_encode_i_64(self.reviewTime, serializer);
+    sse_encode_u_8(self.rating, serializer);
+  }
+
+  @protected
+  void sse_encode_review_queue(ReviewQueue self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_queued_card(self.cards, serializer);
+    sse_encode_u_32(self.newCount, serializer);
+    sse_encode_u_32(self.learningCount, serializer);
+    sse_encode_u_32(self.reviewCount, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.nextLearningDue, serializer);
+  }
+
+  @protected
+  void sse_encode_review_sort_order(
+    ReviewSortOrder self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_schedule_preview(
+    SchedulePreview self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_8(self.rating, serializer);
+    sse_encode_i_64(self.due, serializer);
+    sse_encode_i_64(self.intervalSeconds, serializer);
+    sse_encode_String(self.intervalText, serializer);
+    sse_encode_card_memory_state(self.card, serializer);
+  }
+
+  @protected
+  void sse_encode_scheduler_algorithm(
+    SchedulerAlgorithm self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_scheduler_config(
+    SchedulerConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_scheduler_algorithm(self.algorithm, serializer);
+    sse_encode_list_prim_f_64_strict(self.weights, serializer);
+    sse_encode_f_64(self.requestRetention, serializer);
+    sse_encode_i_32(self.maximumInterval, serializer);
+    sse_encode_bool(self.enableFuzz, serializer);
+    sse_encode_bool(self.enableShortTerm, serializer);
+    sse_encode_list_prim_f_64_strict(self.learnSteps, serializer);
+    sse_encode_list_prim_f_64_strict(self.relearnSteps, serializer);
+    sse_encode_i_32(self.graduatingIntervalGood, serializer);
+    sse_encode_i_32(self.graduatingIntervalEasy, serializer);
+    sse_encode_f_64(self.initialEase, serializer);
+    sse_encode_f_64(self.easyMultiplier, serializer);
+    sse_encode_f_64(self.hardMultiplier, serializer);
+    sse_encode_f_64(self.lapseMultiplier, serializer);
+    sse_encode_f_64(self.intervalMultiplier, serializer);
+    sse_encode_i_32(self.minimumLapseInterval, serializer);
+    sse_encode_i_32(self.learnAheadSecs, serializer);
+    sse_encode_u_32(self.newPerDay, serializer);
+    sse_encode_new_card_gather_priority(self.newCardGatherPriority, serializer);
+    sse_encode_new_card_sort_order(self.newCardSortOrder, serializer);
+    sse_encode_u_32(self.reviewsPerDay, serializer);
+    sse_encode_review_sort_order(self.reviewSortOrder, serializer);
+    sse_encode_new_review_mix(self.newMix, serializer);
+    sse_encode_bool(self.buryNew, serializer);
+    sse_encode_bool(self.buryReviews, serializer);
+    sse_encode_bool(self.buryInterdayLearning, serializer);
+    sse_encode_u_32(self.leechThreshold, serializer);
+    sse_encode_leech_action(self.leechAction, serializer);
+  }
+
   @protected
   void sse_encode_single_note_result(
     SingleNoteResult self,
@@ -1186,6 +4778,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.front, serializer);
     sse_encode_String(self.back, serializer);
     sse_encode_String(self.css, serializer);
+    sse_encode_String(self.latexPre, serializer);
+    sse_encode_String(self.latexPost, serializer);
+    sse_encode_i_64(self.sortFieldIdx, serializer);
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
   }
 
   @protected
@@ -1204,16 +4805,4 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putBigUint64(self);
   }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
 }
@@ -6,6 +6,7 @@
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/error.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -29,12 +30,85 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<ImportProgress> dco_decode_StreamSink_import_progress_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<LeechEvent> dco_decode_StreamSink_leech_event_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<OptimizeProgress> dco_decode_StreamSink_optimize_progress_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AnkiError dco_decode_anki_error(dynamic raw);
+
+  @protected
+  BatchCard dco_decode_batch_card(dynamic raw);
+
+  @protected
+  BatchNotetype dco_decode_batch_notetype(dynamic raw);
+
+  @protected
+  BatchTemplate dco_decode_batch_template(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  CardMemoryState dco_decode_box_autoadd_card_memory_state(dynamic raw);
+
+  @protected
+  ExtractResult dco_decode_box_autoadd_extract_result(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
   @protected
   NotetypeExt dco_decode_box_autoadd_notetype_ext(dynamic raw);
 
+  @protected
+  OptimizeResult dco_decode_box_autoadd_optimize_result(dynamic raw);
+
+  @protected
+  QueueLimits dco_decode_box_autoadd_queue_limits(dynamic raw);
+
+  @protected
+  SchedulerConfig dco_decode_box_autoadd_scheduler_config(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  CardExt dco_decode_card_ext(dynamic raw);
+
+  @protected
+  CardMemoryState dco_decode_card_memory_state(dynamic raw);
+
+  @protected
+  CardState dco_decode_card_state(dynamic raw);
+
+  @protected
+  CardStatus dco_decode_card_status(dynamic raw);
+
+  @protected
+  CardsBatch dco_decode_cards_batch(dynamic raw);
+
+  @protected
+  ColpkgDeck dco_decode_colpkg_deck(dynamic raw);
+
+  @protected
+  ColpkgImportResult dco_decode_colpkg_import_result(dynamic raw);
+
+  @protected
+  ErrorKind dco_decode_error_kind(dynamic raw);
+
   @protected
   ExtractResult dco_decode_extract_result(dynamic raw);
 
@@ -47,24 +121,90 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FsrsScheduleResult dco_decode_fsrs_schedule_result(dynamic raw);
 
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  ImportPhase dco_decode_import_phase(dynamic raw);
+
+  @protected
+  ImportProgress dco_decode_import_progress(dynamic raw);
+
+  @protected
+  IntradayLearningQueue dco_decode_intraday_learning_queue(dynamic raw);
+
+  @protected
+  LeechAction dco_decode_leech_action(dynamic raw);
+
+  @protected
+  LeechEvent dco_decode_leech_event(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<BatchCard> dco_decode_list_batch_card(dynamic raw);
+
+  @protected
+  List<BatchNotetype> dco_decode_list_batch_notetype(dynamic raw);
+
+  @protected
+  List<BatchTemplate> dco_decode_list_batch_template(dynamic raw);
+
+  @protected
+  List<CardExt> dco_decode_list_card_ext(dynamic raw);
+
+  @protected
+  List<CardStatus> dco_decode_list_card_status(dynamic raw);
+
+  @protected
+  List<ColpkgDeck> dco_decode_list_colpkg_deck(dynamic raw);
+
   @protected
   List<FieldExt> dco_decode_list_field_ext(dynamic raw);
 
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
+
   @protected
   Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
 
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<QueuedCard> dco_decode_list_queued_card(dynamic raw);
+
   @protected
   List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
 
+  @protected
+  List<ReviewLogEntry> dco_decode_list_review_log_entry(dynamic raw);
+
+  @protected
+  List<SchedulePreview> dco_decode_list_schedule_preview(dynamic raw);
+
+  @protected
+  MergeResult dco_decode_merge_result(dynamic raw);
+
+  @protected
+  MergeSummary dco_decode_merge_summary(dynamic raw);
+
+  @protected
+  NewCardGatherPriority dco_decode_new_card_gather_priority(dynamic raw);
+
+  @protected
+  NewCardQueue dco_decode_new_card_queue(dynamic raw);
+
+  @protected
+  NewCardSortOrder dco_decode_new_card_sort_order(dynamic raw);
+
+  @protected
+  NewReviewMix dco_decode_new_review_mix(dynamic raw);
+
   @protected
   NoteExt dco_decode_note_ext(dynamic raw);
 
@@ -74,15 +214,66 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  ExtractResult? dco_decode_opt_box_autoadd_extract_result(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
   @protected
   NotetypeExt? dco_decode_opt_box_autoadd_notetype_ext(dynamic raw);
 
+  @protected
+  OptimizeResult? dco_decode_opt_box_autoadd_optimize_result(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  OptimizeProgress dco_decode_optimize_progress(dynamic raw);
+
+  @protected
+  OptimizeResult dco_decode_optimize_result(dynamic raw);
+
+  @protected
+  QueueKind dco_decode_queue_kind(dynamic raw);
+
+  @protected
+  QueueLimits dco_decode_queue_limits(dynamic raw);
+
+  @protected
+  QueuedCard dco_decode_queued_card(dynamic raw);
+
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw);
 
+  @protected
+  RenderedCard dco_decode_rendered_card(dynamic raw);
+
+  @protected
+  ReviewLogEntry dco_decode_review_log_entry(dynamic raw);
+
+  @protected
+  ReviewQueue dco_decode_review_queue(dynamic raw);
+
+  @protected
+  ReviewSortOrder dco_decode_review_sort_order(dynamic raw);
+
+  @protected
+  SchedulePreview dco_decode_schedule_preview(dynamic raw);
+
+  @protected
+  SchedulerAlgorithm dco_decode_scheduler_algorithm(dynamic raw);
+
+  @protected
+  SchedulerConfig dco_decode_scheduler_config(dynamic raw);
+
   @protected
   SingleNoteResult dco_decode_single_note_result(dynamic raw);
 
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
@@ -105,12 +296,97 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<ImportProgress> sse_decode_StreamSink_import_progress_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<LeechEvent> sse_decode_StreamSink_leech_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<OptimizeProgress> sse_decode_StreamSink_optimize_progress_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AnkiError sse_decode_anki_error(SseDeserializer deserializer);
+
+  @protected
+  BatchCard sse_decode_batch_card(SseDeserializer deserializer);
+
+  @protected
+  BatchNotetype sse_decode_batch_notetype(SseDeserializer deserializer);
+
+  @protected
+  BatchTemplate sse_decode_batch_template(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  CardMemoryState sse_decode_box_autoadd_card_memory_state(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExtractResult sse_decode_box_autoadd_extract_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
   @protected
   NotetypeExt sse_decode_box_autoadd_notetype_ext(SseDeserializer deserializer);
 
+  @protected
+  OptimizeResult sse_decode_box_autoadd_optimize_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QueueLimits sse_decode_box_autoadd_queue_limits(SseDeserializer deserializer);
+
+  @protected
+  SchedulerConfig sse_decode_box_autoadd_scheduler_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  CardExt sse_decode_card_ext(SseDeserializer deserializer);
+
+  @protected
+  CardMemoryState sse_decode_card_memory_state(SseDeserializer deserializer);
+
+  @protected
+  CardState sse_decode_card_state(SseDeserializer deserializer);
+
+  @protected
+  CardStatus sse_decode_card_status(SseDeserializer deserializer);
+
+  @protected
+  CardsBatch sse_decode_cards_batch(SseDeserializer deserializer);
+
+  @protected
+  ColpkgDeck sse_decode_colpkg_deck(SseDeserializer deserializer);
+
+  @protected
+  ColpkgImportResult sse_decode_colpkg_import_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ErrorKind sse_decode_error_kind(SseDeserializer deserializer);
+
   @protected
   ExtractResult sse_decode_extract_result(SseDeserializer deserializer);
 
@@ -125,26 +401,104 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
   @protected
   PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
+  @protected
+  ImportPhase sse_decode_import_phase(SseDeserializer deserializer);
+
+  @protected
+  ImportProgress sse_decode_import_progress(SseDeserializer deserializer);
+
+  @protected
+  IntradayLearningQueue sse_decode_intraday_learning_queue(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LeechAction sse_decode_leech_action(SseDeserializer deserializer);
+
+  @protected
+  LeechEvent sse_decode_leech_event(SseDeserializer deserializer);
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<BatchCard> sse_decode_list_batch_card(SseDeserializer deserializer);
+
+  @protected
+  List<BatchNotetype> sse_decode_list_batch_notetype(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<BatchTemplate> sse_decode_list_batch_template(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CardExt> sse_decode_list_card_ext(SseDeserializer deserializer);
+
+  @protected
+  List<CardStatus> sse_decode_list_card_status(SseDeserializer deserializer);
+
+  @protected
+  List<ColpkgDeck> sse_decode_list_colpkg_deck(SseDeserializer deserializer);
+
   @protected
   List<FieldExt> sse_decode_list_field_ext(SseDeserializer deserializer);
 
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+
   @protected
   Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
 
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<QueuedCard> sse_decode_list_queued_card(SseDeserializer deserializer);
+
   @protected
   List<(String, String)> sse_decode_list_record_string_string(
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<ReviewLogEntry> sse_decode_list_review_log_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SchedulePreview> sse_decode_list_schedule_preview(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MergeResult sse_decode_merge_result(SseDeserializer deserializer);
+
+  @protected
+  MergeSummary sse_decode_merge_summary(SseDeserializer deserializer);
+
+  @protected
+  NewCardGatherPriority sse_decode_new_card_gather_priority(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NewCardQueue sse_decode_new_card_queue(SseDeserializer deserializer);
+
+  @protected
+  NewCardSortOrder sse_decode_new_card_sort_order(SseDeserializer deserializer);
+
+  @protected
+  NewReviewMix sse_decode_new_review_mix(SseDeserializer deserializer);
+
   @protected
   NoteExt sse_decode_note_ext(SseDeserializer deserializer);
 
@@ -154,33 +508,84 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
+  @protected
+  ExtractResult? sse_decode_opt_box_autoadd_extract_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
   @protected
   NotetypeExt? sse_decode_opt_box_autoadd_notetype_ext(
     SseDeserializer deserializer,
   );
 
+  @protected
+  OptimizeResult? sse_decode_opt_box_autoadd_optimize_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  OptimizeProgress sse_decode_optimize_progress(SseDeserializer deserializer);
+
+  @protected
+  OptimizeResult sse_decode_optimize_result(SseDeserializer deserializer);
+
+  @protected
+  QueueKind sse_decode_queue_kind(SseDeserializer deserializer);
+
+  @protected
+  QueueLimits sse_decode_queue_limits(SseDeserializer deserializer);
+
+  @protected
+  QueuedCard sse_decode_queued_card(SseDeserializer deserializer);
+
   @protected
   (String, String) sse_decode_record_string_string(
     SseDeserializer deserializer,
   );
 
   @protected
-  SingleNoteResult sse_decode_single_note_result(SseDeserializer deserializer);
+  RenderedCard sse_decode_rendered_card(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  ReviewLogEntry sse_decode_review_log_entry(SseDeserializer deserializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  ReviewQueue sse_decode_review_queue(SseDeserializer deserializer);
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  ReviewSortOrder sse_decode_review_sort_order(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  SchedulePreview sse_decode_schedule_preview(SseDeserializer deserializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  SchedulerAlgorithm sse_decode_scheduler_algorithm(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SchedulerConfig sse_decode_scheduler_config(SseDeserializer deserializer);
+
+  @protected
+  SingleNoteResult sse_decode_single_note_result(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
 
   @protected
   void sse_encode_AnyhowException(
@@ -200,15 +605,117 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_import_progress_Sse(
+    RustStreamSink<ImportProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_leech_event_Sse(
+    RustStreamSink<LeechEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_optimize_progress_Sse(
+    RustStreamSink<OptimizeProgress> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_anki_error(AnkiError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_batch_card(BatchCard self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_batch_notetype(BatchNotetype self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_batch_template(BatchTemplate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_card_memory_state(
+    CardMemoryState self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_extract_result(
+    ExtractResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_notetype_ext(
     NotetypeExt self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_optimize_result(
+    OptimizeResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_queue_limits(
+    QueueLimits self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_scheduler_config(
+    SchedulerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_card_ext(CardExt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_card_memory_state(
+    CardMemoryState self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_card_state(CardState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_card_status(CardStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cards_batch(CardsBatch self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_colpkg_deck(ColpkgDeck self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_colpkg_import_result(
+    ColpkgImportResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_error_kind(ErrorKind self, SseSerializer serializer);
+
   @protected
   void sse_encode_extract_result(ExtractResult self, SseSerializer serializer);
 
@@ -224,15 +731,78 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_import_phase(ImportPhase self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_progress(
+    ImportProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_intraday_learning_queue(
+    IntradayLearningQueue self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_leech_action(LeechAction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_leech_event(LeechEvent self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_batch_card(
+    List<BatchCard> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_batch_notetype(
+    List<BatchNotetype> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_batch_template(
+    List<BatchTemplate> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_card_ext(List<CardExt> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_card_status(
+    List<CardStatus> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_colpkg_deck(
+    List<ColpkgDeck> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_field_ext(List<FieldExt> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_prim_f_64_strict(
+    Float64List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_i_64_strict(
     Int64List self,
@@ -245,12 +815,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_queued_card(
+    List<QueuedCard> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_record_string_string(
     List<(String, String)> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_review_log_entry(
+    List<ReviewLogEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_schedule_preview(
+    List<SchedulePreview> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_merge_result(MergeResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_merge_summary(MergeSummary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_new_card_gather_priority(
+    NewCardGatherPriority self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_new_card_queue(NewCardQueue self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_new_card_sort_order(
+    NewCardSortOrder self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_new_review_mix(NewReviewMix self, SseSerializer serializer);
+
   @protected
   void sse_encode_note_ext(NoteExt self, SseSerializer serializer);
 
@@ -260,24 +872,105 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_extract_result(
+    ExtractResult? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_notetype_ext(
     NotetypeExt? self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_optimize_result(
+    OptimizeResult? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_optimize_progress(
+    OptimizeProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_optimize_result(
+    OptimizeResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_queue_kind(QueueKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_queue_limits(QueueLimits self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_queued_card(QueuedCard self, SseSerializer serializer);
+
   @protected
   void sse_encode_record_string_string(
     (String, String) self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_rendered_card(RenderedCard self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_review_log_entry(
+    ReviewLogEntry self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_review_queue(ReviewQueue self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_review_sort_order(
+    ReviewSortOrder self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_schedule_preview(
+    SchedulePreview self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_scheduler_algorithm(
+    SchedulerAlgorithm self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_scheduler_config(
+    SchedulerConfig self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_single_note_result(
     SingleNoteResult self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
@@ -286,12 +979,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -4,6 +4,9 @@ import '../providers.dart';
 import '../model.dart';
 import '../pages/card_review_page.dart';
 import '../db.dart';
+import 'package:file_picker/file_picker.dart';
+import 'package:open_anki/src/rust/api/simple.dart';
+import '../error_message.dart';
 
 class DeckProgressTile extends StatelessWidget {
   final DeckInfo deck;
@@ -39,6 +42,7 @@ class DeckProgressTile extends StatelessWidget {
       items: [
         const PopupMenuItem(value: 'preview', child: Text('自由浏览')),
         const PopupMenuItem(value: 'rename', child: Text('重命名')),
+        const PopupMenuItem(value: 'update', child: Text('从新版本更新')),
         const PopupMenuItem(value: 'reset', child: Text('重置学习进度')),
         const PopupMenuItem(value: 'delete', child: Text('删除')),
       ],
@@ -59,6 +63,9 @@ class DeckProgressTile extends StatelessWidget {
           await AppDb.renameDeck(deck.deckId, newName);
         }
         break;
+      case 'update':
+        await _updateFromNewVersion(context);
+        break;
       case 'preview':
         Navigator.push(
           context,
@@ -129,6 +136,57 @@ class DeckProgressTile extends StatelessWidget {
     }
   }
 
+  // 用新版本的 apkg 更新题库，按 guid 合并笔记，已有卡片的学习进度保持不变
+  Future<void> _updateFromNewVersion(BuildContext context) async {
+    final picked = await FilePicker.platform.pickFiles();
+    final path = picked?.files.single.path;
+    if (path == null || !context.mounted) return;
+    final removeMissing = await showDialog<bool>(
+      context: context,
+      builder: (context) => AlertDialog(
+        title: const Text('从新版本更新'),
+        content: const Text('新版本里已经删除的笔记要一起删除吗？删除的卡片学习进度无法恢复。'),
+        actions: [
+          TextButton(
+            onPressed: () => Navigator.pop(context, false),
+            child: const Text('保留'),
+          ),
+          TextButton(
+            onPressed: () => Navigator.pop(context, true),
+            child: const Text('删除'),
+          ),
+        ],
+      ),
+    );
+    if (removeMissing == null || !context.mounted) return;
+    final messenger = ScaffoldMessenger.of(context);
+    final container = ProviderScope.containerOf(context);
+    try {
+      final result = await mergeApkg(
+        apkgPath: path,
+        deckId: deck.deckId,
+        removeMissing: removeMissing,
+      );
+      final summary = result.summary;
+      final cardCount = await getCardCount(deckId: deck.deckId);
+      // 本地数据库按笔记 id 记录学习进度
+      await AppDb.applyDeckUpdate(
+        deck.deckId,
+        addedCardIds: summary.addedNoteIds.toList(),
+        removedCardIds: summary.removedNoteIds.toList(),
+        cardCount: cardCount,
+      );
+      container.invalidate(allDecksProvider);
+      container.invalidate(recentDecksProvider);
+      messenger.showSnackBar(SnackBar(
+        content: Text('更新完成：新增 ${summary.addedNotes}，修改 ${summary.updatedNotes}，'
+            '删除 ${summary.removedNotes}，跳过 ${summary.skippedNotes}'),
+      ));
+    } catch (e) {
+      messenger.showSnackBar(SnackBar(content: Text('更新失败: ${context.mounted ? errorMessage(context, e) : e}')));
+    }
+  }
+
   @override
   Widget build(BuildContext context) {
     final cardCount = deck.cardCount;
@@ -0,0 +1,249 @@
+// anki21b (schema 18) 中 notetypes/fields/templates/deck_config 表的 config 列和 decks 表的 kind 列是 protobuf 编码的，
+// 这里按 Anki 源码 proto/anki/notetypes.proto、deck_config.proto 和 decks.proto 手写对应的消息定义，字段号必须保持一致。
+// 新版 apkg 里的 meta 和 media 文件也是 protobuf，定义在 proto/anki/import_export.proto。
+// 未用到的字段也保留，避免以后需要时再去对字段号。
+
+use prost::Message;
+use crate::error::{AnkiError, ResultExt};
+
+/// Notetype.Config.Kind
+pub const NOTETYPE_KIND_CLOZE: i32 = 1;
+
+/// Notetype.Config
+#[derive(Clone, PartialEq, Message)]
+pub struct NotetypeConfig {
+    #[prost(int32, tag = "1")]
+    pub kind: i32,
+    #[prost(uint32, tag = "2")]
+    pub sort_field_idx: u32,
+    #[prost(string, tag = "3")]
+    pub css: String,
+    #[prost(int64, tag = "4")]
+    pub target_deck_id_unused: i64,
+    #[prost(string, tag = "5")]
+    pub latex_pre: String,
+    #[prost(string, tag = "6")]
+    pub latex_post: String,
+    #[prost(bool, tag = "7")]
+    pub latex_svg: bool,
+    #[prost(message, repeated, tag = "8")]
+    pub reqs: Vec<CardRequirement>,
+    #[prost(int32, tag = "9")]
+    pub original_stock_kind: i32,
+    #[prost(int64, optional, tag = "10")]
+    pub original_id: Option<i64>,
+    #[prost(bytes = "vec", tag = "255")]
+    pub other: Vec<u8>,
+}
+
+/// Notetype.Config.CardRequirement
+#[derive(Clone, PartialEq, Message)]
+pub struct CardRequirement {
+    #[prost(uint32, tag = "1")]
+    pub card_ord: u32,
+    #[prost(int32, tag = "2")]
+    pub kind: i32,
+    #[prost(uint32, repeated, tag = "3")]
+    pub field_ords: Vec<u32>,
+}
+
+/// Notetype.Field.Config
+#[derive(Clone, PartialEq, Message)]
+pub struct FieldConfig {
+    #[prost(bool, tag = "1")]
+    pub sticky: bool,
+    #[prost(bool, tag = "2")]
+    pub rtl: bool,
+    #[prost(string, tag = "3")]
+    pub font_name: String,
+    #[prost(uint32, tag = "4")]
+    pub font_size: u32,
+    #[prost(string, tag = "5")]
+    pub description: String,
+    #[prost(bool, tag = "6")]
+    pub plain_text: bool,
+    #[prost(bool, tag = "7")]
+    pub collapsed: bool,
+    #[prost(bool, tag = "8")]
+    pub exclude_from_search: bool,
+    #[prost(int64, optional, tag = "9")]
+    pub id: Option<i64>,
+    #[prost(uint32, optional, tag = "10")]
+    pub tag: Option<u32>,
+    #[prost(bool, tag = "11")]
+    pub prevent_deletion: bool,
+    #[prost(bytes = "vec", tag = "255")]
+    pub other: Vec<u8>,
+}
+
+/// Notetype.Template.Config
+#[derive(Clone, PartialEq, Message)]
+pub struct TemplateConfig {
+    #[prost(string, tag = "1")]
+    pub q_format: String,
+    #[prost(string, tag = "2")]
+    pub a_format: String,
+    #[prost(string, tag = "3")]
+    pub q_format_browser: String,
+    #[prost(string, tag = "4")]
+    pub a_format_browser: String,
+    #[prost(int64, tag = "5")]
+    pub target_deck_id: i64,
+    #[prost(string, tag = "6")]
+    pub browser_font_name: String,
+    #[prost(uint32, tag = "7")]
+    pub browser_font_size: u32,
+    #[prost(int64, optional, tag = "8")]
+    pub id: Option<i64>,
+    #[prost(bytes = "vec", tag = "255")]
+    pub other: Vec<u8>,
+}
+
+/// DeckConfig.Config，调度相关的牌组选项
+#[derive(Clone, PartialEq, Message)]
+pub struct DeckConfigConfig {
+    #[prost(float, repeated, tag = "1")]
+    pub learn_steps: Vec<f32>,
+    #[prost(float, repeated, tag = "2")]
+    pub relearn_steps: Vec<f32>,
+    #[prost(float, repeated, tag = "3")]
+    pub fsrs_params_4: Vec<f32>,
+    #[prost(float, repeated, tag = "5")]
+    pub fsrs_params_5: Vec<f32>,
+    #[prost(float, repeated, tag = "6")]
+    pub fsrs_params_6: Vec<f32>,
+    #[prost(uint32, tag = "9")]
+    pub new_per_day: u32,
+    #[prost(uint32, tag = "10")]
+    pub reviews_per_day: u32,
+    #[prost(float, tag = "11")]
+    pub initial_ease: f32,
+    #[prost(float, tag = "12")]
+    pub easy_multiplier: f32,
+    #[prost(float, tag = "13")]
+    pub hard_multiplier: f32,
+    #[prost(float, tag = "14")]
+    pub lapse_multiplier: f32,
+    #[prost(float, tag = "15")]
+    pub interval_multiplier: f32,
+    #[prost(uint32, tag = "16")]
+    pub maximum_review_interval: u32,
+    #[prost(uint32, tag = "17")]
+    pub minimum_lapse_interval: u32,
+    #[prost(uint32, tag = "18")]
+    pub graduating_interval_good: u32,
+    #[prost(uint32, tag = "19")]
+    pub graduating_interval_easy: u32,
+    #[prost(int32, tag = "20")]
+    pub new_card_insert_order: i32,
+    #[prost(int32, tag = "21")]
+    pub leech_action: i32,
+    #[prost(uint32, tag = "22")]
+    pub leech_threshold: u32,
+    #[prost(bool, tag = "27")]
+    pub bury_new: bool,
+    #[prost(bool, tag = "28")]
+    pub bury_reviews: bool,
+    #[prost(bool, tag = "29")]
+    pub bury_interday_learning: bool,
+    #[prost(int32, tag = "30")]
+    pub new_mix: i32,
+    #[prost(int32, tag = "31")]
+    pub interday_learning_mix: i32,
+    #[prost(int32, tag = "32")]
+    pub new_card_sort_order: i32,
+    #[prost(int32, tag = "33")]
+    pub review_order: i32,
+    #[prost(int32, tag = "34")]
+    pub new_card_gather_priority: i32,
+    #[prost(float, tag = "37")]
+    pub desired_retention: f32,
+    #[prost(bytes = "vec", tag = "255")]
+    pub other: Vec<u8>,
+}
+
+/// Deck.Normal
+#[derive(Clone, PartialEq, Message)]
+pub struct DeckNormal {
+    #[prost(int64, tag = "1")]
+    pub config_id: i64,
+    #[prost(uint32, tag = "2")]
+    pub extend_new: u32,
+    #[prost(uint32, tag = "3")]
+    pub extend_review: u32,
+    #[prost(string, tag = "4")]
+    pub description: String,
+    #[prost(bool, tag = "5")]
+    pub markdown_description: bool,
+    #[prost(uint32, optional, tag = "6")]
+    pub review_limit: Option<u32>,
+    #[prost(uint32, optional, tag = "7")]
+    pub new_limit: Option<u32>,
+}
+
+/// Deck.KindContainer，筛选牌组（tag 2）没有自己的选项，这里不解析
+#[derive(Clone, PartialEq, Message)]
+pub struct DeckKindContainer {
+    #[prost(message, optional, tag = "1")]
+    pub normal: Option<DeckNormal>,
+}
+
+/// PackageMetadata.Version
+pub const PACKAGE_VERSION_LEGACY_1: i32 = 1; // collection.anki2
+pub const PACKAGE_VERSION_LEGACY_2: i32 = 2; // collection.anki21
+pub const PACKAGE_VERSION_LATEST: i32 = 3; // collection.anki21b，media 映射和媒体文件都经过 zstd 压缩
+
+/// PackageMetadata，apkg 里的 meta 文件
+#[derive(Clone, PartialEq, Message)]
+pub struct PackageMetadata {
+    #[prost(int32, tag = "1")]
+    pub version: i32,
+}
+
+/// MediaEntries.MediaEntry，在列表中的位置就是 zip 里的数字文件名
+#[derive(Clone, PartialEq, Message)]
+pub struct MediaEntry {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(uint32, tag = "2")]
+    pub size: u32,
+    #[prost(bytes = "vec", tag = "3")]
+    pub sha1: Vec<u8>,
+    #[prost(uint32, optional, tag = "255")]
+    pub legacy_zip_filename: Option<u32>,
+}
+
+/// MediaEntries，新版 apkg 里的 media 文件
+#[derive(Clone, PartialEq, Message)]
+pub struct MediaEntries {
+    #[prost(message, repeated, tag = "1")]
+    pub entries: Vec<MediaEntry>,
+}
+
+pub fn decode_notetype_config(bytes: &[u8]) -> Result<NotetypeConfig, AnkiError> {
+    NotetypeConfig::decode(bytes).context("解析notetype config失败")
+}
+
+pub fn decode_field_config(bytes: &[u8]) -> Result<FieldConfig, AnkiError> {
+    FieldConfig::decode(bytes).context("解析field config失败")
+}
+
+pub fn decode_template_config(bytes: &[u8]) -> Result<TemplateConfig, AnkiError> {
+    TemplateConfig::decode(bytes).context("解析template config失败")
+}
+
+pub fn decode_deck_config(bytes: &[u8]) -> Result<DeckConfigConfig, AnkiError> {
+    DeckConfigConfig::decode(bytes).context("解析deck config失败")
+}
+
+pub fn decode_deck_kind(bytes: &[u8]) -> Result<DeckKindContainer, AnkiError> {
+    DeckKindContainer::decode(bytes).context("解析deck kind失败")
+}
+
+pub fn decode_package_metadata(bytes: &[u8]) -> Result<PackageMetadata, AnkiError> {
+    PackageMetadata::decode(bytes).context("解析apkg meta失败")
+}
+
+pub fn decode_media_entries(bytes: &[u8]) -> Result<MediaEntries, AnkiError> {
+    MediaEntries::decode(bytes).context("解析media映射失败")
+}
@@ -0,0 +1,29 @@
+// 接口统一返回的错误。kind 用来在 Flutter 端区分错误、按语言显示提示；context 和 message 是给日志看的中文说明。
+// 构造和转换在 crate::error 里
+
+/// 错误类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    NotInitialized,   // 集合库还没有初始化
+    NotFound,         // 文件、牌组、笔记或卡片不存在
+    InvalidPackage,   // 不是有效的 zip，或者 apkg/colpkg 里没有可用的集合
+    UnsafeEntry,      // apkg 里的条目名或媒体文件名不安全
+    TooLarge,         // 解压后超过大小限制
+    DiskFull,
+    PermissionDenied,
+    Io,
+    Database,
+    Decode,           // protobuf、JSON 或 zstd 数据无法解析
+    InvalidArgument,  // 参数不合法，如 rating 或调度配置超出范围
+    Scheduler,        // FSRS 计算或参数训练失败
+    Cancelled,
+}
+
+/// 接口返回的错误
+#[derive(Debug, Clone)]
+pub struct AnkiError {
+    pub kind: ErrorKind,
+    pub context: String,      // 出错时在做的事，如 “打开sqlite失败”
+    pub message: String,      // 底层错误的原文，没有时为空
+    pub path: Option<String>, // 相关的文件路径或 apkg 条目名
+}
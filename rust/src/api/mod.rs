@@ -1,2 +1,3 @@
+pub mod error;
 pub mod simple;
 pub use simple::{ApkgParseResult, Note};
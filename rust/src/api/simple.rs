@@ -9,35 +9,54 @@ pub fn init_app() {
     flutter_rust_bridge::setup_default_user_utils();
 }
 
-use std::fs::File;
-use std::io::Read;
 use std::collections::HashMap;
-use rusqlite::Connection;
-use zip::ZipArchive;
+use std::collections::hash_map::Entry;
+use rusqlite::{Connection, OptionalExtension};
 use serde::{Serialize, Deserialize};
-use std::path::PathBuf;
+use std::path::Path;
 use std::fs;
-use zstd::stream::decode_all;
-use md5::compute;
-use std::io::Write;
-use itertools::Itertools;
 use crate::frb_generated::StreamSink;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
 use lazy_static::lazy_static;
-use rs_fsrs::{FSRS, Card, Rating};
+use rs_fsrs::Rating;
+use crate::apkg::{stage_package, ImportTracker, MEDIA_DIR, SQLITE_FILE};
+use crate::collection;
+use crate::collection::{card_state, cloze_cards};
+use crate::collection::import::{import_collection, DeckFilter};
+use crate::collection::media::{forget_media, scan_media, MediaFile};
+use crate::collection::upgrade::upgrade_legacy;
+use crate::colpkg::split_collection;
+use crate::anki_proto::{decode_notetype_config, decode_field_config, decode_template_config, NOTETYPE_KIND_CLOZE};
+use crate::template::render_question_answer;
+use crate::scheduler::{build_scheduler, format_interval, rating_from_u8, rating_to_u8};
+use crate::scheduler::bootstrap::{bootstrap_collection, load_card_memory_state};
+use crate::scheduler::steps::split_learning_queue;
+use crate::scheduler::storage::{load_intraday_learning, save_answer};
+use crate::scheduler::bury::{bury_siblings, unbury};
+use crate::scheduler::leech::{handle as handle_leech, is_leech};
+use crate::scheduler::queue::{build_new_queue, build_queue, today};
+use crate::scheduler::timing;
+use crate::scheduler::optimizer::{build_card_histories, optimize as optimize_weights};
+use crate::scheduler::config::{default_weights as default_scheduler_weights, has_saved as has_saved_scheduler_config, imported_config, load as load_scheduler_config, save as save_scheduler_config};
+use crate::error::{AnkiError, ErrorKind, ResultExt};
+use std::collections::BTreeSet;
 use chrono::{Utc, TimeZone};
 
 lazy_static! {
     static ref LOG_SINK: Mutex<Option<StreamSink<String>>> = Mutex::new(None);
+    static ref LEECH_SINK: Mutex<Option<StreamSink<LeechEvent>>> = Mutex::new(None);
+    // 正在进行的导入任务的取消标记，按调用方给的 task_id 索引
+    static ref IMPORT_CANCEL_FLAGS: Mutex<HashMap<String, Arc<AtomicBool>>> = Mutex::new(HashMap::new());
 }
 
 #[flutter_rust_bridge::frb]
 pub fn register_log_callback(sink: StreamSink<String>) {
-    *LOG_SINK.lock().unwrap() = Some(sink);
+    *LOG_SINK.lock().unwrap_or_else(|e| e.into_inner()) = Some(sink);
 }
 
-fn rust_log(msg: &str) {
-    if let Some(sink) = &*LOG_SINK.lock().unwrap() {
+pub(crate) fn rust_log(msg: &str) {
+    if let Some(sink) = &*LOG_SINK.lock().unwrap_or_else(|e| e.into_inner()) {
         let _ = sink.add(msg.to_string());
     }
 }
@@ -62,214 +81,333 @@ pub struct ApkgParseResult {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ExtractResult {
-    pub dir: String,
-    pub md5: String,
+    pub md5: String, // 牌组 id，之后按它查询牌组
     pub media_map: HashMap<String, String>, // 文件名 -> 数字编号的映射
     pub version: String, // 新增：anki2/anki21b/anki21
+    pub scheduled_card_count: u32, // 从复习记录或 SM-2 参数恢复了记忆状态的卡片数
+    pub summary: MergeSummary, // 同一个文件再次导入时合并进已有牌组，保留学习进度
 }
 
+/// 初始化并打开集合库，base_dir 是应用的 anki_data 目录，其他接口都要在这之后调用。
+/// 以前每个牌组一个 sqlite 的目录会导入集合库，返回导入的牌组数
 #[flutter_rust_bridge::frb]
-pub fn extract_apkg(apkg_path: String, base_dir: String) -> Result<ExtractResult, String> {
+pub fn init_collection(base_dir: String) -> Result<u32, AnkiError> {
+    rust_log(&format!("DEBUG: init_collection 被调用, base_dir={}", base_dir));
+    collection::init(Path::new(&base_dir)).inspect_err(|e| rust_log(&format!("DEBUG: 初始化集合失败: {}", e)))
+}
+
+/// 重新打开 close_collection 关闭的集合库，应用回到前台时调用；已经打开时什么都不做
+#[flutter_rust_bridge::frb]
+pub fn open_collection() -> Result<(), AnkiError> {
+    rust_log("DEBUG: open_collection 被调用");
+    collection::open().inspect_err(|e| rust_log(&format!("DEBUG: 打开集合失败: {}", e)))
+}
+
+/// 关闭集合库的连接，应用进入后台时调用；之后要先调用 open_collection 才能使用其他接口
+#[flutter_rust_bridge::frb]
+pub fn close_collection() {
+    rust_log("DEBUG: close_collection 被调用");
+    collection::close();
+}
+
+/// 媒体文件所在的目录，所有牌组共用
+#[flutter_rust_bridge::frb]
+pub fn get_media_dir() -> Result<String, AnkiError> {
+    Ok(collection::media_dir()?.to_string_lossy().to_string())
+}
+
+#[flutter_rust_bridge::frb]
+pub fn extract_apkg(apkg_path: String) -> Result<ExtractResult, AnkiError> {
     rust_log(&format!("DEBUG: extract_apkg 被调用"));
-    // 1. 计算md5
-    let mut file = File::open(&apkg_path).map_err(|e| format!("无法打开apkg文件: {e}"))?;
-    let mut buf = Vec::new();
-    file.read_to_end(&mut buf).map_err(|e| format!("读取apkg失败: {e}"))?;
-    let md5str = format!("{:x}", compute(&buf));
-    // 2. 建立目录
-    let deck_dir = PathBuf::from(&base_dir).join(&md5str);
-    // 如果目录已存在，递归删除
-    if deck_dir.exists() {
-        rust_log(&format!("DEBUG: 递归删除已存在的deck目录: {}", deck_dir.display()));
-        fs::remove_dir_all(&deck_dir).map_err(|e| format!("递归删除deck目录失败: {} - {}", deck_dir.display(), e))?;
-    }
-    fs::create_dir_all(&deck_dir).map_err(|e| format!("创建目录失败: {e}"))?;
-    // 3. 解压apkg
-    let file = File::open(&apkg_path).map_err(|e| format!("无法打开apkg文件: {e}"))?;
-    let mut zip = ZipArchive::new(file).map_err(|e| format!("不是有效的apkg/zip文件: {e}"))?;
-    for i in 0..zip.len() {
-        let mut entry = zip.by_index(i).map_err(|e| format!("读取zip entry失败: {e}"))?;
-        let outpath = deck_dir.join(entry.name());
-        if entry.is_dir() {
-            fs::create_dir_all(&outpath).map_err(|e| format!("创建子目录失败: {e}"))?;
-        } else {
-            if let Some(parent) = outpath.parent() {
-                fs::create_dir_all(parent).map_err(|e| format!("创建父目录失败: {e}"))?;
-            }
-            let mut outfile = File::create(&outpath).map_err(|e| format!("创建文件失败: {e}"))?;
-            std::io::copy(&mut entry, &mut outfile).map_err(|e| format!("写入文件失败: {e}"))?;
-        }
+    let cancelled = AtomicBool::new(false);
+    let mut ignore = |_: &ImportProgress| {};
+    let mut tracker = ImportTracker::new(&cancelled, &mut ignore);
+    import_apkg(&apkg_path, &mut tracker)
+}
+
+/// 导入阶段
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImportPhase {
+    Extracting, // 解包 zip
+    Hashing,    // 单独计算 MD5（只有 apkg 无法顺序读取时才有）
+    Finalizing, // 整理媒体文件
+    Scheduling, // 恢复卡片记忆状态、导入集合库
+    Done,
+    Cancelled,
+}
+
+/// 导入进度，最后一条消息（phase 为 Done）带上导入结果
+#[derive(Debug, Clone)]
+pub struct ImportProgress {
+    pub phase: ImportPhase,
+    pub bytes_processed: i64, // 当前阶段已读取的 apkg 字节数
+    pub total_bytes: i64,
+    pub entries_done: u32,
+    pub entries_total: u32, // 读不到 zip 目录时为 0
+    pub current_file: Option<String>, // 正在解包的 zip 条目
+    pub result: Option<ExtractResult>,
+}
+
+// 导入任务的取消标记，登记在 IMPORT_CANCEL_FLAGS 里，任务结束（包括出错）时删掉
+struct CancelFlag {
+    task_id: String,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelFlag {
+    fn register(task_id: &str) -> Self {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        IMPORT_CANCEL_FLAGS.lock().unwrap_or_else(|e| e.into_inner()).insert(task_id.to_string(), cancelled.clone());
+        CancelFlag { task_id: task_id.to_string(), cancelled }
     }
-    // 4. 处理 collection 文件
-    let anki21b = deck_dir.join("collection.anki21b");
-    let anki21 = deck_dir.join("collection.anki21"); // 新增 anki21
-    let anki2 = deck_dir.join("collection.anki2");
-    let sqlite_path = deck_dir.join("collection.sqlite");
-
-    rust_log(&format!("DEBUG: 检查 collection 文件"));
-    rust_log(&format!("DEBUG: anki21b 路径: {}", anki21b.display()));
-    rust_log(&format!("DEBUG: anki21 路径: {}", anki21.display()));
-    rust_log(&format!("DEBUG: anki2 路径: {}", anki2.display()));
-    rust_log(&format!("DEBUG: sqlite 目标路径: {}", sqlite_path.display()));
-    rust_log(&format!("DEBUG: anki21b 存在: {}", anki21b.exists()));
-    rust_log(&format!("DEBUG: anki21 存在: {}", anki21.exists()));
-    rust_log(&format!("DEBUG: anki2 存在: {}", anki2.exists()));
-
-    let version = if anki21b.exists() {
-        // 新版：zstd 解压
-        rust_log(&format!("DEBUG: 开始处理 anki21b"));
-        let mut zstd_file = File::open(&anki21b).map_err(|e| format!("打开anki21b失败: {e}"))?;
-        let mut zstd_bytes = Vec::new();
-        zstd_file.read_to_end(&mut zstd_bytes).map_err(|e| format!("读取anki21b失败: {e}"))?;
-        rust_log(&format!("DEBUG: anki21b 大小: {} bytes", zstd_bytes.len()));
-        let sqlite_bytes = decode_all(&zstd_bytes[..]).map_err(|e| format!("zstd解压失败: {e}"))?;
-        rust_log(&format!("DEBUG: 解压后大小: {} bytes", sqlite_bytes.len()));
-        let mut out = File::create(&sqlite_path).map_err(|e| format!("创建sqlite文件失败: {e}"))?;
-        out.write_all(&sqlite_bytes).map_err(|e| format!("写入sqlite失败: {e}"))?;
-        rust_log(&format!("DEBUG: anki21b 处理完成"));
-        "anki21b"
-    } else if anki21.exists() {
-        // anki21：直接复制
-        rust_log(&format!("DEBUG: 开始处理 anki21"));
-        fs::copy(&anki21, &sqlite_path).map_err(|e| format!("复制anki21失败: {e}"))?;
-        rust_log(&format!("DEBUG: anki21 复制完成"));
-        "anki21"
-    } else if anki2.exists() {
-        // 老版：直接复制
-        rust_log(&format!("DEBUG: 开始处理 anki2"));
-        fs::copy(&anki2, &sqlite_path).map_err(|e| format!("复制anki2失败: {e}"))?;
-        rust_log(&format!("DEBUG: anki2 复制完成"));
-        "anki2"
-    } else {
-        // 警告：未找到 collection.anki21b、collection.anki21 或 collection.anki2
-        rust_log(&format!("DEBUG: 警告：未找到 collection.anki21b、collection.anki21 或 collection.anki2"));
-        "unknown"
-    };
+}
 
-    rust_log(&format!("DEBUG: 最终 sqlite 文件存在: {}", sqlite_path.exists()));
-    if sqlite_path.exists() {
-        if let Ok(metadata) = fs::metadata(&sqlite_path) {
-            rust_log(&format!("DEBUG: sqlite 文件大小: {} bytes", metadata.len()));
-        }
+impl Drop for CancelFlag {
+    fn drop(&mut self) {
+        IMPORT_CANCEL_FLAGS.lock().unwrap_or_else(|e| e.into_inner()).remove(&self.task_id);
     }
+}
 
-    // 5. 解压媒体文件
-    rust_log(&format!("DEBUG: 开始解压媒体文件"));
-    let media_dir = deck_dir.join("unarchived_media");
-    
-    // 检查 unarchived_media 路径是否存在
-    if media_dir.exists() {
-        if media_dir.is_file() {
-            // 如果是文件，删除它
-            fs::remove_file(&media_dir).map_err(|e| format!("删除已存在的unarchived_media文件失败: {e}"))?;
-            rust_log(&format!("DEBUG: 删除了已存在的unarchived_media文件"));
-        } else {
-            // 如果是目录，清空它
-            fs::remove_dir_all(&media_dir).map_err(|e| format!("清空已存在的unarchived_media目录失败: {e}"))?;
-            rust_log(&format!("DEBUG: 清空了已存在的unarchived_media目录"));
+// 运行一个可以取消的导入任务，进度交给 send，结束时推送结果或“已取消”
+fn run_import_task(
+    task_id: &str,
+    send: &mut dyn FnMut(&ImportProgress),
+    import: impl FnOnce(&mut ImportTracker) -> Result<ExtractResult, AnkiError>,
+) -> Result<(), AnkiError> {
+    let flag = CancelFlag::register(task_id);
+    let mut tracker = ImportTracker::new(&flag.cancelled, send);
+    match import(&mut tracker) {
+        Ok(result) => {
+            tracker.finish(result);
+            Ok(())
         }
-    }
-    
-    // 先解压所有文件，包括media映射文件
-    let file = File::open(&apkg_path).map_err(|e| format!("无法打开apkg文件: {e}"))?;
-    let mut zip = ZipArchive::new(file).map_err(|e| format!("不是有效的apkg/zip文件: {e}"))?;
-    // 先解析 media 映射
-    let mut media_map: HashMap<String, String> = HashMap::new();
-    for i in 0..zip.len() {
-        let mut entry = zip.by_index(i).map_err(|e| format!("读取zip entry失败: {e}"))?;
-        let name = entry.name().to_string();
-        if name == "media" && entry.is_file() {
-            let mut bytes = Vec::new();
-            entry.read_to_end(&mut bytes).map_err(|e| format!("读取media映射文件失败: {e}"))?;
-            let buf = String::from_utf8_lossy(&bytes);
-            if let Ok(media_json) = serde_json::from_str::<serde_json::Value>(&buf) {
-                if let Some(obj) = media_json.as_object() {
-                    for (key, value) in obj.iter() {
-                        if let Some(filename) = value.as_str() {
-                            media_map.insert(key.clone(), filename.to_string());
-                        }
-                    }
-                }
+        Err(e) => {
+            if tracker.is_cancelled() {
+                rust_log(&format!("DEBUG: 导入已取消, task_id={}", task_id));
+                tracker.set_phase(ImportPhase::Cancelled);
             }
+            Err(e)
         }
     }
-    // 再次遍历解压媒体文件（数字编号文件），用真实文件名存储
-    let file = File::open(&apkg_path).map_err(|e| format!("无法打开apkg文件: {e}"))?;
-    let mut zip = ZipArchive::new(file).map_err(|e| format!("不是有效的apkg/zip文件: {e}"))?;
-    for i in 0..zip.len() {
-        let mut entry = zip.by_index(i).map_err(|e| format!("读取zip entry失败: {e}"))?;
-        let name = entry.name().to_string();
-        // 跳过目录和collection文件和media映射文件
-        if name.ends_with('/') || name.starts_with("collection.") || name == "meta" || name == "media" {
-            continue;
+}
+
+/// 带进度的导入，进度和最终结果都通过 progress 推送；导入进行中用同一个 task_id 调用 cancel_import 可以中途取消，
+/// 取消后集合库保持不变，解包的临时目录也会删掉
+#[flutter_rust_bridge::frb]
+pub fn extract_apkg_with_progress(apkg_path: String, task_id: String, progress: StreamSink<ImportProgress>) -> Result<(), AnkiError> {
+    rust_log(&format!("DEBUG: extract_apkg_with_progress 被调用, task_id={}", task_id));
+    let mut send = |p: &ImportProgress| {
+        let _ = progress.add(p.clone());
+    };
+    run_import_task(&task_id, &mut send, |tracker| import_apkg(&apkg_path, tracker))
+}
+
+/// 取消正在进行的导入；task_id 不存在或导入已经结束时什么也不做
+#[flutter_rust_bridge::frb(sync)]
+pub fn cancel_import(task_id: String) {
+    rust_log(&format!("DEBUG: cancel_import 被调用, task_id={}", task_id));
+    match IMPORT_CANCEL_FLAGS.lock().unwrap_or_else(|e| e.into_inner()).get(&task_id) {
+        Some(cancelled) => cancelled.store(true, Ordering::Relaxed),
+        None => rust_log(&format!("DEBUG: 没有进行中的导入, task_id={}", task_id)),
+    }
+}
+
+// 导入前在解包出的集合上做准备：旧格式转换成 anki21b 的表，按调度配置从 revlog 或 SM-2 参数恢复已学卡片的记忆状态。
+// 牌组已经导入过时用牌组自己的配置，否则用集合里的牌组选项；恢复失败不影响导入。
+// deck_id 不为空时只处理集合里的这个牌组（colpkg 按牌组拆分），否则用卡片最多的牌组的选项、处理所有卡片
+fn prepare_collection(sqlite_path: &Path, deck_key: &str, deck_id: Option<i64>) -> Result<(Option<SchedulerConfig>, u32), AnkiError> {
+    if !sqlite_path.exists() {
+        return Err(AnkiError::new(ErrorKind::InvalidPackage, "没有找到集合文件").with_path(sqlite_path));
+    }
+    let mut conn = Connection::open(sqlite_path).context("打开sqlite失败")?;
+    upgrade_legacy(&mut conn).inspect_err(|e| rust_log(&format!("DEBUG: 转换旧格式集合失败: {}", e)))?;
+    let saved = collection::with_collection_read(|store| {
+        if has_saved_scheduler_config(store, deck_key)? { Ok(Some(load_scheduler_config(store, deck_key)?)) } else { Ok(None) }
+    })?;
+    let imported = match saved {
+        Some(_) => None,
+        None => imported_config(&conn, deck_id)
+            .inspect_err(|e| rust_log(&format!("[调度] 读取导入的牌组选项失败: {e}")))
+            .ok()
+            .flatten(),
+    };
+    let config = saved.or_else(|| imported.clone()).unwrap_or_default();
+    let restored = match bootstrap_collection(&mut conn, &config, deck_id) {
+        Ok(count) => {
+            rust_log(&format!("DEBUG: 已恢复 {} 张卡片的记忆状态", count));
+            count
         }
-        // 只处理数字编号文件
-        if let Some(real_name) = media_map.get(&name) {
-            let outpath = media_dir.join(real_name);
-            if let Some(parent) = outpath.parent() {
-                fs::create_dir_all(parent).map_err(|e| format!("创建父目录失败: {} - {}", parent.display(), e))?;
-            }
-            let mut outfile = File::create(&outpath).map_err(|e| format!("创建媒体文件失败: {} - {}", outpath.display(), e))?;
-            std::io::copy(&mut entry, &mut outfile).map_err(|e| format!("写入媒体文件失败: {} - {}", outpath.display(), e))?;
+        Err(e) => {
+            rust_log(&format!("DEBUG: 恢复记忆状态失败: {}", e));
+            0
         }
+    };
+    Ok((imported, restored))
+}
+
+// 解包出的媒体文件，校验和在拿写连接之前算好
+fn staged_media(dir: &Path) -> Result<Vec<MediaFile>, AnkiError> {
+    scan_media(&dir.join(MEDIA_DIR)).inspect_err(|e| rust_log(&format!("DEBUG: 读取媒体文件失败: {}", e)))
+}
+
+// 把解包出的集合导入集合库，牌组第一次导入时按集合里的牌组选项保存调度配置，filter 见 import_collection
+// 事务提交后交还写连接再移动媒体文件
+fn import_into_collection(
+    sqlite_path: &Path,
+    media: &[MediaFile],
+    deck_key: &str,
+    config: Option<SchedulerConfig>,
+    remove_missing: bool,
+    filter: Option<&DeckFilter>,
+) -> Result<MergeSummary, AnkiError> {
+    let media_dir = collection::media_dir()?;
+    let ((summary, staged), saved) = collection::with_collection(|conn| {
+        let imported = import_collection(conn, sqlite_path, media, &media_dir, deck_key, remove_missing, filter)
+            .inspect_err(|e| rust_log(&format!("DEBUG: 导入集合失败: {}", e)))?;
+        // 导入已经提交，保存配置失败时也要把媒体文件移过去
+        let saved = match config {
+            Some(config) => save_scheduler_config(conn, deck_key, &config).inspect_err(|e| rust_log(&format!("[调度] 保存调度配置失败: {e}"))),
+            None => Ok(()),
+        };
+        Ok((imported, saved))
+    })?;
+    let failed = staged.move_files();
+    if !failed.is_empty() {
+        rust_log(&format!("DEBUG: {} 个媒体文件没能移到媒体目录", failed.len()));
+        collection::with_collection(|conn| forget_media(conn, &failed))?;
     }
-    rust_log(&format!("DEBUG: 媒体文件解压完成"));
-    
-    // 6. 解析 media 映射文件
-    
-
-    rust_log(&format!("DEBUG: 媒体文件解压完成"));
-    
-    // 6. 解析 media 映射文件
-    rust_log(&format!("DEBUG: 开始解析 media 映射"));
-    let mut media_map: HashMap<String, String> = HashMap::new();
-    let media_mapping_file = deck_dir.join("media");
-    rust_log(&format!("DEBUG: media 映射文件路径: {}", media_mapping_file.display()));
-    rust_log(&format!("DEBUG: media 映射文件存在: {}", media_mapping_file.exists()));
-    rust_log(&format!("DEBUG: media 映射文件是文件: {}", media_mapping_file.is_file()));
-    
-    if media_mapping_file.exists() && media_mapping_file.is_file() {
-        rust_log(&format!("DEBUG: 尝试读取 media 映射文件"));
-        match fs::read_to_string(&media_mapping_file) {
-            Ok(media_content) => {
-                rust_log(&format!("DEBUG: media 文件内容长度: {}", media_content.len()));
-                rust_log(&format!("DEBUG: media 文件内容前100字符: {}", &media_content[..media_content.len().min(100)]));
-                
-                match serde_json::from_str::<serde_json::Value>(&media_content) {
-                    Ok(media_json) => {
-                        rust_log(&format!("DEBUG: JSON 解析成功"));
-                        if let Some(obj) = media_json.as_object() {
-                            rust_log(&format!("DEBUG: JSON 对象键数量: {}", obj.len()));
-                            for (key, value) in obj.iter() {
-                                if let Some(filename) = value.as_str() {
-                                    media_map.insert(filename.to_string(), key.clone());
-                                    //rust_log(&format!("DEBUG: 媒体映射: {} -> {}", filename, key));
-                                } else {
-                                    rust_log(&format!("DEBUG: 跳过非字符串值: key={}, value={:?}", key, value));
-                                }
-                            }
-                        } else {
-                            rust_log(&format!("DEBUG: JSON 不是对象类型"));
-                        }
-                    }
-                    Err(e) => rust_log(&format!("DEBUG: JSON 解析失败: {}", e)),
-                }
-            }
-            Err(e) => rust_log(&format!("DEBUG: 读取 media 文件失败: {}", e)),
-        }
-    } else {
-        rust_log(&format!("DEBUG: media 映射文件不存在或不是文件"));
+    saved?;
+    Ok(summary)
+}
+
+fn import_apkg(apkg_path: &str, tracker: &mut ImportTracker) -> Result<ExtractResult, AnkiError> {
+    let staged = stage_package(Path::new(apkg_path), &collection::base_dir()?, tracker)
+        .inspect_err(|e| rust_log(&format!("DEBUG: 解包apkg失败: {}", e)))?;
+    let sqlite_path = staged.dir().join(SQLITE_FILE);
+    rust_log(&format!("DEBUG: 最终 sqlite 文件存在: {}", sqlite_path.exists()));
+    if let Ok(metadata) = fs::metadata(&sqlite_path) {
+        rust_log(&format!("DEBUG: sqlite 文件大小: {} bytes", metadata.len()));
     }
-    rust_log(&format!("DEBUG: media 映射解析完成，共 {} 个文件", media_map.len()));
-    
+
+    tracker.set_phase(ImportPhase::Scheduling);
+    let (config, scheduled_card_count) = prepare_collection(&sqlite_path, &staged.md5, None)?;
+    // 导入集合库之前还可以取消，临时目录会随 staged 一起删除
+    tracker.check_cancelled()?;
+    let media = staged_media(staged.dir())?;
+    let summary = import_into_collection(&sqlite_path, &media, &staged.md5, config, false, None)?;
+
     Ok(ExtractResult {
-        dir: deck_dir.to_string_lossy().to_string(),
-        md5: md5str,
-        media_map,
-        version: version.to_string(), // 修正类型
+        md5: staged.md5.clone(),
+        media_map: staged.media_map.clone(),
+        version: staged.version.clone(),
+        scheduled_card_count,
+        summary,
     })
 }
 
+/// 合并导入的差异统计
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MergeSummary {
+    pub added_notes: u32,
+    pub updated_notes: u32,   // 字段或标签有变化的笔记
+    pub unchanged_notes: u32,
+    pub removed_notes: u32,   // 只有 remove_missing 时才会删除
+    pub skipped_notes: u32,   // 笔记类型结构不一致，没有合并的笔记
+    pub added_notetypes: u32,
+    pub updated_notetypes: u32,
+    pub added_note_ids: Vec<i64>,
+    pub removed_note_ids: Vec<i64>,
+    pub added_card_ids: Vec<i64>,
+    pub removed_card_ids: Vec<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MergeResult {
+    pub md5: String, // 被更新的牌组
+    pub version: String,
+    pub media_map: HashMap<String, String>, // 新版本 apkg 的媒体映射
+    pub summary: MergeSummary,
+}
+
+/// 用新版本的 apkg 更新已导入的牌组：笔记按 guid 对应，更新有变化的笔记、添加新笔记，
+/// remove_missing 为 true 时删除新版本里已经没有的笔记；已有卡片的学习进度保持不变
+#[flutter_rust_bridge::frb]
+pub fn merge_apkg(apkg_path: String, deck_id: String, remove_missing: bool) -> Result<MergeResult, AnkiError> {
+    rust_log(&format!("DEBUG: merge_apkg 被调用, deck_id={}, remove_missing={}", deck_id, remove_missing));
+    if !collection::with_collection_read(|conn| collection::deck_exists(conn, &deck_id))? {
+        return Err(AnkiError::not_found(format!("牌组不存在: {}", deck_id)));
+    }
+    let cancelled = AtomicBool::new(false);
+    let mut ignore = |_: &ImportProgress| {};
+    let mut tracker = ImportTracker::new(&cancelled, &mut ignore);
+    let staged = stage_package(Path::new(&apkg_path), &collection::base_dir()?, &mut tracker)
+        .inspect_err(|e| rust_log(&format!("DEBUG: 解包apkg失败: {}", e)))?;
+    let sqlite_path = staged.dir().join(SQLITE_FILE);
+    let (config, _) = prepare_collection(&sqlite_path, &deck_id, None)?;
+    let media = staged_media(staged.dir())?;
+    let summary = import_into_collection(&sqlite_path, &media, &deck_id, config, remove_missing, None)?;
+    Ok(MergeResult { md5: deck_id, version: staged.version.clone(), media_map: staged.media_map.clone(), summary })
+}
+
+/// .colpkg 拆分出的一个牌组
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ColpkgDeck {
+    pub md5: String, // 和 ExtractResult.md5 一样用作牌组 id
+    pub deck_id: i64, // 原集合里的牌组 id
+    pub name: String, // 完整名称，层级用 :: 分隔
+    pub parent_name: Option<String>,
+    pub media_map: HashMap<String, String>,
+    pub card_count: u32,
+    pub scheduled_card_count: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ColpkgImportResult {
+    pub md5: String, // 整个 colpkg 文件的 MD5
+    pub version: String,
+    pub decks: Vec<ColpkgDeck>,
+}
+
+/// 导入整个集合的备份（.colpkg），按牌组拆成多个牌组导入集合库，每个牌组保留自己的卡片调度和复习记录
+#[flutter_rust_bridge::frb]
+pub fn extract_colpkg(colpkg_path: String) -> Result<ColpkgImportResult, AnkiError> {
+    rust_log(&format!("DEBUG: extract_colpkg 被调用"));
+    let cancelled = AtomicBool::new(false);
+    let mut ignore = |_: &ImportProgress| {};
+    let mut tracker = ImportTracker::new(&cancelled, &mut ignore);
+    let base_dir = collection::base_dir()?;
+    // 整个集合留在临时目录里，每个牌组都从它导入，导完随 package 一起删掉
+    let package = stage_package(Path::new(&colpkg_path), &base_dir, &mut tracker)
+        .inspect_err(|e| rust_log(&format!("DEBUG: 解包colpkg失败: {}", e)))?;
+    let sqlite_path = package.dir().join(SQLITE_FILE);
+    let mut conn = Connection::open(&sqlite_path).context("打开sqlite失败")?;
+    upgrade_legacy(&mut conn).inspect_err(|e| rust_log(&format!("DEBUG: 转换旧格式集合失败: {}", e)))?;
+    drop(conn);
+    let split = split_collection(package.dir(), &package.md5, &package.media_map, &tracker)
+        .inspect_err(|e| rust_log(&format!("DEBUG: 拆分colpkg失败: {}", e)))?;
+    let media = staged_media(package.dir())?;
+
+    tracker.set_phase(ImportPhase::Scheduling);
+    let mut decks = Vec::new();
+    for deck in split {
+        let (config, scheduled_card_count) = prepare_collection(&sqlite_path, &deck.key, Some(deck.deck_id))?;
+        // 几个牌组共用的文件第一次导入时已经移走，后面的牌组按校验和在 media 表里找到，直接共用
+        let deck_media: Vec<MediaFile> = media.iter().filter(|f| deck.media_map.contains_key(&f.name)).cloned().collect();
+        import_into_collection(&sqlite_path, &deck_media, &deck.key, config, false, Some(&deck.filter))?;
+        decks.push(ColpkgDeck {
+            scheduled_card_count,
+            md5: deck.key,
+            deck_id: deck.deck_id,
+            parent_name: deck.name.rsplit_once("::").map(|(parent, _)| parent.to_string()),
+            name: deck.name,
+            media_map: deck.media_map,
+            card_count: deck.card_count,
+        });
+    }
+    rust_log(&format!("DEBUG: colpkg 导入完成，共 {} 个牌组", decks.len()));
+    Ok(ColpkgImportResult { md5: package.md5.clone(), version: package.version.clone(), decks })
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct DeckNotesResult {
     pub notes: Vec<NoteExt>,
@@ -301,6 +439,10 @@ pub struct FieldExt {
     pub notetype_id: i64,
     pub name: String,
     pub ord: i64,
+    pub font_name: String,
+    pub font_size: i64,
+    pub rtl: bool,
+    pub sticky: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -322,31 +464,18 @@ pub struct SingleNoteResult {
     pub front: String, // 正面模板
     pub back: String,  // 反面模板
     pub css: String,
+    pub latex_pre: String,
+    pub latex_post: String,
+    pub sort_field_idx: i64, // 排序字段序号
 }
 
-// 辅助函数：判断表是否包含所有指定字段
-fn table_has_columns(conn: &rusqlite::Connection, table: &str, columns: &[&str]) -> bool {
-    let sql = format!("PRAGMA table_info({})", table);
-    if let Ok(mut stmt) = conn.prepare(&sql) {
-        if let Ok(mut rows) = stmt.query([]) {
-            let mut found = vec![];
-            while let Ok(Some(row)) = rows.next() {
-                let name: String = row.get(1).unwrap_or_default();
-                found.push(name);
-            }
-            return columns.iter().all(|c| found.contains(&c.to_string()));
-        }
-    }
-    false
+#[flutter_rust_bridge::frb]
+pub fn get_deck_note(note_id: i64) -> Result<SingleNoteResult, AnkiError> {
+    rust_log(&format!("DEBUG: get_deck_note 被调用, note_id={}", note_id));
+    collection::with_collection_read(|conn| load_deck_note(conn, note_id))
 }
 
-#[flutter_rust_bridge::frb]
-pub fn get_deck_note(sqlite_path: String, note_id: i64, version: String) -> Result<SingleNoteResult, String> {
-    rust_log(&format!("DEBUG: get_deck_note 被调用, note_id={}, version={}", note_id, version));
-    if !std::path::Path::new(&sqlite_path).exists() {
-        return Err(format!("文件不存在: {sqlite_path}"));
-    }
-    let conn = Connection::open(&sqlite_path).map_err(|e| format!("打开sqlite失败: {e}"))?;
+fn load_deck_note(conn: &Connection, note_id: i64) -> Result<SingleNoteResult, AnkiError> {
     let mut note: Option<NoteExt> = None;
     let mut notetype: Option<NotetypeExt> = None;
     let mut fields: Vec<FieldExt> = vec![];
@@ -354,199 +483,376 @@ pub fn get_deck_note(sqlite_path: String, note_id: i64, version: String) -> Resu
     let mut front = String::new();
     let mut back = String::new();
     let mut css = String::new();
-    if version == "anki21b" {
-        // 新版表结构
-        let mut stmt = conn.prepare("SELECT id, guid, mid, flds FROM notes WHERE id = ?").map_err(|e| format!("准备SQL失败: {e}"))?;
-        let mut rows = stmt.query([note_id]).map_err(|e| format!("查询SQL失败: {e}"))?;
-        if let Some(row) = rows.next().map_err(|e| format!("遍历SQL失败: {e}"))? {
-            let id: i64 = row.get(0).map_err(|e| format!("读取id失败: {e}"))?;
-            let guid: String = row.get(1).map_err(|e| format!("读取guid失败: {e}"))?;
-            let mid: i64 = row.get(2).map_err(|e| format!("读取mid失败: {e}"))?;
-            let flds: String = row.get(3).map_err(|e| format!("读取flds失败: {e}"))?;
-            let flds_vec: Vec<String> = flds.split('\x1f').map(|s| s.to_string()).collect();
-            // 查找卡片ord
-            let mut stmt_card = conn.prepare("SELECT ord FROM cards WHERE nid = ? LIMIT 1").map_err(|e| format!("准备SQL失败: {e}"))?;
-            let mut rows_card = stmt_card.query([id]).map_err(|e| format!("查询SQL失败: {e}"))?;
-            if let Some(row_card) = rows_card.next().map_err(|e| format!("遍历SQL失败: {e}"))? {
-                ord = row_card.get(0).map_err(|e| format!("读取ord失败: {e}"))?;
-            }
-            // notetype
-            let mut stmt2 = conn.prepare("SELECT id, name, config FROM notetypes WHERE id = ?").map_err(|e| format!("准备SQL失败: {e}"))?;
-            let mut rows2 = stmt2.query([mid]).map_err(|e| format!("查询SQL失败: {e}"))?;
-            if let Some(row2) = rows2.next().map_err(|e| format!("遍历SQL失败: {e}"))? {
-                let nid: i64 = row2.get(0).map_err(|e| format!("读取id失败: {e}"))?;
-                let name: String = row2.get(1).map_err(|e| format!("读取name失败: {e}"))?;
-                let config: Option<String> = row2.get::<_, Option<String>>(2).unwrap_or(Some(String::new()));
-                notetype = Some(NotetypeExt { id: nid, name, config });
-            }
-            // fields
-            let mut stmt3 = conn.prepare("SELECT ntid, ord, name FROM fields WHERE ntid = ? ORDER BY ord ASC").map_err(|e| format!("准备SQL失败: {e}"))?;
-            let mut rows3 = stmt3.query([mid]).map_err(|e| format!("查询SQL失败: {e}"))?;
-            let mut field_vec = vec![];
-            while let Some(row3) = rows3.next().map_err(|e| format!("遍历SQL失败: {e}"))? {
-                let notetype_id: i64 = row3.get(0).map_err(|e| format!("读取ntid失败: {e}"))?;
-                let ord_f: i64 = row3.get(1).map_err(|e| format!("读取ord失败: {e}"))?;
-                let name: String = row3.get(2).map_err(|e| format!("读取name失败: {e}"))?;
-                let id = notetype_id * 1000 + ord_f;
-                field_vec.push(FieldExt { id, notetype_id, name, ord: ord_f });
-            }
-            let field_names: Vec<String> = field_vec.iter().map(|f| f.name.clone()).collect();
-            note = Some(NoteExt { id, guid, mid, flds: flds_vec, notetype_name: notetype.as_ref().map(|n| n.name.clone()).unwrap_or_default(), field_names });
-            fields = field_vec;
-            // 查模板
-            let mut stmt_tpl = conn.prepare("SELECT config FROM templates WHERE ntid = ? AND ord = ?").map_err(|e| format!("准备SQL失败: {e}"))?;
-            let mut rows_tpl = stmt_tpl.query([mid, ord]).map_err(|e| format!("查询SQL失败: {e}"))?;
-            if let Some(row_tpl) = rows_tpl.next().map_err(|e| format!("遍历SQL失败: {e}"))? {
-                let config_bytes: Vec<u8> = row_tpl.get(0).map_err(|e| format!("读取config失败: {e}"))?;
-                let config = String::from_utf8_lossy(&config_bytes).to_string();
-
-                // 通用分割正反面：优先用 <hr id=answer>，否则用 <hr>，否则用两个换行
-                if let Some(idx) = config.find("\n\n") {
-                    front = config[..idx].to_string();
-                    back = config[idx + 2..].to_string();
-                } else {
-                    front = config.clone();
-                    back = String::new();
-                }
-            }
-            // 查样式 1580121962837
-            let mut stmt_css = conn.prepare("SELECT config FROM notetypes WHERE id = ?").map_err(|e| format!("准备SQL失败: {e}"))?;
-            let mut rows_css = stmt_css.query([mid]).map_err(|e| format!("查询SQL失败: {e}"))?;
-            rust_log(&format!("[DEBUG] begin to query row_css"));
-            if let Some(row_css) = rows_css.next().map_err(|e| format!("遍历SQL失败: {e}"))? {
-                if let Ok(Some(config_bytes)) = row_css.get::<_, Option<Vec<u8>>>(0) {
-                    let config_str = String::from_utf8_lossy(&config_bytes).to_string();
-                    // 先尝试json解析
-                    if let Ok(model) = serde_json::from_str::<serde_json::Value>(&config_str) {
-                        if let Some(css_val) = model.get("css").and_then(|v| v.as_str()) {
-                            css = css_val.to_string();
-                        } else {
-                            rust_log("[DEBUG] JSON中未找到css字段");
-                        }
-                    } else if let Some(idx) = config_str.find("\\documentclass") {
-                        rust_log(&format!("[DEBUG] config_str 不是JSON，\\documentclass分割点: {}", idx));
-                        css = config_str[..idx].trim().to_string();
-                    } else {
-                        rust_log("[DEBUG] config_str 不是JSON，也没有\\documentclass分割点，直接trim");
-                        css = config_str.trim().to_string();
-                    }
-                } else {
-                    rust_log("[DEBUG] row_css.get::<_, Option<Vec<u8>>>(0) 失败或为None");
-                }
-            }
-        }
-    } else {
-        // anki2 或其他老版本
-        // 1. 通过 card_id 查 cards 表，拿到 nid（note id）和 ord（模板序号）
-        let mut stmt_card = conn.prepare("SELECT nid, ord FROM cards WHERE nid = ?").map_err(|e| format!("准备SQL失败: {e}"))?;
-        let mut rows_card = stmt_card.query([note_id]).map_err(|e| format!("查询SQL失败: {e}"))?;
-        let (nid, ord): (i64, i64) = if let Some(row) = rows_card.next().map_err(|e| format!("遍历SQL失败: {e}"))? {
-            (row.get(0).map_err(|e| format!("读取nid失败: {e}"))?, row.get(1).map_err(|e| format!("读取ord失败: {e}"))?)
-        } else {
-            return Err("未找到指定id的card".to_string());
-        };
-        // 2. 用 nid 查 notes 表
-        let mut stmt_note = conn.prepare("SELECT id, guid, mid, flds FROM notes WHERE id = ?").map_err(|e| format!("准备SQL失败: {e}"))?;
-        let mut rows_note = stmt_note.query([nid]).map_err(|e| format!("查询SQL失败: {e}"))?;
-        let (id, guid, mid, flds): (i64, String, i64, String) = if let Some(row) = rows_note.next().map_err(|e| format!("遍历SQL失败: {e}"))? {
-            (row.get(0).map_err(|e| format!("读取id失败: {e}"))?, row.get(1).map_err(|e| format!("读取guid失败: {e}"))?, row.get(2).map_err(|e| format!("读取mid失败: {e}"))?, row.get(3).map_err(|e| format!("读取flds失败: {e}"))?)
-        } else {
-            return Err("未找到指定id的note".to_string());
-        };
+    let mut latex_pre = String::new();
+    let mut latex_post = String::new();
+    let mut sort_field_idx: i64 = 0;
+    // 新版表结构
+    let mut stmt = conn.prepare_cached("SELECT id, guid, mid, flds FROM notes WHERE id = ?").context("准备SQL失败")?;
+    let mut rows = stmt.query([note_id]).context("查询SQL失败")?;
+    if let Some(row) = rows.next().context("遍历SQL失败")? {
+        let id: i64 = row.get(0).context("读取id失败")?;
+        let guid: String = row.get(1).context("读取guid失败")?;
+        let mid: i64 = row.get(2).context("读取mid失败")?;
+        let flds: String = row.get(3).context("读取flds失败")?;
         let flds_vec: Vec<String> = flds.split('\x1f').map(|s| s.to_string()).collect();
-        // 3. 读取 col.models
-        let mut stmt_models = conn.prepare("SELECT models FROM col LIMIT 1").map_err(|e| format!("准备SQL失败: {e}"))?;
-        let mut rows_models = stmt_models.query([]).map_err(|e| format!("查询SQL失败: {e}"))?;
-        let models_json: String = if let Some(row) = rows_models.next().map_err(|e| format!("遍历SQL失败: {e}"))? {
-            row.get(0).map_err(|e| format!("读取models失败: {e}"))?
-        } else {
-            return Err("未找到col.models".to_string());
-        };
-        let models: serde_json::Value = serde_json::from_str(&models_json).map_err(|e| format!("解析models JSON失败: {e}"))?;
-        // 4. 用 mid 关联模型
-        let model = models.get(mid.to_string()).ok_or("未找到模型")?;
-        // 5. 取 tpls[ord]
-        let tpls = model.get("tmpls").and_then(|v| v.as_array()).ok_or("tmpls 字段缺失或不是数组")?;
-        let tpl = tpls.get(ord as usize).or_else(|| tpls.get(0)).ok_or("未找到模板")?;
-        front = tpl.get("qfmt").and_then(|v| v.as_str()).unwrap_or("").to_string();
-        back = tpl.get("afmt").and_then(|v| v.as_str()).unwrap_or("").to_string();
-        css = model.get("css").and_then(|v| v.as_str()).unwrap_or("").to_string();
-        // 组装 note/ext/fields
-        let mut notetype_name = model.get("name").and_then(|v| v.as_str()).unwrap_or("").to_string();
-        let mut field_names = vec![];
-        let mut fields_vec = vec![];
-        if let Some(flds_def) = model.get("flds").and_then(|v| v.as_array()) {
-            for (ord_f, f) in flds_def.iter().enumerate() {
-                let fname = f.get("name").and_then(|v| v.as_str()).unwrap_or("").to_string();
-                field_names.push(fname.clone());
-                fields_vec.push(FieldExt {
-                    id: ord_f as i64,
-                    notetype_id: mid,
-                    name: fname,
-                    ord: ord_f as i64,
-                });
-            }
+        // 查找卡片ord
+        let mut stmt_card = conn.prepare_cached("SELECT ord FROM cards WHERE nid = ? ORDER BY ord LIMIT 1").context("准备SQL失败")?;
+        let mut rows_card = stmt_card.query([id]).context("查询SQL失败")?;
+        if let Some(row_card) = rows_card.next().context("遍历SQL失败")? {
+            ord = row_card.get(0).context("读取ord失败")?;
+        }
+        // notetype，config 是 protobuf 编码的 NotetypeConfig
+        let mut stmt2 = conn.prepare_cached("SELECT id, name, config FROM notetypes WHERE id = ?").context("准备SQL失败")?;
+        let mut rows2 = stmt2.query([mid]).context("查询SQL失败")?;
+        if let Some(row2) = rows2.next().context("遍历SQL失败")? {
+            let nid: i64 = row2.get(0).context("读取id失败")?;
+            let name: String = row2.get(1).context("读取name失败")?;
+            let config_bytes: Vec<u8> = row2.get(2).context("读取config失败")?;
+            let nt_config = decode_notetype_config(&config_bytes)?;
+            css = nt_config.css;
+            latex_pre = nt_config.latex_pre;
+            latex_post = nt_config.latex_post;
+            sort_field_idx = nt_config.sort_field_idx as i64;
+            notetype = Some(NotetypeExt { id: nid, name, config: None });
+        }
+        // fields，config 是 protobuf 编码的 FieldConfig
+        let mut stmt3 = conn.prepare_cached("SELECT ntid, ord, name, config FROM fields WHERE ntid = ? ORDER BY ord ASC").context("准备SQL失败")?;
+        let mut rows3 = stmt3.query([mid]).context("查询SQL失败")?;
+        let mut field_vec = vec![];
+        while let Some(row3) = rows3.next().context("遍历SQL失败")? {
+            let notetype_id: i64 = row3.get(0).context("读取ntid失败")?;
+            let ord_f: i64 = row3.get(1).context("读取ord失败")?;
+            let name: String = row3.get(2).context("读取name失败")?;
+            let config_bytes: Vec<u8> = row3.get(3).context("读取config失败")?;
+            let f_config = decode_field_config(&config_bytes)?;
+            let id = notetype_id * 1000 + ord_f;
+            field_vec.push(FieldExt {
+                id,
+                notetype_id,
+                name,
+                ord: ord_f,
+                font_name: f_config.font_name,
+                font_size: f_config.font_size as i64,
+                rtl: f_config.rtl,
+                sticky: f_config.sticky,
+            });
+        }
+        let field_names: Vec<String> = field_vec.iter().map(|f| f.name.clone()).collect();
+        note = Some(NoteExt { id, guid, mid, flds: flds_vec, notetype_name: notetype.as_ref().map(|n| n.name.clone()).unwrap_or_default(), field_names });
+        fields = field_vec;
+        // 查模板，config 是 protobuf 编码的 TemplateConfig
+        let mut stmt_tpl = conn.prepare_cached("SELECT config FROM templates WHERE ntid = ? AND ord = ?").context("准备SQL失败")?;
+        let mut rows_tpl = stmt_tpl.query([mid, ord]).context("查询SQL失败")?;
+        if let Some(row_tpl) = rows_tpl.next().context("遍历SQL失败")? {
+            let config_bytes: Vec<u8> = row_tpl.get(0).context("读取config失败")?;
+            let tpl_config = decode_template_config(&config_bytes)?;
+            front = tpl_config.q_format;
+            back = tpl_config.a_format;
         }
-        notetype = Some(NotetypeExt { id: mid, name: notetype_name.clone(), config: Some(model.to_string()) });
-        note = Some(NoteExt { id, guid, mid, flds: flds_vec, notetype_name, field_names });
-        fields = fields_vec;
     }
     if let Some(note) = note {
-        // 查模板和样式同上，直接从 notetype.config 解析
-        if let Some(nt) = &notetype {
-            if let Some(config_str) = &nt.config {
-                if let Ok(model) = serde_json::from_str::<serde_json::Value>(config_str) {
-                    if let Some(tpls) = model.get("tpls").and_then(|v| v.as_array()) {
-                        let tpl = tpls.get(ord as usize).or_else(|| tpls.get(0));
-                        if let Some(tpl) = tpl {
-                            front = tpl.get("qfmt").and_then(|v| v.as_str()).unwrap_or("").to_string();
-                            back = tpl.get("afmt").and_then(|v| v.as_str()).unwrap_or("").to_string();
-                        }
-                    }
-                    if let Some(css_val) = model.get("css").and_then(|v| v.as_str()) {
-                        css = css_val.to_string();
-                    }
-                }
-            }
-        }
-        //rust_log(&format!("[DEBUG]: css内容: {}", css));
-        Ok(SingleNoteResult { note, notetype, fields, ord, front, back, css })
+        Ok(SingleNoteResult { note, notetype, fields, ord, front, back, css, latex_pre, latex_post, sort_field_idx })
     } else {
-        Err("未找到指定id的note".to_string())
+        Err(AnkiError::not_found("未找到指定id的note"))
     }
 }
 
-#[flutter_rust_bridge::frb]
-pub fn get_card_count(sqlite_path: String) -> usize {
-    use rusqlite::Connection;
-    let conn = Connection::open(sqlite_path).unwrap();
-    let mut stmt = conn.prepare("SELECT COUNT(*) FROM notes").unwrap();
-    let count: usize = stmt.query_row([], |row| row.get(0)).unwrap_or(0);
-    count
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RenderedCard {
+    pub card_id: i64,
+    pub note_id: i64,
+    pub ord: i64,
+    pub question: String, // 渲染后的正面HTML
+    pub answer: String,   // 渲染后的反面HTML
+    pub css: String,
+    pub diagnostics: Vec<String>, // 渲染过程中发现的问题，如未知字段、条件块不匹配
 }
 
-#[flutter_rust_bridge::frb]
-pub fn get_card_count_from_deck(app_doc_dir: String, md5: String) -> usize {
-    use std::path::Path;
-    
-    let sqlite_path1 = format!("{}/anki_data/{}/collection.sqlite", app_doc_dir, md5);
-    let sqlite_path2 = format!("{}/anki_data/{}/collection.anki2", app_doc_dir, md5);
-    
-    let real_path = if Path::new(&sqlite_path1).exists() {
-        Some(sqlite_path1)
-    } else if Path::new(&sqlite_path2).exists() {
-        Some(sqlite_path2)
-    } else {
-        None
+// 渲染一张卡片需要的原始数据
+struct CardRenderData {
+    note_id: i64,
+    notetype_id: i64,
+    ord: i64,
+    template_ord: i64,
+    fields: HashMap<String, String>, // 字段名 -> 内容，包括特殊字段
+    qfmt: String,
+    afmt: String,
+    css: String,
+}
+
+struct TemplateInfo {
+    ord: i64,
+    name: String,
+    qfmt: String,
+    afmt: String,
+}
+
+// 笔记类型中渲染和生成卡片要用到的部分
+struct NotetypeInfo {
+    name: String,
+    is_cloze: bool,
+    css: String,
+    field_names: Vec<String>,
+    templates: Vec<TemplateInfo>, // 按 ord 排序
+}
+
+fn load_notetype_info(conn: &Connection, mid: i64) -> Result<NotetypeInfo, AnkiError> {
+    let (name, config_bytes): (String, Vec<u8>) = conn
+        .prepare_cached("SELECT name, config FROM notetypes WHERE id = ?")
+        .context("准备SQL失败")?
+        .query_row([mid], |row| Ok((row.get(0)?, row.get(1)?)))
+        .context("未找到模型")?;
+    let nt_config = decode_notetype_config(&config_bytes)?;
+    let mut stmt = conn.prepare_cached("SELECT name FROM fields WHERE ntid = ? ORDER BY ord ASC").context("准备SQL失败")?;
+    let rows = stmt.query_map([mid], |row| row.get(0)).context("查询SQL失败")?;
+    let field_names = rows.collect::<Result<Vec<String>, _>>().context("读取name失败")?;
+    let mut stmt_tpl = conn.prepare_cached("SELECT ord, name, config FROM templates WHERE ntid = ? ORDER BY ord ASC").context("准备SQL失败")?;
+    let mut rows_tpl = stmt_tpl.query([mid]).context("查询SQL失败")?;
+    let mut templates = vec![];
+    while let Some(row) = rows_tpl.next().context("遍历SQL失败")? {
+        let config_bytes: Vec<u8> = row.get(2).context("读取config失败")?;
+        let tpl_config = decode_template_config(&config_bytes)?;
+        templates.push(TemplateInfo {
+            ord: row.get(0).context("读取ord失败")?,
+            name: row.get(1).context("读取name失败")?,
+            qfmt: tpl_config.q_format,
+            afmt: tpl_config.a_format,
+        });
+    }
+    Ok(NotetypeInfo { name, is_cloze: nt_config.kind == NOTETYPE_KIND_CLOZE, css: nt_config.css, field_names, templates })
+}
+
+fn load_deck_name(conn: &Connection, did: i64) -> String {
+    // 牌组名用 \x1f 分隔层级
+    conn.query_row("SELECT name FROM decks WHERE id = ?", [did], |row| row.get::<_, String>(0))
+        .map(|n| n.replace('\x1f', "::"))
+        .unwrap_or_default()
+}
+
+// 按 card_id 读出卡片对应的笔记、模板和牌组，读过的笔记类型放在 notetypes 里复用
+fn load_card_render_data(conn: &Connection, card_id: i64, notetypes: &mut HashMap<i64, NotetypeInfo>) -> Result<CardRenderData, AnkiError> {
+    let (note_id, ord, did, flags): (i64, i64, i64, i64) = conn
+        .prepare_cached("SELECT nid, ord, did, flags FROM cards WHERE id = ?")
+        .context("准备SQL失败")?
+        .query_row([card_id], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+        })
+        .context("未找到指定id的card")?;
+    let (mid, flds, tags): (i64, String, String) = conn
+        .prepare_cached("SELECT mid, flds, tags FROM notes WHERE id = ?")
+        .context("准备SQL失败")?
+        .query_row([note_id], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })
+        .context("未找到指定id的note")?;
+    let field_values: Vec<&str> = flds.split('\x1f').collect();
+    let notetype = match notetypes.entry(mid) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => entry.insert(load_notetype_info(conn, mid)?),
     };
-    
-    if let Some(path) = real_path {
-        get_card_count(path)
+    // 填空题只有一个模板，ord 表示填空序号；普通笔记找不到对应 ord 的模板时退回第一个模板
+    let template = if notetype.is_cloze {
+        notetype.templates.first()
     } else {
-        0
+        notetype.templates.iter().find(|t| t.ord == ord).or_else(|| notetype.templates.first())
+    }
+    .ok_or_else(|| AnkiError::not_found("未找到模板"))?;
+    let deck_name = load_deck_name(conn, did);
+
+    let mut fields: HashMap<String, String> = notetype
+        .field_names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.clone(), field_values.get(i).copied().unwrap_or("").to_string()))
+        .collect();
+    // Anki 的特殊字段
+    fields.insert("Tags".to_string(), tags.trim().to_string());
+    fields.insert("Type".to_string(), notetype.name.clone());
+    fields.insert("Subdeck".to_string(), deck_name.rsplit("::").next().unwrap_or("").to_string());
+    fields.insert("Deck".to_string(), deck_name);
+    fields.insert("Card".to_string(), template.name.clone());
+    fields.insert("CardFlag".to_string(), if flags & 0b111 != 0 { format!("flag{}", flags & 0b111) } else { String::new() });
+    fields.insert("CardID".to_string(), card_id.to_string());
+    Ok(CardRenderData {
+        note_id,
+        notetype_id: mid,
+        ord,
+        template_ord: template.ord,
+        fields,
+        qfmt: template.qfmt.clone(),
+        afmt: template.afmt.clone(),
+        css: notetype.css.clone(),
+    })
+}
+
+/// 获取笔记的全部卡片，按 ord 排序。填空题每个填空序号对应一张卡片，导入时已经补齐
+#[flutter_rust_bridge::frb]
+pub fn get_note_cards(note_id: i64) -> Result<Vec<CardExt>, AnkiError> {
+    rust_log(&format!("DEBUG: get_note_cards 被调用, note_id={}", note_id));
+    collection::with_collection_read(|conn| load_note_cards(conn, note_id))
+}
+
+fn load_note_cards(conn: &Connection, note_id: i64) -> Result<Vec<CardExt>, AnkiError> {
+    let mut stmt = conn
+        .prepare_cached("SELECT id, nid, ord, type, queue, due FROM cards WHERE nid = ? ORDER BY ord ASC")
+        .context("准备SQL失败")?;
+    let rows = stmt
+        .query_map([note_id], |row| {
+            Ok(CardExt { id: row.get(0)?, nid: row.get(1)?, ord: row.get(2)?, type_: row.get(3)?, queue: row.get(4)?, due: row.get(5)? })
+        })
+        .context("查询SQL失败")?;
+    rows.collect::<Result<Vec<_>, _>>().context("读取card失败")
+}
+
+/// 按填空题笔记字段里的填空序号补齐缺失的卡片，新卡片排在所在牌组的新卡最后，返回新卡片的 id。
+/// 导入时已经补齐，修改过笔记字段后调用
+#[flutter_rust_bridge::frb]
+pub fn generate_cloze_cards(note_id: i64) -> Result<Vec<i64>, AnkiError> {
+    rust_log(&format!("DEBUG: generate_cloze_cards 被调用, note_id={}", note_id));
+    collection::with_collection(|conn| {
+        let tx = conn.transaction().context("开启事务失败")?;
+        let card_ids = cloze_cards::generate_cloze_cards(&tx, note_id)?;
+        tx.commit().context("提交事务失败")?;
+        Ok(card_ids)
+    })
+}
+
+/// 在 Rust 侧按 Anki 模板语法渲染一张卡片的正反面
+#[flutter_rust_bridge::frb]
+pub fn render_card(card_id: i64) -> Result<RenderedCard, AnkiError> {
+    rust_log(&format!("DEBUG: render_card 被调用, card_id={}", card_id));
+    let data = collection::with_collection_read(|conn| load_card_render_data(conn, card_id, &mut HashMap::new()))?;
+    let rendered = render_question_answer(&data.qfmt, &data.afmt, &data.fields, data.ord.max(0) as u16);
+    for d in &rendered.diagnostics {
+        rust_log(&format!("[Template] card_id={}: {}", card_id, d));
+    }
+    Ok(RenderedCard {
+        card_id,
+        note_id: data.note_id,
+        ord: data.ord,
+        question: rendered.question,
+        answer: rendered.answer,
+        css: data.css,
+        diagnostics: rendered.diagnostics,
+    })
+}
+
+/// 批量取卡时笔记类型的模板
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BatchTemplate {
+    pub ord: i64,
+    pub name: String,
+    pub qfmt: String, // 正面模板
+    pub afmt: String, // 反面模板
+}
+
+/// 批量取卡时同一笔记类型的卡片共用的数据，卡片里只带 notetype_id
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BatchNotetype {
+    pub id: i64,
+    pub name: String,
+    pub is_cloze: bool,
+    pub css: String,
+    pub field_names: Vec<String>,
+    pub templates: Vec<BatchTemplate>, // 按 ord 排序
+}
+
+/// 批量取卡时的一张卡片，样式和模板到 notetypes 里按 notetype_id 查
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BatchCard {
+    pub card_id: i64,
+    pub note_id: i64,
+    pub notetype_id: i64,
+    pub ord: i64,          // 卡片 ord，填空题是填空序号减一
+    pub template_ord: i64, // 实际使用的模板序号
+    pub question: String,  // 渲染后的正面HTML
+    pub answer: String,    // 渲染后的反面HTML
+    pub diagnostics: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CardsBatch {
+    pub cards: Vec<BatchCard>,         // 顺序和请求的 card_ids 相同
+    pub notetypes: Vec<BatchNotetype>, // cards 用到的笔记类型，每个只出现一次
+    pub missing_card_ids: Vec<i64>,    // 不存在或不属于该牌组的卡片
+}
+
+/// 一次取回多张卡片的渲染结果，复习界面预取后面的卡片时用，笔记类型的模板和样式按 id 共用
+#[flutter_rust_bridge::frb]
+pub fn get_cards_batch(deck_id: String, card_ids: Vec<i64>) -> Result<CardsBatch, AnkiError> {
+    rust_log(&format!("DEBUG: get_cards_batch 被调用, deck_id={}, 卡片数={}", deck_id, card_ids.len()));
+    collection::with_collection_read(|conn| load_cards_batch(conn, &deck_id, &card_ids))
+        .inspect_err(|e| rust_log(&format!("DEBUG: 批量读取卡片失败: {}", e)))
+}
+
+fn load_cards_batch(conn: &Connection, deck_id: &str, card_ids: &[i64]) -> Result<CardsBatch, AnkiError> {
+    let mut notetypes: HashMap<i64, NotetypeInfo> = HashMap::new();
+    let mut missing_card_ids = vec![];
+    let mut loaded = vec![];
+    {
+        let mut in_deck = conn
+            .prepare_cached(&format!("SELECT 1 FROM cards WHERE id = ? AND {}", collection::IN_DECK))
+            .context("准备SQL失败")?;
+        for &card_id in card_ids {
+            if !in_deck.exists(rusqlite::params![card_id, deck_id]).context("查询card失败")? {
+                missing_card_ids.push(card_id);
+                continue;
+            }
+            loaded.push((card_id, load_card_render_data(conn, card_id, &mut notetypes)?));
+        }
     }
+    let cards: Vec<BatchCard> = loaded
+        .into_iter()
+        .map(|(card_id, data)| {
+            let rendered = render_question_answer(&data.qfmt, &data.afmt, &data.fields, data.ord.max(0) as u16);
+            for d in &rendered.diagnostics {
+                rust_log(&format!("[Template] card_id={}: {}", card_id, d));
+            }
+            BatchCard {
+                card_id,
+                note_id: data.note_id,
+                notetype_id: data.notetype_id,
+                ord: data.ord,
+                template_ord: data.template_ord,
+                question: rendered.question,
+                answer: rendered.answer,
+                diagnostics: rendered.diagnostics,
+            }
+        })
+        .collect();
+    let mut notetypes: Vec<BatchNotetype> = notetypes
+        .into_iter()
+        .map(|(id, nt)| BatchNotetype {
+            id,
+            name: nt.name,
+            is_cloze: nt.is_cloze,
+            css: nt.css,
+            field_names: nt.field_names,
+            templates: nt
+                .templates
+                .into_iter()
+                .map(|t| BatchTemplate { ord: t.ord, name: t.name, qfmt: t.qfmt, afmt: t.afmt })
+                .collect(),
+        })
+        .collect();
+    notetypes.sort_by_key(|nt| nt.id);
+    Ok(CardsBatch { cards, notetypes, missing_card_ids })
+}
+
+/// 牌组里的笔记数，卡片全部暂停的笔记不算
+#[flutter_rust_bridge::frb]
+pub fn get_card_count(deck_id: String) -> Result<u32, AnkiError> {
+    collection::with_collection_read(|conn| collection::note_count(conn, &deck_id))
+}
+
+/// 删除牌组的卡片、笔记、复习记录和调度配置
+#[flutter_rust_bridge::frb]
+pub fn remove_deck(deck_id: String) -> Result<(), AnkiError> {
+    rust_log(&format!("DEBUG: remove_deck 被调用, deck_id={}", deck_id));
+    collection::with_collection(|conn| collection::remove_deck(conn, &deck_id)).inspect_err(|e| rust_log(&format!("DEBUG: 删除牌组失败: {}", e)))
 }
 
+/// 复习界面用的调度结果
 #[derive(Debug, Clone)]
 pub struct FsrsScheduleResult {
     pub due: i64,
@@ -554,130 +860,972 @@ pub struct FsrsScheduleResult {
     pub difficulty: f64,
 }
 
+impl From<&CardMemoryState> for FsrsScheduleResult {
+    fn from(state: &CardMemoryState) -> Self {
+        FsrsScheduleResult { due: state.due, stability: state.stability, difficulty: state.difficulty }
+    }
+}
+
+// 复习界面按笔记学习，评分落在笔记的第一张卡片上
+fn first_card_id(conn: &Connection, note_id: i64) -> Result<i64, AnkiError> {
+    conn.prepare_cached("SELECT id FROM cards WHERE nid = ? ORDER BY ord LIMIT 1")
+        .context("准备SQL失败")?
+        .query_row([note_id], |row| row.get(0))
+        .context("未找到笔记的卡片")
+}
+
+// 复习界面用的调度配置：牌组的配置，simple 为 true 时（设置里选了简单算法）改用 SM-2，学习步骤等选项不变
+fn review_page_config(conn: &Connection, deck_id: &str, simple: bool) -> Result<SchedulerConfig, AnkiError> {
+    let mut config = load_scheduler_config(conn, deck_id)?;
+    if simple {
+        config.algorithm = SchedulerAlgorithm::Sm2;
+    }
+    Ok(config)
+}
+
+fn answer_note(deck_id: String, note_id: i64, rating: u8, now: i64, simple: bool) -> Result<FsrsScheduleResult, AnkiError> {
+    rust_log(&format!("[调度] 按笔记评分: deck_id={}, note_id={}, rating={}, simple={}, now={}", deck_id, note_id, rating, simple, now));
+    let rating = rating_from_u8(rating)?;
+    let (card_id, next, leech) = collection::with_collection(|conn| {
+        let config = review_page_config(conn, &deck_id, simple)?;
+        let card_id = first_card_id(conn, note_id)?;
+        let (next, leech) = answer_in_collection(conn, &deck_id, card_id, rating, &config, now)?;
+        Ok((card_id, next, leech))
+    })?;
+    if let Some(event) = leech {
+        emit_leech(event);
+    }
+    rust_log(&format!("[调度] 调度结果: card_id={}, state={:?}, step={}, due={}, stability={}, difficulty={}",
+        card_id, next.state, next.step, next.due, next.stability, next.difficulty));
+    Ok(FsrsScheduleResult::from(&next))
+}
+
+fn preview_note(deck_id: String, note_id: i64, rating: u8, now: i64, simple: bool) -> Result<FsrsScheduleResult, AnkiError> {
+    let rating = rating_from_u8(rating)?;
+    let (_, next) = collection::with_collection_read(|conn| {
+        let config = review_page_config(conn, &deck_id, simple)?;
+        next_card_state(conn, first_card_id(conn, note_id)?, rating, &config, now)
+    })?;
+    Ok(FsrsScheduleResult::from(&next))
+}
+
+/// 复习界面按笔记评分：按牌组的调度配置（算法、参数、学习步骤）调度笔记的第一张卡片，结果写回集合并记录 revlog。
+/// rating: 0-重来, 1-困难, 2-良好, 3-简单。
+/// 不兼容的改动：以前的参数是 (stability, difficulty, last_review, rating, now)，rating 0-2 表示困难/一般/简单，
+/// 由调用方保存调度状态；现在状态保存在集合里，调用方只传 (deck_id, note_id, rating, now)，rating 的含义也和以前不同
+#[flutter_rust_bridge::frb]
+pub fn update_card_schedule(deck_id: String, note_id: i64, rating: u8, now: i64) -> Result<FsrsScheduleResult, AnkiError> {
+    answer_note(deck_id, note_id, rating, now, false)
+}
+
+/// 和 update_card_schedule 一样，但用 SM-2 调度，学习和重学步骤按牌组配置。rating 同样是 0-3
+#[flutter_rust_bridge::frb]
+pub fn update_card_schedule_simple(deck_id: String, note_id: i64, rating: u8, now: i64) -> Result<FsrsScheduleResult, AnkiError> {
+    answer_note(deck_id, note_id, rating, now, true)
+}
+
+/// 预览复习界面某个按钮的评分结果，和 update_card_schedule 的调度一样，但不写入集合
+#[flutter_rust_bridge::frb]
+pub fn preview_card_schedule(deck_id: String, note_id: i64, rating: u8, now: i64) -> Result<FsrsScheduleResult, AnkiError> {
+    preview_note(deck_id, note_id, rating, now, false)
+}
+
+/// 预览 update_card_schedule_simple 的评分结果，不写入集合
+#[flutter_rust_bridge::frb]
+pub fn preview_card_schedule_simple(deck_id: String, note_id: i64, rating: u8, now: i64) -> Result<FsrsScheduleResult, AnkiError> {
+    preview_note(deck_id, note_id, rating, now, true)
+}
+
+/// 卡片所处阶段，和 Anki/FSRS 的 New/Learning/Review/Relearning 对应
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardState {
+    New,
+    Learning,
+    Review,
+    Relearning,
+}
+
+/// 卡片完整的记忆状态，调度时原样传入、原样保存返回值
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CardMemoryState {
+    pub state: CardState,
+    pub due: i64, // 下次复习时间戳（秒）
+    pub stability: f64,
+    pub difficulty: f64,
+    pub elapsed_days: i64,   // 上次复习距本次复习的天数
+    pub scheduled_days: i64, // 本次安排的间隔天数
+    pub reps: i32,
+    pub lapses: i32,
+    pub step: i32,                // 学习/重学阶段当前所在的步骤
+    pub last_review: Option<i64>, // 上次复习时间戳（秒），新卡为空
+    pub ease_factor: f64,         // SM-2 的 ease（如 2.5），新卡为 0，FSRS 不使用
+}
+
+/// 新卡的初始状态
+#[flutter_rust_bridge::frb(sync)]
+pub fn new_card_memory_state(now: i64) -> CardMemoryState {
+    CardMemoryState {
+        state: CardState::New,
+        due: now,
+        stability: 0.0,
+        difficulty: 0.0,
+        elapsed_days: 0,
+        scheduled_days: 0,
+        reps: 0,
+        lapses: 0,
+        step: 0,
+        last_review: None,
+        ease_factor: 0.0,
+    }
+}
+
+/// 调度算法
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchedulerAlgorithm {
+    Fsrs,
+    Sm2, // Anki 传统的 SM-2 算法
+}
+
+/// 新卡片的收集顺序，决定每天的新卡上限从哪些卡片里取
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewCardGatherPriority {
+    Deck,                // 按牌组名依次收集，牌组内按位置
+    DeckThenRandomNotes, // 按牌组名依次收集，牌组内随机取笔记
+    LowestPosition,      // 位置从小到大
+    HighestPosition,     // 位置从大到小
+    RandomNotes,         // 随机取笔记，同一笔记的卡片放在一起
+    RandomCards,         // 随机取卡片
+}
+
+/// 收集到的新卡片在队列里的排列方式
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewCardSortOrder {
+    Template,               // 按卡片模板，同一模板内保持收集顺序
+    NoSort,                 // 保持收集顺序
+    TemplateThenRandom,     // 按卡片模板，同一模板内随机
+    RandomNoteThenTemplate, // 随机笔记，同一笔记内按卡片模板
+    RandomCard,             // 随机卡片
+}
+
+/// 到期复习卡的排列方式
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewSortOrder {
+    Due,                     // 按到期日，同一天内随机
+    RetrievabilityAscending, // 可提取性从低到高，最容易忘的先复习
+    Random,
+    RelativeOverdueness, // 过期天数相对间隔的比例从大到小
+}
+
+/// 新卡和复习卡的穿插方式
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewReviewMix {
+    MixWithReviews, // 新卡均匀穿插在复习卡之间
+    ReviewsFirst,
+    NewFirst,
+}
+
+/// 卡片成为难记卡片后的处理方式
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeechAction {
+    Suspend,
+    TagOnly, // 只给笔记加上 leech 标签
+}
+
+/// 牌组的调度配置，SM-2 相关的选项和 Anki 牌组选项里的同名设置一致
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct SchedulerConfig {
+    pub algorithm: SchedulerAlgorithm,
+    pub weights: Vec<f64>,       // FSRS-5 的 19 个参数或 FSRS-6 的 21 个参数，FSRS-6 调度时只用前 19 个
+    pub request_retention: f64,  // 期望记忆保留率
+    pub maximum_interval: i32,   // 最大间隔（天）
+    pub enable_fuzz: bool,       // 间隔随机浮动
+    pub enable_short_term: bool, // 启用短期（当天内）调度
+    pub learn_steps: Vec<f64>,   // 学习步骤（分钟）
+    pub relearn_steps: Vec<f64>, // 重学步骤（分钟）
+    pub graduating_interval_good: i32, // 毕业间隔（天）
+    pub graduating_interval_easy: i32, // 简单间隔（天）
+    pub initial_ease: f64,
+    pub easy_multiplier: f64,     // 简单奖励
+    pub hard_multiplier: f64,     // 困难间隔
+    pub lapse_multiplier: f64,    // 遗忘后的新间隔
+    pub interval_multiplier: f64, // 间隔修饰
+    pub minimum_lapse_interval: i32, // 遗忘后的最小间隔（天）
+    pub learn_ahead_secs: i32,       // 提前学习时限（秒），没有其他卡片可学时可以提前显示这段时间内到期的学习卡
+    pub new_per_day: u32,            // 每天新卡上限
+    pub new_card_gather_priority: NewCardGatherPriority,
+    pub new_card_sort_order: NewCardSortOrder,
+    pub reviews_per_day: u32, // 每天复习上限
+    pub review_sort_order: ReviewSortOrder,
+    pub new_mix: NewReviewMix,
+    pub bury_new: bool,               // 回答后搁置同一笔记的新卡
+    pub bury_reviews: bool,           // 回答后搁置同一笔记今天到期的复习卡
+    pub bury_interday_learning: bool, // 回答后搁置同一笔记今天到期的跨天学习卡
+    pub leech_threshold: u32,         // 遗忘次数达到这个值时成为难记卡片
+    pub leech_action: LeechAction,
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        SchedulerConfig {
+            algorithm: SchedulerAlgorithm::Fsrs,
+            weights: default_scheduler_weights(),
+            request_retention: 0.9,
+            maximum_interval: 36500,
+            enable_fuzz: false,
+            enable_short_term: true,
+            learn_steps: vec![1.0, 10.0],
+            relearn_steps: vec![10.0],
+            graduating_interval_good: 1,
+            graduating_interval_easy: 4,
+            initial_ease: 2.5,
+            easy_multiplier: 1.3,
+            hard_multiplier: 1.2,
+            lapse_multiplier: 0.0,
+            interval_multiplier: 1.0,
+            minimum_lapse_interval: 1,
+            learn_ahead_secs: 1200,
+            new_per_day: 20,
+            new_card_gather_priority: NewCardGatherPriority::Deck,
+            new_card_sort_order: NewCardSortOrder::Template,
+            reviews_per_day: 200,
+            review_sort_order: ReviewSortOrder::Due,
+            new_mix: NewReviewMix::MixWithReviews,
+            bury_new: false,
+            bury_reviews: false,
+            bury_interday_learning: false,
+            leech_threshold: 8,
+            leech_action: LeechAction::TagOnly,
+        }
+    }
+}
+
+/// 读取牌组的调度配置，没有设置过时使用导入集合里的牌组选项，都没有时返回默认配置
+#[flutter_rust_bridge::frb]
+pub fn get_scheduler_config(deck_id: String) -> Result<SchedulerConfig, AnkiError> {
+    collection::with_collection_read(|conn| load_scheduler_config(conn, &deck_id))
+}
+
+/// 保存牌组的调度配置，参数超出范围时返回错误
+#[flutter_rust_bridge::frb]
+pub fn set_scheduler_config(deck_id: String, config: SchedulerConfig) -> Result<(), AnkiError> {
+    rust_log(&format!("DEBUG: set_scheduler_config 被调用, deck_id={}, config={:?}", deck_id, config));
+    collection::with_collection(|conn| save_scheduler_config(conn, &deck_id, &config)).inspect_err(|e| rust_log(&format!("[FSRS] 保存调度配置失败: {e}")))
+}
+
+/// 集合的换天时刻（本地时间 0-23 点），每天的上限和今天学过的卡片按它划分学习日，默认凌晨 4 点
+#[flutter_rust_bridge::frb]
+pub fn get_rollover_hour() -> Result<u8, AnkiError> {
+    collection::with_collection_read(|conn| timing::rollover_hour(conn))
+}
+
+/// 设置集合的换天时刻，超出 0-23 时返回错误
+#[flutter_rust_bridge::frb]
+pub fn set_rollover_hour(hour: u8) -> Result<(), AnkiError> {
+    rust_log(&format!("DEBUG: set_rollover_hour 被调用, hour={}", hour));
+    collection::with_collection(|conn| timing::set_rollover_hour(conn, hour, Utc::now().timestamp()))
+        .inspect_err(|e| rust_log(&format!("[调度] 保存换天时刻失败: {e}")))
+}
+
+/// 四按钮调度，按配置选择 FSRS 或 SM-2，rating: 0-重来, 1-困难, 2-良好, 3-简单
+#[flutter_rust_bridge::frb]
+pub fn schedule_card(card: CardMemoryState, rating: u8, now: i64, config: SchedulerConfig) -> Result<CardMemoryState, AnkiError> {
+    rust_log(&format!("[调度] schedule_card: algorithm={:?}, state={:?}, reps={}, lapses={}, step={}, rating={}, now={}",
+        config.algorithm, card.state, card.reps, card.lapses, card.step, rating, now));
+    let rating = rating_from_u8(rating)?;
+    let scheduler = build_scheduler(&config).inspect_err(|e| rust_log(&format!("[调度] 调度配置无效: {e}")))?;
+    let review_time = Utc.timestamp_opt(now, 0).single().unwrap_or(Utc::now());
+    let next = scheduler.next_state(&card, rating, review_time);
+    rust_log(&format!("[调度] schedule_card 结果: state={:?}, due={}, scheduled_days={}, stability={}, difficulty={}, ease_factor={}",
+        next.state, next.due, next.scheduled_days, next.stability, next.difficulty, next.ease_factor));
+    Ok(next)
+}
+
+/// 某个评分按钮对应的调度结果
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SchedulePreview {
+    pub rating: u8, // 0-重来, 1-困难, 2-良好, 3-简单
+    pub due: i64,
+    pub interval_seconds: i64,
+    pub interval_text: String, // 按钮上显示的间隔，如 10m、1d、3.5mo
+    pub card: CardMemoryState,
+}
+
+/// 一次返回四个评分按钮的下次复习时间和记忆状态，复习界面用来显示按钮上的间隔
+#[flutter_rust_bridge::frb]
+pub fn preview_schedule(card: CardMemoryState, now: i64, config: SchedulerConfig) -> Result<Vec<SchedulePreview>, AnkiError> {
+    let scheduler = build_scheduler(&config).inspect_err(|e| rust_log(&format!("[调度] 调度配置无效: {e}")))?;
+    let review_time = Utc.timestamp_opt(now, 0).single().unwrap_or(Utc::now());
+    let previews: Vec<SchedulePreview> = scheduler
+        .preview_states(&card, review_time)
+        .into_iter()
+        .map(|(rating, next)| {
+            let interval_seconds = next.due - now;
+            SchedulePreview {
+                rating: rating_to_u8(rating),
+                due: next.due,
+                interval_seconds,
+                interval_text: format_interval(interval_seconds),
+                card: next,
+            }
+        })
+        .collect();
+    if previews.len() != 4 {
+        return Err(AnkiError::new(ErrorKind::Scheduler, "调度失败"));
+    }
+    Ok(previews)
+}
+
+/// 读取卡片当前的记忆状态，导入时没有恢复过的卡片按 SM-2 参数估算
 #[flutter_rust_bridge::frb]
-pub fn update_card_schedule_simple(
-    stability: f64,
-    difficulty: f64,
-    last_review: i64, // unix timestamp (秒)
-    rating: u8,       // 0-困难, 1-一般, 2-简单
-    now: i64          // 当前时间戳（秒）
-) -> Result<FsrsScheduleResult, String> {
-    rust_log(&format!("[Simple] 开始简单调度计算"));
-    rust_log(&format!("[Simple] 输入参数: stability={}, difficulty={}, rating={}", 
-        stability, difficulty, rating));
-    
-    let mut new_stability = stability;
-    let mut new_difficulty = difficulty;
-    let mut interval_minutes: i64 = 0;
-    
-    match rating {
-        0 => { // 困难
-            new_stability = (stability * 0.5).max(0.1);
-            new_difficulty = (difficulty + 0.2).min(10.0);
-            interval_minutes = 5; // 困难的卡片5分钟后重试
-        },
-        1 => { // 一般
-            new_stability = (stability * 0.8).max(0.5);
-            new_difficulty = (difficulty + 0.15).min(10.0);
-            interval_minutes = 10; // 一般的卡片10分钟后重试
-        },
-        2 => { // 简单
-            new_stability = (stability * 1.2).max(1.0);
-            new_difficulty = difficulty; // 保持不变
-            interval_minutes = 30; // 简单的卡片30分钟后重试
-        },
-        _ => return Err("无效的rating，必须是0-2之间的值".to_string()),
-    }
-    
-    let due_timestamp = now + (interval_minutes * 60);
-    
-    rust_log(&format!("[Simple] 调度结果: interval_minutes={}, due_timestamp={}", 
-        interval_minutes, due_timestamp));
-    rust_log(&format!("[Simple] 新参数: stability={}, difficulty={}", new_stability, new_difficulty));
-    
-    Ok(FsrsScheduleResult {
-        due: due_timestamp,
-        stability: new_stability,
-        difficulty: new_difficulty,
+pub fn get_card_memory_state(deck_id: String, card_id: i64, now: i64) -> Result<CardMemoryState, AnkiError> {
+    collection::with_collection_read(|conn| {
+        let config = load_scheduler_config(conn, &deck_id)?;
+        load_card_memory_state(conn, card_id, &config, now)
     })
 }
 
+/// 对牌组里的一张卡片评分：按牌组配置调度，把结果写回 cards 表并记录 revlog，返回新的记忆状态。
+/// 卡片不在牌组里时返回 NotFound，已暂停或搁置时返回 InvalidArgument。
+/// 评分结果、难记卡片的处理和兄弟卡片的搁置在同一个事务里提交，提交后才发送难记卡片事件
 #[flutter_rust_bridge::frb]
-pub fn update_card_schedule(
-    stability: f64,
-    difficulty: f64,
-    last_review: i64, // unix timestamp (秒)
-    rating: u8,       // 0-困难, 1-一般, 2-简单
-    now: i64          // 当前时间戳（秒）
-) -> Result<FsrsScheduleResult, String> {
-    rust_log(&format!("[FSRS] 开始调度计算"));
-    rust_log(&format!("[FSRS] 输入参数: stability={}, difficulty={}, last_review={}, rating={}, now={}", 
-        stability, difficulty, last_review, rating, now));
-    
-    // 构造FSRS参数
-    let card = Card {
-        stability,
-        difficulty,
-        last_review: Utc.timestamp_opt(last_review, 0).single().unwrap_or(Utc::now()),
-        due: Utc.timestamp_opt(now, 0).single().unwrap_or(Utc::now()),
-        ..Default::default()
-    };
-    
-    // 使用默认参数，但添加日志
-    let fsrs = FSRS::default();
-    
-    let rating = match rating {
-        0 => Rating::Again,  // 困难
-        1 => Rating::Hard,   // 一般
-        2 => Rating::Good,   // 简单
-        _ => return Err("无效的rating，必须是0-2之间的值".to_string()),
-    };
-    
+pub fn answer_card(deck_id: String, card_id: i64, rating: u8, now: i64) -> Result<CardMemoryState, AnkiError> {
+    rust_log(&format!("[调度] answer_card: deck_id={}, card_id={}, rating={}, now={}", deck_id, card_id, rating, now));
+    let rating = rating_from_u8(rating)?;
+    let (next, leech) = collection::with_collection(|conn| {
+        let config = load_scheduler_config(conn, &deck_id)?;
+        answer_in_collection(conn, &deck_id, card_id, rating, &config, now)
+    })?;
+    if let Some(event) = leech {
+        emit_leech(event);
+    }
+    rust_log(&format!("[调度] answer_card 结果: state={:?}, step={}, due={}, scheduled_days={}",
+        next.state, next.step, next.due, next.scheduled_days));
+    Ok(next)
+}
+
+// 按配置算出卡片评分前后的记忆状态
+fn next_card_state(
+    conn: &Connection,
+    card_id: i64,
+    rating: Rating,
+    config: &SchedulerConfig,
+    now: i64,
+) -> Result<(CardMemoryState, CardMemoryState), AnkiError> {
+    let scheduler = build_scheduler(config).inspect_err(|e| rust_log(&format!("[调度] 调度配置无效: {e}")))?;
+    let card = load_card_memory_state(conn, card_id, config, now)
+        .inspect_err(|e| rust_log(&format!("[调度] 读取卡片状态失败: {e}")))?;
     let review_time = Utc.timestamp_opt(now, 0).single().unwrap_or(Utc::now());
-    let result = fsrs.repeat(card.clone(), review_time);
-    let item = result.get(&rating).ok_or("FSRS调度失败")?;
-    
-    let due_timestamp = item.card.due.timestamp();
-    let interval_seconds = due_timestamp - now;
-    let interval_minutes = interval_seconds / 60;
-    let interval_hours = interval_seconds / 3600;
-    let interval_days = interval_seconds / 86400;
-    
-    rust_log(&format!("[FSRS] 调度结果: due={}, interval_seconds={}, interval_minutes={}, interval_hours={}, interval_days={}", 
-        due_timestamp, interval_seconds, interval_minutes, interval_hours, interval_days));
-    rust_log(&format!("[FSRS] 新参数: stability={}, difficulty={}", item.card.stability, item.card.difficulty));
-    
-    Ok(FsrsScheduleResult {
-        due: due_timestamp,
-        stability: item.card.stability,
-        difficulty: item.card.difficulty,
+    let next = scheduler.next_state(&card, rating, review_time);
+    Ok((card, next))
+}
+
+// 只能给牌组里没有暂停或搁置的卡片评分，别的牌组的卡片会用错牌组配置
+fn check_answerable(conn: &Connection, deck_id: &str, card_id: i64) -> Result<(), AnkiError> {
+    let queue: Option<i64> = conn
+        .prepare_cached(&format!("SELECT queue FROM cards WHERE id = ? AND {}", collection::IN_DECK))
+        .context("准备SQL失败")?
+        .query_row(rusqlite::params![card_id, deck_id], |row| row.get(0))
+        .optional()
+        .context("查询card失败")?;
+    match queue {
+        None => Err(AnkiError::not_found(format!("牌组 {deck_id} 里没有卡片 {card_id}"))),
+        Some(queue) if queue < 0 => Err(AnkiError::invalid_argument(format!("卡片 {card_id} 已暂停或搁置，不能评分"))),
+        Some(_) => Ok(()),
+    }
+}
+
+// 评分结果、难记卡片的处理和兄弟卡片的搁置在同一个事务里提交；难记卡片事件由调用方在提交后发送
+fn answer_in_collection(
+    conn: &mut Connection,
+    deck_id: &str,
+    card_id: i64,
+    rating: Rating,
+    config: &SchedulerConfig,
+    now: i64,
+) -> Result<(CardMemoryState, Option<LeechEvent>), AnkiError> {
+    check_answerable(conn, deck_id, card_id).inspect_err(|e| rust_log(&format!("[调度] 不能评分: {e}")))?;
+    let (card, next) = next_card_state(conn, card_id, rating, config, now)?;
+    let tx = conn.transaction().context("开启事务失败")?;
+    save_answer(&tx, card_id, &card, &next, rating, config, now)
+        .inspect_err(|e| rust_log(&format!("[调度] 保存复习结果失败: {e}")))?;
+    let mut leech = None;
+    if next.lapses > card.lapses && is_leech(next.lapses, config.leech_threshold) {
+        let (note_id, suspended) = handle_leech(&tx, deck_id, card_id, config, now)
+            .inspect_err(|e| rust_log(&format!("[调度] 处理难记卡片失败: {e}")))?;
+        leech = Some(LeechEvent { deck_id: deck_id.to_string(), card_id, note_id, lapses: next.lapses, suspended });
+    }
+    let (day, _) = today(&tx, now)?;
+    let buried = bury_siblings(&tx, card_id, config, day, now)
+        .inspect_err(|e| rust_log(&format!("[调度] 搁置兄弟卡片失败: {e}")))?;
+    tx.commit().context("提交事务失败")?;
+    if buried > 0 {
+        rust_log(&format!("[调度] 已搁置 {} 张兄弟卡片", buried));
+    }
+    Ok((next, leech))
+}
+
+/// 卡片成为难记卡片时发给 Flutter 的事件
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LeechEvent {
+    pub deck_id: String,
+    pub card_id: i64,
+    pub note_id: i64,
+    pub lapses: i32,
+    pub suspended: bool, // 按牌组选项已经暂停了这张卡片
+}
+
+/// 注册难记卡片事件的接收端，answer_card 里卡片成为难记卡片时发送
+#[flutter_rust_bridge::frb]
+pub fn register_leech_callback(sink: StreamSink<LeechEvent>) {
+    *LEECH_SINK.lock().unwrap_or_else(|e| e.into_inner()) = Some(sink);
+}
+
+fn emit_leech(event: LeechEvent) {
+    rust_log(&format!("[调度] 卡片 {} 成为难记卡片, lapses={}, suspended={}", event.card_id, event.lapses, event.suspended));
+    if let Some(sink) = &*LEECH_SINK.lock().unwrap_or_else(|e| e.into_inner()) {
+        let _ = sink.add(event);
+    }
+}
+
+/// 当天的学习队列（学习/重学步骤中、按秒调度的卡片）
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IntradayLearningQueue {
+    pub due: Vec<i64>,         // 已到期的卡片 id，按到期时间排序，应先于其他卡片显示
+    pub learn_ahead: Vec<i64>, // 提前学习时限内到期的卡片 id，没有其他卡片可学时才显示
+    pub next_due: Option<i64>, // 下一张未到期学习卡的到期时间，没有时为空
+}
+
+/// 读取当天的学习队列，learn_ahead 按牌组配置的提前学习时限计算
+#[flutter_rust_bridge::frb]
+pub fn get_intraday_learning_queue(deck_id: String, now: i64) -> Result<IntradayLearningQueue, AnkiError> {
+    let (config, cards) = collection::with_collection_read(|conn| {
+        let config = load_scheduler_config(conn, &deck_id)?;
+        let cards = load_intraday_learning(conn, &deck_id).inspect_err(|e| rust_log(&format!("[调度] 读取学习队列失败: {e}")))?;
+        Ok((config, cards))
+    })?;
+    let (due, learn_ahead) = split_learning_queue(&cards, now, config.learn_ahead_secs as i64);
+    let next_due = cards.iter().map(|(_, due)| *due).find(|due| *due > now);
+    Ok(IntradayLearningQueue { due, learn_ahead, next_due })
+}
+
+/// 卡片在队列里的类别
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueueKind {
+    New,
+    Learning, // 学习或重学步骤中的卡片
+    Review,
+}
+
+/// 队列里的一张卡片
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QueuedCard {
+    pub card_id: i64,
+    pub note_id: i64,
+    pub kind: QueueKind,
+}
+
+/// 今天的新卡队列
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewCardQueue {
+    pub cards: Vec<QueuedCard>, // 按牌组配置的收集顺序和排序方式排好，已扣掉今天学过的新卡
+    pub introduced_today: u32,  // 今天已经学过的新卡数
+    pub available: u32,         // 牌组里能学的新卡总数，不含暂停和搁置的
+}
+
+/// 按牌组配置的每天上限、收集顺序和排序方式构建今天的新卡队列
+#[flutter_rust_bridge::frb]
+pub fn get_new_card_queue(deck_id: String, now: i64) -> Result<NewCardQueue, AnkiError> {
+    rust_log(&format!("[调度] get_new_card_queue: deck_id={}, now={}", deck_id, now));
+    collection::with_collection(|conn| {
+        let config = load_scheduler_config(conn, &deck_id)?;
+        build_new_queue(conn, &deck_id, &config, now)
+    })
+    .inspect_err(|e| rust_log(&format!("[调度] 构建新卡队列失败: {e}")))
+}
+
+/// 构建复习队列时的每日上限，为空时使用牌组配置里的上限
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct QueueLimits {
+    pub new_cards: Option<u32>,
+    pub reviews: Option<u32>,
+}
+
+/// 现在要学的卡片，按显示顺序排列
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReviewQueue {
+    pub cards: Vec<QueuedCard>,
+    pub new_count: u32,
+    pub learning_count: u32,
+    pub review_count: u32,
+    pub next_learning_due: Option<i64>, // 下一张还没到期（也不在提前学习时限内）的学习卡的到期时间
+}
+
+/// 构建现在的学习队列：已到期的学习卡在最前，之后是按牌组配置排序、穿插了新卡的复习卡，
+/// 最后是提前学习时限内的学习卡。复习卡和新卡扣掉今天已经学过的数量后按每日上限截断
+#[flutter_rust_bridge::frb]
+pub fn build_review_queue(deck_id: String, now: i64, limits: QueueLimits) -> Result<ReviewQueue, AnkiError> {
+    rust_log(&format!("[调度] build_review_queue: deck_id={}, now={}, limits={:?}", deck_id, now, limits));
+    collection::with_collection(|conn| {
+        let config = load_scheduler_config(conn, &deck_id)?;
+        build_queue(conn, &deck_id, &config, &limits, now)
     })
+    .inspect_err(|e| rust_log(&format!("[调度] 构建复习队列失败: {e}")))
+}
+
+/// 恢复牌组里所有搁置的卡片（回答后自动搁置的和手动搁置的），返回恢复的卡片数
+#[flutter_rust_bridge::frb]
+pub fn unbury_deck(deck_id: String) -> Result<u32, AnkiError> {
+    rust_log(&format!("[调度] unbury_deck: deck_id={}", deck_id));
+    collection::with_collection(|conn| unbury(conn, &deck_id))
+        .map(|count| count as u32)
+        .inspect_err(|e| rust_log(&format!("[调度] 恢复搁置的卡片失败: {e}")))
+}
+
+/// 卡片的暂停、搁置、旗标和标记状态
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CardStatus {
+    pub card_id: i64,
+    pub note_id: i64,
+    pub suspended: bool,
+    pub buried: bool, // 回答后自动搁置或手动搁置
+    pub flag: u8,     // 0 表示没有旗标，1-7 对应 Anki 的七种颜色
+    pub marked: bool, // 笔记带有 marked 标签
+}
+
+/// 读取牌组里卡片的暂停、搁置、旗标和标记状态，不存在或不在牌组里的卡片不返回
+#[flutter_rust_bridge::frb]
+pub fn get_card_status(deck_id: String, card_ids: Vec<i64>) -> Result<Vec<CardStatus>, AnkiError> {
+    collection::with_collection_read(|conn| card_state::statuses(conn, &deck_id, &card_ids))
+}
+
+/// 牌组里暂停的卡片 id，包括导入的集合里已经暂停的
+#[flutter_rust_bridge::frb]
+pub fn get_suspended_card_ids(deck_id: String) -> Result<Vec<i64>, AnkiError> {
+    collection::with_collection_read(|conn| card_state::suspended_card_ids(conn, &deck_id))
+}
+
+// 批量修改牌组里卡片的状态，不在牌组里的卡片跳过，分批执行的几条语句放在同一个事务里，返回状态有变化的卡片数
+fn update_card_states(
+    deck_id: &str,
+    card_ids: &[i64],
+    update: impl FnOnce(&Connection, &str, &[i64], i64) -> Result<usize, AnkiError>,
+) -> Result<u32, AnkiError> {
+    collection::with_collection(|conn| {
+        let tx = conn.transaction().context("开启事务失败")?;
+        let count = update(&tx, deck_id, card_ids, Utc::now().timestamp())?;
+        tx.commit().context("提交事务失败")?;
+        Ok(count as u32)
+    })
+}
+
+/// 暂停牌组里的卡片，暂停后不会出现在任何队列里，返回状态有变化的卡片数
+#[flutter_rust_bridge::frb]
+pub fn suspend_cards(deck_id: String, card_ids: Vec<i64>) -> Result<u32, AnkiError> {
+    rust_log(&format!("DEBUG: suspend_cards 被调用, deck_id={}, card_ids={:?}", deck_id, card_ids));
+    update_card_states(&deck_id, &card_ids, card_state::suspend)
+        .inspect_err(|e| rust_log(&format!("DEBUG: 暂停卡片失败: {}", e)))
+}
+
+/// 取消暂停，卡片回到暂停前所在的队列，返回状态有变化的卡片数
+#[flutter_rust_bridge::frb]
+pub fn unsuspend_cards(deck_id: String, card_ids: Vec<i64>) -> Result<u32, AnkiError> {
+    rust_log(&format!("DEBUG: unsuspend_cards 被调用, deck_id={}, card_ids={:?}", deck_id, card_ids));
+    update_card_states(&deck_id, &card_ids, card_state::unsuspend)
+        .inspect_err(|e| rust_log(&format!("DEBUG: 取消暂停失败: {}", e)))
+}
+
+/// 手动搁置牌组里的卡片，换天后自动恢复，暂停的卡片不变，返回状态有变化的卡片数
+#[flutter_rust_bridge::frb]
+pub fn bury_cards(deck_id: String, card_ids: Vec<i64>) -> Result<u32, AnkiError> {
+    rust_log(&format!("DEBUG: bury_cards 被调用, deck_id={}, card_ids={:?}", deck_id, card_ids));
+    update_card_states(&deck_id, &card_ids, card_state::bury)
+        .inspect_err(|e| rust_log(&format!("DEBUG: 搁置卡片失败: {}", e)))
 }
 
+/// 恢复搁置的卡片，返回状态有变化的卡片数
 #[flutter_rust_bridge::frb]
-pub fn get_all_note_ids(sqlite_path: String, version: String) -> Result<Vec<i64>, String> {
-    use rusqlite::Connection;
-    let conn = Connection::open(&sqlite_path).map_err(|e| format!("打开sqlite失败: {e}"))?;
-    let mut stmt = conn.prepare("SELECT id FROM notes ORDER BY id").map_err(|e| format!("准备SQL失败: {e}"))?;
-    let rows = stmt.query_map([], |row| row.get(0)).map_err(|e| format!("查询SQL失败: {e}"))?;
-    let mut ids = Vec::new();
-    for id_result in rows {
-        let id: i64 = id_result.map_err(|e| format!("读取id失败: {e}"))?;
-        ids.push(id);
+pub fn unbury_cards(deck_id: String, card_ids: Vec<i64>) -> Result<u32, AnkiError> {
+    rust_log(&format!("DEBUG: unbury_cards 被调用, deck_id={}, card_ids={:?}", deck_id, card_ids));
+    update_card_states(&deck_id, &card_ids, card_state::unbury)
+        .inspect_err(|e| rust_log(&format!("DEBUG: 恢复搁置的卡片失败: {}", e)))
+}
+
+/// 设置牌组里卡片的旗标，flag: 0-清除, 1-7 对应 Anki 的七种颜色，返回旗标有变化的卡片数
+#[flutter_rust_bridge::frb]
+pub fn set_card_flag(deck_id: String, card_ids: Vec<i64>, flag: u8) -> Result<u32, AnkiError> {
+    rust_log(&format!("DEBUG: set_card_flag 被调用, deck_id={}, card_ids={:?}, flag={}", deck_id, card_ids, flag));
+    update_card_states(&deck_id, &card_ids, |conn, deck_id, card_ids, now| card_state::set_flag(conn, deck_id, card_ids, flag, now))
+        .inspect_err(|e| rust_log(&format!("DEBUG: 设置旗标失败: {}", e)))
+}
+
+/// 切换笔记的 marked 标签，返回切换后是否标记
+#[flutter_rust_bridge::frb]
+pub fn toggle_note_marked(note_id: i64) -> Result<bool, AnkiError> {
+    rust_log(&format!("DEBUG: toggle_note_marked 被调用, note_id={}", note_id));
+    collection::with_collection(|conn| card_state::toggle_marked(conn, note_id, Utc::now().timestamp()))
+        .inspect_err(|e| rust_log(&format!("DEBUG: 切换标记失败: {}", e)))
+}
+
+/// 一条复习记录，可以来自导入的 Anki revlog，也可以是应用内记录的复习
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReviewLogEntry {
+    pub card_id: i64,
+    pub review_time: i64, // 复习时间戳（秒）
+    pub rating: u8,       // 0-重来, 1-困难, 2-良好, 3-简单
+}
+
+/// 优化进度，最后一条消息带上优化结果
+#[derive(Debug, Clone)]
+pub struct OptimizeProgress {
+    pub current: u32,
+    pub total: u32,
+    pub result: Option<OptimizeResult>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OptimizeResult {
+    pub weights: Vec<f64>, // 优化后的 19 个 FSRS 参数
+    pub log_loss_before: f64,
+    pub log_loss_after: f64,
+    pub rmse_before: f64,
+    pub rmse_after: f64,
+    pub review_count: u32, // 参与训练的复习条数
+}
+
+/// 读取牌组的 revlog，跳过手动改期等没有评分的记录
+#[flutter_rust_bridge::frb]
+pub fn get_review_logs(deck_id: String) -> Result<Vec<ReviewLogEntry>, AnkiError> {
+    rust_log(&format!("DEBUG: get_review_logs 被调用, deck_id={}", deck_id));
+    collection::with_collection_read(|conn| load_review_logs(conn, &deck_id))
+}
+
+fn load_review_logs(conn: &Connection, deck_id: &str) -> Result<Vec<ReviewLogEntry>, AnkiError> {
+    // revlog.id 是毫秒时间戳，ease 为 1-4，type 4/5 是手动改期和重排
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT cid, id, ease FROM revlog WHERE ease BETWEEN 1 AND 4 AND type IN (0, 1, 2, 3) \
+             AND cid IN (SELECT id FROM cards WHERE {}) ORDER BY id",
+            collection::IN_DECK
+        ))
+        .context("准备SQL失败")?;
+    let rows = stmt
+        .query_map([deck_id], |row| {
+            Ok(ReviewLogEntry {
+                card_id: row.get(0)?,
+                review_time: row.get::<_, i64>(1)? / 1000,
+                rating: row.get::<_, u8>(2)? - 1,
+            })
+        })
+        .context("查询SQL失败")?;
+    rows.collect::<Result<Vec<_>, _>>().context("读取revlog失败")
+}
+
+/// 用复习记录训练 FSRS 参数，训练进度和最终结果都通过 progress 推送。
+/// 复习间隔按集合的换天时刻划分学习日，需要先打开集合；rating 超出 0-3 时返回错误
+#[flutter_rust_bridge::frb]
+pub fn optimize_fsrs_parameters(review_log: Vec<ReviewLogEntry>, progress: StreamSink<OptimizeProgress>) -> Result<(), AnkiError> {
+    rust_log(&format!("[FSRS] 开始优化参数, 复习记录数={}", review_log.len()));
+    if let Some(entry) = review_log.iter().find(|r| r.rating > 3) {
+        return Err(AnkiError::invalid_argument(format!("卡片{}的复习记录rating={}无效，必须是0-3之间的值", entry.card_id, entry.rating)));
     }
-    Ok(ids)
+    let reviews: Vec<(i64, i64, u8)> = review_log.iter().map(|r| (r.card_id, r.review_time, r.rating + 1)).collect();
+    // 天数和调度一样按集合的学习日算
+    let timing = collection::with_collection_read(|conn| timing::load(conn))?;
+    let cards = build_card_histories(&reviews, &timing);
+    let output = optimize_weights(&cards, &default_scheduler_weights(), &mut |current, total| {
+        let _ = progress.add(OptimizeProgress { current: current as u32, total: total as u32, result: None });
+    })
+    .inspect_err(|e| rust_log(&format!("[FSRS] 优化参数失败: {e}")))?;
+    rust_log(&format!("[FSRS] 优化完成: log_loss {} -> {}, rmse {} -> {}, weights={:?}",
+        output.log_loss_before, output.log_loss_after, output.rmse_before, output.rmse_after, output.weights));
+    let result = OptimizeResult {
+        weights: output.weights,
+        log_loss_before: output.log_loss_before,
+        log_loss_after: output.log_loss_after,
+        rmse_before: output.rmse_before,
+        rmse_after: output.rmse_after,
+        review_count: output.item_count as u32,
+    };
+    progress
+        .add(OptimizeProgress { current: output.total_steps as u32, total: output.total_steps as u32, result: Some(result) })
+        .map_err(|e| AnkiError::new(ErrorKind::Io, "推送优化结果失败").with_message(format!("{e:?}")))?;
+    Ok(())
 }
 
+/// 牌组里的全部笔记 id，按 id 排序，卡片全部暂停的笔记不算
 #[flutter_rust_bridge::frb]
-pub fn get_new_note_ids(sqlite_path: String, limit: usize, version: String) -> Result<Vec<i64>, String> {
-    use rusqlite::Connection;
-    let conn = Connection::open(&sqlite_path).map_err(|e| format!("打开sqlite失败: {e}"))?;
-    let mut stmt = conn.prepare("SELECT id FROM notes ORDER BY id LIMIT ?").map_err(|e| format!("准备SQL失败: {e}"))?;
-    let rows = stmt.query_map([limit as i64], |row| row.get(0)).map_err(|e| format!("查询SQL失败: {e}"))?;
-    let mut ids = Vec::new();
-    for id_result in rows {
-        let id: i64 = id_result.map_err(|e| format!("读取id失败: {e}"))?;
-        ids.push(id);
+pub fn get_all_note_ids(deck_id: String) -> Result<Vec<i64>, AnkiError> {
+    rust_log(&format!("DEBUG: get_all_note_ids 被调用, deck_id={}", deck_id));
+    collection::with_collection_read(|conn| collection::note_ids(conn, &deck_id))
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_new_note_ids(deck_id: String, limit: usize) -> Result<Vec<i64>, AnkiError> {
+    rust_log(&format!("DEBUG: get_new_note_ids 被调用, deck_id={}, limit={}", deck_id, limit));
+    // 复习界面还是按笔记显示，取新卡队列里卡片所属的笔记，按队列顺序去重
+    let queue = get_new_card_queue(deck_id, Utc::now().timestamp())?;
+    let mut seen = BTreeSet::new();
+    Ok(queue.cards.into_iter().map(|c| c.note_id).filter(|nid| seen.insert(*nid)).take(limit).collect())
+}
+#[cfg(test)]
+mod tests {
+    use rusqlite::params;
+    use crate::collection::{add_test_card, memory_collection};
+    use super::*;
+
+    const DECK: &str = "deck";
+
+    // 集合创建后第 3 天的中午
+    fn noon(conn: &Connection) -> i64 {
+        timing::collection_crt(conn).unwrap() + 3 * 86400 + 12 * 3600
+    }
+
+    fn collection_with_cards(now: i64) -> Connection {
+        let conn = memory_collection();
+        add_test_card(&conn, DECK, 1, 100, 1000, 0);
+        add_test_card(&conn, DECK, 1, 101, 1001, 0);
+        // 10 天前复习过、今天到期的复习卡
+        let (day, _) = today(&conn, now).unwrap();
+        let data = format!(r#"{{"s":10.0,"d":5.0,"lrt":{}}}"#, now - 10 * 86400);
+        conn.execute(
+            "UPDATE cards SET type = 2, queue = 2, due = ?, ivl = 10, factor = 2500, reps = 3, data = ? WHERE id = 1001",
+            params![day, data],
+        )
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn preview_matches_persisted_answer() {
+        for card_id in [1000, 1001] {
+            for rating in 0..4u8 {
+                let mut conn = collection_with_cards(0);
+                let now = noon(&conn);
+                let config = load_scheduler_config(&conn, DECK).unwrap();
+                let card = load_card_memory_state(&conn, card_id, &config, now).unwrap();
+                let preview = preview_schedule(card, now, config.clone()).unwrap().remove(rating as usize);
+                assert_eq!(preview.rating, rating);
+                let (next, _) = answer_in_collection(&mut conn, DECK, card_id, rating_from_u8(rating).unwrap(), &config, now).unwrap();
+                assert_eq!((next.state, next.due, next.step), (preview.card.state, preview.due, preview.card.step));
+                assert_eq!((next.stability, next.difficulty), (preview.card.stability, preview.card.difficulty));
+
+                // 写回集合的状态和预览的一致
+                let saved = load_card_memory_state(&conn, card_id, &config, now).unwrap();
+                assert_eq!((saved.state, saved.step, saved.reps, saved.lapses), (next.state, next.step, next.reps, next.lapses));
+                // cards.data 里的 JSON 数字不保证最后一位精度
+                assert!((saved.stability - next.stability).abs() < 1e-9 * next.stability.max(1.0));
+                assert!((saved.difficulty - next.difficulty).abs() < 1e-9);
+                assert_eq!(saved.last_review, Some(now));
+                let (queue, due, ivl): (i64, i64, i64) = conn
+                    .query_row("SELECT queue, due, ivl FROM cards WHERE id = ?", [card_id], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+                    .unwrap();
+                if preview.card.state == CardState::Review {
+                    let timing = timing::load(&conn).unwrap();
+                    assert_eq!((queue, due, ivl), (2, timing.day(preview.due), preview.card.scheduled_days));
+                } else {
+                    assert_eq!((queue, due), (1, preview.due));
+                }
+                assert_eq!(preview.interval_seconds, preview.due - now);
+                assert_eq!(preview.interval_text, format_interval(preview.interval_seconds));
+                let ease: i64 = conn.query_row("SELECT ease FROM revlog WHERE cid = ?", [card_id], |row| row.get(0)).unwrap();
+                assert_eq!(ease, rating as i64 + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn preview_lists_every_rating_in_order() {
+        let conn = collection_with_cards(0);
+        let now = noon(&conn);
+        let config = load_scheduler_config(&conn, DECK).unwrap();
+        let card = load_card_memory_state(&conn, 1000, &config, now).unwrap();
+        let previews = preview_schedule(card, now, config).unwrap();
+        let texts: Vec<(u8, &str)> = previews.iter().map(|p| (p.rating, p.interval_text.as_str())).collect();
+        assert_eq!(texts[..3], [(0, "1m"), (1, "6m"), (2, "10m")]);
+        assert_eq!(texts[3].0, 3);
+        assert!(texts[3].1.ends_with('d'), "{:?}", texts[3]);
+    }
+
+    // 遗忘了 7 次的复习卡 1001，再答“重来”就达到阈值 8
+    fn leech_collection(now: i64) -> (Connection, SchedulerConfig) {
+        let conn = collection_with_cards(now);
+        conn.execute("UPDATE cards SET lapses = 7 WHERE id = 1001", []).unwrap();
+        let config = SchedulerConfig { leech_threshold: 8, leech_action: LeechAction::Suspend, ..Default::default() };
+        (conn, config)
+    }
+
+    fn card_queue_lapses_tags(conn: &Connection) -> (i64, i32, String) {
+        conn.query_row("SELECT c.queue, c.lapses, n.tags FROM cards c JOIN notes n ON n.id = c.nid WHERE c.id = 1001", [], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn leech_event_is_returned_with_the_committed_answer() {
+        let (mut conn, config) = leech_collection(0);
+        let now = noon(&conn);
+        let (next, leech) = answer_in_collection(&mut conn, DECK, 1001, Rating::Again, &config, now).unwrap();
+        let event = leech.unwrap();
+        assert_eq!((event.deck_id.as_str(), event.card_id, event.note_id, event.lapses, event.suspended), (DECK, 1001, 101, 8, true));
+        assert_eq!(next.lapses, 8);
+        // 返回时事务已经提交
+        assert!(conn.is_autocommit());
+        assert_eq!(card_queue_lapses_tags(&conn), (-1, 8, " leech ".to_string()));
+    }
+
+    #[test]
+    fn no_leech_event_when_the_answer_is_rolled_back() {
+        let (mut conn, mut config) = leech_collection(0);
+        let now = noon(&conn);
+        // 搁置兄弟卡片失败时整个评分回滚，难记卡片的标签和暂停也不保留
+        add_test_card(&conn, DECK, 1, 101, 1002, 1);
+        config.bury_new = true;
+        conn.execute_batch(
+            "CREATE TRIGGER fail_bury BEFORE UPDATE OF queue ON cards WHEN NEW.queue = -2 BEGIN SELECT RAISE(ABORT, 'bury failed'); END",
+        )
+        .unwrap();
+        assert!(answer_in_collection(&mut conn, DECK, 1001, Rating::Again, &config, now).is_err());
+        assert_eq!(card_queue_lapses_tags(&conn), (2, 7, String::new()));
     }
-    Ok(ids)
-}
\ No newline at end of file
+
+    fn cancel_flag_exists(task_id: &str) -> bool {
+        IMPORT_CANCEL_FLAGS.lock().unwrap().contains_key(task_id)
+    }
+
+    #[test]
+    fn cancel_removes_staged_files_and_reports_cancelled() {
+        use std::io::Write;
+        let base = crate::apkg::TempDir::new(&std::env::temp_dir()).unwrap();
+        let apkg = base.path.join("deck.apkg");
+        let mut zip = zip::ZipWriter::new(fs::File::create(&apkg).unwrap());
+        zip.start_file("collection.anki21", zip::write::FileOptions::default()).unwrap();
+        zip.write_all(&[0; 4096]).unwrap();
+        zip.finish().unwrap();
+
+        let task_id = "cancel-test";
+        let mut phases = Vec::new();
+        // 第一条进度到达时取消，这时解包的临时目录马上就要建好
+        let mut send = |p: &ImportProgress| {
+            if phases.is_empty() {
+                cancel_import(task_id.to_string());
+            }
+            phases.push(p.phase);
+        };
+        let result = run_import_task(task_id, &mut send, |tracker| {
+            stage_package(&apkg, &base.path, tracker)?;
+            Err(AnkiError::invalid_argument("导入应该已经取消"))
+        });
+        assert_eq!(result.unwrap_err().kind, ErrorKind::Cancelled);
+        assert_eq!(phases.first(), Some(&ImportPhase::Extracting));
+        assert_eq!(phases.last(), Some(&ImportPhase::Cancelled));
+        // 只剩下 apkg 本身
+        let left: Vec<_> = fs::read_dir(&base.path).unwrap().map(|e| e.unwrap().file_name()).collect();
+        assert_eq!(left, [std::ffi::OsString::from("deck.apkg")]);
+        assert!(!cancel_flag_exists(task_id));
+    }
+
+    #[test]
+    fn cancel_ignores_unknown_and_finished_tasks() {
+        cancel_import("unknown-task".to_string());
+        assert!(!cancel_flag_exists("unknown-task"));
+
+        let task_id = "finished-task";
+        let mut ignore = |_: &ImportProgress| {};
+        let result = run_import_task(task_id, &mut ignore, |tracker| {
+            assert!(cancel_flag_exists(task_id));
+            tracker.check_cancelled()?;
+            Err(AnkiError::invalid_argument("导入失败"))
+        });
+        assert_eq!(result.unwrap_err().kind, ErrorKind::InvalidArgument);
+        assert!(!cancel_flag_exists(task_id));
+        cancel_import(task_id.to_string());
+        assert!(!cancel_flag_exists(task_id));
+    }
+
+    #[test]
+    fn answer_rejects_cards_outside_the_deck() {
+        let mut conn = collection_with_cards(0);
+        add_test_card(&conn, "other", 2, 200, 2000, 0);
+        let now = noon(&conn);
+        let config = load_scheduler_config(&conn, DECK).unwrap();
+        for card_id in [2000, 9999] {
+            let err = answer_in_collection(&mut conn, DECK, card_id, Rating::Good, &config, now).unwrap_err();
+            assert_eq!(err.kind, ErrorKind::NotFound);
+        }
+        let reps: i64 = conn.query_row("SELECT reps FROM cards WHERE id = 2000", [], |row| row.get(0)).unwrap();
+        assert_eq!(reps, 0);
+        let revlogs: i64 = conn.query_row("SELECT count() FROM revlog", [], |row| row.get(0)).unwrap();
+        assert_eq!(revlogs, 0);
+    }
+
+    #[test]
+    fn answer_rejects_suspended_and_buried_cards() {
+        for queue in [-1, -2, -3] {
+            let mut conn = collection_with_cards(0);
+            let now = noon(&conn);
+            let config = load_scheduler_config(&conn, DECK).unwrap();
+            conn.execute("UPDATE cards SET queue = ? WHERE id = 1001", [queue]).unwrap();
+            let err = answer_in_collection(&mut conn, DECK, 1001, Rating::Good, &config, now).unwrap_err();
+            assert_eq!(err.kind, ErrorKind::InvalidArgument);
+            let state: (i64, i64) =
+                conn.query_row("SELECT queue, reps FROM cards WHERE id = 1001", [], |row| Ok((row.get(0)?, row.get(1)?))).unwrap();
+            assert_eq!(state, (queue, 3));
+        }
+    }
+
+    fn add_test_notetype(conn: &Connection, id: i64, name: &str, templates: &[(&str, &str)]) {
+        use prost::Message;
+        use crate::anki_proto::{FieldConfig, NotetypeConfig, TemplateConfig};
+        let config = NotetypeConfig { css: format!(".{name} {{}}"), ..Default::default() };
+        conn.execute(
+            "INSERT INTO notetypes (id, name, mtime_secs, usn, config) VALUES (?, ?, 0, 0, ?)",
+            params![id, name, config.encode_to_vec()],
+        )
+        .unwrap();
+        for (ord, field) in ["Front", "Back"].into_iter().enumerate() {
+            conn.execute(
+                "INSERT INTO fields (ntid, ord, name, config) VALUES (?, ?, ?, ?)",
+                params![id, ord, field, FieldConfig::default().encode_to_vec()],
+            )
+            .unwrap();
+        }
+        for (ord, &(qfmt, afmt)) in templates.iter().enumerate() {
+            let config = TemplateConfig { q_format: qfmt.to_string(), a_format: afmt.to_string(), ..Default::default() };
+            conn.execute(
+                "INSERT INTO templates (ntid, ord, name, mtime_secs, usn, config) VALUES (?, ?, ?, 0, 0, ?)",
+                params![id, ord, format!("Card {}", ord + 1), config.encode_to_vec()],
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn cards_batch_shares_notetypes_by_id() {
+        let conn = memory_collection();
+        add_test_notetype(&conn, 1, "Basic", &[("{{Front}}", "{{Back}}"), ("{{Back}}", "{{Front}}")]);
+        add_test_notetype(&conn, 2, "Prefixed", &[("Q: {{Front}}", "A: {{Back}}")]);
+        add_test_card(&conn, DECK, 1, 100, 1000, 0);
+        add_test_card(&conn, DECK, 1, 100, 1001, 1);
+        add_test_card(&conn, DECK, 1, 101, 1002, 0);
+        add_test_card(&conn, "other", 2, 200, 2000, 0);
+        conn.execute("UPDATE notes SET mid = 2 WHERE id = 101", []).unwrap();
+
+        let batch = load_cards_batch(&conn, DECK, &[1002, 2000, 1000, 9999, 1001]).unwrap();
+        let card_ids: Vec<i64> = batch.cards.iter().map(|c| c.card_id).collect();
+        assert_eq!(card_ids, [1002, 1000, 1001]);
+        assert_eq!(batch.missing_card_ids, [2000, 9999]);
+        // 三张卡片只用到两个笔记类型，每个只返回一次
+        let notetype_ids: Vec<i64> = batch.notetypes.iter().map(|nt| nt.id).collect();
+        assert_eq!(notetype_ids, [1, 2]);
+        assert_eq!(batch.notetypes[0].templates.len(), 2);
+        assert_eq!(batch.notetypes[1].css, ".Prefixed {}");
+
+        let card = |id: i64| batch.cards.iter().find(|c| c.card_id == id).unwrap();
+        assert_eq!([card(1000).notetype_id, card(1001).notetype_id, card(1002).notetype_id], [1, 1, 2]);
+        assert_eq!((card(1000).question.as_str(), card(1000).template_ord), ("front", 0));
+        assert_eq!((card(1001).question.as_str(), card(1001).template_ord), ("back", 1));
+        assert_eq!(card(1002).question, "Q: front");
+    }
+}
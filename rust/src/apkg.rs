@@ -0,0 +1,881 @@
+// apkg 解包：只读一遍 apkg，边读边算 MD5，collection.anki21b 流式 zstd 解压到磁盘，媒体文件逐个写出，
+// 内存占用和 apkg 大小无关。
+// 解压到 base_dir 下的临时目录，导入集合库后删除；出错时临时目录也会被删掉。
+// 顺序读取依赖本地文件头里的文件大小，遇到使用 data descriptor 的 apkg（如 Java 的 ZipOutputStream 生成的）
+// 时改为按中央目录读取，这种情况下 MD5 需要单独再读一遍文件。
+// 读取的每一块数据都经过 ImportTracker，用来上报进度和检查是否已取消。
+// apkg 来自不可信的来源：条目名和媒体文件名都要先检查再落盘，解压出的数据量也有上限。
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use zip::read::read_zipfile_from_stream;
+use zip::result::ZipError;
+use zip::ZipArchive;
+use crate::anki_proto::{decode_media_entries, decode_package_metadata, PACKAGE_VERSION_LATEST, PACKAGE_VERSION_LEGACY_1, PACKAGE_VERSION_LEGACY_2};
+use crate::api::simple::{rust_log, ImportPhase, ImportProgress};
+use crate::error::{AnkiError, ResultExt};
+
+const READ_BUFFER_SIZE: usize = 1 << 16;
+// media 映射文件（JSON 或 protobuf），解压后超过这个大小的视为无效
+const MAX_MEDIA_MAP_SIZE: u64 = 64 << 20;
+// meta 文件只有一个版本号
+const MAX_META_SIZE: u64 = 1 << 20;
+// 媒体文件先按 apkg 里的数字编号写到这个目录，读到 media 映射后再改名
+const PENDING_MEDIA_DIR: &str = ".pending_media";
+pub const MEDIA_DIR: &str = "unarchived_media";
+pub const SQLITE_FILE: &str = "collection.sqlite";
+// 同一阶段内进度最多每 100ms 推送一次
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+// 解压限制，防止 zip 炸弹：单个条目和全部条目解压后的大小，以及解压超过一定大小后的压缩比
+const MAX_ENTRY_SIZE: u64 = 4 << 30;
+const MAX_TOTAL_SIZE: u64 = 8 << 30;
+const MAX_COMPRESSION_RATIO: u64 = 100;
+const RATIO_CHECK_THRESHOLD: u64 = 16 << 20;
+// Windows 的保留设备名，不区分大小写，带扩展名也不行
+const RESERVED_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8", "COM9",
+    "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// apkg 里的条目或媒体文件被拒绝的原因
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RejectReason {
+    EmptyName,
+    EmptyComponent, // 路径里有空的或 "." 的部分
+    AbsolutePath,
+    ParentDir,
+    NulByte,
+    Backslash,
+    PathSeparator, // 媒体文件名里有 /
+    ReservedName,
+    InvalidMediaKey, // media 映射里的编号不是数字
+    EntryTooLarge,
+    TotalTooLarge,
+    RatioTooHigh,
+}
+
+/// 被拒绝的条目：entry 是 zip 条目名或 media 映射里的文件名
+#[derive(Debug, Clone)]
+pub struct RejectedEntry {
+    pub entry: String,
+    pub reason: RejectReason,
+}
+
+impl RejectedEntry {
+    pub fn reason_text(&self) -> String {
+        match self.reason {
+            RejectReason::EmptyName => "文件名为空".to_string(),
+            RejectReason::EmptyComponent => "路径中有空的部分".to_string(),
+            RejectReason::AbsolutePath => "不能是绝对路径".to_string(),
+            RejectReason::ParentDir => "路径中不能有 ..".to_string(),
+            RejectReason::NulByte => "文件名中有 NUL 字符".to_string(),
+            RejectReason::Backslash => "文件名中有反斜杠".to_string(),
+            RejectReason::PathSeparator => "媒体文件名中不能有 /".to_string(),
+            RejectReason::ReservedName => "文件名是系统保留名".to_string(),
+            RejectReason::InvalidMediaKey => "媒体编号不是数字".to_string(),
+            RejectReason::EntryTooLarge => format!("解压后超过 {} MB", MAX_ENTRY_SIZE >> 20),
+            RejectReason::TotalTooLarge => format!("apkg 解压后总大小超过 {} MB", MAX_TOTAL_SIZE >> 20),
+            RejectReason::RatioTooHigh => format!("压缩比超过 {}:1", MAX_COMPRESSION_RATIO),
+        }
+    }
+}
+
+impl fmt::Display for RejectedEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "apkg条目被拒绝: {} - {}", self.entry, self.reason_text())
+    }
+}
+
+fn is_reserved_name(component: &str) -> bool {
+    let stem = component.split('.').next().unwrap_or_default().trim_end();
+    RESERVED_NAMES.iter().any(|r| r.eq_ignore_ascii_case(stem))
+}
+
+/// 检查要写到解包目录下的相对路径，只允许用 / 分隔的普通文件名
+fn check_relative_path(name: &str) -> Result<(), RejectReason> {
+    if name.is_empty() {
+        return Err(RejectReason::EmptyName);
+    }
+    if name.contains('\0') {
+        return Err(RejectReason::NulByte);
+    }
+    if name.contains('\\') {
+        return Err(RejectReason::Backslash);
+    }
+    let bytes = name.as_bytes();
+    let has_drive = bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':';
+    if name.starts_with('/') || has_drive || Path::new(name).is_absolute() {
+        return Err(RejectReason::AbsolutePath);
+    }
+    for component in name.split('/') {
+        match component {
+            "" | "." => return Err(RejectReason::EmptyComponent),
+            ".." => return Err(RejectReason::ParentDir),
+            _ if is_reserved_name(component) => return Err(RejectReason::ReservedName),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+// 媒体文件都放在媒体目录的第一层，文件名里不能带目录
+fn check_media_filename(name: &str) -> Result<(), RejectReason> {
+    check_relative_path(name)?;
+    if name.contains('/') {
+        return Err(RejectReason::PathSeparator);
+    }
+    Ok(())
+}
+
+// 写入前先看条目名和 zip 里声明的大小，声明的大小可能是假的，实际写出时还会再检查
+fn check_entry(name: &str, size: u64) -> Result<(), RejectedEntry> {
+    let reject = |reason| RejectedEntry { entry: name.to_string(), reason };
+    // 目录条目以 / 结尾
+    check_relative_path(name.strip_suffix('/').unwrap_or(name)).map_err(reject)?;
+    if size > MAX_ENTRY_SIZE {
+        return Err(reject(RejectReason::EntryTooLarge));
+    }
+    Ok(())
+}
+
+// 统计写出的解压数据，超过限制时让写入失败，原因记在 rejected 里
+struct SizeGuard<'b, W> {
+    inner: W,
+    compressed_size: u64,
+    written: u64,
+    total: &'b mut u64, // 整个 apkg 已解压的字节数
+    rejected: Option<RejectReason>,
+}
+
+impl<W: Write> Write for SizeGuard<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.written + buf.len() as u64;
+        let reason = if written > MAX_ENTRY_SIZE {
+            Some(RejectReason::EntryTooLarge)
+        } else if *self.total + buf.len() as u64 > MAX_TOTAL_SIZE {
+            Some(RejectReason::TotalTooLarge)
+        } else if written > RATIO_CHECK_THRESHOLD && written > self.compressed_size.saturating_mul(MAX_COMPRESSION_RATIO) {
+            Some(RejectReason::RatioTooHigh)
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            self.rejected = Some(reason);
+            return Err(io::Error::other("解压数据超出限制"));
+        }
+        let n = self.inner.write(buf)?;
+        self.written += n as u64;
+        *self.total += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// 已解包到临时目录、还没导入集合库的 apkg，丢弃时临时目录会被删除
+pub struct StagedPackage {
+    pub temp: TempDir,
+    pub md5: String,
+    pub version: String,                    // anki21b/anki21/anki2/unknown
+    pub media_map: HashMap<String, String>, // 文件名 -> 数字编号
+}
+
+/// 导入进度的上报和取消检查
+pub struct ImportTracker<'a> {
+    progress: ImportProgress,
+    cancelled: &'a AtomicBool,
+    sink: &'a mut dyn FnMut(&ImportProgress),
+    last_emit: Option<Instant>,
+}
+
+impl<'a> ImportTracker<'a> {
+    pub fn new(cancelled: &'a AtomicBool, sink: &'a mut dyn FnMut(&ImportProgress)) -> Self {
+        ImportTracker {
+            progress: ImportProgress {
+                phase: ImportPhase::Extracting,
+                bytes_processed: 0,
+                total_bytes: 0,
+                entries_done: 0,
+                entries_total: 0,
+                current_file: None,
+                result: None,
+            },
+            cancelled,
+            sink,
+            last_emit: None,
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn check_cancelled(&self) -> Result<(), AnkiError> {
+        if self.is_cancelled() {
+            Err(AnkiError::cancelled())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn emit(&mut self) {
+        (self.sink)(&self.progress);
+        self.last_emit = Some(Instant::now());
+    }
+
+    fn emit_throttled(&mut self) {
+        if self.last_emit.map(|t| t.elapsed() >= PROGRESS_INTERVAL).unwrap_or(true) {
+            self.emit();
+        }
+    }
+
+    /// 进入新阶段时总是推送一次
+    pub fn set_phase(&mut self, phase: ImportPhase) {
+        self.progress.phase = phase;
+        self.progress.current_file = None;
+        self.emit();
+    }
+
+    /// 推送最后一条进度，带上导入结果
+    pub fn finish(&mut self, result: crate::api::simple::ExtractResult) {
+        self.progress.phase = ImportPhase::Done;
+        self.progress.current_file = None;
+        self.progress.result = Some(result);
+        self.emit();
+    }
+
+    fn add_bytes(&mut self, n: usize) {
+        self.progress.bytes_processed += n as i64;
+        self.emit_throttled();
+    }
+
+    fn start_entry(&mut self, name: &str) {
+        self.progress.current_file = Some(name.to_string());
+        self.emit_throttled();
+    }
+
+    fn finish_entry(&mut self) {
+        self.progress.entries_done += 1;
+        self.emit_throttled();
+    }
+}
+
+// 读取时顺带算 MD5、统计字节数；取消后返回 EOF，让 zip 和 zstd 尽快结束，由调用方把错误换成“已取消”
+struct TrackingReader<'t, 'm, 'a, R> {
+    inner: R,
+    md5: md5::Context,
+    tracker: &'t RefCell<&'m mut ImportTracker<'a>>,
+}
+
+impl<R: Read> Read for TrackingReader<'_, '_, '_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.tracker.borrow().is_cancelled() {
+            return Ok(0);
+        }
+        let n = self.inner.read(buf)?;
+        self.md5.consume(&buf[..n]);
+        self.tracker.borrow_mut().add_bytes(n);
+        Ok(n)
+    }
+}
+
+impl<R: Seek> Seek for TrackingReader<'_, '_, '_, R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.inner.seek(pos)
+    }
+}
+
+// 从中央目录结尾记录读出条目总数，只读文件末尾一小段；ZIP64 或找不到时返回 None
+fn read_entry_count(path: &Path) -> Option<u32> {
+    let mut file = File::open(path).ok()?;
+    let len = file.metadata().ok()?.len();
+    // 结尾记录 22 字节，后面最多跟 65535 字节的注释
+    let tail_len = len.min(22 + 65535);
+    file.seek(SeekFrom::End(-(tail_len as i64))).ok()?;
+    let mut buf = vec![0; tail_len as usize];
+    file.read_exact(&mut buf).ok()?;
+    let pos = buf.windows(4).rposition(|w| w == [0x50, 0x4b, 0x05, 0x06])?;
+    let count = u16::from_le_bytes([*buf.get(pos + 10)?, *buf.get(pos + 11)?]);
+    (count != 0xFFFF).then_some(count as u32)
+}
+
+/// 临时目录，丢弃时自动删除
+pub struct TempDir {
+    pub path: PathBuf,
+}
+
+impl TempDir {
+    /// 在 base_dir 下建一个 .importing- 开头的临时目录，和集合的媒体目录在同一个文件系统上，媒体文件可以直接移过去
+    pub fn new(base_dir: &Path) -> Result<Self, AnkiError> {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        let temp = TempDir { path: base_dir.join(format!(".importing-{}-{}", std::process::id(), nanos)) };
+        fs::create_dir_all(&temp.path).context("创建目录失败")?;
+        Ok(temp)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        if self.path.exists() {
+            if let Err(e) = fs::remove_dir_all(&self.path) {
+                rust_log(&format!("DEBUG: 删除临时目录失败: {} - {}", self.path.display(), e));
+            }
+        }
+    }
+}
+
+// 解包过程中收集到的信息
+#[derive(Default)]
+struct EntryState {
+    has_anki21b: bool,
+    has_anki21: bool,
+    has_anki2: bool,
+    package_version: Option<i32>, // meta 文件里的版本，没有 meta 的是旧格式
+    media_list: Option<Vec<u8>>,  // media 映射的原始内容，读完 meta 才知道怎么解析
+    undecoded_media: HashSet<String>, // 读到 meta 之前写出的媒体文件，可能还要再解一层 zstd
+    decompressed: u64, // 已解压写出的字节数
+}
+
+fn create_file(path: &Path) -> Result<File, AnkiError> {
+    File::create(path).path_context("创建文件失败", path)
+}
+
+// 把条目解压写到 path，zstd 为 true 时再解一层 zstd；超过解压限制时返回 RejectedEntry
+fn write_entry(reader: &mut dyn Read, path: &Path, name: &str, compressed_size: u64, total: &mut u64, zstd: bool) -> Result<(), AnkiError> {
+    let mut out = SizeGuard {
+        inner: io::BufWriter::with_capacity(READ_BUFFER_SIZE, create_file(path)?),
+        compressed_size,
+        written: 0,
+        total,
+        rejected: None,
+    };
+    let result = if zstd {
+        zstd::stream::copy_decode(reader, &mut out).context("zstd解压失败")
+    } else {
+        io::copy(reader, &mut out).map(|_| ()).path_context("写入文件失败", path)
+    };
+    let result = result.and_then(|_| out.flush().path_context("写入文件失败", path));
+    if let Some(reason) = out.rejected {
+        return Err(RejectedEntry { entry: name.to_string(), reason }.into());
+    }
+    result
+}
+
+fn is_media_entry(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_digit())
+}
+
+// 处理一个 zip 条目，reader 只在这次调用里读取
+fn handle_entry(dir: &Path, name: &str, compressed_size: u64, reader: &mut dyn Read, state: &mut EntryState) -> Result<(), AnkiError> {
+    let total = &mut state.decompressed;
+    match name {
+        "collection.anki21b" => {
+            rust_log("DEBUG: 开始流式解压 anki21b");
+            write_entry(reader, &dir.join(SQLITE_FILE), name, compressed_size, total, true)?;
+            state.has_anki21b = true;
+        }
+        "collection.anki21" => {
+            write_entry(reader, &dir.join(name), name, compressed_size, total, false)?;
+            state.has_anki21 = true;
+        }
+        "collection.anki2" => {
+            write_entry(reader, &dir.join(name), name, compressed_size, total, false)?;
+            state.has_anki2 = true;
+        }
+        "media" => {
+            let mut bytes = Vec::new();
+            reader
+                .take(MAX_MEDIA_MAP_SIZE)
+                .read_to_end(&mut bytes)
+                .context("读取media映射文件失败")?;
+            state.media_list = Some(bytes);
+        }
+        "meta" => {
+            let mut bytes = Vec::new();
+            reader.take(MAX_META_SIZE).read_to_end(&mut bytes).context("读取meta失败")?;
+            fs::write(dir.join(name), &bytes).context("写入文件失败")?;
+            match decode_package_metadata(&bytes) {
+                Ok(meta) => {
+                    rust_log(&format!("DEBUG: apkg meta 版本: {}", meta.version));
+                    state.package_version = Some(meta.version);
+                }
+                Err(e) => rust_log(&format!("DEBUG: {}", e)),
+            }
+        }
+        _ if is_media_entry(name) => {
+            // 新格式的媒体文件经过 zstd 压缩；还没读到 meta 时先原样写出，最后再处理
+            let zstd = state.package_version == Some(PACKAGE_VERSION_LATEST);
+            if state.package_version.is_none() {
+                state.undecoded_media.insert(name.to_string());
+            }
+            write_entry(reader, &dir.join(PENDING_MEDIA_DIR).join(name), name, compressed_size, total, zstd)?
+        }
+        _ => rust_log(&format!("DEBUG: 跳过未知的apkg条目: {}", name)),
+    }
+    Ok(())
+}
+
+fn tracking_reader<'t, 'm, 'a>(path: &Path, tracker: &'t RefCell<&'m mut ImportTracker<'a>>) -> Result<TrackingReader<'t, 'm, 'a, BufReader<File>>, AnkiError> {
+    let file = File::open(path).path_context("无法打开apkg文件", path)?;
+    Ok(TrackingReader { inner: BufReader::with_capacity(READ_BUFFER_SIZE, file), md5: md5::Context::new(), tracker })
+}
+
+fn track_entry(tracker: &RefCell<&mut ImportTracker>, dir: &Path, name: &str, compressed_size: u64, reader: &mut dyn Read, state: &mut EntryState) -> Result<(), AnkiError> {
+    tracker.borrow_mut().start_entry(name);
+    handle_entry(dir, name, compressed_size, reader, state)?;
+    tracker.borrow_mut().finish_entry();
+    Ok(())
+}
+
+// 顺序读取本地文件头，一遍读完同时算出 MD5；返回 Ok(None) 表示 apkg 用了 data descriptor，需要改用中央目录读取
+fn extract_streaming(apkg_path: &Path, dir: &Path, state: &mut EntryState, tracker: &RefCell<&mut ImportTracker>) -> Result<Option<String>, AnkiError> {
+    let mut reader = tracking_reader(apkg_path, tracker)?;
+    loop {
+        match read_zipfile_from_stream(&mut reader) {
+            Ok(Some(mut entry)) => {
+                let name = entry.name().to_string();
+                check_entry(&name, entry.size()).inspect_err(|e| rust_log(&format!("DEBUG: {}", e)))?;
+                if entry.is_dir() {
+                    tracker.borrow_mut().finish_entry();
+                    continue;
+                }
+                let compressed_size = entry.compressed_size();
+                track_entry(tracker, dir, &name, compressed_size, &mut entry, state)?;
+            }
+            Ok(None) => break,
+            Err(ZipError::UnsupportedArchive(msg)) => {
+                rust_log(&format!("DEBUG: 无法顺序读取apkg（{}），改为按中央目录读取", msg));
+                return Ok(None);
+            }
+            Err(e) => return Err(e).context("不是有效的apkg/zip文件"),
+        }
+    }
+    // 中央目录不用解析，但要算进 MD5
+    io::copy(&mut reader, &mut io::sink()).context("读取apkg失败")?;
+    Ok(Some(format!("{:x}", reader.md5.compute())))
+}
+
+fn compute_file_md5(path: &Path, tracker: &RefCell<&mut ImportTracker>) -> Result<String, AnkiError> {
+    let mut reader = tracking_reader(path, tracker)?;
+    io::copy(&mut reader, &mut io::sink()).context("读取apkg失败")?;
+    Ok(format!("{:x}", reader.md5.compute()))
+}
+
+fn extract_with_central_directory(apkg_path: &Path, dir: &Path, state: &mut EntryState, tracker: &RefCell<&mut ImportTracker>) -> Result<String, AnkiError> {
+    let mut zip = ZipArchive::new(tracking_reader(apkg_path, tracker)?).context("不是有效的apkg/zip文件")?;
+    tracker.borrow_mut().progress.entries_total = zip.len() as u32;
+    for i in 0..zip.len() {
+        let mut entry = zip.by_index(i).context("读取zip entry失败")?;
+        let name = entry.name().to_string();
+        check_entry(&name, entry.size()).inspect_err(|e| rust_log(&format!("DEBUG: {}", e)))?;
+        if entry.is_dir() {
+            tracker.borrow_mut().finish_entry();
+            continue;
+        }
+        let compressed_size = entry.compressed_size();
+        track_entry(tracker, dir, &name, compressed_size, &mut entry, state)?;
+    }
+    drop(zip);
+    // 按中央目录读取时不是顺序读完整个文件，MD5 要再读一遍
+    tracker.borrow_mut().progress.bytes_processed = 0;
+    tracker.borrow_mut().set_phase(ImportPhase::Hashing);
+    compute_file_md5(apkg_path, tracker)
+}
+
+// 解析 media 映射，返回 (数字编号, 文件名)。旧格式是 {"0": "a.jpg"} 形式的 JSON，
+// 新格式是 zstd 压缩的 MediaEntries，条目在列表里的位置就是编号
+fn parse_media_list(bytes: Option<&[u8]>, latest: bool) -> Vec<(String, String)> {
+    let Some(bytes) = bytes else {
+        rust_log("DEBUG: media 映射文件不存在");
+        return Vec::new();
+    };
+    if latest {
+        let mut data = Vec::new();
+        let decoded = zstd::stream::read::Decoder::new(bytes)
+            .and_then(|decoder| decoder.take(MAX_MEDIA_MAP_SIZE).read_to_end(&mut data))
+            .context("zstd解压media映射失败")
+            .and_then(|_| decode_media_entries(&data));
+        return match decoded {
+            Ok(list) => list
+                .entries
+                .into_iter()
+                .enumerate()
+                .map(|(index, entry)| (entry.legacy_zip_filename.map(|n| n as usize).unwrap_or(index).to_string(), entry.name))
+                .collect(),
+            Err(e) => {
+                rust_log(&format!("DEBUG: {}", e));
+                Vec::new()
+            }
+        };
+    }
+    let entries = match serde_json::from_slice::<serde_json::Value>(bytes) {
+        Ok(serde_json::Value::Object(obj)) => obj,
+        Ok(_) => {
+            rust_log("DEBUG: JSON 不是对象类型");
+            serde_json::Map::new()
+        }
+        Err(e) => {
+            rust_log(&format!("DEBUG: JSON 解析失败: {}", e));
+            serde_json::Map::new()
+        }
+    };
+    entries
+        .into_iter()
+        .filter_map(|(key, value)| match value.as_str() {
+            Some(filename) => Some((key, filename.to_string())),
+            None => {
+                rust_log(&format!("DEBUG: 跳过非字符串值: key={}, value={:?}", key, value));
+                None
+            }
+        })
+        .collect()
+}
+
+// 按 media 映射把数字编号的媒体文件改成真实文件名，返回 文件名 -> 数字编号
+fn finish_media(dir: &Path, state: &mut EntryState) -> Result<HashMap<String, String>, AnkiError> {
+    let mut media_map = HashMap::new();
+    let pending_dir = dir.join(PENDING_MEDIA_DIR);
+    let media_dir = dir.join(MEDIA_DIR);
+    fs::create_dir_all(&media_dir).context("创建媒体目录失败")?;
+    let latest = state.package_version == Some(PACKAGE_VERSION_LATEST);
+    let entries = parse_media_list(state.media_list.as_deref(), latest);
+    for (key, filename) in entries {
+        // 编号和文件名都来自 apkg，拼到目录上之前先检查，防止读写解包目录以外的文件
+        if !is_media_entry(&key) {
+            let e = RejectedEntry { entry: key, reason: RejectReason::InvalidMediaKey };
+            rust_log(&format!("DEBUG: {}", e));
+            return Err(e.into());
+        }
+        check_media_filename(&filename)
+            .map_err(|reason| RejectedEntry { entry: filename.clone(), reason })
+            .inspect_err(|e| rust_log(&format!("DEBUG: {}", e)))?;
+        let pending = pending_dir.join(&key);
+        media_map.insert(filename.clone(), key.clone());
+        if !pending.exists() {
+            continue;
+        }
+        let outpath = media_dir.join(&filename);
+        if latest && state.undecoded_media.contains(&key) {
+            let mut file = File::open(&pending).context("打开媒体文件失败")?;
+            let compressed_size = file.metadata().context("读取媒体文件失败")?.len();
+            write_entry(&mut file, &outpath, &key, compressed_size, &mut state.decompressed, true)?;
+        } else {
+            fs::rename(&pending, &outpath).path_context("写入媒体文件失败", &outpath)?;
+        }
+    }
+    if pending_dir.exists() {
+        fs::remove_dir_all(&pending_dir).context("删除临时媒体目录失败")?;
+    }
+    Ok(media_map)
+}
+
+/// 解包 apkg 到 base_dir 下的临时目录。取消或出错时不会留下写了一半的目录
+pub fn stage_package(apkg_path: &Path, base_dir: &Path, tracker: &mut ImportTracker) -> Result<StagedPackage, AnkiError> {
+    let result = stage_package_inner(apkg_path, base_dir, tracker);
+    match result {
+        Err(_) if tracker.is_cancelled() => Err(AnkiError::cancelled()),
+        other => other,
+    }
+}
+
+fn stage_package_inner(apkg_path: &Path, base_dir: &Path, tracker: &mut ImportTracker) -> Result<StagedPackage, AnkiError> {
+    tracker.progress.total_bytes = fs::metadata(apkg_path).path_context("无法打开apkg文件", apkg_path)?.len() as i64;
+    tracker.progress.entries_total = read_entry_count(apkg_path).unwrap_or(0);
+    tracker.set_phase(ImportPhase::Extracting);
+    let temp = TempDir::new(base_dir)?;
+    fs::create_dir_all(temp.path.join(PENDING_MEDIA_DIR)).context("创建目录失败")?;
+
+    let mut state = EntryState::default();
+    let md5 = {
+        let cell = RefCell::new(&mut *tracker);
+        match extract_streaming(apkg_path, &temp.path, &mut state, &cell)? {
+            Some(md5) => md5,
+            None => {
+                // 已经写出的部分全部重来
+                fs::remove_dir_all(&temp.path).context("清空临时目录失败")?;
+                fs::create_dir_all(temp.path.join(PENDING_MEDIA_DIR)).context("创建目录失败")?;
+                state = EntryState::default();
+                {
+                    let mut t = cell.borrow_mut();
+                    t.progress.bytes_processed = 0;
+                    t.progress.entries_done = 0;
+                    t.set_phase(ImportPhase::Extracting);
+                }
+                extract_with_central_directory(apkg_path, &temp.path, &mut state, &cell)?
+            }
+        }
+    };
+    tracker.check_cancelled()?;
+    tracker.set_phase(ImportPhase::Finalizing);
+
+    // 新版 apkg 同时带有 collection.anki2（提示升级的空集合）和 collection.anki21b，
+    // 按 meta 里的版本选择集合文件，没有 meta 时优先用新格式
+    let sqlite_path = temp.path.join(SQLITE_FILE);
+    let version = match state.package_version {
+        Some(PACKAGE_VERSION_LATEST) if state.has_anki21b => "anki21b",
+        Some(PACKAGE_VERSION_LEGACY_2) if state.has_anki21 => "anki21",
+        Some(PACKAGE_VERSION_LEGACY_1) if state.has_anki2 => "anki2",
+        _ if state.has_anki21b => "anki21b",
+        _ if state.has_anki21 => "anki21",
+        _ if state.has_anki2 => "anki2",
+        _ => "unknown",
+    };
+    match version {
+        "anki21" => {
+            fs::copy(temp.path.join("collection.anki21"), &sqlite_path).context("复制anki21失败")?;
+        }
+        "anki2" => {
+            fs::copy(temp.path.join("collection.anki2"), &sqlite_path).context("复制anki2失败")?;
+        }
+        "unknown" => rust_log("DEBUG: 警告：未找到 collection.anki21b、collection.anki21 或 collection.anki2"),
+        _ => {}
+    }
+    let media_map = finish_media(&temp.path, &mut state)?;
+    rust_log(&format!("DEBUG: media 映射解析完成，共 {} 个文件", media_map.len()));
+
+    Ok(StagedPackage { temp, md5, version: version.to_string(), media_map })
+}
+
+impl StagedPackage {
+    pub fn dir(&self) -> &Path {
+        &self.temp.path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+    use crate::anki_proto::{MediaEntries, MediaEntry, PackageMetadata};
+    use super::*;
+
+    fn guard(total: &mut u64, compressed_size: u64, written: u64) -> SizeGuard<'_, io::Sink> {
+        SizeGuard { inner: io::sink(), compressed_size, written, total, rejected: None }
+    }
+
+    #[test]
+    fn relative_path_accepts_plain_names() {
+        assert_eq!(check_relative_path("collection.anki21"), Ok(()));
+        assert_eq!(check_relative_path("media/0"), Ok(()));
+        assert_eq!(check_relative_path("console.txt"), Ok(()));
+        assert_eq!(check_relative_path("..a"), Ok(()));
+    }
+
+    #[test]
+    fn relative_path_rejects_escapes() {
+        assert_eq!(check_relative_path(""), Err(RejectReason::EmptyName));
+        assert_eq!(check_relative_path(".."), Err(RejectReason::ParentDir));
+        assert_eq!(check_relative_path("a/../../b"), Err(RejectReason::ParentDir));
+        assert_eq!(check_relative_path("a//b"), Err(RejectReason::EmptyComponent));
+        assert_eq!(check_relative_path("./a"), Err(RejectReason::EmptyComponent));
+        assert_eq!(check_relative_path("/etc/passwd"), Err(RejectReason::AbsolutePath));
+        assert_eq!(check_relative_path("C:evil.jpg"), Err(RejectReason::AbsolutePath));
+        assert_eq!(check_relative_path("c:/Windows/a.jpg"), Err(RejectReason::AbsolutePath));
+        assert_eq!(check_relative_path("..\\a.jpg"), Err(RejectReason::Backslash));
+        assert_eq!(check_relative_path("a\0.jpg"), Err(RejectReason::NulByte));
+    }
+
+    #[test]
+    fn relative_path_rejects_reserved_names() {
+        assert_eq!(check_relative_path("CON"), Err(RejectReason::ReservedName));
+        assert_eq!(check_relative_path("nul.jpg"), Err(RejectReason::ReservedName));
+        assert_eq!(check_relative_path("Com1 .mp3"), Err(RejectReason::ReservedName));
+        assert_eq!(check_relative_path("media/lpt9"), Err(RejectReason::ReservedName));
+    }
+
+    #[test]
+    fn media_filename_rejects_separators() {
+        assert_eq!(check_media_filename("a.jpg"), Ok(()));
+        assert_eq!(check_media_filename("a/b.jpg"), Err(RejectReason::PathSeparator));
+        assert_eq!(check_media_filename("a\\b.jpg"), Err(RejectReason::Backslash));
+        assert_eq!(check_media_filename("../a.jpg"), Err(RejectReason::ParentDir));
+    }
+
+    #[test]
+    fn entry_rejects_declared_size_over_cap() {
+        assert!(check_entry("media/", 0).is_ok());
+        assert!(check_entry("0", MAX_ENTRY_SIZE).is_ok());
+        let e = check_entry("0", MAX_ENTRY_SIZE + 1).unwrap_err();
+        assert_eq!(e.reason, RejectReason::EntryTooLarge);
+    }
+
+    #[test]
+    fn size_guard_counts_written_bytes() {
+        let mut total = 10;
+        let mut out = guard(&mut total, 100, 0);
+        assert_eq!(out.write(&[0; 64]).unwrap(), 64);
+        assert_eq!(out.written, 64);
+        assert_eq!(out.rejected, None);
+        assert_eq!(total, 74);
+    }
+
+    #[test]
+    fn size_guard_rejects_entry_over_cap() {
+        let mut total = 0;
+        let mut out = guard(&mut total, MAX_ENTRY_SIZE, MAX_ENTRY_SIZE - 4);
+        assert_eq!(out.write(&[0; 4]).unwrap(), 4);
+        assert!(out.write(&[0; 1]).is_err());
+        assert_eq!(out.rejected, Some(RejectReason::EntryTooLarge));
+    }
+
+    #[test]
+    fn size_guard_rejects_total_over_cap() {
+        let mut total = MAX_TOTAL_SIZE - 4;
+        let mut out = guard(&mut total, 100, 0);
+        assert_eq!(out.write(&[0; 4]).unwrap(), 4);
+        assert!(out.write(&[0; 1]).is_err());
+        assert_eq!(out.rejected, Some(RejectReason::TotalTooLarge));
+        assert_eq!(total, MAX_TOTAL_SIZE);
+    }
+
+    #[test]
+    fn size_guard_checks_ratio_only_past_threshold() {
+        // 没超过阈值时压缩比再高也放行
+        let mut total = 0;
+        let mut out = guard(&mut total, 1, RATIO_CHECK_THRESHOLD - 4);
+        assert_eq!(out.write(&[0; 4]).unwrap(), 4);
+        assert!(out.write(&[0; 1]).is_err());
+        assert_eq!(out.rejected, Some(RejectReason::RatioTooHigh));
+
+        // 超过阈值但压缩比在限制内
+        let mut total = 0;
+        let compressed_size = RATIO_CHECK_THRESHOLD / MAX_COMPRESSION_RATIO + 1;
+        let mut out = guard(&mut total, compressed_size, RATIO_CHECK_THRESHOLD);
+        assert_eq!(out.write(&[0; 64]).unwrap(), 64);
+        assert_eq!(out.rejected, None);
+    }
+
+    fn zstd(bytes: &[u8]) -> Vec<u8> {
+        zstd::stream::encode_all(bytes, 0).unwrap()
+    }
+
+    fn meta(version: i32) -> Vec<u8> {
+        PackageMetadata { version }.encode_to_vec()
+    }
+
+    fn media_entries(names: &[&str]) -> Vec<u8> {
+        let entries = names.iter().map(|name| MediaEntry { name: name.to_string(), ..Default::default() }).collect();
+        zstd(&MediaEntries { entries }.encode_to_vec())
+    }
+
+    // 解包结果和放 apkg 的临时目录，解包目录在这个目录下面，丢弃时一起删掉
+    struct Package {
+        staged: StagedPackage,
+        md5: String,
+        _dir: TempDir,
+    }
+
+    // 按顺序写一个 apkg 并解包到同一个临时目录下
+    fn stage(entries: &[(&str, Vec<u8>)]) -> Package {
+        let dir = TempDir::new(&std::env::temp_dir()).unwrap();
+        let path = dir.path.join("test.apkg");
+        let mut zip = ZipWriter::new(File::create(&path).unwrap());
+        for (name, bytes) in entries {
+            zip.start_file(*name, FileOptions::default()).unwrap();
+            zip.write_all(bytes).unwrap();
+        }
+        zip.finish().unwrap();
+        let md5 = format!("{:x}", md5::compute(fs::read(&path).unwrap()));
+        let cancelled = AtomicBool::new(false);
+        let mut ignore = |_: &ImportProgress| {};
+        let mut tracker = ImportTracker::new(&cancelled, &mut ignore);
+        let staged = stage_package(&path, &dir.path, &mut tracker).unwrap();
+        Package { staged, md5, _dir: dir }
+    }
+
+    fn read(staged: &StagedPackage, name: &str) -> Vec<u8> {
+        fs::read(staged.dir().join(name)).unwrap()
+    }
+
+    fn media(staged: &StagedPackage, name: &str) -> Vec<u8> {
+        fs::read(staged.dir().join(MEDIA_DIR).join(name)).unwrap()
+    }
+
+    #[test]
+    fn latest_package_decodes_zstd_collection_and_media() {
+        let Package { staged, md5, _dir } = stage(&[
+            ("collection.anki2", b"please upgrade".to_vec()),
+            ("collection.anki21b", zstd(b"anki21b collection")),
+            ("meta", meta(PACKAGE_VERSION_LATEST)),
+            ("media", media_entries(&["a.png", "b.mp3"])),
+            ("0", zstd(b"png bytes")),
+            ("1", zstd(b"mp3 bytes")),
+        ]);
+        assert_eq!(staged.version, "anki21b");
+        assert_eq!(staged.md5, md5);
+        assert_eq!(read(&staged, SQLITE_FILE), b"anki21b collection");
+        assert_eq!(media(&staged, "a.png"), b"png bytes");
+        assert_eq!(media(&staged, "b.mp3"), b"mp3 bytes");
+        assert_eq!(staged.media_map, HashMap::from([("a.png".to_string(), "0".to_string()), ("b.mp3".to_string(), "1".to_string())]));
+        assert!(!staged.dir().join(PENDING_MEDIA_DIR).exists());
+    }
+
+    #[test]
+    fn media_written_before_meta_is_decoded_afterwards() {
+        let Package { staged, _dir, .. } = stage(&[
+            ("0", zstd(b"png bytes")),
+            ("collection.anki21b", zstd(b"anki21b collection")),
+            ("media", media_entries(&["a.png"])),
+            ("meta", meta(PACKAGE_VERSION_LATEST)),
+        ]);
+        assert_eq!(media(&staged, "a.png"), b"png bytes");
+    }
+
+    #[test]
+    fn media_entry_uses_legacy_zip_filename() {
+        let entries = vec![MediaEntry { name: "a.png".to_string(), legacy_zip_filename: Some(7), ..Default::default() }];
+        let Package { staged, _dir, .. } = stage(&[
+            ("meta", meta(PACKAGE_VERSION_LATEST)),
+            ("collection.anki21b", zstd(b"anki21b collection")),
+            ("media", zstd(&MediaEntries { entries }.encode_to_vec())),
+            ("7", zstd(b"png bytes")),
+        ]);
+        assert_eq!(staged.media_map, HashMap::from([("a.png".to_string(), "7".to_string())]));
+        assert_eq!(media(&staged, "a.png"), b"png bytes");
+    }
+
+    #[test]
+    fn legacy_package_uses_json_media_map() {
+        let Package { staged, _dir, .. } = stage(&[
+            ("collection.anki21", b"anki21 collection".to_vec()),
+            ("media", br#"{"0": "a.png", "1": 5}"#.to_vec()),
+            ("0", b"png bytes".to_vec()),
+        ]);
+        assert_eq!(staged.version, "anki21");
+        assert_eq!(read(&staged, SQLITE_FILE), b"anki21 collection");
+        // 媒体文件原样保存，不解 zstd；值不是字符串的条目跳过
+        assert_eq!(media(&staged, "a.png"), b"png bytes");
+        assert_eq!(staged.media_map, HashMap::from([("a.png".to_string(), "0".to_string())]));
+    }
+
+    #[test]
+    fn meta_version_selects_the_collection() {
+        let Package { staged, _dir, .. } = stage(&[
+            ("meta", meta(PACKAGE_VERSION_LEGACY_2)),
+            ("collection.anki21b", zstd(b"anki21b collection")),
+            ("collection.anki21", b"anki21 collection".to_vec()),
+        ]);
+        assert_eq!(staged.version, "anki21");
+        assert_eq!(read(&staged, SQLITE_FILE), b"anki21 collection");
+
+        // 没有 meta 时优先用新格式
+        let Package { staged, _dir, .. } = stage(&[
+            ("collection.anki2", b"anki2 collection".to_vec()),
+            ("collection.anki21b", zstd(b"anki21b collection")),
+        ]);
+        assert_eq!(staged.version, "anki21b");
+        assert_eq!(read(&staged, SQLITE_FILE), b"anki21b collection");
+    }
+}
@@ -0,0 +1,255 @@
+// 填空题（cloze）解析：支持 {{c1::答案}}、{{c1::答案::提示}}、{{c1,2::答案}} 以及嵌套的 {{c1::外层 {{c2::内层}}}}。
+// 问题面把当前序号的填空替换成 [...] 或 [提示]，其他序号的填空照常显示；答案面显示当前序号的答案。
+
+use std::collections::BTreeSet;
+use regex::Regex;
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref CLOZE_OPEN: Regex = Regex::new(r"\{\{c(\d+(?:,\d+)*)::").unwrap();
+}
+
+#[derive(Debug)]
+enum TextOrCloze {
+    Text(String),
+    Cloze(ExtractedCloze),
+}
+
+#[derive(Debug)]
+struct ExtractedCloze {
+    ordinals: Vec<u16>,
+    nodes: Vec<TextOrCloze>,
+    hint: Option<String>,
+    // 原始的开始标记，填空没有闭合时原样还原
+    open_tag: String,
+}
+
+fn current_nodes<'a>(stack: &'a mut [ExtractedCloze], output: &'a mut Vec<TextOrCloze>) -> &'a mut Vec<TextOrCloze> {
+    match stack.last_mut() {
+        Some(cloze) => &mut cloze.nodes,
+        None => output,
+    }
+}
+
+fn parse(text: &str) -> Vec<TextOrCloze> {
+    let mut stack: Vec<ExtractedCloze> = vec![];
+    let mut output: Vec<TextOrCloze> = vec![];
+    let mut rest = text;
+    loop {
+        let open = CLOZE_OPEN.captures(rest);
+        let open_range = open.as_ref().and_then(|c| c.get(0)).map(|m| (m.start(), m.end()));
+        // 只有在填空内部时 }} 才是结束标记
+        let close_pos = if stack.is_empty() { None } else { rest.find("}}") };
+        match (open_range, close_pos) {
+            (Some((start, end)), c) if c.map(|c| start < c).unwrap_or(true) => {
+                if start > 0 {
+                    current_nodes(&mut stack, &mut output).push(TextOrCloze::Text(rest[..start].to_string()));
+                }
+                let ordinals = open
+                    .as_ref()
+                    .and_then(|c| c.get(1))
+                    .map(|m| m.as_str().split(',').filter_map(|n| n.parse::<u16>().ok()).collect())
+                    .unwrap_or_default();
+                stack.push(ExtractedCloze { ordinals, nodes: vec![], hint: None, open_tag: rest[start..end].to_string() });
+                rest = &rest[end..];
+            }
+            (_, Some(c)) => {
+                if c > 0 {
+                    current_nodes(&mut stack, &mut output).push(TextOrCloze::Text(rest[..c].to_string()));
+                }
+                if let Some(mut cloze) = stack.pop() {
+                    // 提示是最后一段文本里最后一个 :: 之后的内容
+                    if let Some(TextOrCloze::Text(last)) = cloze.nodes.last_mut() {
+                        if let Some((content, hint)) = last.rsplit_once("::") {
+                            cloze.hint = Some(hint.to_string());
+                            *last = content.to_string();
+                        }
+                    }
+                    current_nodes(&mut stack, &mut output).push(TextOrCloze::Cloze(cloze));
+                }
+                rest = &rest[c + 2..];
+            }
+            _ => {
+                if !rest.is_empty() {
+                    current_nodes(&mut stack, &mut output).push(TextOrCloze::Text(rest.to_string()));
+                }
+                break;
+            }
+        }
+    }
+    // 没有闭合的填空按普通文本处理
+    while let Some(cloze) = stack.pop() {
+        let parent = current_nodes(&mut stack, &mut output);
+        parent.push(TextOrCloze::Text(cloze.open_tag));
+        parent.extend(cloze.nodes);
+    }
+    output
+}
+
+fn escape_attr(text: &str) -> String {
+    text.replace('&', "&amp;").replace('"', "&quot;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+fn join_ordinals(ordinals: &[u16]) -> String {
+    ordinals.iter().map(|o| o.to_string()).collect::<Vec<_>>().join(",")
+}
+
+fn render_nodes(nodes: &[TextOrCloze], ord: u16, question: bool, out: &mut String) {
+    for node in nodes {
+        match node {
+            TextOrCloze::Text(text) => out.push_str(text),
+            TextOrCloze::Cloze(cloze) => render_cloze(cloze, ord, question, out),
+        }
+    }
+}
+
+fn render_cloze(cloze: &ExtractedCloze, ord: u16, question: bool, out: &mut String) {
+    let ordinals = join_ordinals(&cloze.ordinals);
+    if !cloze.ordinals.contains(&ord) {
+        out.push_str(&format!(r#"<span class="cloze-inactive" data-ordinal="{ordinals}">"#));
+        render_nodes(&cloze.nodes, ord, question, out);
+        out.push_str("</span>");
+    } else if question {
+        let mut content = String::new();
+        render_nodes(&cloze.nodes, ord, false, &mut content);
+        let placeholder = match &cloze.hint {
+            Some(hint) => format!("[{hint}]"),
+            None => "[...]".to_string(),
+        };
+        out.push_str(&format!(
+            r#"<span class="cloze" data-cloze="{}" data-ordinal="{ordinals}">{placeholder}</span>"#,
+            escape_attr(&content)
+        ));
+    } else {
+        out.push_str(&format!(r#"<span class="cloze" data-ordinal="{ordinals}">"#));
+        render_nodes(&cloze.nodes, ord, question, out);
+        out.push_str("</span>");
+    }
+}
+
+fn has_ordinal(nodes: &[TextOrCloze], ord: u16) -> bool {
+    nodes.iter().any(|node| match node {
+        TextOrCloze::Text(_) => false,
+        TextOrCloze::Cloze(cloze) => cloze.ordinals.contains(&ord) || has_ordinal(&cloze.nodes, ord),
+    })
+}
+
+fn collect_ordinals(nodes: &[TextOrCloze], ords: &mut BTreeSet<u16>) {
+    for node in nodes {
+        if let TextOrCloze::Cloze(cloze) = node {
+            ords.extend(cloze.ordinals.iter().copied());
+            collect_ordinals(&cloze.nodes, ords);
+        }
+    }
+}
+
+fn collect_active_text(nodes: &[TextOrCloze], ord: u16, out: &mut Vec<String>) {
+    for node in nodes {
+        if let TextOrCloze::Cloze(cloze) = node {
+            if cloze.ordinals.contains(&ord) {
+                let mut text = String::new();
+                render_nodes(&cloze.nodes, ord, false, &mut text);
+                out.push(text);
+            } else {
+                collect_active_text(&cloze.nodes, ord, out);
+            }
+        }
+    }
+}
+
+/// 渲染第 ord 个填空（从 1 开始）的问题面或答案面。
+/// 文本里没有这个序号的填空时返回空字符串，和 Anki 一样让模板判断为空卡。
+pub fn reveal_cloze_text(text: &str, ord: u16, question: bool) -> String {
+    let nodes = parse(text);
+    if !has_ordinal(&nodes, ord) {
+        return String::new();
+    }
+    let mut out = String::new();
+    render_nodes(&nodes, ord, question, &mut out);
+    out
+}
+
+/// {{cloze-only:}} 和 {{type:cloze:}} 用：只取第 ord 个填空的答案，多个用 ", " 连接
+pub fn reveal_cloze_text_only(text: &str, ord: u16) -> String {
+    let mut out = vec![];
+    collect_active_text(&parse(text), ord, &mut out);
+    out.join(", ")
+}
+
+/// 文本中出现的所有填空序号
+pub fn cloze_numbers_in_string(text: &str) -> BTreeSet<u16> {
+    let mut ords = BTreeSet::new();
+    collect_ordinals(&parse(text), &mut ords);
+    ords
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_cloze_question_and_answer() {
+        let text = "首都是{{c1::北京}}。";
+        assert_eq!(reveal_cloze_text(text, 1, true), r#"首都是<span class="cloze" data-cloze="北京" data-ordinal="1">[...]</span>。"#);
+        assert_eq!(reveal_cloze_text(text, 1, false), r#"首都是<span class="cloze" data-ordinal="1">北京</span>。"#);
+    }
+
+    #[test]
+    fn hint_replaces_placeholder() {
+        let text = "{{c1::Paris::city}} {{c1::a::b::c}}";
+        assert_eq!(
+            reveal_cloze_text(text, 1, true),
+            r#"<span class="cloze" data-cloze="Paris" data-ordinal="1">[city]</span> <span class="cloze" data-cloze="a::b" data-ordinal="1">[c]</span>"#
+        );
+        assert_eq!(reveal_cloze_text_only(text, 1), "Paris, a::b");
+    }
+
+    #[test]
+    fn multiple_ordinals() {
+        let text = "{{c1,3::x}} {{c2::y}}";
+        assert_eq!(cloze_numbers_in_string(text), BTreeSet::from([1, 2, 3]));
+        assert_eq!(
+            reveal_cloze_text(text, 3, true),
+            r#"<span class="cloze" data-cloze="x" data-ordinal="1,3">[...]</span> <span class="cloze-inactive" data-ordinal="2">y</span>"#
+        );
+        assert_eq!(reveal_cloze_text_only(text, 1), "x");
+        assert_eq!(reveal_cloze_text_only(text, 2), "y");
+    }
+
+    #[test]
+    fn nested_clozes() {
+        let text = "{{c1::外 {{c2::内}}}}";
+        assert_eq!(cloze_numbers_in_string(text), BTreeSet::from([1, 2]));
+        // 外层是当前填空时整个隐藏，data-cloze 里是内层展开后的答案
+        assert_eq!(
+            reveal_cloze_text(text, 1, true),
+            r#"<span class="cloze" data-cloze="外 &lt;span class=&quot;cloze-inactive&quot; data-ordinal=&quot;2&quot;&gt;内&lt;/span&gt;" data-ordinal="1">[...]</span>"#
+        );
+        assert_eq!(
+            reveal_cloze_text(text, 2, true),
+            r#"<span class="cloze-inactive" data-ordinal="1">外 <span class="cloze" data-cloze="内" data-ordinal="2">[...]</span></span>"#
+        );
+        assert_eq!(reveal_cloze_text_only(text, 2), "内");
+    }
+
+    #[test]
+    fn missing_ordinal_renders_empty() {
+        let text = "{{c1::a}} {{c2::b}}";
+        assert_eq!(reveal_cloze_text(text, 3, true), "");
+        assert_eq!(reveal_cloze_text(text, 3, false), "");
+        assert_eq!(reveal_cloze_text_only(text, 3), "");
+        assert_eq!(reveal_cloze_text("没有填空", 1, true), "");
+    }
+
+    #[test]
+    fn unclosed_cloze_is_plain_text() {
+        let text = "{{c1::a {{c2::b}}";
+        assert_eq!(cloze_numbers_in_string(text), BTreeSet::from([2]));
+        assert_eq!(
+            reveal_cloze_text(text, 2, false),
+            r#"{{c1::a <span class="cloze" data-ordinal="2">b</span>"#
+        );
+        // 填空外面的 }} 不是结束标记
+        assert_eq!(reveal_cloze_text("}} {{c1::a}}", 1, false), r#"}} <span class="cloze" data-ordinal="1">a</span>"#);
+    }
+}
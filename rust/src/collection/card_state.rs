@@ -0,0 +1,262 @@
+// 卡片的暂停、搁置、旗标和笔记的标签，和 Anki 一样直接存在 cards.queue、cards.flags 和 notes.tags 里，
+// 导入的集合里已经暂停或搁置的卡片保持原样。构建队列只取 queue 0-3 的卡片，暂停和搁置的卡片自然不会出现。
+
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection};
+use crate::api::simple::CardStatus;
+use crate::error::{AnkiError, ResultExt};
+use super::IN_DECK;
+
+pub const QUEUE_SUSPENDED: i64 = -1;
+pub const QUEUE_SIBLING_BURIED: i64 = -2;
+pub const QUEUE_MANUALLY_BURIED: i64 = -3;
+
+/// 暂停或搁置前的 queue 由 type 推出：学习中的卡片按 due 是时间戳还是天数放回 queue 1 或 3
+pub const RESTORED_QUEUE: &str =
+    "(CASE WHEN type IN (1, 3) THEN (CASE WHEN due > 1000000000 THEN 1 ELSE 3 END) ELSE type END)";
+
+// 旗标占 flags 的低 3 位
+const FLAG_MASK: i64 = 0b111;
+const MARKED_TAG: &str = "marked";
+// SQLite 旧版本默认最多 999 个绑定参数
+const CHUNK_SIZE: usize = 500;
+
+fn placeholders(count: usize) -> String {
+    vec!["?"; count].join(", ")
+}
+
+// 绑定参数：先是 IN_DECK 的 deck key，再是一批 card_id
+fn deck_and_ids(deck_key: &str, card_ids: &[i64]) -> Vec<Value> {
+    std::iter::once(Value::from(deck_key.to_string())).chain(card_ids.iter().map(|id| Value::from(*id))).collect()
+}
+
+// 按 card_id 批量更新牌组里的卡片，不在牌组里的跳过，extra 是额外的 WHERE 条件。每条语句最多绑定 CHUNK_SIZE 个 id，
+// 避免超过 SQLite 的参数个数上限，几批放在调用方开启的同一个事务里
+fn update_cards(conn: &Connection, deck_key: &str, set: &str, extra: &str, card_ids: &[i64], now: i64) -> Result<usize, AnkiError> {
+    let mut count = 0;
+    for chunk in card_ids.chunks(CHUNK_SIZE) {
+        let sql = format!(
+            "UPDATE cards SET {set}, mod = {now}, usn = -1 WHERE {IN_DECK} AND id IN ({}) AND {extra}",
+            placeholders(chunk.len())
+        );
+        count += conn.execute(&sql, params_from_iter(deck_and_ids(deck_key, chunk))).context("更新card失败")?;
+    }
+    Ok(count)
+}
+
+/// 暂停卡片，已经暂停的不变，返回更新的卡片数
+pub fn suspend(conn: &Connection, deck_key: &str, card_ids: &[i64], now: i64) -> Result<usize, AnkiError> {
+    update_cards(conn, deck_key, &format!("queue = {QUEUE_SUSPENDED}"), &format!("queue != {QUEUE_SUSPENDED}"), card_ids, now)
+}
+
+pub fn unsuspend(conn: &Connection, deck_key: &str, card_ids: &[i64], now: i64) -> Result<usize, AnkiError> {
+    update_cards(conn, deck_key, &format!("queue = {RESTORED_QUEUE}"), &format!("queue = {QUEUE_SUSPENDED}"), card_ids, now)
+}
+
+/// 手动搁置卡片到明天，暂停的卡片不变
+pub fn bury(conn: &Connection, deck_key: &str, card_ids: &[i64], now: i64) -> Result<usize, AnkiError> {
+    update_cards(conn, deck_key, &format!("queue = {QUEUE_MANUALLY_BURIED}"), "queue >= 0", card_ids, now)
+}
+
+pub fn unbury(conn: &Connection, deck_key: &str, card_ids: &[i64], now: i64) -> Result<usize, AnkiError> {
+    update_cards(
+        conn,
+        deck_key,
+        &format!("queue = {RESTORED_QUEUE}"),
+        &format!("queue IN ({QUEUE_SIBLING_BURIED}, {QUEUE_MANUALLY_BURIED})"),
+        card_ids,
+        now,
+    )
+}
+
+/// 设置旗标，0 表示清除，1-7 对应 Anki 的七种颜色
+pub fn set_flag(conn: &Connection, deck_key: &str, card_ids: &[i64], flag: u8, now: i64) -> Result<usize, AnkiError> {
+    if flag as i64 > FLAG_MASK {
+        return Err(AnkiError::invalid_argument(format!("无效的旗标{flag}，必须是0-7之间的值")));
+    }
+    update_cards(conn, deck_key, &format!("flags = (flags & ~{FLAG_MASK}) | {flag}"), &format!("flags & {FLAG_MASK} != {flag}"), card_ids, now)
+}
+
+fn has_tag(tags: &str, tag: &str) -> bool {
+    tags.split_whitespace().any(|t| t.eq_ignore_ascii_case(tag))
+}
+
+fn note_tags(conn: &Connection, note_id: i64) -> Result<String, AnkiError> {
+    conn.query_row("SELECT tags FROM notes WHERE id = ?", [note_id], |row| row.get(0))
+        .context("未找到指定id的note")
+}
+
+fn save_tags(conn: &Connection, note_id: i64, list: &[&str], now: i64) -> Result<(), AnkiError> {
+    // Anki 的 tags 列前后各有一个空格，没有标签时为空
+    let tags = if list.is_empty() { String::new() } else { format!(" {} ", list.join(" ")) };
+    conn.execute("UPDATE notes SET tags = ?, mod = ?, usn = -1 WHERE id = ?", params![tags, now, note_id])
+        .context("更新note失败")?;
+    Ok(())
+}
+
+/// 给笔记加上标签，已经有这个标签时不变
+pub fn add_tag(conn: &Connection, note_id: i64, tag: &str, now: i64) -> Result<(), AnkiError> {
+    let tags = note_tags(conn, note_id)?;
+    if has_tag(&tags, tag) {
+        return Ok(());
+    }
+    let mut list: Vec<&str> = tags.split_whitespace().collect();
+    list.push(tag);
+    save_tags(conn, note_id, &list, now)
+}
+
+/// 切换笔记的 marked 标签，返回切换后是否标记
+pub fn toggle_marked(conn: &Connection, note_id: i64, now: i64) -> Result<bool, AnkiError> {
+    let tags = note_tags(conn, note_id)?;
+    let marked = !has_tag(&tags, MARKED_TAG);
+    let mut list: Vec<&str> = tags.split_whitespace().filter(|t| !t.eq_ignore_ascii_case(MARKED_TAG)).collect();
+    if marked {
+        list.push(MARKED_TAG);
+    }
+    save_tags(conn, note_id, &list, now)?;
+    Ok(marked)
+}
+
+/// 牌组里卡片的暂停、搁置、旗标和标记状态，不存在或不在牌组里的卡片不返回
+pub fn statuses(conn: &Connection, deck_key: &str, card_ids: &[i64]) -> Result<Vec<CardStatus>, AnkiError> {
+    let mut statuses = Vec::with_capacity(card_ids.len());
+    for chunk in card_ids.chunks(CHUNK_SIZE) {
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT c.id, c.nid, c.queue, c.flags, n.tags FROM cards c JOIN notes n ON n.id = c.nid WHERE c.{IN_DECK} AND c.id IN ({})",
+                placeholders(chunk.len())
+            ))
+            .context("准备SQL失败")?;
+        let rows = stmt
+            .query_map(params_from_iter(deck_and_ids(deck_key, chunk)), |row| {
+                let queue: i64 = row.get(2)?;
+                Ok(CardStatus {
+                    card_id: row.get(0)?,
+                    note_id: row.get(1)?,
+                    suspended: queue == QUEUE_SUSPENDED,
+                    buried: queue == QUEUE_SIBLING_BURIED || queue == QUEUE_MANUALLY_BURIED,
+                    flag: (row.get::<_, i64>(3)? & FLAG_MASK) as u8,
+                    marked: has_tag(&row.get::<_, String>(4)?, MARKED_TAG),
+                })
+            })
+            .context("查询SQL失败")?;
+        for row in rows {
+            statuses.push(row.context("读取card失败")?);
+        }
+    }
+    Ok(statuses)
+}
+
+/// 牌组里暂停的卡片 id，按 id 排序
+pub fn suspended_card_ids(conn: &Connection, deck_key: &str) -> Result<Vec<i64>, AnkiError> {
+    let mut stmt = conn
+        .prepare_cached(&format!("SELECT id FROM cards WHERE queue = {QUEUE_SUSPENDED} AND {IN_DECK} ORDER BY id"))
+        .context("准备SQL失败")?;
+    let rows = stmt.query_map([deck_key], |row| row.get(0)).context("查询SQL失败")?;
+    rows.collect::<Result<Vec<_>, _>>().context("读取id失败")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{add_test_card, memory_collection};
+    use super::*;
+
+    const DECK: &str = "deck";
+    const NOW: i64 = 1_700_000_000;
+
+    // 新卡 1、当天学习 2、跨天学习 3、当天重学 4、复习卡 5，另一个牌组里的卡片 6
+    fn cards() -> Connection {
+        let conn = memory_collection();
+        for cid in 1..=5 {
+            add_test_card(&conn, DECK, 1, cid, cid, 0);
+        }
+        add_test_card(&conn, "other", 2, 6, 6, 0);
+        for (cid, card_type, due) in [(2, 1, NOW), (3, 1, 10), (4, 3, NOW), (5, 2, 10)] {
+            let queue = if due > 1_000_000_000 { 1 } else if card_type == 1 { 3 } else { 2 };
+            conn.execute("UPDATE cards SET type = ?, queue = ?, due = ? WHERE id = ?", params![card_type, queue, due, cid]).unwrap();
+        }
+        conn
+    }
+
+    fn column(conn: &Connection, column: &str) -> Vec<i64> {
+        let mut stmt = conn.prepare(&format!("SELECT {column} FROM cards ORDER BY id")).unwrap();
+        let rows = stmt.query_map([], |row| row.get(0)).unwrap();
+        rows.collect::<Result<_, _>>().unwrap()
+    }
+
+    const ALL: [i64; 6] = [1, 2, 3, 4, 5, 6];
+
+    #[test]
+    fn unsuspend_restores_the_queue_from_type_and_due() {
+        let conn = cards();
+        assert_eq!(suspend(&conn, DECK, &ALL, NOW).unwrap(), 5);
+        assert_eq!(column(&conn, "queue"), [-1, -1, -1, -1, -1, 0]);
+        assert_eq!(suspend(&conn, DECK, &ALL, NOW).unwrap(), 0);
+        assert_eq!(suspended_card_ids(&conn, DECK).unwrap(), [1, 2, 3, 4, 5]);
+
+        assert_eq!(unsuspend(&conn, DECK, &ALL, NOW).unwrap(), 5);
+        assert_eq!(column(&conn, "queue"), [0, 1, 3, 1, 2, 0]);
+    }
+
+    #[test]
+    fn bury_leaves_suspended_cards_alone() {
+        let conn = cards();
+        suspend(&conn, DECK, &[1], NOW).unwrap();
+        assert_eq!(bury(&conn, DECK, &ALL, NOW).unwrap(), 4);
+        assert_eq!(column(&conn, "queue"), [-1, -3, -3, -3, -3, 0]);
+        conn.execute("UPDATE cards SET queue = ? WHERE id = 2", [QUEUE_SIBLING_BURIED]).unwrap();
+        assert_eq!(unbury(&conn, DECK, &ALL, NOW).unwrap(), 4);
+        assert_eq!(column(&conn, "queue"), [-1, 1, 3, 1, 2, 0]);
+    }
+
+    #[test]
+    fn flag_keeps_the_other_bits() {
+        let conn = cards();
+        conn.execute("UPDATE cards SET flags = ?", [0b1000_0011]).unwrap();
+        assert_eq!(set_flag(&conn, DECK, &[1, 2], 5, NOW).unwrap(), 2);
+        assert_eq!(set_flag(&conn, DECK, &[1, 2], 5, NOW).unwrap(), 0);
+        assert_eq!(column(&conn, "flags")[..3], [0b1000_0101, 0b1000_0101, 0b1000_0011]);
+        set_flag(&conn, DECK, &[1], 0, NOW).unwrap();
+        assert_eq!(column(&conn, "flags")[0], 0b1000_0000);
+        assert_eq!(set_flag(&conn, DECK, &[1], 8, NOW).unwrap_err().kind, crate::api::error::ErrorKind::InvalidArgument);
+    }
+
+    fn tags(conn: &Connection, note_id: i64) -> String {
+        note_tags(conn, note_id).unwrap()
+    }
+
+    #[test]
+    fn toggles_the_marked_tag() {
+        let conn = cards();
+        assert!(toggle_marked(&conn, 1, NOW).unwrap());
+        assert_eq!(tags(&conn, 1), " marked ");
+        assert!(!toggle_marked(&conn, 1, NOW).unwrap());
+        assert_eq!(tags(&conn, 1), "");
+
+        // 大小写不同的 Marked 也算，其他标签保留
+        conn.execute("UPDATE notes SET tags = ' foo Marked bar ' WHERE id = 2", []).unwrap();
+        assert!(!toggle_marked(&conn, 2, NOW).unwrap());
+        assert_eq!(tags(&conn, 2), " foo bar ");
+        assert!(toggle_marked(&conn, 2, NOW).unwrap());
+        assert_eq!(tags(&conn, 2), " foo bar marked ");
+    }
+
+    #[test]
+    fn statuses_only_cover_cards_in_the_deck() {
+        let conn = cards();
+        suspend(&conn, DECK, &[1], NOW).unwrap();
+        bury(&conn, DECK, &[2], NOW).unwrap();
+        set_flag(&conn, DECK, &[3], 7, NOW).unwrap();
+        toggle_marked(&conn, 4, NOW).unwrap();
+        // 另一个牌组的卡片和不存在的卡片都不返回，也不会被修改
+        assert_eq!(suspend(&conn, DECK, &[6], NOW).unwrap(), 0);
+        let statuses = statuses(&conn, DECK, &[1, 2, 3, 4, 6, 99]).unwrap();
+        let summary: Vec<_> = statuses.iter().map(|s| (s.card_id, s.suspended, s.buried, s.flag, s.marked)).collect();
+        assert_eq!(summary, [
+            (1, true, false, 0, false),
+            (2, false, true, 0, false),
+            (3, false, false, 7, false),
+            (4, false, false, 0, true)
+        ]);
+    }
+}
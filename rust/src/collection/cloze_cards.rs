@@ -0,0 +1,145 @@
+// 填空题的卡片：每个填空序号对应一张卡片（ord = 序号 - 1）。导入的集合里可能缺卡片，
+// 导入时按字段里出现的填空序号补齐，单独修改笔记后也可以调用这里补齐。
+
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap};
+use chrono::Utc;
+use rusqlite::{params, Connection};
+use crate::anki_proto::{decode_notetype_config, decode_template_config, NOTETYPE_KIND_CLOZE};
+use crate::api::simple::rust_log;
+use crate::cloze::cloze_numbers_in_string;
+use crate::error::{AnkiError, ResultExt};
+use crate::template::cloze_fields;
+use super::IN_DECK;
+
+// 笔记类型里 {{cloze:}} 引用的字段下标，不是填空题时为 None
+fn cloze_field_indices(conn: &Connection, mid: i64) -> Result<Option<Vec<usize>>, AnkiError> {
+    let config: Vec<u8> = conn
+        .prepare_cached("SELECT config FROM notetypes WHERE id = ?")
+        .context("准备SQL失败")?
+        .query_row([mid], |row| row.get(0))
+        .context("未找到指定id的notetype")?;
+    if decode_notetype_config(&config)?.kind != NOTETYPE_KIND_CLOZE {
+        return Ok(None);
+    }
+    let mut stmt = conn.prepare_cached("SELECT name FROM fields WHERE ntid = ? ORDER BY ord ASC").context("准备SQL失败")?;
+    let rows = stmt.query_map([mid], |row| row.get(0)).context("查询SQL失败")?;
+    let field_names = rows.collect::<Result<Vec<String>, _>>().context("读取name失败")?;
+    // 填空题只有一个模板
+    let qfmt = match conn
+        .prepare_cached("SELECT config FROM templates WHERE ntid = ? ORDER BY ord ASC LIMIT 1")
+        .context("准备SQL失败")?
+        .query_row([mid], |row| row.get::<_, Vec<u8>>(0))
+    {
+        Ok(bytes) => decode_template_config(&bytes)?.q_format,
+        Err(rusqlite::Error::QueryReturnedNoRows) => String::new(),
+        Err(e) => return Err(e).context("读取template失败"),
+    };
+    let indices = cloze_fields(&qfmt).iter().filter_map(|name| field_names.iter().position(|n| n == name)).collect();
+    Ok(Some(indices))
+}
+
+// 新卡片排在目标牌组的新卡最后；同一笔记已有新卡时用它的位置，和 Anki 一样让兄弟卡片挨在一起
+fn new_card_position(conn: &Connection, note_id: i64, did: i64) -> Result<i64, AnkiError> {
+    let sibling: Option<i64> = conn
+        .prepare_cached("SELECT MIN(due) FROM cards WHERE nid = ? AND did = ? AND type = 0")
+        .context("准备SQL失败")?
+        .query_row(params![note_id, did], |row| row.get(0))
+        .context("查询SQL失败")?;
+    if let Some(due) = sibling {
+        return Ok(due);
+    }
+    conn.prepare_cached("SELECT COALESCE(MAX(due), 0) + 1 FROM cards WHERE did = ? AND type = 0")
+        .context("准备SQL失败")?
+        .query_row([did], |row| row.get(0))
+        .context("查询SQL失败")
+}
+
+// 为填空题笔记补齐缺失的卡片，新卡片放在笔记已有卡片所在的牌组。
+// notetypes 缓存笔记类型的填空字段，next_id 是下一个可用的卡片 id（0 表示还没查过）。返回新卡片的 id
+fn generate_note_cards(
+    conn: &Connection,
+    note_id: i64,
+    notetypes: &mut HashMap<i64, Option<Vec<usize>>>,
+    next_id: &mut i64,
+) -> Result<Vec<i64>, AnkiError> {
+    let (mid, flds): (i64, String) = conn
+        .prepare_cached("SELECT mid, flds FROM notes WHERE id = ?")
+        .context("准备SQL失败")?
+        .query_row([note_id], |row| Ok((row.get(0)?, row.get(1)?)))
+        .context("未找到指定id的note")?;
+    let indices = match notetypes.entry(mid) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => entry.insert(cloze_field_indices(conn, mid)?),
+    };
+    let Some(indices) = indices else {
+        return Ok(vec![]);
+    };
+    let field_values: Vec<&str> = flds.split('\x1f').collect();
+    let mut ordinals = BTreeSet::new();
+    for idx in indices {
+        ordinals.extend(cloze_numbers_in_string(field_values.get(*idx).copied().unwrap_or("")));
+    }
+    // 和 Anki 一样，没有任何填空时也保留第一张卡
+    if ordinals.is_empty() {
+        ordinals.insert(1);
+    }
+    let mut stmt = conn.prepare_cached("SELECT ord, did FROM cards WHERE nid = ? ORDER BY ord ASC").context("准备SQL失败")?;
+    let existing: Vec<(i64, i64)> = stmt
+        .query_map([note_id], |row| Ok((row.get(0)?, row.get(1)?)))
+        .context("查询SQL失败")?
+        .collect::<Result<_, _>>()
+        .context("读取card失败")?;
+    let Some(did) = existing.first().map(|(_, did)| *did) else {
+        return Err(AnkiError::not_found("笔记没有任何卡片，无法确定牌组"));
+    };
+    let missing: Vec<i64> = ordinals.into_iter().map(|ord| ord as i64 - 1).filter(|ord| !existing.iter().any(|(o, _)| o == ord)).collect();
+    if missing.is_empty() {
+        return Ok(vec![]);
+    }
+    let now = Utc::now();
+    if *next_id == 0 {
+        let max: i64 = conn
+            .query_row("SELECT COALESCE(MAX(id), 0) FROM cards", [], |row| row.get(0))
+            .context("查询SQL失败")?;
+        *next_id = max.max(now.timestamp_millis()) + 1;
+    }
+    let due = new_card_position(conn, note_id, did)?;
+    let mut added = vec![];
+    for card_ord in missing {
+        let id = *next_id;
+        *next_id += 1;
+        conn.execute(
+            "INSERT INTO cards (id, nid, did, ord, mod, usn, type, queue, due, ivl, factor, reps, lapses, left, odue, odid, flags, data) \
+             VALUES (?, ?, ?, ?, ?, -1, 0, 0, ?, 0, 0, 0, 0, 0, 0, 0, 0, '')",
+            params![id, note_id, did, card_ord, now.timestamp(), due],
+        )
+        .context("插入card失败")?;
+        rust_log(&format!("DEBUG: 为填空 c{} 生成卡片 card_id={}, note_id={}", card_ord + 1, id, note_id));
+        added.push(id);
+    }
+    Ok(added)
+}
+
+/// 为一条笔记补齐缺失的填空卡片，返回新卡片的 id
+pub fn generate_cloze_cards(conn: &Connection, note_id: i64) -> Result<Vec<i64>, AnkiError> {
+    generate_note_cards(conn, note_id, &mut HashMap::new(), &mut 0)
+}
+
+/// 为牌组里所有填空题笔记补齐缺失的卡片，返回新卡片的 id
+pub fn generate_deck_cloze_cards(conn: &Connection, deck_key: &str) -> Result<Vec<i64>, AnkiError> {
+    let note_ids: Vec<i64> = {
+        let mut stmt = conn
+            .prepare(&format!("SELECT DISTINCT nid FROM cards WHERE {IN_DECK} ORDER BY nid"))
+            .context("准备SQL失败")?;
+        let rows = stmt.query_map([deck_key], |row| row.get(0)).context("查询SQL失败")?;
+        rows.collect::<Result<_, _>>().context("读取card失败")?
+    };
+    let mut notetypes = HashMap::new();
+    let mut next_id = 0;
+    let mut added = vec![];
+    for note_id in note_ids {
+        added.extend(generate_note_cards(conn, note_id, &mut notetypes, &mut next_id)?);
+    }
+    Ok(added)
+}
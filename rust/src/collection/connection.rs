@@ -0,0 +1,196 @@
+// 打开的 sqlite 连接按文件路径登记，显式打开后一直复用到显式关闭；连接上缓存预编译的语句，
+// 连续翻卡片时不用每次都重新打开数据库、重新准备语句。
+// 每个文件一个写连接，同一时间只交给一个调用方；另外有几个只读连接（query_only）给只读的接口用。
+// 连接开启 WAL，只读连接读到的是最近一次提交的数据，导入这样的长事务进行时也不用等写连接。
+// 闭包里不能再取同一个文件的写连接。
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use lazy_static::lazy_static;
+use rusqlite::Connection;
+use crate::api::simple::rust_log;
+use crate::error::{AnkiError, ErrorKind, ResultExt};
+
+const STATEMENT_CACHE_CAPACITY: usize = 64;
+// 另一个连接（如导入时 ATTACH 的源集合）持有写锁时的等待时间
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+// 用完后留着复用的只读连接数，同时读的调用方更多时临时打开新的连接
+const READER_POOL_SIZE: usize = 2;
+
+// 一个文件的连接：写连接关闭后为 None，readers 是空闲的只读连接
+struct Pool {
+    writer: Mutex<Option<Connection>>,
+    readers: Mutex<Vec<Connection>>,
+}
+
+lazy_static! {
+    static ref CONNECTIONS: Mutex<HashMap<PathBuf, Arc<Pool>>> = Mutex::new(HashMap::new());
+}
+
+fn open_connection(path: &Path) -> Result<Connection, AnkiError> {
+    let conn = Connection::open(path).path_context("打开sqlite失败", path)?;
+    // journal_mode 会返回设置后的模式，不能用 execute
+    let mode: String = conn.query_row("PRAGMA journal_mode = WAL", [], |row| row.get(0)).context("开启WAL失败")?;
+    conn.pragma_update(None, "synchronous", "NORMAL").context("设置synchronous失败")?;
+    conn.busy_timeout(BUSY_TIMEOUT).context("设置busy_timeout失败")?;
+    conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
+    rust_log(&format!("DEBUG: 已打开 {}，journal_mode={}", path.display(), mode));
+    Ok(conn)
+}
+
+fn open_reader(path: &Path) -> Result<Connection, AnkiError> {
+    let conn = Connection::open(path).path_context("打开sqlite失败", path)?;
+    conn.pragma_update(None, "query_only", true).context("设置query_only失败")?;
+    conn.busy_timeout(BUSY_TIMEOUT).context("设置busy_timeout失败")?;
+    conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
+    Ok(conn)
+}
+
+fn pool(path: &Path) -> Result<Arc<Pool>, AnkiError> {
+    CONNECTIONS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(path)
+        .cloned()
+        .ok_or_else(|| AnkiError::new(ErrorKind::NotInitialized, "数据库没有打开或已经关闭").with_path(path))
+}
+
+/// 打开 path 的写连接和一个只读连接，已经打开时什么都不做
+pub fn open(path: &Path) -> Result<(), AnkiError> {
+    let mut connections = CONNECTIONS.lock().unwrap_or_else(|e| e.into_inner());
+    if connections.contains_key(path) {
+        return Ok(());
+    }
+    // 先打开写连接，WAL 模式要在只读连接打开前设置好
+    let writer = open_connection(path)?;
+    let reader = open_reader(path)?;
+    let pool = Pool { writer: Mutex::new(Some(writer)), readers: Mutex::new(vec![reader]) };
+    connections.insert(path.to_path_buf(), Arc::new(pool));
+    Ok(())
+}
+
+/// 用 path 的写连接执行 f，需要先 open
+pub fn with_connection<T>(path: &Path, f: impl FnOnce(&mut Connection) -> Result<T, AnkiError>) -> Result<T, AnkiError> {
+    let pool = pool(path)?;
+    let mut guard = pool.writer.lock().unwrap_or_else(|e| e.into_inner());
+    let conn = guard.as_mut().ok_or_else(|| AnkiError::new(ErrorKind::NotInitialized, "数据库已经关闭").with_path(path))?;
+    f(conn)
+}
+
+/// 用 path 的一个只读连接执行 f，不用等写连接上正在进行的事务。需要先 open
+pub fn with_reader<T>(path: &Path, f: impl FnOnce(&Connection) -> Result<T, AnkiError>) -> Result<T, AnkiError> {
+    let pool = pool(path)?;
+    let idle = pool.readers.lock().unwrap_or_else(|e| e.into_inner()).pop();
+    let conn = match idle {
+        Some(conn) => conn,
+        None => open_reader(path)?,
+    };
+    let result = f(&conn);
+    let mut readers = pool.readers.lock().unwrap_or_else(|e| e.into_inner());
+    if readers.len() < READER_POOL_SIZE {
+        readers.push(conn);
+    }
+    result
+}
+
+fn close_connection(path: &Path, conn: Connection) {
+    // 关闭前清掉缓存的语句，否则 close 会因为还有未释放的语句失败
+    conn.flush_prepared_statement_cache();
+    if let Err((_, e)) = conn.close() {
+        rust_log(&format!("DEBUG: 关闭sqlite失败: {} - {}", path.display(), e));
+    }
+}
+
+// 正在使用的写连接会等用完再关闭；正在使用的只读连接用完后随 pool 一起释放
+fn close_pool(path: &Path, pool: Arc<Pool>) {
+    let readers = std::mem::take(&mut *pool.readers.lock().unwrap_or_else(|e| e.into_inner()));
+    for conn in readers {
+        close_connection(path, conn);
+    }
+    if let Some(conn) = pool.writer.lock().unwrap_or_else(|e| e.into_inner()).take() {
+        close_connection(path, conn);
+    }
+    rust_log(&format!("DEBUG: 已关闭 {}", path.display()));
+}
+
+/// 关闭所有连接，之后要重新 open 才能使用
+pub fn close_all() {
+    let pools: Vec<(PathBuf, Arc<Pool>)> = CONNECTIONS.lock().unwrap_or_else(|e| e.into_inner()).drain().collect();
+    for (path, pool) in pools {
+        close_pool(&path, pool);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::ErrorCode;
+    use crate::apkg::TempDir;
+    use super::*;
+
+    // close_all 会关闭所有文件的连接，用到它的测试不能同时跑
+    static CONNECTIONS_LOCK: Mutex<()> = Mutex::new(());
+
+    fn open_test_db(dir: &TempDir) -> PathBuf {
+        let path = dir.path.join("collection.sqlite");
+        open(&path).unwrap();
+        with_connection(&path, |conn| {
+            conn.execute_batch("CREATE TABLE t (v INTEGER NOT NULL); INSERT INTO t VALUES (1);").context("建表失败")
+        })
+        .unwrap();
+        path
+    }
+
+    fn count(conn: &Connection) -> i64 {
+        conn.query_row("SELECT count() FROM t", [], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn reader_rejects_writes_and_sees_committed_data() {
+        let _lock = CONNECTIONS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = TempDir::new(&std::env::temp_dir()).unwrap();
+        let path = open_test_db(&dir);
+        let mode: String = with_connection(&path, |conn| conn.query_row("PRAGMA journal_mode", [], |row| row.get(0)).context("读取失败")).unwrap();
+        assert_eq!(mode, "wal");
+
+        let err = with_reader(&path, |conn| conn.execute("INSERT INTO t VALUES (2)", []).context("写入失败")).unwrap_err();
+        assert!(err.to_string().contains("写入失败"), "{err}");
+        let readonly = with_reader(&path, |conn| Ok(conn.execute("DELETE FROM t", []))).unwrap();
+        assert!(matches!(readonly, Err(rusqlite::Error::SqliteFailure(e, _)) if e.code == ErrorCode::ReadOnly));
+
+        // 写连接上的事务没提交时，只读连接不用等，读到的是提交前的数据
+        with_connection(&path, |conn| {
+            let tx = conn.transaction().context("开始事务失败")?;
+            tx.execute("INSERT INTO t VALUES (3)", []).context("写入失败")?;
+            assert_eq!(with_reader(&path, |reader| Ok(count(reader)))?, 1);
+            tx.commit().context("提交失败")
+        })
+        .unwrap();
+        assert_eq!(with_reader(&path, |reader| Ok(count(reader))).unwrap(), 2);
+        close_all();
+    }
+
+    #[test]
+    fn close_then_reopen() {
+        let _lock = CONNECTIONS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = TempDir::new(&std::env::temp_dir()).unwrap();
+        let path = open_test_db(&dir);
+        // 同时读的调用方多于连接池大小时临时打开新的只读连接
+        let nested = with_reader(&path, |a| with_reader(&path, |b| with_reader(&path, |c| Ok(count(a) + count(b) + count(c))))).unwrap();
+        assert_eq!(nested, 3);
+        close_all();
+
+        let err = with_connection(&path, |conn| Ok(count(conn))).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NotInitialized);
+        assert_eq!(with_reader(&path, |conn| Ok(count(conn))).unwrap_err().kind, ErrorKind::NotInitialized);
+
+        open(&path).unwrap();
+        with_connection(&path, |conn| conn.execute("INSERT INTO t VALUES (2)", []).context("写入失败")).unwrap();
+        assert_eq!(with_reader(&path, |conn| Ok(count(conn))).unwrap(), 2);
+        // 重复 open 不会换掉已经打开的连接
+        open(&path).unwrap();
+        assert_eq!(with_connection(&path, |conn| Ok(count(conn))).unwrap(), 2);
+        close_all();
+    }
+}
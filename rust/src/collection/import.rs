@@ -0,0 +1,806 @@
+// 把解包出来的集合导入统一的集合库。旧格式的集合先经过 upgrade 转换成 anki21b 的表，
+// 再以 src 名字 ATTACH 到集合库上，整个导入在一个事务里完成，媒体文件等事务提交后再移到媒体目录：
+// - 应用里的一个牌组用 deck key（导入时的 md5）标识，它拥有导入时有卡片的那些 Anki 牌组，记在 open_anki_decks 表
+// - 牌组按完整名称对应：没有卡片的上级牌组总是共用；有卡片的牌组被别的 deck key 占用时在名字后面加序号
+// - 笔记类型按 id 对应，字段名和模板名（按顺序）都相同才认为结构一致；新版本更新时间更晚时替换模板和样式，
+//   结构不一致时另存一份新的笔记类型
+// - 笔记在同一个 deck key 里按 guid 对应，内容或标签变了就更新，新的笔记连同卡片（和复习记录）一起加进来
+// - 填空题按字段里的填空序号补齐缺少的卡片（比如更新后的笔记多了填空）
+// 已有卡片的调度列、data 和复习记录都不动。复习卡的 due 是相对集合创建时间（crt）的天数，按两个集合 crt 的差调整。
+// colpkg 按牌组拆分时，每个牌组都从同一个解包出的集合导入，用 DeckFilter 只取这个牌组的卡片。
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use chrono::Utc;
+use prost::Message;
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use crate::anki_proto::{decode_deck_kind, DeckKindContainer, DeckNormal};
+use crate::api::simple::{rust_log, MergeSummary};
+use crate::scheduler::steps::SECONDS_PER_DAY;
+use crate::error::{AnkiError, ErrorKind, ResultExt};
+use super::cloze_cards::generate_deck_cloze_cards;
+use super::media::{import_media, rewrite_media_references, MediaFile, StagedMedia};
+use super::{has_table, IN_DECK};
+
+const SOURCE: &str = "src";
+// Anki 的默认牌组和默认牌组选项
+const DEFAULT_DECK_ID: i64 = 1;
+const DEFAULT_DECK_CONFIG_ID: i64 = 1;
+
+// 笔记类型的结构：字段名和模板名，都按 ord 排好
+struct NotetypeSchema {
+    name: String,
+    fields: Vec<String>,
+    templates: Vec<String>,
+    mtime: i64,
+}
+
+impl NotetypeSchema {
+    fn same_structure(&self, other: &NotetypeSchema) -> bool {
+        self.fields == other.fields && self.templates == other.templates
+    }
+}
+
+struct SourceNote {
+    id: i64,
+    guid: String,
+    mid: i64,
+    tags: String,
+    flds: String,
+    sfld: rusqlite::types::Value,
+    csum: i64,
+    flags: i64,
+    data: String,
+}
+
+struct SourceDeck {
+    id: i64,
+    name: String,
+    mtime: i64,
+    common: Vec<u8>,
+    kind: Vec<u8>,
+}
+
+/// 只导入源集合里一个牌组的卡片（连同笔记和复习记录），牌组只导入 decks 里的（这个牌组和它的上级牌组）
+pub struct DeckFilter {
+    pub deck_id: i64,
+    pub decks: HashSet<i64>,
+}
+
+// 源牌组 id 到集合库牌组 id 的对应；卡片所在的牌组没有导入时放到卡片最多的牌组
+struct DeckMapping {
+    ids: HashMap<i64, i64>,
+    fallback: Option<i64>,
+    day_offset: i64,
+    only_deck: Option<i64>, // 有 DeckFilter 时只导入这个源牌组的卡片
+}
+
+impl DeckMapping {
+    fn target(&self, did: i64) -> Result<i64, AnkiError> {
+        self.ids.get(&did).copied().or(self.fallback).ok_or_else(|| AnkiError::not_found(format!("找不到卡片所在的牌组: {did}")))
+    }
+}
+
+// 主键冲突时从当前时间戳和最大 id 之后顺延，和 Anki 生成 id 的方式一致
+// 每条笔记、卡片和复习记录都要查一次，语句用缓存的
+fn free_id(tx: &Transaction, table: &str, wanted: i64, next: &mut i64) -> Result<i64, AnkiError> {
+    let taken = tx
+        .prepare_cached(&format!("SELECT 1 FROM {table} WHERE id = ?"))
+        .context("准备SQL失败")?
+        .query_row([wanted], |_| Ok(()))
+        .optional()
+        .context(&format!("查询{table}失败"))?
+        .is_some();
+    if !taken {
+        return Ok(wanted);
+    }
+    if *next == 0 {
+        let max: i64 = tx
+            .query_row(&format!("SELECT COALESCE(MAX(id), 0) FROM {table}"), [], |row| row.get(0))
+            .context(&format!("查询{table}失败"))?;
+        *next = max.max(Utc::now().timestamp_millis()) + 1;
+    }
+    let id = *next;
+    *next += 1;
+    Ok(id)
+}
+
+/// 筛选牌组只是临时借用卡片，把卡片放回原牌组，恢复原来的到期时间和队列
+pub fn return_filtered_cards(conn: &Connection, schema: &str) -> Result<usize, AnkiError> {
+    conn.execute(
+        &format!(
+            "UPDATE {schema}.cards SET did = odid, due = CASE WHEN odue != 0 THEN odue ELSE due END, odid = 0, odue = 0, \
+             queue = CASE \
+                WHEN queue < 0 THEN queue \
+                WHEN type IN (1, 3) THEN (CASE WHEN (CASE WHEN odue != 0 THEN odue ELSE due END) > 1000000000 THEN 1 ELSE 3 END) \
+                ELSE type END \
+             WHERE odid != 0"
+        ),
+        [],
+    )
+    .context("恢复筛选牌组中的卡片失败")
+}
+
+fn load_notetypes(conn: &Connection, schema: &str) -> Result<HashMap<i64, NotetypeSchema>, AnkiError> {
+    let mut notetypes = HashMap::new();
+    let mut stmt = conn
+        .prepare(&format!("SELECT id, name, mtime_secs FROM {schema}.notetypes"))
+        .context("准备SQL失败")?;
+    let rows = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+        .context("查询SQL失败")?;
+    for row in rows {
+        let (id, name, mtime): (i64, String, i64) = row.context("读取notetype失败")?;
+        notetypes.insert(id, NotetypeSchema { name, fields: Vec::new(), templates: Vec::new(), mtime });
+    }
+    for (table, is_field) in [("fields", true), ("templates", false)] {
+        let mut stmt = conn
+            .prepare(&format!("SELECT ntid, name FROM {schema}.{table} ORDER BY ntid, ord"))
+            .context("准备SQL失败")?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?))).context("查询SQL失败")?;
+        for row in rows {
+            let (ntid, name): (i64, String) = row.context(&format!("读取{table}失败"))?;
+            if let Some(notetype) = notetypes.get_mut(&ntid) {
+                if is_field { notetype.fields.push(name) } else { notetype.templates.push(name) }
+            }
+        }
+    }
+    Ok(notetypes)
+}
+
+// notetypes/fields/templates 三张表整行复制，replace 时先删掉集合库里原来的字段和模板
+fn copy_notetype(tx: &Transaction, source_id: i64, id: i64, name: &str, replace: bool) -> Result<(), AnkiError> {
+    if replace {
+        tx.execute(
+            &format!(
+                "UPDATE notetypes SET config = (SELECT config FROM {SOURCE}.notetypes WHERE id = ?1), \
+                 mtime_secs = (SELECT mtime_secs FROM {SOURCE}.notetypes WHERE id = ?1), usn = -1 WHERE id = ?2"
+            ),
+            [source_id, id],
+        )
+        .context("复制笔记类型失败")?;
+        tx.execute("DELETE FROM fields WHERE ntid = ?", [id]).context("复制笔记类型失败")?;
+        tx.execute("DELETE FROM templates WHERE ntid = ?", [id]).context("复制笔记类型失败")?;
+    } else {
+        tx.execute(
+            &format!("INSERT INTO notetypes (id, name, mtime_secs, usn, config) SELECT ?, ?, mtime_secs, -1, config FROM {SOURCE}.notetypes WHERE id = ?"),
+            params![id, name, source_id],
+        )
+        .context("复制笔记类型失败")?;
+    }
+    tx.execute(&format!("INSERT INTO fields SELECT ?, ord, name, config FROM {SOURCE}.fields WHERE ntid = ?"), [id, source_id])
+        .context("复制笔记类型失败")?;
+    tx.execute(
+        &format!("INSERT INTO templates SELECT ?, ord, name, mtime_secs, -1, config FROM {SOURCE}.templates WHERE ntid = ?"),
+        [id, source_id],
+    )
+    .context("复制笔记类型失败")?;
+    Ok(())
+}
+
+// 对比两边的笔记类型，新增或更新可以共用的，结构不一致的另存一份，返回源笔记类型 id 到集合库 id 的对应
+fn import_notetypes(tx: &Transaction, summary: &mut MergeSummary) -> Result<HashMap<i64, i64>, AnkiError> {
+    let target = load_notetypes(tx, "main")?;
+    let source = load_notetypes(tx, SOURCE)?;
+    let mut mapping = HashMap::new();
+    let mut next_id = 0;
+    for (id, schema) in &source {
+        // 结构不一致时另存的副本叫“名称 (源id)”，再次导入时按这个名字找回来
+        let copy_name = format!("{} ({})", schema.name, id);
+        let existing = match target.get(id) {
+            Some(existing) if existing.same_structure(schema) => Some((*id, existing)),
+            _ => target.iter().find(|(_, t)| t.name == copy_name && t.same_structure(schema)).map(|(tid, t)| (*tid, t)),
+        };
+        let target_id = match existing {
+            Some((target_id, existing)) => {
+                if schema.mtime > existing.mtime {
+                    copy_notetype(tx, *id, target_id, "", true)?;
+                    summary.updated_notetypes += 1;
+                }
+                target_id
+            }
+            None => {
+                let target_id = free_id(tx, "notetypes", *id, &mut next_id)?;
+                let taken = target.values().any(|t| t.name == schema.name);
+                copy_notetype(tx, *id, target_id, if taken { &copy_name } else { &schema.name }, false)?;
+                summary.added_notetypes += 1;
+                target_id
+            }
+        };
+        mapping.insert(*id, target_id);
+    }
+    Ok(mapping)
+}
+
+fn load_source_decks(tx: &Transaction) -> Result<Vec<SourceDeck>, AnkiError> {
+    // 按名称排序，上级牌组总在下级之前
+    let mut stmt = tx
+        .prepare(&format!("SELECT id, name, mtime_secs, common, kind FROM {SOURCE}.decks ORDER BY name"))
+        .context("准备SQL失败")?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(SourceDeck { id: row.get(0)?, name: row.get(1)?, mtime: row.get(2)?, common: row.get(3)?, kind: row.get(4)? })
+        })
+        .context("查询SQL失败")?;
+    rows.collect::<Result<Vec<_>, _>>().context("读取牌组失败")
+}
+
+// 牌组选项复制一份给新建的牌组，选项被删掉时 Anki 回退到默认选项
+fn copy_deck_config(tx: &Transaction, config_id: i64, next: &mut i64) -> Result<i64, AnkiError> {
+    let row: Option<(i64, String, i64, Vec<u8>)> = tx
+        .query_row(
+            &format!("SELECT id, name, mtime_secs, config FROM {SOURCE}.deck_config WHERE id IN (?1, 1) ORDER BY id = ?1 DESC LIMIT 1"),
+            [config_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .optional()
+        .context("读取deck_config失败")?;
+    let Some((source_id, name, mtime, config)) = row else {
+        return Ok(DEFAULT_DECK_CONFIG_ID);
+    };
+    let id = free_id(tx, "deck_config", source_id, next)?;
+    tx.execute(
+        "INSERT INTO deck_config (id, name, mtime_secs, usn, config) VALUES (?, ?, ?, -1, ?)",
+        params![id, name, mtime, config],
+    )
+    .context("复制deck_config失败")?;
+    Ok(id)
+}
+
+fn child_name(parent: Option<&str>, leaf: &str) -> String {
+    match parent {
+        Some(parent) => format!("{parent}\x1f{leaf}"),
+        None => leaf.to_string(),
+    }
+}
+
+// 集合创建时间不同，复习卡的 due 要加上的天数
+fn day_offset(tx: &Transaction) -> Result<i64, AnkiError> {
+    let crt = |schema: &str| {
+        tx.query_row(&format!("SELECT crt FROM {schema}.col LIMIT 1"), [], |row| row.get::<_, i64>(0))
+            .context("读取col.crt失败")
+    };
+    let diff = crt(SOURCE)? - crt("main")?;
+    Ok((diff as f64 / SECONDS_PER_DAY as f64).round() as i64)
+}
+
+fn import_decks(tx: &Transaction, deck_key: &str, filter: Option<&DeckFilter>) -> Result<DeckMapping, AnkiError> {
+    let mut decks = load_source_decks(tx)?;
+    let only_deck = filter.map(|f| f.deck_id);
+    if let Some(filter) = filter {
+        decks.retain(|d| filter.decks.contains(&d.id));
+    }
+    let counts: HashMap<i64, i64> = {
+        let mut stmt = tx
+            .prepare(&format!("SELECT did, COUNT(*) FROM {SOURCE}.cards WHERE ?1 IS NULL OR did = ?1 GROUP BY did"))
+            .context("准备SQL失败")?;
+        let rows = stmt.query_map([only_deck], |row| Ok((row.get(0)?, row.get(1)?))).context("查询SQL失败")?;
+        rows.collect::<Result<_, _>>().context("读取card失败")?
+    };
+    // 卡片所在的牌组不存在时，按牌组 id 新建一个使用默认选项的牌组
+    let missing: Vec<i64> = counts.keys().filter(|did| !decks.iter().any(|d| d.id == **did)).copied().collect();
+    for did in missing {
+        let kind = DeckKindContainer { normal: Some(DeckNormal { config_id: DEFAULT_DECK_CONFIG_ID, ..Default::default() }) };
+        decks.push(SourceDeck { id: did, name: did.to_string(), mtime: 0, common: Vec::new(), kind: kind.encode_to_vec() });
+    }
+    let mut names: HashMap<String, String> = HashMap::new();
+    let mut ids = HashMap::new();
+    let mut configs: HashMap<i64, i64> = HashMap::new();
+    let (mut next_deck_id, mut next_config_id) = (0, 0);
+    for deck in &decks {
+        let mut kind = decode_deck_kind(&deck.kind)?;
+        let Some(normal) = kind.normal.as_mut() else {
+            continue;
+        };
+        let card_count = counts.get(&deck.id).copied().unwrap_or(0);
+        let prefix = format!("{}\x1f", deck.name);
+        // 每个集合都有默认牌组，没有卡片也没有下级牌组时不导入
+        if deck.id == DEFAULT_DECK_ID && card_count == 0 && !decks.iter().any(|d| d.name.starts_with(&prefix)) {
+            continue;
+        }
+        let (parent, leaf) = match deck.name.rsplit_once('\x1f') {
+            Some((parent, leaf)) => (Some(names.get(parent).cloned().unwrap_or_else(|| parent.to_string())), leaf),
+            None => (None, deck.name.as_str()),
+        };
+        let mut name = child_name(parent.as_deref(), leaf);
+        let mut suffix = 1;
+        let target_id = loop {
+            let existing: Option<(i64, Option<String>)> = tx
+                .query_row(
+                    "SELECT d.id, o.deck_key FROM decks d LEFT JOIN open_anki_decks o ON o.did = d.id WHERE d.name = ?",
+                    [&name],
+                    |row| Ok((row.get(0)?, row.get(1)?)),
+                )
+                .optional()
+                .context("查询牌组失败")?;
+            match existing {
+                Some((id, owner)) if card_count == 0 || owner.is_none() || owner.as_deref() == Some(deck_key) => break id,
+                Some(_) => {
+                    suffix += 1;
+                    name = child_name(parent.as_deref(), &format!("{leaf} ({suffix})"));
+                }
+                None => {
+                    let config_id = match configs.get(&normal.config_id) {
+                        Some(id) => *id,
+                        None => {
+                            let id = copy_deck_config(tx, normal.config_id, &mut next_config_id)?;
+                            configs.insert(normal.config_id, id);
+                            id
+                        }
+                    };
+                    normal.config_id = config_id;
+                    let id = free_id(tx, "decks", deck.id, &mut next_deck_id)?;
+                    tx.execute(
+                        "INSERT INTO decks (id, name, mtime_secs, usn, common, kind) VALUES (?, ?, ?, -1, ?, ?)",
+                        params![id, name, deck.mtime, deck.common, kind.encode_to_vec()],
+                    )
+                    .context("添加牌组失败")?;
+                    break id;
+                }
+            }
+        };
+        if card_count > 0 {
+            tx.execute("INSERT OR IGNORE INTO open_anki_decks (deck_key, did) VALUES (?, ?)", params![deck_key, target_id])
+                .context("登记牌组失败")?;
+        }
+        names.insert(deck.name.clone(), name);
+        ids.insert(deck.id, target_id);
+    }
+    let fallback = counts
+        .iter()
+        .filter(|(did, _)| ids.contains_key(did))
+        .max_by_key(|(did, count)| (**count, -**did))
+        .map(|(did, _)| ids[did]);
+    Ok(DeckMapping { ids, fallback, day_offset: day_offset(tx)?, only_deck })
+}
+
+fn load_source_notes(tx: &Transaction, only_deck: Option<i64>) -> Result<Vec<SourceNote>, AnkiError> {
+    let mut stmt = tx
+        .prepare(&format!(
+            "SELECT id, guid, mid, tags, flds, sfld, csum, flags, data FROM {SOURCE}.notes \
+             WHERE ?1 IS NULL OR id IN (SELECT nid FROM {SOURCE}.cards WHERE did = ?1) ORDER BY id"
+        ))
+        .context("准备SQL失败")?;
+    let rows = stmt
+        .query_map([only_deck], |row| {
+            Ok(SourceNote {
+                id: row.get(0)?,
+                guid: row.get(1)?,
+                mid: row.get(2)?,
+                tags: row.get(3)?,
+                flds: row.get(4)?,
+                sfld: row.get(5)?,
+                csum: row.get(6)?,
+                flags: row.get(7)?,
+                data: row.get(8)?,
+            })
+        })
+        .context("查询SQL失败")?;
+    rows.collect::<Result<Vec<_>, _>>().context("读取note失败")
+}
+
+// 新笔记的卡片连同复习记录一起复制过来，复习记录的 id 冲突时和卡片一样换一个新的 id
+fn copy_cards(
+    tx: &Transaction,
+    source_nid: i64,
+    nid: i64,
+    decks: &DeckMapping,
+    next_card_id: &mut i64,
+    next_revlog_id: &mut i64,
+    summary: &mut MergeSummary,
+) -> Result<(), AnkiError> {
+    let cards: Vec<(i64, i64)> = {
+        let mut stmt = tx
+            .prepare_cached(&format!("SELECT id, did FROM {SOURCE}.cards WHERE nid = ?1 AND (?2 IS NULL OR did = ?2)"))
+            .context("准备SQL失败")?;
+        let rows = stmt
+            .query_map(params![source_nid, decks.only_deck], |row| Ok((row.get(0)?, row.get(1)?)))
+            .context("查询SQL失败")?;
+        rows.collect::<Result<_, _>>().context("读取card失败")?
+    };
+    for (source_cid, did) in cards {
+        let cid = free_id(tx, "cards", source_cid, next_card_id)?;
+        // 学习中的卡片 due 是时间戳，按天调度的卡片 due 是天数
+        tx.prepare_cached(&format!(
+            "INSERT INTO cards (id, nid, did, ord, mod, usn, type, queue, due, ivl, factor, reps, lapses, left, odue, odid, flags, data) \
+             SELECT ?, ?, ?, ord, mod, -1, type, queue, CASE WHEN type IN (1, 2, 3) AND due < 1000000000 THEN due + ? ELSE due END, \
+             ivl, factor, reps, lapses, left, 0, 0, flags, data FROM {SOURCE}.cards WHERE id = ?"
+        ))
+        .context("准备SQL失败")?
+        .execute(params![cid, nid, decks.target(did)?, decks.day_offset, source_cid])
+        .context("添加card失败")?;
+        let revlog_ids: Vec<i64> = {
+            let mut stmt = tx
+                .prepare_cached(&format!("SELECT id FROM {SOURCE}.revlog WHERE cid = ? ORDER BY id"))
+                .context("准备SQL失败")?;
+            let rows = stmt.query_map([source_cid], |row| row.get(0)).context("查询SQL失败")?;
+            rows.collect::<Result<_, _>>().context("读取revlog失败")?
+        };
+        for source_id in revlog_ids {
+            let id = free_id(tx, "revlog", source_id, next_revlog_id)?;
+            tx.prepare_cached(&format!(
+                "INSERT INTO revlog (id, cid, usn, ease, ivl, lastIvl, factor, time, type) \
+                 SELECT ?, ?, -1, ease, ivl, lastIvl, factor, time, type FROM {SOURCE}.revlog WHERE id = ?"
+            ))
+            .context("准备SQL失败")?
+            .execute(params![id, cid, source_id])
+            .context("添加revlog失败")?;
+        }
+        summary.added_card_ids.push(cid);
+    }
+    Ok(())
+}
+
+fn import_notes(
+    tx: &Transaction,
+    deck_key: &str,
+    notetypes: &HashMap<i64, i64>,
+    decks: &DeckMapping,
+    renamed_media: &HashMap<String, String>,
+    remove_missing: bool,
+    summary: &mut MergeSummary,
+) -> Result<(), AnkiError> {
+    let now = Utc::now().timestamp();
+    let existing: HashMap<String, (i64, i64, String, String)> = {
+        let mut stmt = tx
+            .prepare(&format!("SELECT guid, id, mid, flds, tags FROM notes WHERE id IN (SELECT nid FROM cards WHERE {IN_DECK})"))
+            .context("准备SQL失败")?;
+        let rows = stmt
+            .query_map([deck_key], |row| Ok((row.get(0)?, (row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?))))
+            .context("查询SQL失败")?;
+        rows.collect::<Result<_, _>>().context("读取note失败")?
+    };
+    let (mut next_note_id, mut next_card_id, mut next_revlog_id) = (0, 0, 0);
+    let source_notes = load_source_notes(tx, decks.only_deck)?;
+    for note in &source_notes {
+        let Some(mid) = notetypes.get(&note.mid).copied() else {
+            summary.skipped_notes += 1;
+            continue;
+        };
+        let flds = rewrite_media_references(&note.flds, renamed_media);
+        match existing.get(&note.guid) {
+            // 笔记类型的结构变了，已有卡片的模板对不上，不做修改
+            Some((_, existing_mid, _, _)) if *existing_mid != mid => summary.skipped_notes += 1,
+            Some((id, _, old_flds, old_tags)) if *old_flds != flds || *old_tags != note.tags => {
+                tx.prepare_cached("UPDATE notes SET flds = ?, sfld = ?, csum = ?, tags = ?, mod = ?, usn = -1 WHERE id = ?")
+                    .context("准备SQL失败")?
+                    .execute(params![flds, note.sfld, note.csum, note.tags, now, id])
+                    .context("更新note失败")?;
+                summary.updated_notes += 1;
+            }
+            Some(_) => summary.unchanged_notes += 1,
+            None => {
+                let nid = free_id(tx, "notes", note.id, &mut next_note_id)?;
+                tx.prepare_cached("INSERT INTO notes (id, guid, mid, mod, usn, tags, flds, sfld, csum, flags, data) VALUES (?, ?, ?, ?, -1, ?, ?, ?, ?, ?, ?)")
+                    .context("准备SQL失败")?
+                    .execute(params![nid, note.guid, mid, now, note.tags, flds, note.sfld, note.csum, note.flags, note.data])
+                    .context("添加note失败")?;
+                copy_cards(tx, note.id, nid, decks, &mut next_card_id, &mut next_revlog_id, summary)?;
+                summary.added_note_ids.push(nid);
+                summary.added_notes += 1;
+            }
+        }
+    }
+    if remove_missing {
+        // 复习记录和 Anki 一样保留
+        let source_guids: std::collections::HashSet<&str> = source_notes.iter().map(|n| n.guid.as_str()).collect();
+        for (guid, (id, _, _, _)) in &existing {
+            if source_guids.contains(guid.as_str()) {
+                continue;
+            }
+            let mut stmt = tx.prepare_cached("SELECT id FROM cards WHERE nid = ?").context("准备SQL失败")?;
+            let rows = stmt.query_map([id], |row| row.get(0)).context("查询SQL失败")?;
+            let card_ids: Vec<i64> = rows.collect::<Result<_, _>>().context("读取card失败")?;
+            tx.execute("DELETE FROM cards WHERE nid = ?", [id]).context("删除card失败")?;
+            tx.execute("DELETE FROM notes WHERE id = ?", [id]).context("删除note失败")?;
+            summary.removed_card_ids.extend(card_ids);
+            summary.removed_note_ids.push(*id);
+            summary.removed_notes += 1;
+        }
+    }
+    Ok(())
+}
+
+fn import_attached(
+    conn: &mut Connection,
+    media: &[MediaFile],
+    target_media_dir: &Path,
+    deck_key: &str,
+    remove_missing: bool,
+    filter: Option<&DeckFilter>,
+) -> Result<(MergeSummary, StagedMedia), AnkiError> {
+    if !has_table(conn, SOURCE, "notetypes") {
+        return Err(AnkiError::new(ErrorKind::InvalidPackage, "导入的集合不是 anki21b 格式"));
+    }
+    let mut summary = MergeSummary::default();
+    let tx = conn.transaction().context("开启事务失败")?;
+    let returned = return_filtered_cards(&tx, SOURCE)?;
+    if returned > 0 {
+        rust_log(&format!("DEBUG: {} 张卡片从筛选牌组放回了原牌组", returned));
+    }
+    let media = import_media(&tx, media, target_media_dir)?;
+    if !media.renamed.is_empty() {
+        rust_log(&format!("DEBUG: {} 个媒体文件和已有文件重名，已改名", media.renamed.len()));
+    }
+    let notetypes = import_notetypes(&tx, &mut summary)?;
+    let decks = import_decks(&tx, deck_key, filter)?;
+    import_notes(&tx, deck_key, &notetypes, &decks, &media.renamed, remove_missing, &mut summary)?;
+    let generated = generate_deck_cloze_cards(&tx, deck_key)?;
+    if !generated.is_empty() {
+        rust_log(&format!("DEBUG: 为填空题补齐了 {} 张卡片", generated.len()));
+    }
+    summary.added_card_ids.extend(generated);
+    tx.commit().context("提交事务失败")?;
+    Ok((summary, media))
+}
+
+/// 把 source_path 的集合和 media 里的媒体文件导入集合库，归到 deck_key 这个牌组下。
+/// 媒体文件只在 media 表里登记，返回的 StagedMedia 由调用方在交还写连接后移到 target_media_dir。
+/// filter 不为空时只导入源集合里的一个牌组
+pub fn import_collection(
+    conn: &mut Connection,
+    source_path: &Path,
+    media: &[MediaFile],
+    target_media_dir: &Path,
+    deck_key: &str,
+    remove_missing: bool,
+    filter: Option<&DeckFilter>,
+) -> Result<(MergeSummary, StagedMedia), AnkiError> {
+    conn.execute(&format!("ATTACH DATABASE ? AS {SOURCE}"), [source_path.to_string_lossy()])
+        .context("打开导入的集合失败")?;
+    let result = import_attached(conn, media, target_media_dir, deck_key, remove_missing, filter);
+    conn.execute(&format!("DETACH DATABASE {SOURCE}"), [])
+        .inspect_err(|e| rust_log(&format!("DEBUG: DETACH 失败: {}", e)))
+        .ok();
+    let (summary, staged) = result?;
+    rust_log(&format!(
+        "DEBUG: 导入完成: 新增笔记 {}, 更新 {}, 未变 {}, 删除 {}, 跳过 {}, 新增笔记类型 {}, 更新笔记类型 {}",
+        summary.added_notes, summary.updated_notes, summary.unchanged_notes, summary.removed_notes, summary.skipped_notes,
+        summary.added_notetypes, summary.updated_notetypes
+    ));
+    Ok((summary, staged))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use crate::apkg::TempDir;
+    use super::super::{create_tables, memory_collection};
+    use super::*;
+
+    const DAY: i64 = SECONDS_PER_DAY;
+
+    // 一个待导入的 anki21b 集合，放在临时目录里，导入时 ATTACH。字段按顺序释放，先关连接再删目录
+    struct Source {
+        conn: Connection,
+        path: PathBuf,
+        _dir: TempDir,
+    }
+
+    fn source(crt: i64) -> Source {
+        let dir = TempDir::new(&std::env::temp_dir()).unwrap();
+        let path = dir.path.join("collection.anki21b");
+        let conn = Connection::open(&path).unwrap();
+        create_tables(&conn).unwrap();
+        conn.execute("UPDATE col SET crt = ?", [crt]).unwrap();
+        conn.execute("INSERT INTO deck_config (id, name, mtime_secs, usn, config) VALUES (1, 'Default', 0, 0, x'')", []).unwrap();
+        Source { conn, path, _dir: dir }
+    }
+
+    impl Source {
+        fn notetype(&self, id: i64, name: &str, fields: &[&str], mtime: i64) {
+            self.conn
+                .execute("INSERT INTO notetypes (id, name, mtime_secs, usn, config) VALUES (?, ?, ?, 0, x'')", params![id, name, mtime])
+                .unwrap();
+            for (ord, field) in fields.iter().enumerate() {
+                self.conn.execute("INSERT INTO fields VALUES (?, ?, ?, x'')", params![id, ord as i64, field]).unwrap();
+            }
+            self.conn.execute("INSERT INTO templates VALUES (?, 0, 'Card 1', 0, 0, x'')", [id]).unwrap();
+        }
+
+        fn deck(&self, id: i64, name: &str) {
+            let kind = DeckKindContainer { normal: Some(DeckNormal { config_id: DEFAULT_DECK_CONFIG_ID, ..Default::default() }) };
+            self.conn
+                .execute(
+                    "INSERT INTO decks (id, name, mtime_secs, usn, common, kind) VALUES (?, ?, 0, 0, x'', ?)",
+                    params![id, name, kind.encode_to_vec()],
+                )
+                .unwrap();
+        }
+
+        // 一条笔记和它的一张新卡片
+        fn note(&self, id: i64, guid: &str, mid: i64, flds: &str, did: i64, card_id: i64) {
+            self.conn
+                .execute(
+                    "INSERT INTO notes (id, guid, mid, mod, usn, tags, flds, sfld, csum, flags, data) VALUES (?, ?, ?, 0, 0, '', ?, '', 0, 0, '')",
+                    params![id, guid, mid, flds],
+                )
+                .unwrap();
+            self.conn
+                .execute(
+                    "INSERT INTO cards (id, nid, did, ord, mod, usn, type, queue, due, ivl, factor, reps, lapses, left, odue, odid, flags, data) \
+                     VALUES (?, ?, ?, 0, 0, 0, 0, 0, ?, 0, 0, 0, 0, 0, 0, 0, 0, '')",
+                    params![card_id, id, did, card_id],
+                )
+                .unwrap();
+        }
+
+        // 把卡片改成第 due 天到期的复习卡，并加一条复习记录
+        fn review(&self, card_id: i64, due: i64, revlog_id: i64) {
+            self.conn
+                .execute("UPDATE cards SET type = 2, queue = 2, due = ?, ivl = 5, factor = 2500, reps = 1 WHERE id = ?", [due, card_id])
+                .unwrap();
+            self.conn
+                .execute("INSERT INTO revlog VALUES (?, ?, 0, 3, 5, 0, 2500, 1000, 0)", [revlog_id, card_id])
+                .unwrap();
+        }
+    }
+
+    fn import(conn: &mut Connection, source: &Source, deck_key: &str, remove_missing: bool) -> MergeSummary {
+        let media_dir = source.path.with_file_name("media");
+        let (summary, staged) = import_collection(conn, &source.path, &[], &media_dir, deck_key, remove_missing, None).unwrap();
+        assert!(staged.move_files().is_empty());
+        summary
+    }
+
+    fn query<T: rusqlite::types::FromSql>(conn: &Connection, sql: &str, id: i64) -> T {
+        conn.query_row(sql, [id], |row| row.get(0)).unwrap()
+    }
+
+    fn count(conn: &Connection, table: &str) -> i64 {
+        conn.query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| row.get(0)).unwrap()
+    }
+
+    fn basic_source(crt: i64) -> Source {
+        let source = source(crt);
+        source.notetype(10, "Basic", &["Front", "Back"], 100);
+        source.deck(2, "Deck");
+        source.note(100, "guid-a", 10, "a\x1fA", 2, 1000);
+        source.review(1000, 20, 5000);
+        source
+    }
+
+    #[test]
+    fn second_import_remaps_colliding_ids() {
+        let mut conn = memory_collection();
+        let crt: i64 = conn.query_row("SELECT crt FROM col", [], |row| row.get(0)).unwrap();
+        let first = basic_source(crt);
+        let summary = import(&mut conn, &first, "a", false);
+        assert_eq!((summary.added_notes, summary.added_notetypes), (1, 1));
+        assert_eq!(summary.added_card_ids, vec![1000]);
+
+        // 第二个包的 id 和第一个完全一样，笔记类型同名但字段不同，牌组同名，集合晚两天创建
+        let second = source(crt + 2 * DAY);
+        second.notetype(10, "Basic", &["Question", "Answer", "Extra"], 100);
+        second.deck(2, "Deck");
+        second.note(100, "guid-b", 10, "b\x1fB\x1f", 2, 1000);
+        second.review(1000, 20, 5000);
+        let summary = import(&mut conn, &second, "b", false);
+        assert_eq!((summary.added_notes, summary.added_notetypes), (1, 1));
+        let nid = summary.added_note_ids[0];
+        let cid = summary.added_card_ids[0];
+        assert_ne!(nid, 100);
+        assert_ne!(cid, 1000);
+
+        // 结构不同的笔记类型另存为“名称 (源id)”
+        let mid: i64 = query(&conn, "SELECT mid FROM notes WHERE id = ?", nid);
+        assert_ne!(mid, 10);
+        assert_eq!(query::<String>(&conn, "SELECT name FROM notetypes WHERE id = ?", mid), "Basic (10)");
+        assert_eq!(query::<i64>(&conn, "SELECT COUNT(*) FROM fields WHERE ntid = ?", mid), 3);
+        assert_eq!(query::<String>(&conn, "SELECT name FROM notetypes WHERE id = ?", 10), "Basic");
+        // 同名牌组被 a 占用，b 的牌组加序号
+        let did: i64 = query(&conn, "SELECT did FROM cards WHERE id = ?", cid);
+        assert_ne!(did, 2);
+        assert_eq!(query::<String>(&conn, "SELECT name FROM decks WHERE id = ?", did), "Deck (2)");
+        assert_eq!(query::<String>(&conn, "SELECT deck_key FROM open_anki_decks WHERE did = ?", did), "b");
+        // 复习卡的 due 按两个集合 crt 的差顺延，冲突的复习记录换了 id
+        assert_eq!(query::<i64>(&conn, "SELECT due FROM cards WHERE id = ?", 1000), 20);
+        assert_eq!(query::<i64>(&conn, "SELECT due FROM cards WHERE id = ?", cid), 22);
+        assert_eq!(query::<i64>(&conn, "SELECT COUNT(*) FROM revlog WHERE cid = ?", 1000), 1);
+        let revlog_id: i64 = query(&conn, "SELECT id FROM revlog WHERE cid = ?", cid);
+        assert_ne!(revlog_id, 5000);
+        assert_eq!(count(&conn, "revlog"), 2);
+
+        // 再导入一次第二个包：按副本的名字找回笔记类型，笔记没有变化
+        let summary = import(&mut conn, &second, "b", false);
+        assert_eq!((summary.added_notes, summary.unchanged_notes, summary.added_notetypes), (0, 1, 0));
+        assert_eq!(count(&conn, "notetypes"), 2);
+        assert_eq!(count(&conn, "decks"), 2);
+    }
+
+    #[test]
+    fn shared_notetype_is_updated_when_newer() {
+        let mut conn = memory_collection();
+        let crt: i64 = conn.query_row("SELECT crt FROM col", [], |row| row.get(0)).unwrap();
+        let first = basic_source(crt);
+        import(&mut conn, &first, "a", false);
+        // 结构相同、更新时间更晚的同 id 笔记类型和已有的共用，并替换成新版本
+        let second = source(crt);
+        second.notetype(10, "Basic", &["Front", "Back"], 200);
+        second.deck(3, "Other");
+        second.note(200, "guid-c", 10, "c\x1fC", 3, 2000);
+        let summary = import(&mut conn, &second, "b", false);
+        assert_eq!((summary.added_notetypes, summary.updated_notetypes, summary.added_notes), (0, 1, 1));
+        assert_eq!(query::<i64>(&conn, "SELECT mid FROM notes WHERE id = ?", 200), 10);
+        assert_eq!(query::<i64>(&conn, "SELECT mtime_secs FROM notetypes WHERE id = ?", 10), 200);
+        assert_eq!(query::<i64>(&conn, "SELECT did FROM cards WHERE id = ?", 2000), 3);
+    }
+
+    // 导入 a，再把卡片学成复习卡，模拟用户学过之后再更新牌组
+    fn studied_collection() -> (Connection, Source) {
+        let mut conn = memory_collection();
+        let crt: i64 = conn.query_row("SELECT crt FROM col", [], |row| row.get(0)).unwrap();
+        let first = basic_source(crt);
+        first.note(101, "guid-b", 10, "b\x1fB", 2, 1001);
+        first.note(102, "guid-c", 10, "c\x1fC", 2, 1002);
+        import(&mut conn, &first, "a", false);
+        conn.execute(
+            "UPDATE cards SET type = 2, queue = 2, due = 30, ivl = 12, factor = 2650, reps = 4, lapses = 1, data = '{\"s\":12.0}' WHERE id = 1001",
+            [],
+        )
+        .unwrap();
+        (conn, first)
+    }
+
+    // 新版本：a 的内容改了，b 不变，c 删掉了，多了 d
+    fn updated_source(first: &Source) -> Source {
+        let crt: i64 = first.conn.query_row("SELECT crt FROM col", [], |row| row.get(0)).unwrap();
+        let second = source(crt);
+        second.notetype(10, "Basic", &["Front", "Back"], 100);
+        second.deck(2, "Deck");
+        second.note(100, "guid-a", 10, "a\x1fA changed", 2, 1000);
+        second.note(101, "guid-b", 10, "b\x1fB", 2, 1001);
+        second.note(103, "guid-d", 10, "d\x1fD", 2, 1003);
+        second
+    }
+
+    #[test]
+    fn reimport_updates_fields_and_keeps_scheduling() {
+        let (mut conn, first) = studied_collection();
+        let summary = import(&mut conn, &updated_source(&first), "a", false);
+        assert_eq!((summary.added_notes, summary.updated_notes, summary.unchanged_notes, summary.removed_notes), (1, 1, 1, 0));
+        assert_eq!(summary.added_note_ids, vec![103]);
+        assert_eq!(summary.added_card_ids, vec![1003]);
+        assert_eq!(query::<String>(&conn, "SELECT flds FROM notes WHERE id = ?", 100), "a\x1fA changed");
+        // 学过的卡片的调度列和 data 不变，包里的新卡状态不会覆盖它
+        let sched: (i64, i64, i64, i64, i64, i64, i64, String) = conn
+            .query_row("SELECT type, queue, due, ivl, factor, reps, lapses, data FROM cards WHERE id = 1001", [], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?, row.get(5)?, row.get(6)?, row.get(7)?))
+            })
+            .unwrap();
+        assert_eq!(sched, (2, 2, 30, 12, 2650, 4, 1, r#"{"s":12.0}"#.to_string()));
+        // 复习记录没有重复导入
+        assert_eq!(count(&conn, "revlog"), 1);
+        // 没有 remove_missing 时保留新版本里没有的笔记
+        assert_eq!(query::<i64>(&conn, "SELECT COUNT(*) FROM cards WHERE nid = ?", 102), 1);
+        assert_eq!(count(&conn, "notes"), 4);
+    }
+
+    #[test]
+    fn reimport_removes_missing_notes_only_when_asked() {
+        let (mut conn, first) = studied_collection();
+        let summary = import(&mut conn, &updated_source(&first), "a", true);
+        assert_eq!(summary.removed_notes, 1);
+        assert_eq!(summary.removed_note_ids, vec![102]);
+        assert_eq!(summary.removed_card_ids, vec![1002]);
+        assert_eq!(query::<i64>(&conn, "SELECT COUNT(*) FROM notes WHERE id = ?", 102), 0);
+        assert_eq!(query::<i64>(&conn, "SELECT COUNT(*) FROM cards WHERE nid = ?", 102), 0);
+        assert_eq!(count(&conn, "notes"), 3);
+        assert_eq!(query::<i64>(&conn, "SELECT reps FROM cards WHERE id = ?", 1001), 4);
+    }
+
+    #[test]
+    fn remove_missing_only_touches_the_same_deck_key() {
+        let (mut conn, first) = studied_collection();
+        // 另一个 deck key 里的笔记不在这次导入的范围内
+        let crt: i64 = conn.query_row("SELECT crt FROM col", [], |row| row.get(0)).unwrap();
+        let other = source(crt);
+        other.notetype(10, "Basic", &["Front", "Back"], 100);
+        other.deck(5, "Other");
+        other.note(300, "guid-x", 10, "x\x1fX", 5, 3000);
+        import(&mut conn, &other, "b", false);
+        let summary = import(&mut conn, &updated_source(&first), "a", true);
+        assert_eq!(summary.removed_note_ids, vec![102]);
+        assert_eq!(query::<i64>(&conn, "SELECT COUNT(*) FROM notes WHERE id = ?", 300), 1);
+    }
+}
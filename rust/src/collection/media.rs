@@ -0,0 +1,191 @@
+// 集合库的媒体文件都放在同一个目录，media 表记录每个文件的校验和。
+// 不同牌组里同名但内容不同的文件导入时改名（文件名后加校验和前缀），并同步改写笔记字段里的引用，和 Anki 的做法一致。
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use crate::api::simple::rust_log;
+use crate::error::{AnkiError, ErrorKind, ResultExt};
+
+const CHECKSUM_BUFFER_SIZE: usize = 64 * 1024;
+// 改名时取校验和的前 8 位
+const RENAME_SUFFIX_LEN: usize = 8;
+
+lazy_static! {
+    pub static ref MEDIA_SRC: Regex =
+        Regex::new(r#"(?i)<(?:img|audio|video|source|embed)\b[^>]*?\bsrc\s*=\s*(?:"([^"]+)"|'([^']+)'|([^\s>]+))"#).unwrap();
+    pub static ref MEDIA_SOUND: Regex = Regex::new(r"\[sound:(.+?)\]").unwrap();
+}
+
+pub fn unescape_media_name(name: &str) -> String {
+    name.replace("&lt;", "<").replace("&gt;", ">").replace("&quot;", "\"").replace("&#39;", "'").replace("&amp;", "&")
+}
+
+fn escape_media_name(name: &str) -> String {
+    name.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;").replace('\'', "&#39;")
+}
+
+fn file_checksum(path: &Path) -> Result<String, AnkiError> {
+    let mut file = fs::File::open(path).path_context("打开媒体文件失败", path)?;
+    let mut context = md5::Context::new();
+    let mut buf = vec![0u8; CHECKSUM_BUFFER_SIZE];
+    loop {
+        let n = file.read(&mut buf).path_context("读取媒体文件失败", path)?;
+        if n == 0 {
+            break;
+        }
+        context.consume(&buf[..n]);
+    }
+    Ok(format!("{:x}", context.compute()))
+}
+
+// a.png -> a-1a2b3c4d.png，没有扩展名时直接加在末尾
+fn with_suffix(name: &str, suffix: &str) -> String {
+    match name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() && !ext.contains('/') => format!("{stem}-{suffix}.{ext}"),
+        _ => format!("{name}-{suffix}"),
+    }
+}
+
+// 媒体目录下的所有文件，返回相对路径（用 / 分隔）和完整路径
+fn list_files(dir: &Path, prefix: &str, files: &mut Vec<(String, PathBuf)>) -> Result<(), AnkiError> {
+    for entry in fs::read_dir(dir).context("读取媒体目录失败")? {
+        let entry = entry.context("读取媒体目录失败")?;
+        let name = format!("{prefix}{}", entry.file_name().to_string_lossy());
+        if entry.path().is_dir() {
+            list_files(&entry.path(), &format!("{name}/"), files)?;
+        } else {
+            files.push((name, entry.path()));
+        }
+    }
+    Ok(())
+}
+
+fn move_file(from: &Path, to: &Path) -> Result<(), AnkiError> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent).path_context("创建父目录失败", parent)?;
+    }
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    fs::copy(from, to).map(|_| ()).path_context("复制媒体文件失败", to)
+}
+
+/// 待导入的媒体文件，校验和在拿到集合库的写连接之前算好
+#[derive(Clone)]
+pub struct MediaFile {
+    pub name: String, // 相对媒体目录的文件名
+    path: PathBuf,
+    checksum: String,
+    size: i64,
+}
+
+/// 列出 dir 下的媒体文件并计算校验和，目录不存在时为空
+pub fn scan_media(dir: &Path) -> Result<Vec<MediaFile>, AnkiError> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut files = Vec::new();
+    list_files(dir, "", &mut files)?;
+    files
+        .into_iter()
+        .map(|(name, path)| {
+            let checksum = file_checksum(&path)?;
+            let size = fs::metadata(&path).map(|m| m.len() as i64).unwrap_or(0);
+            Ok(MediaFile { name, path, checksum, size })
+        })
+        .collect()
+}
+
+/// 已登记到 media 表、还没移到媒体目录的文件。事务提交后再调用 move_files，回滚时文件留在原处
+pub struct StagedMedia {
+    moves: Vec<(PathBuf, PathBuf, String)>, // 源文件、目标文件、登记的文件名
+    pub renamed: HashMap<String, String>,    // 因为重名而改了名的文件（原文件名 -> 新文件名）
+}
+
+impl StagedMedia {
+    /// 把文件移到媒体目录，不需要数据库连接。返回移动失败的文件名，要用 forget_media 从 media 表删掉
+    pub fn move_files(self) -> Vec<String> {
+        let mut failed = Vec::new();
+        for (from, to, fname) in self.moves {
+            if let Err(e) = move_file(&from, &to) {
+                rust_log(&format!("DEBUG: 移动媒体文件失败: {}", e));
+                failed.push(fname);
+            }
+        }
+        failed
+    }
+}
+
+/// 删掉没能移到媒体目录的文件的记录，下次导入时可以重新登记
+pub fn forget_media(conn: &Connection, fnames: &[String]) -> Result<(), AnkiError> {
+    for fname in fnames {
+        conn.execute("DELETE FROM media WHERE fname = ?", [fname]).context("删除media失败")?;
+    }
+    Ok(())
+}
+
+/// 为媒体文件在集合的媒体目录 to 里选好文件名并登记到 media 表，文件本身等事务提交后再移动
+pub fn import_media(tx: &Transaction, files: &[MediaFile], to: &Path) -> Result<StagedMedia, AnkiError> {
+    let mut staged = StagedMedia { moves: Vec::new(), renamed: HashMap::new() };
+    for file in files {
+        let name = &file.name;
+        let checksum = &file.checksum;
+        let candidates = [name.clone(), with_suffix(name, &checksum[..RENAME_SUFFIX_LEN]), with_suffix(name, checksum)];
+        let mut target = None;
+        for candidate in candidates {
+            let existing: Option<String> = tx
+                .query_row("SELECT checksum FROM media WHERE fname = ?", [&candidate], |row| row.get(0))
+                .optional()
+                .context("查询media失败")?;
+            match existing {
+                // 内容相同的文件已经有了，直接共用
+                Some(existing) if existing == *checksum => {}
+                Some(_) => continue,
+                None => {
+                    tx.execute(
+                        "INSERT INTO media (fname, checksum, size) VALUES (?, ?, ?)",
+                        params![candidate, checksum, file.size],
+                    )
+                    .context("写入media失败")?;
+                    staged.moves.push((file.path.clone(), to.join(&candidate), candidate.clone()));
+                }
+            }
+            target = Some(candidate);
+            break;
+        }
+        let target = target.ok_or_else(|| AnkiError::new(ErrorKind::Io, "媒体文件重名且无法改名").with_entry(name))?;
+        if target != *name {
+            staged.renamed.insert(name.clone(), target);
+        }
+    }
+    Ok(staged)
+}
+
+// 把一处引用里的文件名换掉，原来是 HTML 转义过的就同样转义
+fn replace_reference(caps: &Captures, renamed: &HashMap<String, String>) -> String {
+    let whole = caps.get(0).unwrap();
+    let Some(group) = (1..caps.len()).find_map(|i| caps.get(i)) else {
+        return whole.as_str().to_string();
+    };
+    let name = unescape_media_name(group.as_str());
+    let Some(new_name) = renamed.get(&name) else {
+        return whole.as_str().to_string();
+    };
+    let new_name = if name != group.as_str() { escape_media_name(new_name) } else { new_name.clone() };
+    let text = whole.as_str();
+    format!("{}{}{}", &text[..group.start() - whole.start()], new_name, &text[group.end() - whole.start()..])
+}
+
+/// 改写笔记字段里对改了名的媒体文件的引用
+pub fn rewrite_media_references(flds: &str, renamed: &HashMap<String, String>) -> String {
+    if renamed.is_empty() {
+        return flds.to_string();
+    }
+    let flds = MEDIA_SRC.replace_all(flds, |caps: &Captures| replace_reference(caps, renamed));
+    MEDIA_SOUND.replace_all(&flds, |caps: &Captures| replace_reference(caps, renamed)).into_owned()
+}
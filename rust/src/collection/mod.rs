@@ -0,0 +1,299 @@
+// 统一的集合库：所有导入的牌组都放在 anki_data/collection.sqlite 一个 sqlite 里，表结构和 Anki 的 anki21b 集合一致，
+// 媒体文件放在 anki_data/collection.media。应用里的一个牌组（deck key，导入时的 md5）对应集合里的一组 Anki 牌组，
+// 对应关系记在 open_anki_decks 表，按牌组查询时用 IN_DECK 条件过滤卡片。
+// 以前每个牌组一个 anki_data/<md5>/collection.sqlite，初始化时导入集合库后删除。
+
+pub mod card_state;
+pub mod cloze_cards;
+pub mod connection;
+pub mod import;
+pub mod media;
+pub mod upgrade;
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use chrono::Utc;
+use lazy_static::lazy_static;
+use rusqlite::{params, Connection, OptionalExtension};
+use crate::anki_proto::decode_deck_kind;
+use crate::api::simple::rust_log;
+use crate::apkg::{MEDIA_DIR, SQLITE_FILE};
+use crate::scheduler::{bury, config};
+use crate::scheduler::steps::SECONDS_PER_DAY;
+use crate::error::{AnkiError, ErrorKind, ResultExt};
+use self::card_state::QUEUE_SUSPENDED;
+use self::import::import_collection;
+use self::media::{forget_media, scan_media};
+use self::upgrade::upgrade_legacy;
+
+pub const COLLECTION_FILE: &str = "collection.sqlite";
+pub const COLLECTION_MEDIA_DIR: &str = "collection.media";
+// 和 Anki 2.1.50 之后的集合一致
+const SCHEMA_VERSION: i64 = 18;
+
+/// anki21b 集合里存放笔记类型、牌组、牌组选项和集合设置的表
+pub const ANKI21B_TABLES_SQL: &str = "
+CREATE TABLE IF NOT EXISTS notetypes (id INTEGER PRIMARY KEY NOT NULL, name TEXT NOT NULL, mtime_secs INTEGER NOT NULL, usn INTEGER NOT NULL, config BLOB NOT NULL);
+CREATE TABLE IF NOT EXISTS fields (ntid INTEGER NOT NULL, ord INTEGER NOT NULL, name TEXT NOT NULL, config BLOB NOT NULL, PRIMARY KEY (ntid, ord)) WITHOUT ROWID;
+CREATE TABLE IF NOT EXISTS templates (ntid INTEGER NOT NULL, ord INTEGER NOT NULL, name TEXT NOT NULL, mtime_secs INTEGER NOT NULL, usn INTEGER NOT NULL, config BLOB NOT NULL, PRIMARY KEY (ntid, ord)) WITHOUT ROWID;
+CREATE TABLE IF NOT EXISTS decks (id INTEGER PRIMARY KEY NOT NULL, name TEXT NOT NULL, mtime_secs INTEGER NOT NULL, usn INTEGER NOT NULL, common BLOB NOT NULL, kind BLOB NOT NULL);
+CREATE TABLE IF NOT EXISTS deck_config (id INTEGER PRIMARY KEY NOT NULL, name TEXT NOT NULL, mtime_secs INTEGER NOT NULL, usn INTEGER NOT NULL, config BLOB NOT NULL);
+CREATE TABLE IF NOT EXISTS config (KEY TEXT NOT NULL PRIMARY KEY, usn INTEGER NOT NULL, mtime_secs INTEGER NOT NULL, val BLOB NOT NULL) WITHOUT ROWID;
+";
+
+// 笔记、卡片和复习记录的列和 Anki 相同；media 记录媒体目录里每个文件的校验和，open_anki_* 是应用自己的表
+const COLLECTION_TABLES_SQL: &str = "
+CREATE TABLE IF NOT EXISTS col (id INTEGER PRIMARY KEY, crt INTEGER NOT NULL, mod INTEGER NOT NULL, scm INTEGER NOT NULL, ver INTEGER NOT NULL, dty INTEGER NOT NULL, usn INTEGER NOT NULL, ls INTEGER NOT NULL, conf TEXT NOT NULL, models TEXT NOT NULL, decks TEXT NOT NULL, dconf TEXT NOT NULL, tags TEXT NOT NULL);
+CREATE TABLE IF NOT EXISTS notes (id INTEGER PRIMARY KEY, guid TEXT NOT NULL, mid INTEGER NOT NULL, mod INTEGER NOT NULL, usn INTEGER NOT NULL, tags TEXT NOT NULL, flds TEXT NOT NULL, sfld INTEGER NOT NULL, csum INTEGER NOT NULL, flags INTEGER NOT NULL, data TEXT NOT NULL);
+CREATE TABLE IF NOT EXISTS cards (id INTEGER PRIMARY KEY, nid INTEGER NOT NULL, did INTEGER NOT NULL, ord INTEGER NOT NULL, mod INTEGER NOT NULL, usn INTEGER NOT NULL, type INTEGER NOT NULL, queue INTEGER NOT NULL, due INTEGER NOT NULL, ivl INTEGER NOT NULL, factor INTEGER NOT NULL, reps INTEGER NOT NULL, lapses INTEGER NOT NULL, left INTEGER NOT NULL, odue INTEGER NOT NULL, odid INTEGER NOT NULL, flags INTEGER NOT NULL, data TEXT NOT NULL);
+CREATE TABLE IF NOT EXISTS revlog (id INTEGER PRIMARY KEY, cid INTEGER NOT NULL, usn INTEGER NOT NULL, ease INTEGER NOT NULL, ivl INTEGER NOT NULL, lastIvl INTEGER NOT NULL, factor INTEGER NOT NULL, time INTEGER NOT NULL, type INTEGER NOT NULL);
+CREATE TABLE IF NOT EXISTS media (fname TEXT NOT NULL PRIMARY KEY, checksum TEXT NOT NULL, size INTEGER NOT NULL) WITHOUT ROWID;
+CREATE TABLE IF NOT EXISTS open_anki_decks (deck_key TEXT NOT NULL, did INTEGER NOT NULL, PRIMARY KEY (deck_key, did)) WITHOUT ROWID;
+CREATE TABLE IF NOT EXISTS open_anki_config (key TEXT PRIMARY KEY, value TEXT NOT NULL);
+CREATE INDEX IF NOT EXISTS ix_notes_guid ON notes (guid);
+CREATE INDEX IF NOT EXISTS ix_cards_nid ON cards (nid);
+CREATE INDEX IF NOT EXISTS ix_cards_sched ON cards (did, queue, due);
+CREATE INDEX IF NOT EXISTS ix_revlog_cid ON revlog (cid);
+CREATE UNIQUE INDEX IF NOT EXISTS ix_decks_name ON decks (name);
+CREATE UNIQUE INDEX IF NOT EXISTS ix_open_anki_decks_did ON open_anki_decks (did);
+";
+
+/// 卡片属于某个 deck key 的条件，参数是 deck key
+pub const IN_DECK: &str = "did IN (SELECT did FROM open_anki_decks WHERE deck_key = ?)";
+
+lazy_static! {
+    static ref COLLECTION_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+}
+
+pub fn has_table(conn: &Connection, schema: &str, table: &str) -> bool {
+    conn.query_row(&format!("SELECT 1 FROM {schema}.sqlite_master WHERE type = 'table' AND name = ?"), [table], |_| Ok(()))
+        .optional()
+        .map(|r| r.is_some())
+        .unwrap_or(false)
+}
+
+/// 集合所在的目录（anki_data），导入时的临时目录也建在这里
+pub fn base_dir() -> Result<PathBuf, AnkiError> {
+    COLLECTION_DIR
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .ok_or_else(|| AnkiError::new(ErrorKind::NotInitialized, "集合还没有初始化，请先调用 init_collection"))
+}
+
+pub fn media_dir() -> Result<PathBuf, AnkiError> {
+    Ok(base_dir()?.join(COLLECTION_MEDIA_DIR))
+}
+
+/// 用集合库的写连接执行 f
+pub fn with_collection<T>(f: impl FnOnce(&mut Connection) -> Result<T, AnkiError>) -> Result<T, AnkiError> {
+    connection::with_connection(&base_dir()?.join(COLLECTION_FILE), f)
+}
+
+/// 用集合库的只读连接执行 f，不会等导入、评分这些写入完成
+pub fn with_collection_read<T>(f: impl FnOnce(&Connection) -> Result<T, AnkiError>) -> Result<T, AnkiError> {
+    connection::with_reader(&base_dir()?.join(COLLECTION_FILE), f)
+}
+
+/// 打开集合库的连接，init 之后已经打开，close 之后要重新打开
+pub fn open() -> Result<(), AnkiError> {
+    connection::open(&base_dir()?.join(COLLECTION_FILE))
+}
+
+/// 关闭集合库的连接
+pub fn close() {
+    connection::close_all();
+}
+
+fn create_tables(conn: &Connection) -> Result<(), AnkiError> {
+    conn.execute_batch(ANKI21B_TABLES_SQL).context("创建集合表失败")?;
+    conn.execute_batch(COLLECTION_TABLES_SQL).context("创建集合表失败")?;
+    // 复习卡的 due 是相对 crt 的天数，crt 取集合创建当天的零点
+    let now = Utc::now().timestamp();
+    let crt = now - now.rem_euclid(SECONDS_PER_DAY);
+    conn.execute(
+        "INSERT INTO col (id, crt, mod, scm, ver, dty, usn, ls, conf, models, decks, dconf, tags) \
+         SELECT 1, ?, ?, ?, ?, 0, 0, 0, '', '', '', '', '' WHERE NOT EXISTS (SELECT 1 FROM col)",
+        params![crt, now * 1000, now * 1000, SCHEMA_VERSION],
+    )
+    .context("初始化col失败")?;
+    Ok(())
+}
+
+/// 测试用的内存集合，表和 init 建的一样
+#[cfg(test)]
+pub(crate) fn memory_collection() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    create_tables(&conn).unwrap();
+    conn
+}
+
+/// 测试用：在 deck_key 对应的牌组 did 里加一张新卡片，笔记不存在时一起加上。新卡的位置就是卡片 id
+#[cfg(test)]
+pub(crate) fn add_test_card(conn: &Connection, deck_key: &str, did: i64, note_id: i64, card_id: i64, ord: i64) {
+    conn.execute("INSERT OR IGNORE INTO open_anki_decks (deck_key, did) VALUES (?, ?)", params![deck_key, did]).unwrap();
+    conn.execute(
+        "INSERT OR IGNORE INTO notes (id, guid, mid, mod, usn, tags, flds, sfld, csum, flags, data) \
+         VALUES (?, ?, 1, 0, 0, '', 'front\x1fback', 'front', 0, 0, '')",
+        params![note_id, format!("guid{note_id}")],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO cards (id, nid, did, ord, mod, usn, type, queue, due, ivl, factor, reps, lapses, left, odue, odid, flags, data) \
+         VALUES (?, ?, ?, ?, 0, 0, 0, 0, ?, 0, 0, 0, 0, 0, 0, 0, 0, '')",
+        params![card_id, note_id, did, ord, card_id],
+    )
+    .unwrap();
+}
+
+/// 设置集合目录，建好集合库和媒体目录，并把以前每个牌组一个 sqlite 的目录导入集合库，返回导入的牌组数
+pub fn init(base_dir: &Path) -> Result<u32, AnkiError> {
+    fs::create_dir_all(base_dir.join(COLLECTION_MEDIA_DIR)).context("创建集合目录失败")?;
+    connection::open(&base_dir.join(COLLECTION_FILE))?;
+    connection::with_connection(&base_dir.join(COLLECTION_FILE), |conn| create_tables(conn))?;
+    *COLLECTION_DIR.lock().unwrap_or_else(|e| e.into_inner()) = Some(base_dir.to_path_buf());
+    with_collection(|conn| migrate_deck_dirs(conn, base_dir))
+}
+
+pub fn deck_exists(conn: &Connection, deck_key: &str) -> Result<bool, AnkiError> {
+    conn.prepare_cached("SELECT 1 FROM open_anki_decks WHERE deck_key = ? LIMIT 1")
+        .context("准备SQL失败")?
+        .query_row([deck_key], |_| Ok(()))
+        .optional()
+        .map(|r| r.is_some())
+        .context("查询牌组失败")
+}
+
+/// 牌组里的笔记 id，按 id 排序，卡片全部暂停的笔记不算
+pub fn note_ids(conn: &Connection, deck_key: &str) -> Result<Vec<i64>, AnkiError> {
+    let mut stmt = conn
+        .prepare_cached(&format!("SELECT DISTINCT nid FROM cards WHERE queue != {QUEUE_SUSPENDED} AND {IN_DECK} ORDER BY nid"))
+        .context("准备SQL失败")?;
+    let rows = stmt
+        .query_map([deck_key], |row| row.get(0))
+        .context("查询SQL失败")?;
+    rows.collect::<Result<Vec<_>, _>>().context("读取id失败")
+}
+
+/// 牌组里的笔记数，卡片全部暂停的笔记不算
+pub fn note_count(conn: &Connection, deck_key: &str) -> Result<u32, AnkiError> {
+    conn.prepare_cached(&format!("SELECT COUNT(DISTINCT nid) FROM cards WHERE queue != {QUEUE_SUSPENDED} AND {IN_DECK}"))
+        .context("准备SQL失败")?
+        .query_row([deck_key], |row| row.get(0))
+        .context("统计note失败")
+}
+
+// 没有卡片、没有子牌组、也不属于任何 deck key 的牌组，从最下层开始删
+fn remove_empty_decks(conn: &Connection) -> Result<usize, AnkiError> {
+    let mut removed = 0;
+    loop {
+        let n = conn
+            .execute(
+                "DELETE FROM decks WHERE id NOT IN (SELECT did FROM open_anki_decks) AND id NOT IN (SELECT did FROM cards) \
+                 AND NOT EXISTS (SELECT 1 FROM decks c WHERE substr(c.name, 1, length(decks.name) + 1) = decks.name || char(31))",
+                [],
+            )
+            .context("删除牌组失败")?;
+        if n == 0 {
+            return Ok(removed);
+        }
+        removed += n;
+    }
+}
+
+// 不再被任何牌组使用的牌组选项，默认选项保留
+fn remove_unused_deck_configs(conn: &Connection) -> Result<(), AnkiError> {
+    let mut used = HashSet::from([1i64]);
+    let mut stmt = conn.prepare("SELECT kind FROM decks").context("准备SQL失败")?;
+    let rows = stmt.query_map([], |row| row.get::<_, Vec<u8>>(0)).context("查询SQL失败")?;
+    for kind in rows {
+        let kind = kind.context("读取deck失败")?;
+        if let Some(normal) = decode_deck_kind(&kind)?.normal {
+            used.insert(normal.config_id);
+        }
+    }
+    let ids = used.iter().map(i64::to_string).collect::<Vec<_>>().join(",");
+    conn.execute(&format!("DELETE FROM deck_config WHERE id NOT IN ({ids})"), [])
+        .context("删除deck_config失败")?;
+    Ok(())
+}
+
+/// 删除牌组的卡片、笔记、复习记录和调度配置，以及不再使用的 Anki 牌组、牌组选项和笔记类型。
+/// 媒体文件可能被其他牌组共用，留在媒体目录里
+pub fn remove_deck(conn: &mut Connection, deck_key: &str) -> Result<(), AnkiError> {
+    let tx = conn.transaction().context("开启事务失败")?;
+    tx.execute(&format!("DELETE FROM revlog WHERE cid IN (SELECT id FROM cards WHERE {IN_DECK})"), [deck_key])
+        .context("删除revlog失败")?;
+    let cards = tx
+        .execute(&format!("DELETE FROM cards WHERE {IN_DECK}"), [deck_key])
+        .context("删除card失败")?;
+    let notes = tx
+        .execute("DELETE FROM notes WHERE id NOT IN (SELECT nid FROM cards)", [])
+        .context("删除note失败")?;
+    tx.execute("DELETE FROM open_anki_decks WHERE deck_key = ?", [deck_key])
+        .context("删除牌组失败")?;
+    let decks = remove_empty_decks(&tx)?;
+    remove_unused_deck_configs(&tx)?;
+    tx.execute("DELETE FROM notetypes WHERE id NOT IN (SELECT mid FROM notes)", [])
+        .context("删除notetype失败")?;
+    tx.execute("DELETE FROM fields WHERE ntid NOT IN (SELECT id FROM notetypes)", [])
+        .context("删除field失败")?;
+    tx.execute("DELETE FROM templates WHERE ntid NOT IN (SELECT id FROM notetypes)", [])
+        .context("删除template失败")?;
+    config::remove(&tx, deck_key)?;
+    bury::remove(&tx, deck_key)?;
+    tx.commit().context("提交事务失败")?;
+    rust_log(&format!("DEBUG: 已删除牌组 {}: {} 张卡片, {} 条笔记, {} 个 Anki 牌组", deck_key, cards, notes, decks));
+    Ok(())
+}
+
+fn is_deck_dir_name(name: &str) -> bool {
+    name.len() == 32 && name.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+// 旧的牌组目录：先转换旧格式的表，再连同保存过的调度配置一起导入集合库
+fn migrate_deck_dir(conn: &mut Connection, deck_dir: &Path, deck_key: &str) -> Result<(), AnkiError> {
+    let sqlite_path = deck_dir.join(SQLITE_FILE);
+    let saved = {
+        let mut old = Connection::open(&sqlite_path).context("打开sqlite失败")?;
+        upgrade_legacy(&mut old)?;
+        config::load_deck_dir_config(&old)?
+    };
+    let media = scan_media(&deck_dir.join(MEDIA_DIR))?;
+    let (_, staged) = import_collection(conn, &sqlite_path, &media, &base_dir()?.join(COLLECTION_MEDIA_DIR), deck_key, false, None)?;
+    forget_media(conn, &staged.move_files())?;
+    if let Some(saved) = saved {
+        config::save(conn, deck_key, &saved)?;
+    }
+    Ok(())
+}
+
+fn migrate_deck_dirs(conn: &mut Connection, base_dir: &Path) -> Result<u32, AnkiError> {
+    let mut migrated = 0;
+    for entry in fs::read_dir(base_dir).context("读取集合目录失败")? {
+        let entry = entry.context("读取集合目录失败")?;
+        let deck_key = entry.file_name().to_string_lossy().to_string();
+        let deck_dir = entry.path();
+        if !is_deck_dir_name(&deck_key) || !deck_dir.join(SQLITE_FILE).exists() {
+            continue;
+        }
+        // 上次导入后没来得及删除目录时直接删掉
+        if !deck_exists(conn, &deck_key)? {
+            rust_log(&format!("DEBUG: 导入旧的牌组目录: {}", deck_dir.display()));
+            match migrate_deck_dir(conn, &deck_dir, &deck_key) {
+                Ok(()) => migrated += 1,
+                Err(e) => {
+                    // 保留目录，下次启动再试
+                    rust_log(&format!("DEBUG: 导入旧的牌组目录失败: {} - {}", deck_dir.display(), e));
+                    continue;
+                }
+            }
+        }
+        fs::remove_dir_all(&deck_dir)
+            .inspect_err(|e| rust_log(&format!("DEBUG: 删除旧的牌组目录失败: {} - {}", deck_dir.display(), e)))
+            .ok();
+    }
+    Ok(migrated)
+}
@@ -0,0 +1,240 @@
+// 旧格式（anki2/anki21）的集合把笔记类型、牌组、牌组选项和集合设置存成 col 表里的 JSON，
+// 导入前先在解包出来的副本上把它们转换成 anki21b 的表，后面的导入和渲染就只需要处理一种表结构。
+// JSON 字段和 protobuf 字段的对应关系参照 Anki 源码 rslib/src/notetype/schema11.rs、decks/schema11.rs 和 deckconfig/schema11.rs。
+
+use prost::Message;
+use rusqlite::{params, Connection, Transaction};
+use serde_json::{Map, Value};
+use crate::anki_proto::{
+    CardRequirement, DeckConfigConfig, DeckKindContainer, DeckNormal, FieldConfig, NotetypeConfig, TemplateConfig,
+};
+use crate::api::simple::rust_log;
+use crate::error::{AnkiError, ResultExt};
+use super::{has_table, ANKI21B_TABLES_SQL};
+
+// 旧格式 new.order：0 随机，1 按添加顺序；protobuf 的 NewCardInsertOrder 正好相反
+const LEGACY_NEW_CARDS_RANDOM: i64 = 0;
+const NEW_CARD_INSERT_ORDER_DUE: i32 = 0;
+const NEW_CARD_INSERT_ORDER_RANDOM: i32 = 1;
+
+fn load_json_map(tx: &Transaction, column: &str) -> Result<Map<String, Value>, AnkiError> {
+    let json: String = tx
+        .query_row(&format!("SELECT {column} FROM col LIMIT 1"), [], |row| row.get(0))
+        .context(&format!("未找到col.{column}"))?;
+    serde_json::from_str(&json).context(&format!("解析{column} JSON失败"))
+}
+
+fn str_of(value: &Value, key: &str) -> String {
+    value[key].as_str().unwrap_or("").to_string()
+}
+
+fn i64_of(value: &Value, key: &str) -> i64 {
+    value[key].as_i64().unwrap_or(0)
+}
+
+fn bool_of(value: &Value, key: &str) -> bool {
+    // 很老的集合里布尔值存成 0/1
+    value[key].as_bool().or_else(|| value[key].as_i64().map(|v| v != 0)).unwrap_or(false)
+}
+
+fn f32_list(value: &Value) -> Vec<f32> {
+    value.as_array().map(|list| list.iter().filter_map(|v| v.as_f64()).map(|v| v as f32).collect()).unwrap_or_default()
+}
+
+// req 的每一项是 [模板序号, "any"/"all"/"none", [字段序号]]
+fn card_requirements(model: &Value) -> Vec<CardRequirement> {
+    let Some(reqs) = model["req"].as_array() else {
+        return Vec::new();
+    };
+    reqs.iter()
+        .filter_map(|req| {
+            let kind = match req.get(1)?.as_str()? {
+                "any" => 1,
+                "all" => 2,
+                _ => 0,
+            };
+            Some(CardRequirement {
+                card_ord: req.get(0)?.as_u64()? as u32,
+                kind,
+                field_ords: req.get(2)?.as_array()?.iter().filter_map(|v| v.as_u64()).map(|v| v as u32).collect(),
+            })
+        })
+        .collect()
+}
+
+fn upgrade_notetypes(tx: &Transaction) -> Result<usize, AnkiError> {
+    let models = load_json_map(tx, "models")?;
+    for model in models.values() {
+        let id = i64_of(model, "id");
+        let config = NotetypeConfig {
+            kind: i64_of(model, "type") as i32,
+            sort_field_idx: i64_of(model, "sortf") as u32,
+            css: str_of(model, "css"),
+            latex_pre: str_of(model, "latexPre"),
+            latex_post: str_of(model, "latexPost"),
+            latex_svg: bool_of(model, "latexsvg"),
+            reqs: card_requirements(model),
+            original_stock_kind: i64_of(model, "originalStockKind") as i32,
+            original_id: model["originalId"].as_i64(),
+            ..Default::default()
+        };
+        tx.execute(
+            "INSERT INTO notetypes (id, name, mtime_secs, usn, config) VALUES (?, ?, ?, ?, ?)",
+            params![id, str_of(model, "name"), i64_of(model, "mod"), i64_of(model, "usn"), config.encode_to_vec()],
+        )
+        .context("写入notetype失败")?;
+        for (ord, field) in model["flds"].as_array().into_iter().flatten().enumerate() {
+            let config = FieldConfig {
+                sticky: bool_of(field, "sticky"),
+                rtl: bool_of(field, "rtl"),
+                font_name: str_of(field, "font"),
+                font_size: i64_of(field, "size") as u32,
+                description: str_of(field, "description"),
+                plain_text: bool_of(field, "plainText"),
+                collapsed: bool_of(field, "collapsed"),
+                exclude_from_search: bool_of(field, "excludeFromSearch"),
+                id: field["id"].as_i64(),
+                ..Default::default()
+            };
+            tx.execute(
+                "INSERT INTO fields (ntid, ord, name, config) VALUES (?, ?, ?, ?)",
+                params![id, ord as i64, str_of(field, "name"), config.encode_to_vec()],
+            )
+            .context("写入field失败")?;
+        }
+        for (ord, tmpl) in model["tmpls"].as_array().into_iter().flatten().enumerate() {
+            let config = TemplateConfig {
+                q_format: str_of(tmpl, "qfmt"),
+                a_format: str_of(tmpl, "afmt"),
+                q_format_browser: str_of(tmpl, "bqfmt"),
+                a_format_browser: str_of(tmpl, "bafmt"),
+                target_deck_id: i64_of(tmpl, "did"),
+                browser_font_name: str_of(tmpl, "bfont"),
+                browser_font_size: i64_of(tmpl, "bsize") as u32,
+                id: tmpl["id"].as_i64(),
+                ..Default::default()
+            };
+            tx.execute(
+                "INSERT INTO templates (ntid, ord, name, mtime_secs, usn, config) VALUES (?, ?, ?, ?, ?, ?)",
+                params![id, ord as i64, str_of(tmpl, "name"), i64_of(model, "mod"), i64_of(model, "usn"), config.encode_to_vec()],
+            )
+            .context("写入template失败")?;
+        }
+    }
+    Ok(models.len())
+}
+
+// 筛选牌组不转换，导入时它借走的卡片会放回原牌组
+fn upgrade_decks(tx: &Transaction) -> Result<usize, AnkiError> {
+    let decks = load_json_map(tx, "decks")?;
+    let mut count = 0;
+    for deck in decks.values().filter(|d| i64_of(d, "dyn") == 0) {
+        let normal = DeckNormal {
+            config_id: deck["conf"].as_i64().unwrap_or(1),
+            extend_new: i64_of(deck, "extendNew") as u32,
+            extend_review: i64_of(deck, "extendRev") as u32,
+            description: str_of(deck, "desc"),
+            markdown_description: bool_of(deck, "md"),
+            review_limit: deck["reviewLimit"].as_u64().map(|v| v as u32),
+            new_limit: deck["newLimit"].as_u64().map(|v| v as u32),
+        };
+        let kind = DeckKindContainer { normal: Some(normal) };
+        tx.execute(
+            "INSERT INTO decks (id, name, mtime_secs, usn, common, kind) VALUES (?, ?, ?, ?, x'', ?)",
+            params![i64_of(deck, "id"), str_of(deck, "name").replace("::", "\x1f"), i64_of(deck, "mod"), i64_of(deck, "usn"), kind.encode_to_vec()],
+        )
+        .context("写入deck失败")?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+fn deck_config_from_json(dconf: &Value) -> DeckConfigConfig {
+    let new = &dconf["new"];
+    let lapse = &dconf["lapse"];
+    let rev = &dconf["rev"];
+    let ints = new["ints"].as_array();
+    let graduating = |i: usize| ints.and_then(|ints| ints.get(i)).and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+    let insert_order = if new["order"].as_i64() == Some(LEGACY_NEW_CARDS_RANDOM) {
+        NEW_CARD_INSERT_ORDER_RANDOM
+    } else {
+        NEW_CARD_INSERT_ORDER_DUE
+    };
+    // FSRS 参数在不同版本里叫 fsrsWeights 或 fsrsParams5
+    let fsrs_params = if dconf["fsrsParams5"].is_array() { &dconf["fsrsParams5"] } else { &dconf["fsrsWeights"] };
+    DeckConfigConfig {
+        learn_steps: f32_list(&new["delays"]),
+        relearn_steps: f32_list(&lapse["delays"]),
+        fsrs_params_5: f32_list(fsrs_params),
+        fsrs_params_6: f32_list(&dconf["fsrsParams6"]),
+        new_per_day: i64_of(new, "perDay") as u32,
+        reviews_per_day: i64_of(rev, "perDay") as u32,
+        // initialFactor 是千分制
+        initial_ease: new["initialFactor"].as_f64().unwrap_or(2500.0) as f32 / 1000.0,
+        easy_multiplier: rev["ease4"].as_f64().unwrap_or(1.3) as f32,
+        hard_multiplier: rev["hardFactor"].as_f64().unwrap_or(1.2) as f32,
+        lapse_multiplier: lapse["mult"].as_f64().unwrap_or(0.0) as f32,
+        interval_multiplier: rev["ivlFct"].as_f64().unwrap_or(1.0) as f32,
+        maximum_review_interval: rev["maxIvl"].as_u64().unwrap_or(36500) as u32,
+        minimum_lapse_interval: lapse["minInt"].as_u64().unwrap_or(1) as u32,
+        graduating_interval_good: graduating(0),
+        graduating_interval_easy: graduating(1),
+        new_card_insert_order: insert_order,
+        leech_action: i64_of(lapse, "leechAction") as i32,
+        leech_threshold: i64_of(lapse, "leechFails") as u32,
+        bury_new: bool_of(new, "bury"),
+        bury_reviews: bool_of(rev, "bury"),
+        bury_interday_learning: bool_of(dconf, "buryInterdayLearning"),
+        new_mix: i64_of(dconf, "newMix") as i32,
+        interday_learning_mix: i64_of(dconf, "interdayLearningMix") as i32,
+        new_card_sort_order: i64_of(dconf, "newSortOrder") as i32,
+        review_order: i64_of(dconf, "reviewOrder") as i32,
+        new_card_gather_priority: i64_of(dconf, "newGatherPriority") as i32,
+        desired_retention: dconf["desiredRetention"].as_f64().unwrap_or(0.9) as f32,
+        ..Default::default()
+    }
+}
+
+fn upgrade_deck_configs(tx: &Transaction) -> Result<usize, AnkiError> {
+    let dconf = load_json_map(tx, "dconf")?;
+    for conf in dconf.values() {
+        tx.execute(
+            "INSERT INTO deck_config (id, name, mtime_secs, usn, config) VALUES (?, ?, ?, ?, ?)",
+            params![i64_of(conf, "id"), str_of(conf, "name"), i64_of(conf, "mod"), i64_of(conf, "usn"), deck_config_from_json(conf).encode_to_vec()],
+        )
+        .context("写入deck_config失败")?;
+    }
+    Ok(dconf.len())
+}
+
+// 集合设置逐项存成 config 表的一行，值是 JSON
+fn upgrade_config(tx: &Transaction) -> Result<(), AnkiError> {
+    let conf = load_json_map(tx, "conf")?;
+    for (key, value) in &conf {
+        tx.execute(
+            "INSERT INTO config (KEY, usn, mtime_secs, val) VALUES (?, 0, 0, ?)",
+            params![key, value.to_string().into_bytes()],
+        )
+        .context("写入config失败")?;
+    }
+    Ok(())
+}
+
+/// 把旧格式集合的笔记类型、牌组、牌组选项和集合设置转换成 anki21b 的表，已经是 anki21b 时什么都不做
+pub fn upgrade_legacy(conn: &mut Connection) -> Result<(), AnkiError> {
+    if has_table(conn, "main", "notetypes") {
+        return Ok(());
+    }
+    let tx = conn.transaction().context("开启事务失败")?;
+    tx.execute_batch(ANKI21B_TABLES_SQL).context("创建anki21b表失败")?;
+    let notetypes = upgrade_notetypes(&tx)?;
+    let decks = upgrade_decks(&tx)?;
+    let deck_configs = upgrade_deck_configs(&tx)?;
+    upgrade_config(&tx)?;
+    tx.commit().context("提交事务失败")?;
+    rust_log(&format!(
+        "DEBUG: 旧格式集合已转换: {} 个笔记类型, {} 个牌组, {} 个牌组选项",
+        notetypes, decks, deck_configs
+    ));
+    Ok(())
+}
@@ -0,0 +1,251 @@
+// .colpkg 是整个 Anki 集合的备份，zip 结构和 apkg 相同，先按 apkg 解包并转换成 anki21b 的表，再把集合按牌组拆开：
+// 每个有卡片的牌组分别导入集合库，都从同一个解包出的集合 ATTACH 进来，用 DeckFilter 只取这个牌组的卡片、笔记和复习记录，
+// 媒体文件只取这些笔记引用到的。上级牌组的记录也一起导入，这样导入后仍然是完整的层级；卡片的调度列和 data 原样保留。
+// 不为每个牌组复制一份集合，手机上大的集合复制和 VACUUM 都很慢。
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use rusqlite::Connection;
+use crate::api::simple::rust_log;
+use crate::apkg::{ImportTracker, SQLITE_FILE};
+use crate::collection::import::{return_filtered_cards, DeckFilter};
+use crate::collection::media::{unescape_media_name, MEDIA_SOUND, MEDIA_SRC};
+use crate::error::{AnkiError, ResultExt};
+
+// Anki 要求默认牌组始终存在
+const DEFAULT_DECK_ID: i64 = 1;
+
+/// 拆分出来的一个牌组
+pub struct SplitDeck {
+    pub key: String, // deck key，由集合的 MD5 和牌组 id 算出，重复导入同一个备份时不变
+    pub deck_id: i64,
+    pub name: String, // 完整名称，层级用 :: 分隔
+    pub filter: DeckFilter, // 导入时只取这个牌组的卡片，以及它和上级牌组的记录
+    pub media_map: HashMap<String, String>, // 文件名 -> 数字编号
+    pub card_count: u32,
+}
+
+struct DeckInfo {
+    id: i64,
+    name: String,
+}
+
+// 牌组名称的层级用 \x1f 分隔，统一成 ::
+fn load_decks(conn: &Connection) -> Result<Vec<DeckInfo>, AnkiError> {
+    let mut stmt = conn.prepare("SELECT id, name FROM decks").context("准备SQL失败")?;
+    let rows = stmt
+        .query_map([], |row| Ok(DeckInfo { id: row.get(0)?, name: row.get::<_, String>(1)?.replace('\x1f', "::") }))
+        .context("查询SQL失败")?;
+    rows.collect::<Result<Vec<_>, _>>().context("读取牌组失败")
+}
+
+fn card_counts_by_deck(conn: &Connection) -> Result<Vec<(i64, u32)>, AnkiError> {
+    let mut stmt = conn
+        .prepare("SELECT did, COUNT(*) FROM cards GROUP BY did ORDER BY did")
+        .context("准备SQL失败")?;
+    let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?))).context("查询SQL失败")?;
+    rows.collect::<Result<Vec<_>, _>>().context("读取card失败")
+}
+
+// 牌组本身、它的所有上级牌组和默认牌组
+fn decks_to_keep(decks: &[DeckInfo], deck_id: i64, name: &str) -> HashSet<i64> {
+    let mut keep: HashSet<i64> = decks
+        .iter()
+        .filter(|d| name.starts_with(&format!("{}::", d.name)))
+        .map(|d| d.id)
+        .collect();
+    keep.insert(deck_id);
+    keep.insert(DEFAULT_DECK_ID);
+    keep
+}
+
+// 一个牌组的笔记字段里引用到的媒体文件；下划线开头的文件一般由模板引用（字体、脚本），总是保留
+fn referenced_media(conn: &Connection, deck_id: i64, media_map: &HashMap<String, String>) -> Result<HashSet<String>, AnkiError> {
+    let mut names = HashSet::new();
+    let mut stmt = conn
+        .prepare("SELECT flds FROM notes WHERE id IN (SELECT nid FROM cards WHERE did = ?)")
+        .context("准备SQL失败")?;
+    let mut rows = stmt.query([deck_id]).context("查询SQL失败")?;
+    while let Some(row) = rows.next().context("遍历SQL失败")? {
+        let flds: String = row.get(0).context("读取flds失败")?;
+        let srcs = MEDIA_SRC.captures_iter(&flds).filter_map(|c| c.get(1).or(c.get(2)).or(c.get(3)));
+        let sounds = MEDIA_SOUND.captures_iter(&flds).filter_map(|c| c.get(1));
+        for name in srcs.chain(sounds) {
+            names.insert(name.as_str().to_string());
+            names.insert(unescape_media_name(name.as_str()));
+        }
+    }
+    names.extend(media_map.keys().filter(|name| name.starts_with('_')).cloned());
+    names.retain(|name| media_map.contains_key(name));
+    Ok(names)
+}
+
+fn split_deck(
+    conn: &Connection,
+    package_md5: &str,
+    deck: &DeckInfo,
+    decks: &[DeckInfo],
+    media_map: &HashMap<String, String>,
+    card_count: u32,
+) -> Result<SplitDeck, AnkiError> {
+    let key = format!("{:x}", md5::compute(format!("{package_md5}:{}", deck.id)));
+    let media_map = referenced_media(conn, deck.id, media_map)?
+        .into_iter()
+        .map(|name| {
+            let number = media_map[&name].clone();
+            (name, number)
+        })
+        .collect();
+    let filter = DeckFilter { deck_id: deck.id, decks: decks_to_keep(decks, deck.id, &deck.name) };
+    Ok(SplitDeck { key, deck_id: deck.id, name: deck.name.clone(), filter, media_map, card_count })
+}
+
+/// 把解包后的整个集合按卡片所在的牌组拆开，每个有卡片的牌组得到一个 DeckFilter 和它用到的媒体文件，集合本身不复制。
+/// 筛选牌组里的卡片先放回原牌组（直接改解包出的集合）
+pub fn split_collection(
+    collection_dir: &Path,
+    package_md5: &str,
+    media_map: &HashMap<String, String>,
+    tracker: &ImportTracker,
+) -> Result<Vec<SplitDeck>, AnkiError> {
+    let conn = Connection::open(collection_dir.join(SQLITE_FILE)).context("打开sqlite失败")?;
+    let returned = return_filtered_cards(&conn, "main")?;
+    if returned > 0 {
+        rust_log(&format!("DEBUG: {} 张卡片从筛选牌组放回了原牌组", returned));
+    }
+    let decks = load_decks(&conn)?;
+    let counts = card_counts_by_deck(&conn)?;
+
+    let mut result: Vec<SplitDeck> = Vec::new();
+    for (deck_id, count) in &counts {
+        tracker.check_cancelled()?;
+        let missing;
+        let deck = match decks.iter().find(|d| d.id == *deck_id) {
+            Some(deck) => deck,
+            None => {
+                rust_log(&format!("DEBUG: 卡片所在的牌组 {} 不存在", deck_id));
+                missing = DeckInfo { id: *deck_id, name: format!("{deck_id}") };
+                &missing
+            }
+        };
+        rust_log(&format!("DEBUG: 拆分牌组 {}，共 {} 张卡片", deck.name, count));
+        result.push(split_deck(&conn, package_md5, deck, &decks, media_map, *count)?);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+    use prost::Message;
+    use rusqlite::params;
+    use crate::anki_proto::{DeckKindContainer, DeckNormal};
+    use crate::api::simple::ImportProgress;
+    use crate::apkg::TempDir;
+    use crate::collection::import::import_collection;
+    use crate::collection::memory_collection;
+    use super::*;
+
+    fn deck(id: i64, name: &str) -> DeckInfo {
+        DeckInfo { id, name: name.to_string() }
+    }
+
+    #[test]
+    fn keeps_the_deck_its_ancestors_and_default() {
+        let decks = [deck(1, "Default"), deck(2, "A"), deck(3, "A::B"), deck(4, "A::B::C"), deck(5, "AB"), deck(6, "X")];
+        assert_eq!(decks_to_keep(&decks, 4, "A::B::C"), HashSet::from([1, 2, 3, 4]));
+        // 同样前缀但不是上级的牌组（AB）和下级牌组都不保留
+        assert_eq!(decks_to_keep(&decks, 3, "A::B"), HashSet::from([1, 2, 3]));
+        assert_eq!(decks_to_keep(&decks, 6, "X"), HashSet::from([1, 6]));
+    }
+
+    // 解包出的集合：A、A::B、X 三个牌组各有一条笔记，A::B 和 X 的笔记引用了媒体文件
+    fn staged_collection(dir: &TempDir) {
+        let conn = memory_collection();
+        conn.execute("INSERT INTO deck_config (id, name, mtime_secs, usn, config) VALUES (1, 'Default', 0, 0, x'')", []).unwrap();
+        conn.execute("INSERT INTO notetypes (id, name, mtime_secs, usn, config) VALUES (10, 'Basic', 0, 0, x'')", []).unwrap();
+        conn.execute("INSERT INTO fields VALUES (10, 0, 'Front', x'')", []).unwrap();
+        conn.execute("INSERT INTO templates VALUES (10, 0, 'Card 1', 0, 0, x'')", []).unwrap();
+        let kind = DeckKindContainer { normal: Some(DeckNormal { config_id: 1, ..Default::default() }) }.encode_to_vec();
+        let notes = [(2, "A", "a"), (3, "A\x1fB", "<img src=\"b.png\">"), (4, "X", "[sound:x.mp3]")];
+        for (did, name, flds) in notes {
+            conn.execute(
+                "INSERT INTO decks (id, name, mtime_secs, usn, common, kind) VALUES (?, ?, 0, 0, x'', ?)",
+                params![did, name, kind],
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO notes (id, guid, mid, mod, usn, tags, flds, sfld, csum, flags, data) VALUES (?, ?, 10, 0, 0, '', ?, '', 0, 0, '')",
+                params![did * 100, format!("guid{did}"), flds],
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO cards (id, nid, did, ord, mod, usn, type, queue, due, ivl, factor, reps, lapses, left, odue, odid, flags, data) \
+                 VALUES (?, ?, ?, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, '')",
+                params![did * 1000, did * 100, did],
+            )
+            .unwrap();
+        }
+        conn.execute("VACUUM INTO ?", [dir.path.join(SQLITE_FILE).to_string_lossy()]).unwrap();
+    }
+
+    fn split(dir: &TempDir) -> Vec<SplitDeck> {
+        let media_map = HashMap::from([
+            ("b.png".to_string(), "0".to_string()),
+            ("x.mp3".to_string(), "1".to_string()),
+            ("_font.ttf".to_string(), "2".to_string()),
+        ]);
+        let cancelled = AtomicBool::new(false);
+        let mut ignore = |_: &ImportProgress| {};
+        let tracker = ImportTracker::new(&cancelled, &mut ignore);
+        split_collection(&dir.path, "md5", &media_map, &tracker).unwrap()
+    }
+
+    #[test]
+    fn splits_by_deck_without_copying() {
+        let dir = TempDir::new(&std::env::temp_dir()).unwrap();
+        staged_collection(&dir);
+        let decks = split(&dir);
+        assert_eq!(decks.iter().map(|d| (d.deck_id, d.name.as_str(), d.card_count)).collect::<Vec<_>>(), [
+            (2, "A", 1),
+            (3, "A::B", 1),
+            (4, "X", 1)
+        ]);
+        let media = |i: usize| decks[i].media_map.keys().cloned().collect::<HashSet<_>>();
+        assert_eq!(media(0), HashSet::from(["_font.ttf".to_string()]));
+        assert_eq!(media(1), HashSet::from(["b.png".to_string(), "_font.ttf".to_string()]));
+        assert_eq!(media(2), HashSet::from(["x.mp3".to_string(), "_font.ttf".to_string()]));
+        assert_eq!(decks[1].filter.decks, HashSet::from([1, 2, 3]));
+        assert_ne!(decks[0].key, decks[1].key);
+        // 临时目录里只有解包出的那一份集合
+        assert_eq!(std::fs::read_dir(&dir.path).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn each_deck_imports_only_its_cards_with_the_parent_hierarchy() {
+        let dir = TempDir::new(&std::env::temp_dir()).unwrap();
+        staged_collection(&dir);
+        let mut conn = memory_collection();
+        let media_dir = dir.path.join("media");
+        for deck in split(&dir) {
+            let (summary, staged) =
+                import_collection(&mut conn, &dir.path.join(SQLITE_FILE), &[], &media_dir, &deck.key, false, Some(&deck.filter)).unwrap();
+            assert!(staged.move_files().is_empty());
+            assert_eq!(summary.added_card_ids, [deck.deck_id * 1000]);
+        }
+        let names: Vec<(String, i64)> = {
+            let mut stmt = conn
+                .prepare("SELECT d.name, COUNT(c.id) FROM decks d LEFT JOIN cards c ON c.did = d.id GROUP BY d.id ORDER BY d.name")
+                .unwrap();
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?))).unwrap();
+            rows.collect::<Result<_, _>>().unwrap()
+        };
+        // 上级牌组 A 只建了一次，A::B 导入时共用；默认牌组没有卡片，不导入
+        assert_eq!(names, [("A".to_string(), 1), ("A\x1fB".to_string(), 1), ("X".to_string(), 1)]);
+        // 每个 deck key 只拥有自己的牌组
+        let owned: i64 = conn
+            .query_row("SELECT COUNT(*) FROM open_anki_decks o JOIN cards c ON c.did = o.did", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(owned, 3);
+    }
+}
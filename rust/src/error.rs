@@ -0,0 +1,150 @@
+// AnkiError 的构造和转换。底层错误按类型归类（sqlite 磁盘已满、文件不存在、zip 损坏等），
+// 调用处用 context/path_context 补上出错时在做的事和相关的文件，原来的错误信息放在 message 里
+
+use std::fmt;
+use std::io;
+use std::path::Path;
+use rusqlite::ErrorCode;
+use zip::result::ZipError;
+use crate::apkg::{RejectReason, RejectedEntry};
+pub use crate::api::error::{AnkiError, ErrorKind};
+
+impl AnkiError {
+    pub fn new(kind: ErrorKind, context: impl Into<String>) -> Self {
+        AnkiError { kind, context: context.into(), message: String::new(), path: None }
+    }
+
+    pub fn with_message(mut self, message: impl fmt::Display) -> Self {
+        self.message = message.to_string();
+        self
+    }
+
+    pub fn with_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.path = Some(path.as_ref().display().to_string());
+        self
+    }
+
+    pub fn with_entry(mut self, entry: &str) -> Self {
+        self.path = Some(entry.to_string());
+        self
+    }
+
+    pub fn not_found(context: impl Into<String>) -> Self {
+        AnkiError::new(ErrorKind::NotFound, context)
+    }
+
+    pub fn invalid_argument(context: impl Into<String>) -> Self {
+        AnkiError::new(ErrorKind::InvalidArgument, context)
+    }
+
+    pub fn cancelled() -> Self {
+        AnkiError::new(ErrorKind::Cancelled, "导入已取消")
+    }
+
+    // 由底层错误得到类别和原文，context 由调用处补上
+    fn from_source(kind: ErrorKind, source: impl fmt::Display) -> Self {
+        AnkiError::new(kind, String::new()).with_message(source)
+    }
+
+    // 外层的上下文加在已有的前面，里层更具体的上下文（如哪个条目被拒绝）不丢
+    fn add_context(mut self, context: &str) -> Self {
+        self.context = if self.context.is_empty() { context.to_string() } else { format!("{context}: {}", self.context) };
+        self
+    }
+}
+
+impl fmt::Display for AnkiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.context.is_empty(), self.message.is_empty()) {
+            (false, false) => write!(f, "{}: {}", self.context, self.message)?,
+            (false, true) => write!(f, "{}", self.context)?,
+            _ => write!(f, "{}", self.message)?,
+        }
+        if let Some(path) = &self.path {
+            write!(f, " - {path}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for AnkiError {}
+
+impl From<io::Error> for AnkiError {
+    fn from(e: io::Error) -> Self {
+        let kind = match e.kind() {
+            io::ErrorKind::NotFound => ErrorKind::NotFound,
+            io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem => ErrorKind::PermissionDenied,
+            io::ErrorKind::StorageFull | io::ErrorKind::QuotaExceeded => ErrorKind::DiskFull,
+            _ => ErrorKind::Io,
+        };
+        AnkiError::from_source(kind, e)
+    }
+}
+
+impl From<rusqlite::Error> for AnkiError {
+    fn from(e: rusqlite::Error) -> Self {
+        let kind = match &e {
+            rusqlite::Error::QueryReturnedNoRows => ErrorKind::NotFound,
+            rusqlite::Error::SqliteFailure(failure, _) => match failure.code {
+                ErrorCode::DiskFull => ErrorKind::DiskFull,
+                ErrorCode::PermissionDenied | ErrorCode::ReadOnly => ErrorKind::PermissionDenied,
+                ErrorCode::NotADatabase => ErrorKind::InvalidPackage,
+                _ => ErrorKind::Database,
+            },
+            _ => ErrorKind::Database,
+        };
+        AnkiError::from_source(kind, e)
+    }
+}
+
+impl From<ZipError> for AnkiError {
+    fn from(e: ZipError) -> Self {
+        match e {
+            ZipError::Io(e) => e.into(),
+            ZipError::FileNotFound => AnkiError::from_source(ErrorKind::NotFound, e),
+            _ => AnkiError::from_source(ErrorKind::InvalidPackage, e),
+        }
+    }
+}
+
+impl From<prost::DecodeError> for AnkiError {
+    fn from(e: prost::DecodeError) -> Self {
+        AnkiError::from_source(ErrorKind::Decode, e)
+    }
+}
+
+impl From<serde_json::Error> for AnkiError {
+    fn from(e: serde_json::Error) -> Self {
+        AnkiError::from_source(ErrorKind::Decode, e)
+    }
+}
+
+impl From<RejectedEntry> for AnkiError {
+    fn from(e: RejectedEntry) -> Self {
+        let kind = match e.reason {
+            RejectReason::EntryTooLarge | RejectReason::TotalTooLarge | RejectReason::RatioTooHigh => ErrorKind::TooLarge,
+            _ => ErrorKind::UnsafeEntry,
+        };
+        AnkiError::new(kind, "apkg条目被拒绝").with_message(e.reason_text()).with_entry(&e.entry)
+    }
+}
+
+/// 给底层错误补上上下文，已有的上下文接在后面
+pub trait ResultExt<T> {
+    fn context(self, context: &str) -> Result<T, AnkiError>;
+    fn path_context(self, context: &str, path: impl AsRef<Path>) -> Result<T, AnkiError>;
+}
+
+impl<T, E: Into<AnkiError>> ResultExt<T> for Result<T, E> {
+    fn context(self, context: &str) -> Result<T, AnkiError> {
+        self.map_err(|e| e.into().add_context(context))
+    }
+
+    // 里层已经记录了路径（如压缩包里的条目）时保留里层的
+    fn path_context(self, context: &str, path: impl AsRef<Path>) -> Result<T, AnkiError> {
+        self.map_err(|e| {
+            let e = e.into().add_context(context);
+            if e.path.is_some() { e } else { e.with_path(path) }
+        })
+    }
+}
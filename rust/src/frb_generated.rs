@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1250749672;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -847349441;
 
 // Section: executor
 
@@ -45,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__simple__extract_apkg_impl(
+fn wire__crate__api__simple__answer_card_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +53,7 @@ fn wire__crate__api__simple__extract_apkg_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "extract_apkg",
+            debug_name: "answer_card",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,19 +67,26 @@ fn wire__crate__api__simple__extract_apkg_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_apkg_path = <String>::sse_decode(&mut deserializer);
-            let api_base_dir = <String>::sse_decode(&mut deserializer);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_card_id = <i64>::sse_decode(&mut deserializer);
+            let api_rating = <u8>::sse_decode(&mut deserializer);
+            let api_now = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::simple::extract_apkg(api_apkg_path, api_base_dir)?;
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::answer_card(
+                        api_deck_id,
+                        api_card_id,
+                        api_rating,
+                        api_now,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__get_all_note_ids_impl(
+fn wire__crate__api__simple__build_review_queue_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -87,7 +94,7 @@ fn wire__crate__api__simple__get_all_note_ids_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_all_note_ids",
+            debug_name: "build_review_queue",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -101,20 +108,21 @@ fn wire__crate__api__simple__get_all_note_ids_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sqlite_path = <String>::sse_decode(&mut deserializer);
-            let api_version = <String>::sse_decode(&mut deserializer);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_now = <i64>::sse_decode(&mut deserializer);
+            let api_limits = <crate::api::simple::QueueLimits>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
                     let output_ok =
-                        crate::api::simple::get_all_note_ids(api_sqlite_path, api_version)?;
+                        crate::api::simple::build_review_queue(api_deck_id, api_now, api_limits)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__get_card_count_impl(
+fn wire__crate__api__simple__bury_cards_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -122,7 +130,7 @@ fn wire__crate__api__simple__get_card_count_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_card_count",
+            debug_name: "bury_cards",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -136,19 +144,51 @@ fn wire__crate__api__simple__get_card_count_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sqlite_path = <String>::sse_decode(&mut deserializer);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_card_ids = <Vec<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::simple::get_card_count(api_sqlite_path))?;
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::bury_cards(api_deck_id, api_card_ids)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__get_card_count_from_deck_impl(
+fn wire__crate__api__simple__cancel_import_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cancel_import",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::simple::cancel_import(api_task_id);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__close_collection_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -156,7 +196,7 @@ fn wire__crate__api__simple__get_card_count_from_deck_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_card_count_from_deck",
+            debug_name: "close_collection",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -170,21 +210,19 @@ fn wire__crate__api__simple__get_card_count_from_deck_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_app_doc_dir = <String>::sse_decode(&mut deserializer);
-            let api_md5 = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::simple::get_card_count_from_deck(api_app_doc_dir, api_md5),
-                    )?;
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::close_collection();
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__get_deck_note_impl(
+fn wire__crate__api__simple__extract_apkg_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -192,7 +230,7 @@ fn wire__crate__api__simple__get_deck_note_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_deck_note",
+            debug_name: "extract_apkg",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -206,24 +244,18 @@ fn wire__crate__api__simple__get_deck_note_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sqlite_path = <String>::sse_decode(&mut deserializer);
-            let api_note_id = <i64>::sse_decode(&mut deserializer);
-            let api_version = <String>::sse_decode(&mut deserializer);
+            let api_apkg_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::simple::get_deck_note(
-                        api_sqlite_path,
-                        api_note_id,
-                        api_version,
-                    )?;
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::extract_apkg(api_apkg_path)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__get_new_note_ids_impl(
+fn wire__crate__api__simple__extract_apkg_with_progress_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -231,7 +263,7 @@ fn wire__crate__api__simple__get_new_note_ids_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_new_note_ids",
+            debug_name: "extract_apkg_with_progress",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -245,16 +277,19 @@ fn wire__crate__api__simple__get_new_note_ids_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sqlite_path = <String>::sse_decode(&mut deserializer);
-            let api_limit = <usize>::sse_decode(&mut deserializer);
-            let api_version = <String>::sse_decode(&mut deserializer);
+            let api_apkg_path = <String>::sse_decode(&mut deserializer);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_progress = <StreamSink<
+                crate::api::simple::ImportProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::simple::get_new_note_ids(
-                        api_sqlite_path,
-                        api_limit,
-                        api_version,
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::extract_apkg_with_progress(
+                        api_apkg_path,
+                        api_task_id,
+                        api_progress,
                     )?;
                     Ok(output_ok)
                 })())
@@ -262,16 +297,17 @@ fn wire__crate__api__simple__get_new_note_ids_impl(
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__simple__extract_colpkg_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "extract_colpkg",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -283,16 +319,18 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_colpkg_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::extract_colpkg(api_colpkg_path)?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__simple__generate_cloze_cards_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -300,7 +338,7 @@ fn wire__crate__api__simple__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "generate_cloze_cards",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -314,19 +352,18 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_note_id = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::generate_cloze_cards(api_note_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__register_log_callback_impl(
+fn wire__crate__api__simple__get_all_note_ids_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -334,7 +371,7 @@ fn wire__crate__api__simple__register_log_callback_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "register_log_callback",
+            debug_name: "get_all_note_ids",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -348,23 +385,18 @@ fn wire__crate__api__simple__register_log_callback_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink =
-                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
-                    &mut deserializer,
-                );
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::register_log_callback(api_sink);
-                    })?;
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::get_all_note_ids(api_deck_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__update_card_schedule_impl(
+fn wire__crate__api__simple__get_card_count_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -372,7 +404,7 @@ fn wire__crate__api__simple__update_card_schedule_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_card_schedule",
+            debug_name: "get_card_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -386,28 +418,18 @@ fn wire__crate__api__simple__update_card_schedule_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_stability = <f64>::sse_decode(&mut deserializer);
-            let api_difficulty = <f64>::sse_decode(&mut deserializer);
-            let api_last_review = <i64>::sse_decode(&mut deserializer);
-            let api_rating = <u8>::sse_decode(&mut deserializer);
-            let api_now = <i64>::sse_decode(&mut deserializer);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::simple::update_card_schedule(
-                        api_stability,
-                        api_difficulty,
-                        api_last_review,
-                        api_rating,
-                        api_now,
-                    )?;
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::get_card_count(api_deck_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__update_card_schedule_simple_impl(
+fn wire__crate__api__simple__get_card_memory_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -415,7 +437,7 @@ fn wire__crate__api__simple__update_card_schedule_simple_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_card_schedule_simple",
+            debug_name: "get_card_memory_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -429,19 +451,15 @@ fn wire__crate__api__simple__update_card_schedule_simple_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_stability = <f64>::sse_decode(&mut deserializer);
-            let api_difficulty = <f64>::sse_decode(&mut deserializer);
-            let api_last_review = <i64>::sse_decode(&mut deserializer);
-            let api_rating = <u8>::sse_decode(&mut deserializer);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_card_id = <i64>::sse_decode(&mut deserializer);
             let api_now = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::simple::update_card_schedule_simple(
-                        api_stability,
-                        api_difficulty,
-                        api_last_review,
-                        api_rating,
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::get_card_memory_state(
+                        api_deck_id,
+                        api_card_id,
                         api_now,
                     )?;
                     Ok(output_ok)
@@ -450,547 +468,4104 @@ fn wire__crate__api__simple__update_card_schedule_simple_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
+fn wire__crate__api__simple__get_card_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_card_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_card_ids = <Vec<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::get_card_status(api_deck_id, api_card_ids)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for std::collections::HashMap<String, String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
-        return inner.into_iter().collect();
-    }
+fn wire__crate__api__simple__get_cards_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_cards_batch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_card_ids = <Vec<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::get_cards_batch(api_deck_id, api_card_ids)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for StreamSink<String, flutter_rust_bridge::for_generated::SseCodec> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
-    }
+fn wire__crate__api__simple__get_deck_note_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_deck_note",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_note_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::get_deck_note(api_note_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
+fn wire__crate__api__simple__get_intraday_learning_queue_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_intraday_learning_queue",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_now = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok =
+                        crate::api::simple::get_intraday_learning_queue(api_deck_id, api_now)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for crate::api::simple::ExtractResult {
+fn wire__crate__api__simple__get_media_dir_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_media_dir",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::get_media_dir()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__get_new_card_queue_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_new_card_queue",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_now = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::get_new_card_queue(api_deck_id, api_now)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__get_new_note_ids_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_new_note_ids",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_limit = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::get_new_note_ids(api_deck_id, api_limit)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__get_note_cards_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_note_cards",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_note_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::get_note_cards(api_note_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__get_review_logs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_review_logs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::get_review_logs(api_deck_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__get_rollover_hour_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_rollover_hour",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::get_rollover_hour()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__get_scheduler_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_scheduler_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::get_scheduler_config(api_deck_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__get_suspended_card_ids_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_suspended_card_ids",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::get_suspended_card_ids(api_deck_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__init_collection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_collection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_base_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::init_collection(api_base_dir)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__merge_apkg_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "merge_apkg",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_apkg_path = <String>::sse_decode(&mut deserializer);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_remove_missing = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::merge_apkg(
+                        api_apkg_path,
+                        api_deck_id,
+                        api_remove_missing,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__merge_summary_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "merge_summary_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::MergeSummary::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__new_card_memory_state_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "new_card_memory_state",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_now = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::simple::new_card_memory_state(api_now))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__open_collection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_collection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::open_collection()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__optimize_fsrs_parameters_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "optimize_fsrs_parameters",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_review_log =
+                <Vec<crate::api::simple::ReviewLogEntry>>::sse_decode(&mut deserializer);
+            let api_progress = <StreamSink<
+                crate::api::simple::OptimizeProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok =
+                        crate::api::simple::optimize_fsrs_parameters(api_review_log, api_progress)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__preview_card_schedule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_card_schedule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_note_id = <i64>::sse_decode(&mut deserializer);
+            let api_rating = <u8>::sse_decode(&mut deserializer);
+            let api_now = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::preview_card_schedule(
+                        api_deck_id,
+                        api_note_id,
+                        api_rating,
+                        api_now,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__preview_card_schedule_simple_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_card_schedule_simple",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_note_id = <i64>::sse_decode(&mut deserializer);
+            let api_rating = <u8>::sse_decode(&mut deserializer);
+            let api_now = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::preview_card_schedule_simple(
+                        api_deck_id,
+                        api_note_id,
+                        api_rating,
+                        api_now,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__preview_schedule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_schedule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_card = <crate::api::simple::CardMemoryState>::sse_decode(&mut deserializer);
+            let api_now = <i64>::sse_decode(&mut deserializer);
+            let api_config = <crate::api::simple::SchedulerConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok =
+                        crate::api::simple::preview_schedule(api_card, api_now, api_config)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__queue_limits_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "queue_limits_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::QueueLimits::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__register_leech_callback_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "register_leech_callback",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::simple::LeechEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::register_leech_callback(api_sink);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__register_log_callback_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "register_log_callback",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink =
+                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::register_log_callback(api_sink);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__remove_deck_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_deck",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::remove_deck(api_deck_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__render_card_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "render_card",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_card_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::render_card(api_card_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__schedule_card_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "schedule_card",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_card = <crate::api::simple::CardMemoryState>::sse_decode(&mut deserializer);
+            let api_rating = <u8>::sse_decode(&mut deserializer);
+            let api_now = <i64>::sse_decode(&mut deserializer);
+            let api_config = <crate::api::simple::SchedulerConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::schedule_card(
+                        api_card, api_rating, api_now, api_config,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__scheduler_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scheduler_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::simple::SchedulerConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__set_card_flag_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_card_flag",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_card_ids = <Vec<i64>>::sse_decode(&mut deserializer);
+            let api_flag = <u8>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok =
+                        crate::api::simple::set_card_flag(api_deck_id, api_card_ids, api_flag)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__set_rollover_hour_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_rollover_hour",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_hour = <u8>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::set_rollover_hour(api_hour)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__set_scheduler_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_scheduler_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_config = <crate::api::simple::SchedulerConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok =
+                        crate::api::simple::set_scheduler_config(api_deck_id, api_config)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__suspend_cards_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "suspend_cards",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_card_ids = <Vec<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::suspend_cards(api_deck_id, api_card_ids)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__toggle_note_marked_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "toggle_note_marked",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_note_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::toggle_note_marked(api_note_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__unbury_cards_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unbury_cards",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_card_ids = <Vec<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::unbury_cards(api_deck_id, api_card_ids)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__unbury_deck_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unbury_deck",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::unbury_deck(api_deck_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__unsuspend_cards_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unsuspend_cards",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_card_ids = <Vec<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::unsuspend_cards(api_deck_id, api_card_ids)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__update_card_schedule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_card_schedule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_note_id = <i64>::sse_decode(&mut deserializer);
+            let api_rating = <u8>::sse_decode(&mut deserializer);
+            let api_now = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::update_card_schedule(
+                        api_deck_id,
+                        api_note_id,
+                        api_rating,
+                        api_now,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__update_card_schedule_simple_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_card_schedule_simple",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_deck_id = <String>::sse_decode(&mut deserializer);
+            let api_note_id = <i64>::sse_decode(&mut deserializer);
+            let api_rating = <u8>::sse_decode(&mut deserializer);
+            let api_now = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::AnkiError>((move || {
+                    let output_ok = crate::api::simple::update_card_schedule_simple(
+                        api_deck_id,
+                        api_note_id,
+                        api_rating,
+                        api_now,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for std::collections::HashMap<String, String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode for StreamSink<String, flutter_rust_bridge::for_generated::SseCodec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::simple::ImportProgress, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::simple::LeechEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::simple::OptimizeProgress,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::error::AnkiError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::error::ErrorKind>::sse_decode(deserializer);
+        let mut var_context = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_path = <Option<String>>::sse_decode(deserializer);
+        return crate::api::error::AnkiError {
+            kind: var_kind,
+            context: var_context,
+            message: var_message,
+            path: var_path,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::BatchCard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cardId = <i64>::sse_decode(deserializer);
+        let mut var_noteId = <i64>::sse_decode(deserializer);
+        let mut var_notetypeId = <i64>::sse_decode(deserializer);
+        let mut var_ord = <i64>::sse_decode(deserializer);
+        let mut var_templateOrd = <i64>::sse_decode(deserializer);
+        let mut var_question = <String>::sse_decode(deserializer);
+        let mut var_answer = <String>::sse_decode(deserializer);
+        let mut var_diagnostics = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::simple::BatchCard {
+            card_id: var_cardId,
+            note_id: var_noteId,
+            notetype_id: var_notetypeId,
+            ord: var_ord,
+            template_ord: var_templateOrd,
+            question: var_question,
+            answer: var_answer,
+            diagnostics: var_diagnostics,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::BatchNotetype {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_isCloze = <bool>::sse_decode(deserializer);
+        let mut var_css = <String>::sse_decode(deserializer);
+        let mut var_fieldNames = <Vec<String>>::sse_decode(deserializer);
+        let mut var_templates = <Vec<crate::api::simple::BatchTemplate>>::sse_decode(deserializer);
+        return crate::api::simple::BatchNotetype {
+            id: var_id,
+            name: var_name,
+            is_cloze: var_isCloze,
+            css: var_css,
+            field_names: var_fieldNames,
+            templates: var_templates,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::BatchTemplate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ord = <i64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_qfmt = <String>::sse_decode(deserializer);
+        let mut var_afmt = <String>::sse_decode(deserializer);
+        return crate::api::simple::BatchTemplate {
+            ord: var_ord,
+            name: var_name,
+            qfmt: var_qfmt,
+            afmt: var_afmt,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::simple::CardExt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_nid = <i64>::sse_decode(deserializer);
+        let mut var_ord = <i64>::sse_decode(deserializer);
+        let mut var_type = <i64>::sse_decode(deserializer);
+        let mut var_queue = <i64>::sse_decode(deserializer);
+        let mut var_due = <i64>::sse_decode(deserializer);
+        return crate::api::simple::CardExt {
+            id: var_id,
+            nid: var_nid,
+            ord: var_ord,
+            type_: var_type,
+            queue: var_queue,
+            due: var_due,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::CardMemoryState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_state = <crate::api::simple::CardState>::sse_decode(deserializer);
+        let mut var_due = <i64>::sse_decode(deserializer);
+        let mut var_stability = <f64>::sse_decode(deserializer);
+        let mut var_difficulty = <f64>::sse_decode(deserializer);
+        let mut var_elapsedDays = <i64>::sse_decode(deserializer);
+        let mut var_scheduledDays = <i64>::sse_decode(deserializer);
+        let mut var_reps = <i32>::sse_decode(deserializer);
+        let mut var_lapses = <i32>::sse_decode(deserializer);
+        let mut var_step = <i32>::sse_decode(deserializer);
+        let mut var_lastReview = <Option<i64>>::sse_decode(deserializer);
+        let mut var_easeFactor = <f64>::sse_decode(deserializer);
+        return crate::api::simple::CardMemoryState {
+            state: var_state,
+            due: var_due,
+            stability: var_stability,
+            difficulty: var_difficulty,
+            elapsed_days: var_elapsedDays,
+            scheduled_days: var_scheduledDays,
+            reps: var_reps,
+            lapses: var_lapses,
+            step: var_step,
+            last_review: var_lastReview,
+            ease_factor: var_easeFactor,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::CardState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::CardState::New,
+            1 => crate::api::simple::CardState::Learning,
+            2 => crate::api::simple::CardState::Review,
+            3 => crate::api::simple::CardState::Relearning,
+            _ => unreachable!("Invalid variant for CardState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::CardStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cardId = <i64>::sse_decode(deserializer);
+        let mut var_noteId = <i64>::sse_decode(deserializer);
+        let mut var_suspended = <bool>::sse_decode(deserializer);
+        let mut var_buried = <bool>::sse_decode(deserializer);
+        let mut var_flag = <u8>::sse_decode(deserializer);
+        let mut var_marked = <bool>::sse_decode(deserializer);
+        return crate::api::simple::CardStatus {
+            card_id: var_cardId,
+            note_id: var_noteId,
+            suspended: var_suspended,
+            buried: var_buried,
+            flag: var_flag,
+            marked: var_marked,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::CardsBatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cards = <Vec<crate::api::simple::BatchCard>>::sse_decode(deserializer);
+        let mut var_notetypes = <Vec<crate::api::simple::BatchNotetype>>::sse_decode(deserializer);
+        let mut var_missingCardIds = <Vec<i64>>::sse_decode(deserializer);
+        return crate::api::simple::CardsBatch {
+            cards: var_cards,
+            notetypes: var_notetypes,
+            missing_card_ids: var_missingCardIds,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ColpkgDeck {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_md5 = <String>::sse_decode(deserializer);
+        let mut var_deckId = <i64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_parentName = <Option<String>>::sse_decode(deserializer);
+        let mut var_mediaMap =
+            <std::collections::HashMap<String, String>>::sse_decode(deserializer);
+        let mut var_cardCount = <u32>::sse_decode(deserializer);
+        let mut var_scheduledCardCount = <u32>::sse_decode(deserializer);
+        return crate::api::simple::ColpkgDeck {
+            md5: var_md5,
+            deck_id: var_deckId,
+            name: var_name,
+            parent_name: var_parentName,
+            media_map: var_mediaMap,
+            card_count: var_cardCount,
+            scheduled_card_count: var_scheduledCardCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ColpkgImportResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_md5 = <String>::sse_decode(deserializer);
+        let mut var_version = <String>::sse_decode(deserializer);
+        let mut var_decks = <Vec<crate::api::simple::ColpkgDeck>>::sse_decode(deserializer);
+        return crate::api::simple::ColpkgImportResult {
+            md5: var_md5,
+            version: var_version,
+            decks: var_decks,
+        };
+    }
+}
+
+impl SseDecode for crate::api::error::ErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::error::ErrorKind::NotInitialized,
+            1 => crate::api::error::ErrorKind::NotFound,
+            2 => crate::api::error::ErrorKind::InvalidPackage,
+            3 => crate::api::error::ErrorKind::UnsafeEntry,
+            4 => crate::api::error::ErrorKind::TooLarge,
+            5 => crate::api::error::ErrorKind::DiskFull,
+            6 => crate::api::error::ErrorKind::PermissionDenied,
+            7 => crate::api::error::ErrorKind::Io,
+            8 => crate::api::error::ErrorKind::Database,
+            9 => crate::api::error::ErrorKind::Decode,
+            10 => crate::api::error::ErrorKind::InvalidArgument,
+            11 => crate::api::error::ErrorKind::Scheduler,
+            12 => crate::api::error::ErrorKind::Cancelled,
+            _ => unreachable!("Invalid variant for ErrorKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ExtractResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_md5 = <String>::sse_decode(deserializer);
+        let mut var_mediaMap =
+            <std::collections::HashMap<String, String>>::sse_decode(deserializer);
+        let mut var_version = <String>::sse_decode(deserializer);
+        let mut var_scheduledCardCount = <u32>::sse_decode(deserializer);
+        let mut var_summary = <crate::api::simple::MergeSummary>::sse_decode(deserializer);
+        return crate::api::simple::ExtractResult {
+            md5: var_md5,
+            media_map: var_mediaMap,
+            version: var_version,
+            scheduled_card_count: var_scheduledCardCount,
+            summary: var_summary,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::simple::FieldExt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_notetypeId = <i64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_ord = <i64>::sse_decode(deserializer);
+        let mut var_fontName = <String>::sse_decode(deserializer);
+        let mut var_fontSize = <i64>::sse_decode(deserializer);
+        let mut var_rtl = <bool>::sse_decode(deserializer);
+        let mut var_sticky = <bool>::sse_decode(deserializer);
+        return crate::api::simple::FieldExt {
+            id: var_id,
+            notetype_id: var_notetypeId,
+            name: var_name,
+            ord: var_ord,
+            font_name: var_fontName,
+            font_size: var_fontSize,
+            rtl: var_rtl,
+            sticky: var_sticky,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::FsrsScheduleResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_due = <i64>::sse_decode(deserializer);
+        let mut var_stability = <f64>::sse_decode(deserializer);
+        let mut var_difficulty = <f64>::sse_decode(deserializer);
+        return crate::api::simple::FsrsScheduleResult {
+            due: var_due,
+            stability: var_stability,
+            difficulty: var_difficulty,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::simple::ImportPhase {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::ImportPhase::Extracting,
+            1 => crate::api::simple::ImportPhase::Hashing,
+            2 => crate::api::simple::ImportPhase::Finalizing,
+            3 => crate::api::simple::ImportPhase::Scheduling,
+            4 => crate::api::simple::ImportPhase::Done,
+            5 => crate::api::simple::ImportPhase::Cancelled,
+            _ => unreachable!("Invalid variant for ImportPhase: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ImportProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_phase = <crate::api::simple::ImportPhase>::sse_decode(deserializer);
+        let mut var_bytesProcessed = <i64>::sse_decode(deserializer);
+        let mut var_totalBytes = <i64>::sse_decode(deserializer);
+        let mut var_entriesDone = <u32>::sse_decode(deserializer);
+        let mut var_entriesTotal = <u32>::sse_decode(deserializer);
+        let mut var_currentFile = <Option<String>>::sse_decode(deserializer);
+        let mut var_result = <Option<crate::api::simple::ExtractResult>>::sse_decode(deserializer);
+        return crate::api::simple::ImportProgress {
+            phase: var_phase,
+            bytes_processed: var_bytesProcessed,
+            total_bytes: var_totalBytes,
+            entries_done: var_entriesDone,
+            entries_total: var_entriesTotal,
+            current_file: var_currentFile,
+            result: var_result,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::IntradayLearningQueue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_due = <Vec<i64>>::sse_decode(deserializer);
+        let mut var_learnAhead = <Vec<i64>>::sse_decode(deserializer);
+        let mut var_nextDue = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::simple::IntradayLearningQueue {
+            due: var_due,
+            learn_ahead: var_learnAhead,
+            next_due: var_nextDue,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::LeechAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::LeechAction::Suspend,
+            1 => crate::api::simple::LeechAction::TagOnly,
+            _ => unreachable!("Invalid variant for LeechAction: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::LeechEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_deckId = <String>::sse_decode(deserializer);
+        let mut var_cardId = <i64>::sse_decode(deserializer);
+        let mut var_noteId = <i64>::sse_decode(deserializer);
+        let mut var_lapses = <i32>::sse_decode(deserializer);
+        let mut var_suspended = <bool>::sse_decode(deserializer);
+        return crate::api::simple::LeechEvent {
+            deck_id: var_deckId,
+            card_id: var_cardId,
+            note_id: var_noteId,
+            lapses: var_lapses,
+            suspended: var_suspended,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::BatchCard> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::BatchCard>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::BatchNotetype> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::BatchNotetype>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::BatchTemplate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::BatchTemplate>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::CardExt> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::CardExt>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::CardStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::CardStatus>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::ColpkgDeck> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::ColpkgDeck>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::FieldExt> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::FieldExt>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::QueuedCard> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::QueuedCard>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, String)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::ReviewLogEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::ReviewLogEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::SchedulePreview> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::SchedulePreview>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::simple::MergeResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_md5 = <String>::sse_decode(deserializer);
+        let mut var_version = <String>::sse_decode(deserializer);
+        let mut var_mediaMap =
+            <std::collections::HashMap<String, String>>::sse_decode(deserializer);
+        let mut var_summary = <crate::api::simple::MergeSummary>::sse_decode(deserializer);
+        return crate::api::simple::MergeResult {
+            md5: var_md5,
+            version: var_version,
+            media_map: var_mediaMap,
+            summary: var_summary,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::MergeSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_addedNotes = <u32>::sse_decode(deserializer);
+        let mut var_updatedNotes = <u32>::sse_decode(deserializer);
+        let mut var_unchangedNotes = <u32>::sse_decode(deserializer);
+        let mut var_removedNotes = <u32>::sse_decode(deserializer);
+        let mut var_skippedNotes = <u32>::sse_decode(deserializer);
+        let mut var_addedNotetypes = <u32>::sse_decode(deserializer);
+        let mut var_updatedNotetypes = <u32>::sse_decode(deserializer);
+        let mut var_addedNoteIds = <Vec<i64>>::sse_decode(deserializer);
+        let mut var_removedNoteIds = <Vec<i64>>::sse_decode(deserializer);
+        let mut var_addedCardIds = <Vec<i64>>::sse_decode(deserializer);
+        let mut var_removedCardIds = <Vec<i64>>::sse_decode(deserializer);
+        return crate::api::simple::MergeSummary {
+            added_notes: var_addedNotes,
+            updated_notes: var_updatedNotes,
+            unchanged_notes: var_unchangedNotes,
+            removed_notes: var_removedNotes,
+            skipped_notes: var_skippedNotes,
+            added_notetypes: var_addedNotetypes,
+            updated_notetypes: var_updatedNotetypes,
+            added_note_ids: var_addedNoteIds,
+            removed_note_ids: var_removedNoteIds,
+            added_card_ids: var_addedCardIds,
+            removed_card_ids: var_removedCardIds,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::NewCardGatherPriority {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::NewCardGatherPriority::Deck,
+            1 => crate::api::simple::NewCardGatherPriority::DeckThenRandomNotes,
+            2 => crate::api::simple::NewCardGatherPriority::LowestPosition,
+            3 => crate::api::simple::NewCardGatherPriority::HighestPosition,
+            4 => crate::api::simple::NewCardGatherPriority::RandomNotes,
+            5 => crate::api::simple::NewCardGatherPriority::RandomCards,
+            _ => unreachable!("Invalid variant for NewCardGatherPriority: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::NewCardQueue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cards = <Vec<crate::api::simple::QueuedCard>>::sse_decode(deserializer);
+        let mut var_introducedToday = <u32>::sse_decode(deserializer);
+        let mut var_available = <u32>::sse_decode(deserializer);
+        return crate::api::simple::NewCardQueue {
+            cards: var_cards,
+            introduced_today: var_introducedToday,
+            available: var_available,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::NewCardSortOrder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::NewCardSortOrder::Template,
+            1 => crate::api::simple::NewCardSortOrder::NoSort,
+            2 => crate::api::simple::NewCardSortOrder::TemplateThenRandom,
+            3 => crate::api::simple::NewCardSortOrder::RandomNoteThenTemplate,
+            4 => crate::api::simple::NewCardSortOrder::RandomCard,
+            _ => unreachable!("Invalid variant for NewCardSortOrder: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::NewReviewMix {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::NewReviewMix::MixWithReviews,
+            1 => crate::api::simple::NewReviewMix::ReviewsFirst,
+            2 => crate::api::simple::NewReviewMix::NewFirst,
+            _ => unreachable!("Invalid variant for NewReviewMix: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::NoteExt {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_dir = <String>::sse_decode(deserializer);
-        let mut var_md5 = <String>::sse_decode(deserializer);
-        let mut var_mediaMap =
-            <std::collections::HashMap<String, String>>::sse_decode(deserializer);
-        let mut var_version = <String>::sse_decode(deserializer);
-        return crate::api::simple::ExtractResult {
-            dir: var_dir,
-            md5: var_md5,
-            media_map: var_mediaMap,
-            version: var_version,
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_guid = <String>::sse_decode(deserializer);
+        let mut var_mid = <i64>::sse_decode(deserializer);
+        let mut var_flds = <Vec<String>>::sse_decode(deserializer);
+        let mut var_notetypeName = <String>::sse_decode(deserializer);
+        let mut var_fieldNames = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::simple::NoteExt {
+            id: var_id,
+            guid: var_guid,
+            mid: var_mid,
+            flds: var_flds,
+            notetype_name: var_notetypeName,
+            field_names: var_fieldNames,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::NotetypeExt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_config = <Option<String>>::sse_decode(deserializer);
+        return crate::api::simple::NotetypeExt {
+            id: var_id,
+            name: var_name,
+            config: var_config,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::simple::ExtractResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::simple::ExtractResult>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::simple::NotetypeExt> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::simple::NotetypeExt>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::simple::OptimizeResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::simple::OptimizeResult>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::simple::OptimizeProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_current = <u32>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        let mut var_result = <Option<crate::api::simple::OptimizeResult>>::sse_decode(deserializer);
+        return crate::api::simple::OptimizeProgress {
+            current: var_current,
+            total: var_total,
+            result: var_result,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::OptimizeResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_weights = <Vec<f64>>::sse_decode(deserializer);
+        let mut var_logLossBefore = <f64>::sse_decode(deserializer);
+        let mut var_logLossAfter = <f64>::sse_decode(deserializer);
+        let mut var_rmseBefore = <f64>::sse_decode(deserializer);
+        let mut var_rmseAfter = <f64>::sse_decode(deserializer);
+        let mut var_reviewCount = <u32>::sse_decode(deserializer);
+        return crate::api::simple::OptimizeResult {
+            weights: var_weights,
+            log_loss_before: var_logLossBefore,
+            log_loss_after: var_logLossAfter,
+            rmse_before: var_rmseBefore,
+            rmse_after: var_rmseAfter,
+            review_count: var_reviewCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::QueueKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::QueueKind::New,
+            1 => crate::api::simple::QueueKind::Learning,
+            2 => crate::api::simple::QueueKind::Review,
+            _ => unreachable!("Invalid variant for QueueKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::QueueLimits {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_newCards = <Option<u32>>::sse_decode(deserializer);
+        let mut var_reviews = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::simple::QueueLimits {
+            new_cards: var_newCards,
+            reviews: var_reviews,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::QueuedCard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cardId = <i64>::sse_decode(deserializer);
+        let mut var_noteId = <i64>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::simple::QueueKind>::sse_decode(deserializer);
+        return crate::api::simple::QueuedCard {
+            card_id: var_cardId,
+            note_id: var_noteId,
+            kind: var_kind,
+        };
+    }
+}
+
+impl SseDecode for (String, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <String>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::api::simple::RenderedCard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cardId = <i64>::sse_decode(deserializer);
+        let mut var_noteId = <i64>::sse_decode(deserializer);
+        let mut var_ord = <i64>::sse_decode(deserializer);
+        let mut var_question = <String>::sse_decode(deserializer);
+        let mut var_answer = <String>::sse_decode(deserializer);
+        let mut var_css = <String>::sse_decode(deserializer);
+        let mut var_diagnostics = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::simple::RenderedCard {
+            card_id: var_cardId,
+            note_id: var_noteId,
+            ord: var_ord,
+            question: var_question,
+            answer: var_answer,
+            css: var_css,
+            diagnostics: var_diagnostics,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ReviewLogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cardId = <i64>::sse_decode(deserializer);
+        let mut var_reviewTime = <i64>::sse_decode(deserializer);
+        let mut var_rating = <u8>::sse_decode(deserializer);
+        return crate::api::simple::ReviewLogEntry {
+            card_id: var_cardId,
+            review_time: var_reviewTime,
+            rating: var_rating,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ReviewQueue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cards = <Vec<crate::api::simple::QueuedCard>>::sse_decode(deserializer);
+        let mut var_newCount = <u32>::sse_decode(deserializer);
+        let mut var_learningCount = <u32>::sse_decode(deserializer);
+        let mut var_reviewCount = <u32>::sse_decode(deserializer);
+        let mut var_nextLearningDue = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::simple::ReviewQueue {
+            cards: var_cards,
+            new_count: var_newCount,
+            learning_count: var_learningCount,
+            review_count: var_reviewCount,
+            next_learning_due: var_nextLearningDue,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ReviewSortOrder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::ReviewSortOrder::Due,
+            1 => crate::api::simple::ReviewSortOrder::RetrievabilityAscending,
+            2 => crate::api::simple::ReviewSortOrder::Random,
+            3 => crate::api::simple::ReviewSortOrder::RelativeOverdueness,
+            _ => unreachable!("Invalid variant for ReviewSortOrder: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::SchedulePreview {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rating = <u8>::sse_decode(deserializer);
+        let mut var_due = <i64>::sse_decode(deserializer);
+        let mut var_intervalSeconds = <i64>::sse_decode(deserializer);
+        let mut var_intervalText = <String>::sse_decode(deserializer);
+        let mut var_card = <crate::api::simple::CardMemoryState>::sse_decode(deserializer);
+        return crate::api::simple::SchedulePreview {
+            rating: var_rating,
+            due: var_due,
+            interval_seconds: var_intervalSeconds,
+            interval_text: var_intervalText,
+            card: var_card,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::SchedulerAlgorithm {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::SchedulerAlgorithm::Fsrs,
+            1 => crate::api::simple::SchedulerAlgorithm::Sm2,
+            _ => unreachable!("Invalid variant for SchedulerAlgorithm: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::SchedulerConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_algorithm = <crate::api::simple::SchedulerAlgorithm>::sse_decode(deserializer);
+        let mut var_weights = <Vec<f64>>::sse_decode(deserializer);
+        let mut var_requestRetention = <f64>::sse_decode(deserializer);
+        let mut var_maximumInterval = <i32>::sse_decode(deserializer);
+        let mut var_enableFuzz = <bool>::sse_decode(deserializer);
+        let mut var_enableShortTerm = <bool>::sse_decode(deserializer);
+        let mut var_learnSteps = <Vec<f64>>::sse_decode(deserializer);
+        let mut var_relearnSteps = <Vec<f64>>::sse_decode(deserializer);
+        let mut var_graduatingIntervalGood = <i32>::sse_decode(deserializer);
+        let mut var_graduatingIntervalEasy = <i32>::sse_decode(deserializer);
+        let mut var_initialEase = <f64>::sse_decode(deserializer);
+        let mut var_easyMultiplier = <f64>::sse_decode(deserializer);
+        let mut var_hardMultiplier = <f64>::sse_decode(deserializer);
+        let mut var_lapseMultiplier = <f64>::sse_decode(deserializer);
+        let mut var_intervalMultiplier = <f64>::sse_decode(deserializer);
+        let mut var_minimumLapseInterval = <i32>::sse_decode(deserializer);
+        let mut var_learnAheadSecs = <i32>::sse_decode(deserializer);
+        let mut var_newPerDay = <u32>::sse_decode(deserializer);
+        let mut var_newCardGatherPriority =
+            <crate::api::simple::NewCardGatherPriority>::sse_decode(deserializer);
+        let mut var_newCardSortOrder =
+            <crate::api::simple::NewCardSortOrder>::sse_decode(deserializer);
+        let mut var_reviewsPerDay = <u32>::sse_decode(deserializer);
+        let mut var_reviewSortOrder =
+            <crate::api::simple::ReviewSortOrder>::sse_decode(deserializer);
+        let mut var_newMix = <crate::api::simple::NewReviewMix>::sse_decode(deserializer);
+        let mut var_buryNew = <bool>::sse_decode(deserializer);
+        let mut var_buryReviews = <bool>::sse_decode(deserializer);
+        let mut var_buryInterdayLearning = <bool>::sse_decode(deserializer);
+        let mut var_leechThreshold = <u32>::sse_decode(deserializer);
+        let mut var_leechAction = <crate::api::simple::LeechAction>::sse_decode(deserializer);
+        return crate::api::simple::SchedulerConfig {
+            algorithm: var_algorithm,
+            weights: var_weights,
+            request_retention: var_requestRetention,
+            maximum_interval: var_maximumInterval,
+            enable_fuzz: var_enableFuzz,
+            enable_short_term: var_enableShortTerm,
+            learn_steps: var_learnSteps,
+            relearn_steps: var_relearnSteps,
+            graduating_interval_good: var_graduatingIntervalGood,
+            graduating_interval_easy: var_graduatingIntervalEasy,
+            initial_ease: var_initialEase,
+            easy_multiplier: var_easyMultiplier,
+            hard_multiplier: var_hardMultiplier,
+            lapse_multiplier: var_lapseMultiplier,
+            interval_multiplier: var_intervalMultiplier,
+            minimum_lapse_interval: var_minimumLapseInterval,
+            learn_ahead_secs: var_learnAheadSecs,
+            new_per_day: var_newPerDay,
+            new_card_gather_priority: var_newCardGatherPriority,
+            new_card_sort_order: var_newCardSortOrder,
+            reviews_per_day: var_reviewsPerDay,
+            review_sort_order: var_reviewSortOrder,
+            new_mix: var_newMix,
+            bury_new: var_buryNew,
+            bury_reviews: var_buryReviews,
+            bury_interday_learning: var_buryInterdayLearning,
+            leech_threshold: var_leechThreshold,
+            leech_action: var_leechAction,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::SingleNoteResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_note = <crate::api::simple::NoteExt>::sse_decode(deserializer);
+        let mut var_notetype = <Option<crate::api::simple::NotetypeExt>>::sse_decode(deserializer);
+        let mut var_fields = <Vec<crate::api::simple::FieldExt>>::sse_decode(deserializer);
+        let mut var_ord = <i64>::sse_decode(deserializer);
+        let mut var_front = <String>::sse_decode(deserializer);
+        let mut var_back = <String>::sse_decode(deserializer);
+        let mut var_css = <String>::sse_decode(deserializer);
+        let mut var_latexPre = <String>::sse_decode(deserializer);
+        let mut var_latexPost = <String>::sse_decode(deserializer);
+        let mut var_sortFieldIdx = <i64>::sse_decode(deserializer);
+        return crate::api::simple::SingleNoteResult {
+            note: var_note,
+            notetype: var_notetype,
+            fields: var_fields,
+            ord: var_ord,
+            front: var_front,
+            back: var_back,
+            css: var_css,
+            latex_pre: var_latexPre,
+            latex_post: var_latexPost,
+            sort_field_idx: var_sortFieldIdx,
         };
     }
 }
-
-impl SseDecode for f64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__simple__answer_card_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__simple__build_review_queue_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__simple__bury_cards_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__simple__close_collection_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__simple__extract_apkg_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__simple__extract_apkg_with_progress_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__simple__extract_colpkg_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__simple__generate_cloze_cards_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__simple__get_all_note_ids_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__simple__get_card_count_impl(port, ptr, rust_vec_len, data_len),
+        12 => {
+            wire__crate__api__simple__get_card_memory_state_impl(port, ptr, rust_vec_len, data_len)
+        }
+        13 => wire__crate__api__simple__get_card_status_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__simple__get_cards_batch_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__simple__get_deck_note_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__simple__get_intraday_learning_queue_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        17 => wire__crate__api__simple__get_media_dir_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__simple__get_new_card_queue_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__simple__get_new_note_ids_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__simple__get_note_cards_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__simple__get_review_logs_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__simple__get_rollover_hour_impl(port, ptr, rust_vec_len, data_len),
+        23 => {
+            wire__crate__api__simple__get_scheduler_config_impl(port, ptr, rust_vec_len, data_len)
+        }
+        24 => {
+            wire__crate__api__simple__get_suspended_card_ids_impl(port, ptr, rust_vec_len, data_len)
+        }
+        26 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__simple__init_collection_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__simple__merge_apkg_impl(port, ptr, rust_vec_len, data_len),
+        29 => {
+            wire__crate__api__simple__merge_summary_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        31 => wire__crate__api__simple__open_collection_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__simple__optimize_fsrs_parameters_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => {
+            wire__crate__api__simple__preview_card_schedule_impl(port, ptr, rust_vec_len, data_len)
+        }
+        34 => wire__crate__api__simple__preview_card_schedule_simple_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        35 => wire__crate__api__simple__preview_schedule_impl(port, ptr, rust_vec_len, data_len),
+        36 => {
+            wire__crate__api__simple__queue_limits_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        37 => wire__crate__api__simple__register_leech_callback_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => {
+            wire__crate__api__simple__register_log_callback_impl(port, ptr, rust_vec_len, data_len)
+        }
+        39 => wire__crate__api__simple__remove_deck_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__simple__render_card_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__simple__schedule_card_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__simple__scheduler_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__crate__api__simple__set_card_flag_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__simple__set_rollover_hour_impl(port, ptr, rust_vec_len, data_len),
+        45 => {
+            wire__crate__api__simple__set_scheduler_config_impl(port, ptr, rust_vec_len, data_len)
+        }
+        46 => wire__crate__api__simple__suspend_cards_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__simple__toggle_note_marked_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__simple__unbury_cards_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__simple__unbury_deck_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__simple__unsuspend_cards_impl(port, ptr, rust_vec_len, data_len),
+        51 => {
+            wire__crate__api__simple__update_card_schedule_impl(port, ptr, rust_vec_len, data_len)
+        }
+        52 => wire__crate__api__simple__update_card_schedule_simple_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        4 => wire__crate__api__simple__cancel_import_impl(ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__simple__new_card_memory_state_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::error::AnkiError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.context.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::error::AnkiError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::error::AnkiError>
+    for crate::api::error::AnkiError
+{
+    fn into_into_dart(self) -> crate::api::error::AnkiError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::BatchCard {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.card_id.into_into_dart().into_dart(),
+            self.note_id.into_into_dart().into_dart(),
+            self.notetype_id.into_into_dart().into_dart(),
+            self.ord.into_into_dart().into_dart(),
+            self.template_ord.into_into_dart().into_dart(),
+            self.question.into_into_dart().into_dart(),
+            self.answer.into_into_dart().into_dart(),
+            self.diagnostics.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::BatchCard {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::BatchCard>
+    for crate::api::simple::BatchCard
+{
+    fn into_into_dart(self) -> crate::api::simple::BatchCard {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::BatchNotetype {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.is_cloze.into_into_dart().into_dart(),
+            self.css.into_into_dart().into_dart(),
+            self.field_names.into_into_dart().into_dart(),
+            self.templates.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::BatchNotetype
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::BatchNotetype>
+    for crate::api::simple::BatchNotetype
+{
+    fn into_into_dart(self) -> crate::api::simple::BatchNotetype {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::BatchTemplate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ord.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.qfmt.into_into_dart().into_dart(),
+            self.afmt.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::BatchTemplate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::BatchTemplate>
+    for crate::api::simple::BatchTemplate
+{
+    fn into_into_dart(self) -> crate::api::simple::BatchTemplate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CardExt {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.nid.into_into_dart().into_dart(),
+            self.ord.into_into_dart().into_dart(),
+            self.type_.into_into_dart().into_dart(),
+            self.queue.into_into_dart().into_dart(),
+            self.due.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::CardExt {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CardExt>
+    for crate::api::simple::CardExt
+{
+    fn into_into_dart(self) -> crate::api::simple::CardExt {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CardMemoryState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.state.into_into_dart().into_dart(),
+            self.due.into_into_dart().into_dart(),
+            self.stability.into_into_dart().into_dart(),
+            self.difficulty.into_into_dart().into_dart(),
+            self.elapsed_days.into_into_dart().into_dart(),
+            self.scheduled_days.into_into_dart().into_dart(),
+            self.reps.into_into_dart().into_dart(),
+            self.lapses.into_into_dart().into_dart(),
+            self.step.into_into_dart().into_dart(),
+            self.last_review.into_into_dart().into_dart(),
+            self.ease_factor.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::CardMemoryState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CardMemoryState>
+    for crate::api::simple::CardMemoryState
+{
+    fn into_into_dart(self) -> crate::api::simple::CardMemoryState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CardState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::New => 0.into_dart(),
+            Self::Learning => 1.into_dart(),
+            Self::Review => 2.into_dart(),
+            Self::Relearning => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::CardState {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CardState>
+    for crate::api::simple::CardState
+{
+    fn into_into_dart(self) -> crate::api::simple::CardState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CardStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.card_id.into_into_dart().into_dart(),
+            self.note_id.into_into_dart().into_dart(),
+            self.suspended.into_into_dart().into_dart(),
+            self.buried.into_into_dart().into_dart(),
+            self.flag.into_into_dart().into_dart(),
+            self.marked.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::CardStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CardStatus>
+    for crate::api::simple::CardStatus
+{
+    fn into_into_dart(self) -> crate::api::simple::CardStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::CardsBatch {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.cards.into_into_dart().into_dart(),
+            self.notetypes.into_into_dart().into_dart(),
+            self.missing_card_ids.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::CardsBatch
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::CardsBatch>
+    for crate::api::simple::CardsBatch
+{
+    fn into_into_dart(self) -> crate::api::simple::CardsBatch {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ColpkgDeck {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.md5.into_into_dart().into_dart(),
+            self.deck_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.parent_name.into_into_dart().into_dart(),
+            self.media_map.into_into_dart().into_dart(),
+            self.card_count.into_into_dart().into_dart(),
+            self.scheduled_card_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ColpkgDeck
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ColpkgDeck>
+    for crate::api::simple::ColpkgDeck
+{
+    fn into_into_dart(self) -> crate::api::simple::ColpkgDeck {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ColpkgImportResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.md5.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.decks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ColpkgImportResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ColpkgImportResult>
+    for crate::api::simple::ColpkgImportResult
+{
+    fn into_into_dart(self) -> crate::api::simple::ColpkgImportResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::error::ErrorKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NotInitialized => 0.into_dart(),
+            Self::NotFound => 1.into_dart(),
+            Self::InvalidPackage => 2.into_dart(),
+            Self::UnsafeEntry => 3.into_dart(),
+            Self::TooLarge => 4.into_dart(),
+            Self::DiskFull => 5.into_dart(),
+            Self::PermissionDenied => 6.into_dart(),
+            Self::Io => 7.into_dart(),
+            Self::Database => 8.into_dart(),
+            Self::Decode => 9.into_dart(),
+            Self::InvalidArgument => 10.into_dart(),
+            Self::Scheduler => 11.into_dart(),
+            Self::Cancelled => 12.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::error::ErrorKind {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::error::ErrorKind>
+    for crate::api::error::ErrorKind
+{
+    fn into_into_dart(self) -> crate::api::error::ErrorKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ExtractResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.md5.into_into_dart().into_dart(),
+            self.media_map.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.scheduled_card_count.into_into_dart().into_dart(),
+            self.summary.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ExtractResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ExtractResult>
+    for crate::api::simple::ExtractResult
+{
+    fn into_into_dart(self) -> crate::api::simple::ExtractResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::FieldExt {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.notetype_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.ord.into_into_dart().into_dart(),
+            self.font_name.into_into_dart().into_dart(),
+            self.font_size.into_into_dart().into_dart(),
+            self.rtl.into_into_dart().into_dart(),
+            self.sticky.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::FieldExt {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::FieldExt>
+    for crate::api::simple::FieldExt
+{
+    fn into_into_dart(self) -> crate::api::simple::FieldExt {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::FsrsScheduleResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.due.into_into_dart().into_dart(),
+            self.stability.into_into_dart().into_dart(),
+            self.difficulty.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::FsrsScheduleResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::FsrsScheduleResult>
+    for crate::api::simple::FsrsScheduleResult
+{
+    fn into_into_dart(self) -> crate::api::simple::FsrsScheduleResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ImportPhase {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Extracting => 0.into_dart(),
+            Self::Hashing => 1.into_dart(),
+            Self::Finalizing => 2.into_dart(),
+            Self::Scheduling => 3.into_dart(),
+            Self::Done => 4.into_dart(),
+            Self::Cancelled => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ImportPhase
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ImportPhase>
+    for crate::api::simple::ImportPhase
+{
+    fn into_into_dart(self) -> crate::api::simple::ImportPhase {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ImportProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.phase.into_into_dart().into_dart(),
+            self.bytes_processed.into_into_dart().into_dart(),
+            self.total_bytes.into_into_dart().into_dart(),
+            self.entries_done.into_into_dart().into_dart(),
+            self.entries_total.into_into_dart().into_dart(),
+            self.current_file.into_into_dart().into_dart(),
+            self.result.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ImportProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ImportProgress>
+    for crate::api::simple::ImportProgress
+{
+    fn into_into_dart(self) -> crate::api::simple::ImportProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::IntradayLearningQueue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.due.into_into_dart().into_dart(),
+            self.learn_ahead.into_into_dart().into_dart(),
+            self.next_due.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::IntradayLearningQueue
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::IntradayLearningQueue>
+    for crate::api::simple::IntradayLearningQueue
+{
+    fn into_into_dart(self) -> crate::api::simple::IntradayLearningQueue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::LeechAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Suspend => 0.into_dart(),
+            Self::TagOnly => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::LeechAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::LeechAction>
+    for crate::api::simple::LeechAction
+{
+    fn into_into_dart(self) -> crate::api::simple::LeechAction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::LeechEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.deck_id.into_into_dart().into_dart(),
+            self.card_id.into_into_dart().into_dart(),
+            self.note_id.into_into_dart().into_dart(),
+            self.lapses.into_into_dart().into_dart(),
+            self.suspended.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::LeechEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::LeechEvent>
+    for crate::api::simple::LeechEvent
+{
+    fn into_into_dart(self) -> crate::api::simple::LeechEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::MergeResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.md5.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.media_map.into_into_dart().into_dart(),
+            self.summary.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::MergeResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::MergeResult>
+    for crate::api::simple::MergeResult
+{
+    fn into_into_dart(self) -> crate::api::simple::MergeResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::MergeSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.added_notes.into_into_dart().into_dart(),
+            self.updated_notes.into_into_dart().into_dart(),
+            self.unchanged_notes.into_into_dart().into_dart(),
+            self.removed_notes.into_into_dart().into_dart(),
+            self.skipped_notes.into_into_dart().into_dart(),
+            self.added_notetypes.into_into_dart().into_dart(),
+            self.updated_notetypes.into_into_dart().into_dart(),
+            self.added_note_ids.into_into_dart().into_dart(),
+            self.removed_note_ids.into_into_dart().into_dart(),
+            self.added_card_ids.into_into_dart().into_dart(),
+            self.removed_card_ids.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::MergeSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::MergeSummary>
+    for crate::api::simple::MergeSummary
+{
+    fn into_into_dart(self) -> crate::api::simple::MergeSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::NewCardGatherPriority {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Deck => 0.into_dart(),
+            Self::DeckThenRandomNotes => 1.into_dart(),
+            Self::LowestPosition => 2.into_dart(),
+            Self::HighestPosition => 3.into_dart(),
+            Self::RandomNotes => 4.into_dart(),
+            Self::RandomCards => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::NewCardGatherPriority
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::NewCardGatherPriority>
+    for crate::api::simple::NewCardGatherPriority
+{
+    fn into_into_dart(self) -> crate::api::simple::NewCardGatherPriority {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::NewCardQueue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.cards.into_into_dart().into_dart(),
+            self.introduced_today.into_into_dart().into_dart(),
+            self.available.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::NewCardQueue
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::NewCardQueue>
+    for crate::api::simple::NewCardQueue
+{
+    fn into_into_dart(self) -> crate::api::simple::NewCardQueue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::NewCardSortOrder {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Template => 0.into_dart(),
+            Self::NoSort => 1.into_dart(),
+            Self::TemplateThenRandom => 2.into_dart(),
+            Self::RandomNoteThenTemplate => 3.into_dart(),
+            Self::RandomCard => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::NewCardSortOrder
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::NewCardSortOrder>
+    for crate::api::simple::NewCardSortOrder
+{
+    fn into_into_dart(self) -> crate::api::simple::NewCardSortOrder {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::NewReviewMix {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::MixWithReviews => 0.into_dart(),
+            Self::ReviewsFirst => 1.into_dart(),
+            Self::NewFirst => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::NewReviewMix
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::NewReviewMix>
+    for crate::api::simple::NewReviewMix
+{
+    fn into_into_dart(self) -> crate::api::simple::NewReviewMix {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::NoteExt {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.guid.into_into_dart().into_dart(),
+            self.mid.into_into_dart().into_dart(),
+            self.flds.into_into_dart().into_dart(),
+            self.notetype_name.into_into_dart().into_dart(),
+            self.field_names.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::NoteExt {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::NoteExt>
+    for crate::api::simple::NoteExt
+{
+    fn into_into_dart(self) -> crate::api::simple::NoteExt {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::NotetypeExt {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.config.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::NotetypeExt
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::NotetypeExt>
+    for crate::api::simple::NotetypeExt
+{
+    fn into_into_dart(self) -> crate::api::simple::NotetypeExt {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::OptimizeProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.current.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+            self.result.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::OptimizeProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::OptimizeProgress>
+    for crate::api::simple::OptimizeProgress
+{
+    fn into_into_dart(self) -> crate::api::simple::OptimizeProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::OptimizeResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.weights.into_into_dart().into_dart(),
+            self.log_loss_before.into_into_dart().into_dart(),
+            self.log_loss_after.into_into_dart().into_dart(),
+            self.rmse_before.into_into_dart().into_dart(),
+            self.rmse_after.into_into_dart().into_dart(),
+            self.review_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::OptimizeResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::OptimizeResult>
+    for crate::api::simple::OptimizeResult
+{
+    fn into_into_dart(self) -> crate::api::simple::OptimizeResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::QueueKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::New => 0.into_dart(),
+            Self::Learning => 1.into_dart(),
+            Self::Review => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::QueueKind {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::QueueKind>
+    for crate::api::simple::QueueKind
+{
+    fn into_into_dart(self) -> crate::api::simple::QueueKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::QueueLimits {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.new_cards.into_into_dart().into_dart(),
+            self.reviews.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::QueueLimits
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::QueueLimits>
+    for crate::api::simple::QueueLimits
+{
+    fn into_into_dart(self) -> crate::api::simple::QueueLimits {
+        self
     }
 }
-
-impl SseDecode for crate::api::simple::FieldExt {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <i64>::sse_decode(deserializer);
-        let mut var_notetypeId = <i64>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_ord = <i64>::sse_decode(deserializer);
-        return crate::api::simple::FieldExt {
-            id: var_id,
-            notetype_id: var_notetypeId,
-            name: var_name,
-            ord: var_ord,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::QueuedCard {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.card_id.into_into_dart().into_dart(),
+            self.note_id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::simple::FsrsScheduleResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_due = <i64>::sse_decode(deserializer);
-        let mut var_stability = <f64>::sse_decode(deserializer);
-        let mut var_difficulty = <f64>::sse_decode(deserializer);
-        return crate::api::simple::FsrsScheduleResult {
-            due: var_due,
-            stability: var_stability,
-            difficulty: var_difficulty,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::QueuedCard
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::QueuedCard>
+    for crate::api::simple::QueuedCard
+{
+    fn into_into_dart(self) -> crate::api::simple::QueuedCard {
+        self
     }
 }
-
-impl SseDecode for i64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::RenderedCard {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.card_id.into_into_dart().into_dart(),
+            self.note_id.into_into_dart().into_dart(),
+            self.ord.into_into_dart().into_dart(),
+            self.question.into_into_dart().into_dart(),
+            self.answer.into_into_dart().into_dart(),
+            self.css.into_into_dart().into_dart(),
+            self.diagnostics.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::RenderedCard
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::RenderedCard>
+    for crate::api::simple::RenderedCard
+{
+    fn into_into_dart(self) -> crate::api::simple::RenderedCard {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ReviewLogEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.card_id.into_into_dart().into_dart(),
+            self.review_time.into_into_dart().into_dart(),
+            self.rating.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ReviewLogEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ReviewLogEntry>
+    for crate::api::simple::ReviewLogEntry
+{
+    fn into_into_dart(self) -> crate::api::simple::ReviewLogEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ReviewQueue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.cards.into_into_dart().into_dart(),
+            self.new_count.into_into_dart().into_dart(),
+            self.learning_count.into_into_dart().into_dart(),
+            self.review_count.into_into_dart().into_dart(),
+            self.next_learning_due.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ReviewQueue
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ReviewQueue>
+    for crate::api::simple::ReviewQueue
+{
+    fn into_into_dart(self) -> crate::api::simple::ReviewQueue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ReviewSortOrder {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Due => 0.into_dart(),
+            Self::RetrievabilityAscending => 1.into_dart(),
+            Self::Random => 2.into_dart(),
+            Self::RelativeOverdueness => 3.into_dart(),
+            _ => unreachable!(),
         }
-        return ans_;
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ReviewSortOrder
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ReviewSortOrder>
+    for crate::api::simple::ReviewSortOrder
+{
+    fn into_into_dart(self) -> crate::api::simple::ReviewSortOrder {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::SchedulePreview {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rating.into_into_dart().into_dart(),
+            self.due.into_into_dart().into_dart(),
+            self.interval_seconds.into_into_dart().into_dart(),
+            self.interval_text.into_into_dart().into_dart(),
+            self.card.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::SchedulePreview
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::SchedulePreview>
+    for crate::api::simple::SchedulePreview
+{
+    fn into_into_dart(self) -> crate::api::simple::SchedulePreview {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::SchedulerAlgorithm {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Fsrs => 0.into_dart(),
+            Self::Sm2 => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::SchedulerAlgorithm
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::SchedulerAlgorithm>
+    for crate::api::simple::SchedulerAlgorithm
+{
+    fn into_into_dart(self) -> crate::api::simple::SchedulerAlgorithm {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::SchedulerConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.algorithm.into_into_dart().into_dart(),
+            self.weights.into_into_dart().into_dart(),
+            self.request_retention.into_into_dart().into_dart(),
+            self.maximum_interval.into_into_dart().into_dart(),
+            self.enable_fuzz.into_into_dart().into_dart(),
+            self.enable_short_term.into_into_dart().into_dart(),
+            self.learn_steps.into_into_dart().into_dart(),
+            self.relearn_steps.into_into_dart().into_dart(),
+            self.graduating_interval_good.into_into_dart().into_dart(),
+            self.graduating_interval_easy.into_into_dart().into_dart(),
+            self.initial_ease.into_into_dart().into_dart(),
+            self.easy_multiplier.into_into_dart().into_dart(),
+            self.hard_multiplier.into_into_dart().into_dart(),
+            self.lapse_multiplier.into_into_dart().into_dart(),
+            self.interval_multiplier.into_into_dart().into_dart(),
+            self.minimum_lapse_interval.into_into_dart().into_dart(),
+            self.learn_ahead_secs.into_into_dart().into_dart(),
+            self.new_per_day.into_into_dart().into_dart(),
+            self.new_card_gather_priority.into_into_dart().into_dart(),
+            self.new_card_sort_order.into_into_dart().into_dart(),
+            self.reviews_per_day.into_into_dart().into_dart(),
+            self.review_sort_order.into_into_dart().into_dart(),
+            self.new_mix.into_into_dart().into_dart(),
+            self.bury_new.into_into_dart().into_dart(),
+            self.bury_reviews.into_into_dart().into_dart(),
+            self.bury_interday_learning.into_into_dart().into_dart(),
+            self.leech_threshold.into_into_dart().into_dart(),
+            self.leech_action.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::SchedulerConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::SchedulerConfig>
+    for crate::api::simple::SchedulerConfig
+{
+    fn into_into_dart(self) -> crate::api::simple::SchedulerConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::SingleNoteResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.note.into_into_dart().into_dart(),
+            self.notetype.into_into_dart().into_dart(),
+            self.fields.into_into_dart().into_dart(),
+            self.ord.into_into_dart().into_dart(),
+            self.front.into_into_dart().into_dart(),
+            self.back.into_into_dart().into_dart(),
+            self.css.into_into_dart().into_dart(),
+            self.latex_pre.into_into_dart().into_dart(),
+            self.latex_post.into_into_dart().into_dart(),
+            self.sort_field_idx.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::SingleNoteResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::SingleNoteResult>
+    for crate::api::simple::SingleNoteResult
+{
+    fn into_into_dart(self) -> crate::api::simple::SingleNoteResult {
+        self
     }
 }
 
-impl SseDecode for Vec<crate::api::simple::FieldExt> {
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::simple::FieldExt>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
     }
 }
 
-impl SseDecode for Vec<i64> {
+impl SseEncode for std::collections::HashMap<String, String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<i64>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for StreamSink<String, flutter_rust_bridge::for_generated::SseCodec> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for Vec<(String, String)> {
+impl SseEncode
+    for StreamSink<crate::api::simple::ImportProgress, flutter_rust_bridge::for_generated::SseCodec>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, String)>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for crate::api::simple::NoteExt {
+impl SseEncode
+    for StreamSink<crate::api::simple::LeechEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <i64>::sse_decode(deserializer);
-        let mut var_guid = <String>::sse_decode(deserializer);
-        let mut var_mid = <i64>::sse_decode(deserializer);
-        let mut var_flds = <Vec<String>>::sse_decode(deserializer);
-        let mut var_notetypeName = <String>::sse_decode(deserializer);
-        let mut var_fieldNames = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::simple::NoteExt {
-            id: var_id,
-            guid: var_guid,
-            mid: var_mid,
-            flds: var_flds,
-            notetype_name: var_notetypeName,
-            field_names: var_fieldNames,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for crate::api::simple::NotetypeExt {
+impl SseEncode
+    for StreamSink<
+        crate::api::simple::OptimizeProgress,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <i64>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_config = <Option<String>>::sse_decode(deserializer);
-        return crate::api::simple::NotetypeExt {
-            id: var_id,
-            name: var_name,
-            config: var_config,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
 
-impl SseDecode for Option<crate::api::simple::NotetypeExt> {
+impl SseEncode for crate::api::error::AnkiError {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::simple::NotetypeExt>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::error::ErrorKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.context, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.path, serializer);
     }
 }
 
-impl SseDecode for (String, String) {
+impl SseEncode for crate::api::simple::BatchCard {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <String>::sse_decode(deserializer);
-        let mut var_field1 = <String>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.card_id, serializer);
+        <i64>::sse_encode(self.note_id, serializer);
+        <i64>::sse_encode(self.notetype_id, serializer);
+        <i64>::sse_encode(self.ord, serializer);
+        <i64>::sse_encode(self.template_ord, serializer);
+        <String>::sse_encode(self.question, serializer);
+        <String>::sse_encode(self.answer, serializer);
+        <Vec<String>>::sse_encode(self.diagnostics, serializer);
     }
 }
 
-impl SseDecode for crate::api::simple::SingleNoteResult {
+impl SseEncode for crate::api::simple::BatchNotetype {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_note = <crate::api::simple::NoteExt>::sse_decode(deserializer);
-        let mut var_notetype = <Option<crate::api::simple::NotetypeExt>>::sse_decode(deserializer);
-        let mut var_fields = <Vec<crate::api::simple::FieldExt>>::sse_decode(deserializer);
-        let mut var_ord = <i64>::sse_decode(deserializer);
-        let mut var_front = <String>::sse_decode(deserializer);
-        let mut var_back = <String>::sse_decode(deserializer);
-        let mut var_css = <String>::sse_decode(deserializer);
-        return crate::api::simple::SingleNoteResult {
-            note: var_note,
-            notetype: var_notetype,
-            fields: var_fields,
-            ord: var_ord,
-            front: var_front,
-            back: var_back,
-            css: var_css,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <bool>::sse_encode(self.is_cloze, serializer);
+        <String>::sse_encode(self.css, serializer);
+        <Vec<String>>::sse_encode(self.field_names, serializer);
+        <Vec<crate::api::simple::BatchTemplate>>::sse_encode(self.templates, serializer);
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::api::simple::BatchTemplate {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.ord, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.qfmt, serializer);
+        <String>::sse_encode(self.afmt, serializer);
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
 }
 
-impl SseDecode for usize {
+impl SseEncode for crate::api::simple::CardExt {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <i64>::sse_encode(self.nid, serializer);
+        <i64>::sse_encode(self.ord, serializer);
+        <i64>::sse_encode(self.type_, serializer);
+        <i64>::sse_encode(self.queue, serializer);
+        <i64>::sse_encode(self.due, serializer);
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for crate::api::simple::CardMemoryState {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::simple::CardState>::sse_encode(self.state, serializer);
+        <i64>::sse_encode(self.due, serializer);
+        <f64>::sse_encode(self.stability, serializer);
+        <f64>::sse_encode(self.difficulty, serializer);
+        <i64>::sse_encode(self.elapsed_days, serializer);
+        <i64>::sse_encode(self.scheduled_days, serializer);
+        <i32>::sse_encode(self.reps, serializer);
+        <i32>::sse_encode(self.lapses, serializer);
+        <i32>::sse_encode(self.step, serializer);
+        <Option<i64>>::sse_encode(self.last_review, serializer);
+        <f64>::sse_encode(self.ease_factor, serializer);
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for crate::api::simple::CardState {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::CardState::New => 0,
+                crate::api::simple::CardState::Learning => 1,
+                crate::api::simple::CardState::Review => 2,
+                crate::api::simple::CardState::Relearning => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__simple__extract_apkg_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__simple__get_all_note_ids_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__simple__get_card_count_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__simple__get_card_count_from_deck_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        5 => wire__crate__api__simple__get_deck_note_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__simple__get_new_note_ids_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        9 => {
-            wire__crate__api__simple__register_log_callback_impl(port, ptr, rust_vec_len, data_len)
-        }
-        10 => {
-            wire__crate__api__simple__update_card_schedule_impl(port, ptr, rust_vec_len, data_len)
-        }
-        11 => wire__crate__api__simple__update_card_schedule_simple_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
+impl SseEncode for crate::api::simple::CardStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.card_id, serializer);
+        <i64>::sse_encode(self.note_id, serializer);
+        <bool>::sse_encode(self.suspended, serializer);
+        <bool>::sse_encode(self.buried, serializer);
+        <u8>::sse_encode(self.flag, serializer);
+        <bool>::sse_encode(self.marked, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        7 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::simple::CardsBatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::simple::BatchCard>>::sse_encode(self.cards, serializer);
+        <Vec<crate::api::simple::BatchNotetype>>::sse_encode(self.notetypes, serializer);
+        <Vec<i64>>::sse_encode(self.missing_card_ids, serializer);
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::ExtractResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.dir.into_into_dart().into_dart(),
-            self.md5.into_into_dart().into_dart(),
-            self.media_map.into_into_dart().into_dart(),
-            self.version.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::ExtractResult
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ExtractResult>
-    for crate::api::simple::ExtractResult
-{
-    fn into_into_dart(self) -> crate::api::simple::ExtractResult {
-        self
+impl SseEncode for crate::api::simple::ColpkgDeck {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.md5, serializer);
+        <i64>::sse_encode(self.deck_id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.parent_name, serializer);
+        <std::collections::HashMap<String, String>>::sse_encode(self.media_map, serializer);
+        <u32>::sse_encode(self.card_count, serializer);
+        <u32>::sse_encode(self.scheduled_card_count, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::FieldExt {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.notetype_id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.ord.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::simple::ColpkgImportResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.md5, serializer);
+        <String>::sse_encode(self.version, serializer);
+        <Vec<crate::api::simple::ColpkgDeck>>::sse_encode(self.decks, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::FieldExt {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::FieldExt>
-    for crate::api::simple::FieldExt
-{
-    fn into_into_dart(self) -> crate::api::simple::FieldExt {
-        self
+
+impl SseEncode for crate::api::error::ErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::error::ErrorKind::NotInitialized => 0,
+                crate::api::error::ErrorKind::NotFound => 1,
+                crate::api::error::ErrorKind::InvalidPackage => 2,
+                crate::api::error::ErrorKind::UnsafeEntry => 3,
+                crate::api::error::ErrorKind::TooLarge => 4,
+                crate::api::error::ErrorKind::DiskFull => 5,
+                crate::api::error::ErrorKind::PermissionDenied => 6,
+                crate::api::error::ErrorKind::Io => 7,
+                crate::api::error::ErrorKind::Database => 8,
+                crate::api::error::ErrorKind::Decode => 9,
+                crate::api::error::ErrorKind::InvalidArgument => 10,
+                crate::api::error::ErrorKind::Scheduler => 11,
+                crate::api::error::ErrorKind::Cancelled => 12,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::FsrsScheduleResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.due.into_into_dart().into_dart(),
-            self.stability.into_into_dart().into_dart(),
-            self.difficulty.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::simple::ExtractResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.md5, serializer);
+        <std::collections::HashMap<String, String>>::sse_encode(self.media_map, serializer);
+        <String>::sse_encode(self.version, serializer);
+        <u32>::sse_encode(self.scheduled_card_count, serializer);
+        <crate::api::simple::MergeSummary>::sse_encode(self.summary, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::FsrsScheduleResult
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::FsrsScheduleResult>
-    for crate::api::simple::FsrsScheduleResult
-{
-    fn into_into_dart(self) -> crate::api::simple::FsrsScheduleResult {
-        self
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::NoteExt {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.guid.into_into_dart().into_dart(),
-            self.mid.into_into_dart().into_dart(),
-            self.flds.into_into_dart().into_dart(),
-            self.notetype_name.into_into_dart().into_dart(),
-            self.field_names.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::simple::FieldExt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <i64>::sse_encode(self.notetype_id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <i64>::sse_encode(self.ord, serializer);
+        <String>::sse_encode(self.font_name, serializer);
+        <i64>::sse_encode(self.font_size, serializer);
+        <bool>::sse_encode(self.rtl, serializer);
+        <bool>::sse_encode(self.sticky, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::NoteExt {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::NoteExt>
-    for crate::api::simple::NoteExt
-{
-    fn into_into_dart(self) -> crate::api::simple::NoteExt {
-        self
+
+impl SseEncode for crate::api::simple::FsrsScheduleResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.due, serializer);
+        <f64>::sse_encode(self.stability, serializer);
+        <f64>::sse_encode(self.difficulty, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::NotetypeExt {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.config.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::NotetypeExt
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::NotetypeExt>
-    for crate::api::simple::NotetypeExt
-{
-    fn into_into_dart(self) -> crate::api::simple::NotetypeExt {
-        self
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::SingleNoteResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.note.into_into_dart().into_dart(),
-            self.notetype.into_into_dart().into_dart(),
-            self.fields.into_into_dart().into_dart(),
-            self.ord.into_into_dart().into_dart(),
-            self.front.into_into_dart().into_dart(),
-            self.back.into_into_dart().into_dart(),
-            self.css.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::simple::ImportPhase {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::ImportPhase::Extracting => 0,
+                crate::api::simple::ImportPhase::Hashing => 1,
+                crate::api::simple::ImportPhase::Finalizing => 2,
+                crate::api::simple::ImportPhase::Scheduling => 3,
+                crate::api::simple::ImportPhase::Done => 4,
+                crate::api::simple::ImportPhase::Cancelled => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::SingleNoteResult
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::SingleNoteResult>
-    for crate::api::simple::SingleNoteResult
-{
-    fn into_into_dart(self) -> crate::api::simple::SingleNoteResult {
-        self
+
+impl SseEncode for crate::api::simple::ImportProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::simple::ImportPhase>::sse_encode(self.phase, serializer);
+        <i64>::sse_encode(self.bytes_processed, serializer);
+        <i64>::sse_encode(self.total_bytes, serializer);
+        <u32>::sse_encode(self.entries_done, serializer);
+        <u32>::sse_encode(self.entries_total, serializer);
+        <Option<String>>::sse_encode(self.current_file, serializer);
+        <Option<crate::api::simple::ExtractResult>>::sse_encode(self.result, serializer);
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for crate::api::simple::IntradayLearningQueue {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <Vec<i64>>::sse_encode(self.due, serializer);
+        <Vec<i64>>::sse_encode(self.learn_ahead, serializer);
+        <Option<i64>>::sse_encode(self.next_due, serializer);
     }
 }
 
-impl SseEncode for std::collections::HashMap<String, String> {
+impl SseEncode for crate::api::simple::LeechAction {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::LeechAction::Suspend => 0,
+                crate::api::simple::LeechAction::TagOnly => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for StreamSink<String, flutter_rust_bridge::for_generated::SseCodec> {
+impl SseEncode for crate::api::simple::LeechEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <String>::sse_encode(self.deck_id, serializer);
+        <i64>::sse_encode(self.card_id, serializer);
+        <i64>::sse_encode(self.note_id, serializer);
+        <i32>::sse_encode(self.lapses, serializer);
+        <bool>::sse_encode(self.suspended, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::simple::ExtractResult {
+impl SseEncode for Vec<crate::api::simple::BatchCard> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.dir, serializer);
-        <String>::sse_encode(self.md5, serializer);
-        <std::collections::HashMap<String, String>>::sse_encode(self.media_map, serializer);
-        <String>::sse_encode(self.version, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::BatchCard>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for Vec<crate::api::simple::BatchNotetype> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::BatchNotetype>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::simple::FieldExt {
+impl SseEncode for Vec<crate::api::simple::BatchTemplate> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i64>::sse_encode(self.id, serializer);
-        <i64>::sse_encode(self.notetype_id, serializer);
-        <String>::sse_encode(self.name, serializer);
-        <i64>::sse_encode(self.ord, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::BatchTemplate>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::simple::FsrsScheduleResult {
+impl SseEncode for Vec<crate::api::simple::CardExt> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i64>::sse_encode(self.due, serializer);
-        <f64>::sse_encode(self.stability, serializer);
-        <f64>::sse_encode(self.difficulty, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::CardExt>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for Vec<crate::api::simple::CardStatus> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::CardStatus>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for Vec<crate::api::simple::ColpkgDeck> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <String>::sse_encode(item, serializer);
+            <crate::api::simple::ColpkgDeck>::sse_encode(item, serializer);
         }
     }
 }
@@ -1005,6 +4580,16 @@ impl SseEncode for Vec<crate::api::simple::FieldExt> {
     }
 }
 
+impl SseEncode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f64>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1025,6 +4610,16 @@ impl SseEncode for Vec<u8> {
     }
 }
 
+impl SseEncode for Vec<crate::api::simple::QueuedCard> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::QueuedCard>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<(String, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1035,6 +4630,118 @@ impl SseEncode for Vec<(String, String)> {
     }
 }
 
+impl SseEncode for Vec<crate::api::simple::ReviewLogEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::ReviewLogEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::SchedulePreview> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::SchedulePreview>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::simple::MergeResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.md5, serializer);
+        <String>::sse_encode(self.version, serializer);
+        <std::collections::HashMap<String, String>>::sse_encode(self.media_map, serializer);
+        <crate::api::simple::MergeSummary>::sse_encode(self.summary, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::MergeSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.added_notes, serializer);
+        <u32>::sse_encode(self.updated_notes, serializer);
+        <u32>::sse_encode(self.unchanged_notes, serializer);
+        <u32>::sse_encode(self.removed_notes, serializer);
+        <u32>::sse_encode(self.skipped_notes, serializer);
+        <u32>::sse_encode(self.added_notetypes, serializer);
+        <u32>::sse_encode(self.updated_notetypes, serializer);
+        <Vec<i64>>::sse_encode(self.added_note_ids, serializer);
+        <Vec<i64>>::sse_encode(self.removed_note_ids, serializer);
+        <Vec<i64>>::sse_encode(self.added_card_ids, serializer);
+        <Vec<i64>>::sse_encode(self.removed_card_ids, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::NewCardGatherPriority {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::NewCardGatherPriority::Deck => 0,
+                crate::api::simple::NewCardGatherPriority::DeckThenRandomNotes => 1,
+                crate::api::simple::NewCardGatherPriority::LowestPosition => 2,
+                crate::api::simple::NewCardGatherPriority::HighestPosition => 3,
+                crate::api::simple::NewCardGatherPriority::RandomNotes => 4,
+                crate::api::simple::NewCardGatherPriority::RandomCards => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::NewCardQueue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::simple::QueuedCard>>::sse_encode(self.cards, serializer);
+        <u32>::sse_encode(self.introduced_today, serializer);
+        <u32>::sse_encode(self.available, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::NewCardSortOrder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::NewCardSortOrder::Template => 0,
+                crate::api::simple::NewCardSortOrder::NoSort => 1,
+                crate::api::simple::NewCardSortOrder::TemplateThenRandom => 2,
+                crate::api::simple::NewCardSortOrder::RandomNoteThenTemplate => 3,
+                crate::api::simple::NewCardSortOrder::RandomCard => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::NewReviewMix {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::NewReviewMix::MixWithReviews => 0,
+                crate::api::simple::NewReviewMix::ReviewsFirst => 1,
+                crate::api::simple::NewReviewMix::NewFirst => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::simple::NoteExt {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1066,6 +4773,26 @@ impl SseEncode for Option<String> {
     }
 }
 
+impl SseEncode for Option<crate::api::simple::ExtractResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::simple::ExtractResult>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::simple::NotetypeExt> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1076,6 +4803,81 @@ impl SseEncode for Option<crate::api::simple::NotetypeExt> {
     }
 }
 
+impl SseEncode for Option<crate::api::simple::OptimizeResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::simple::OptimizeResult>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::simple::OptimizeProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.current, serializer);
+        <u32>::sse_encode(self.total, serializer);
+        <Option<crate::api::simple::OptimizeResult>>::sse_encode(self.result, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::OptimizeResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<f64>>::sse_encode(self.weights, serializer);
+        <f64>::sse_encode(self.log_loss_before, serializer);
+        <f64>::sse_encode(self.log_loss_after, serializer);
+        <f64>::sse_encode(self.rmse_before, serializer);
+        <f64>::sse_encode(self.rmse_after, serializer);
+        <u32>::sse_encode(self.review_count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::QueueKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::QueueKind::New => 0,
+                crate::api::simple::QueueKind::Learning => 1,
+                crate::api::simple::QueueKind::Review => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::QueueLimits {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u32>>::sse_encode(self.new_cards, serializer);
+        <Option<u32>>::sse_encode(self.reviews, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::QueuedCard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.card_id, serializer);
+        <i64>::sse_encode(self.note_id, serializer);
+        <crate::api::simple::QueueKind>::sse_encode(self.kind, serializer);
+    }
+}
+
 impl SseEncode for (String, String) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1084,6 +4886,121 @@ impl SseEncode for (String, String) {
     }
 }
 
+impl SseEncode for crate::api::simple::RenderedCard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.card_id, serializer);
+        <i64>::sse_encode(self.note_id, serializer);
+        <i64>::sse_encode(self.ord, serializer);
+        <String>::sse_encode(self.question, serializer);
+        <String>::sse_encode(self.answer, serializer);
+        <String>::sse_encode(self.css, serializer);
+        <Vec<String>>::sse_encode(self.diagnostics, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::ReviewLogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.card_id, serializer);
+        <i64>::sse_encode(self.review_time, serializer);
+        <u8>::sse_encode(self.rating, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::ReviewQueue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::simple::QueuedCard>>::sse_encode(self.cards, serializer);
+        <u32>::sse_encode(self.new_count, serializer);
+        <u32>::sse_encode(self.learning_count, serializer);
+        <u32>::sse_encode(self.review_count, serializer);
+        <Option<i64>>::sse_encode(self.next_learning_due, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::ReviewSortOrder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::ReviewSortOrder::Due => 0,
+                crate::api::simple::ReviewSortOrder::RetrievabilityAscending => 1,
+                crate::api::simple::ReviewSortOrder::Random => 2,
+                crate::api::simple::ReviewSortOrder::RelativeOverdueness => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::SchedulePreview {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u8>::sse_encode(self.rating, serializer);
+        <i64>::sse_encode(self.due, serializer);
+        <i64>::sse_encode(self.interval_seconds, serializer);
+        <String>::sse_encode(self.interval_text, serializer);
+        <crate::api::simple::CardMemoryState>::sse_encode(self.card, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::SchedulerAlgorithm {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::SchedulerAlgorithm::Fsrs => 0,
+                crate::api::simple::SchedulerAlgorithm::Sm2 => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::SchedulerConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::simple::SchedulerAlgorithm>::sse_encode(self.algorithm, serializer);
+        <Vec<f64>>::sse_encode(self.weights, serializer);
+        <f64>::sse_encode(self.request_retention, serializer);
+        <i32>::sse_encode(self.maximum_interval, serializer);
+        <bool>::sse_encode(self.enable_fuzz, serializer);
+        <bool>::sse_encode(self.enable_short_term, serializer);
+        <Vec<f64>>::sse_encode(self.learn_steps, serializer);
+        <Vec<f64>>::sse_encode(self.relearn_steps, serializer);
+        <i32>::sse_encode(self.graduating_interval_good, serializer);
+        <i32>::sse_encode(self.graduating_interval_easy, serializer);
+        <f64>::sse_encode(self.initial_ease, serializer);
+        <f64>::sse_encode(self.easy_multiplier, serializer);
+        <f64>::sse_encode(self.hard_multiplier, serializer);
+        <f64>::sse_encode(self.lapse_multiplier, serializer);
+        <f64>::sse_encode(self.interval_multiplier, serializer);
+        <i32>::sse_encode(self.minimum_lapse_interval, serializer);
+        <i32>::sse_encode(self.learn_ahead_secs, serializer);
+        <u32>::sse_encode(self.new_per_day, serializer);
+        <crate::api::simple::NewCardGatherPriority>::sse_encode(
+            self.new_card_gather_priority,
+            serializer,
+        );
+        <crate::api::simple::NewCardSortOrder>::sse_encode(self.new_card_sort_order, serializer);
+        <u32>::sse_encode(self.reviews_per_day, serializer);
+        <crate::api::simple::ReviewSortOrder>::sse_encode(self.review_sort_order, serializer);
+        <crate::api::simple::NewReviewMix>::sse_encode(self.new_mix, serializer);
+        <bool>::sse_encode(self.bury_new, serializer);
+        <bool>::sse_encode(self.bury_reviews, serializer);
+        <bool>::sse_encode(self.bury_interday_learning, serializer);
+        <u32>::sse_encode(self.leech_threshold, serializer);
+        <crate::api::simple::LeechAction>::sse_encode(self.leech_action, serializer);
+    }
+}
+
 impl SseEncode for crate::api::simple::SingleNoteResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1094,6 +5011,16 @@ impl SseEncode for crate::api::simple::SingleNoteResult {
         <String>::sse_encode(self.front, serializer);
         <String>::sse_encode(self.back, serializer);
         <String>::sse_encode(self.css, serializer);
+        <String>::sse_encode(self.latex_pre, serializer);
+        <String>::sse_encode(self.latex_post, serializer);
+        <i64>::sse_encode(self.sort_field_idx, serializer);
+    }
+}
+
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
     }
 }
 
@@ -1119,20 +5046,6 @@ impl SseEncode for usize {
     }
 }
 
-impl SseEncode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
-    }
-}
-
-impl SseEncode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
-    }
-}
-
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
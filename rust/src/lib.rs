@@ -18,4 +18,12 @@ fn init() {
 }
 
 pub mod api;
+mod anki_proto;
+mod apkg;
+mod cloze;
+mod colpkg;
+mod collection;
+mod error;
+mod scheduler;
+mod template;
 mod frb_generated;
@@ -0,0 +1,311 @@
+// 导入时恢复卡片的记忆状态：优先用卡片自带的 FSRS 状态（cards.data 的 s/d），
+// 其次按 revlog 重放复习记录，两者都没有时按 SM-2 的 ease 和间隔估算。
+// 结果写回 cards.data，和新版 Anki 保存 FSRS 状态的方式一致，卡片其他列保持不变。
+
+use std::collections::HashMap;
+use chrono::{DateTime, TimeZone, Utc};
+use rs_fsrs::{Card, Parameters, Rating, FSRS};
+use rusqlite::Connection;
+use serde_json::{json, Value};
+use crate::api::simple::{CardMemoryState, CardState, SchedulerConfig};
+use crate::error::{AnkiError, ResultExt};
+use super::config::build_fsrs;
+use super::rating_from_u8;
+use super::steps::{step_from_left, SECONDS_PER_DAY};
+use super::timing::{self, Timing};
+
+const S_MIN: f64 = 0.01;
+// SM-2 卡片没有记录期望保留率，按 Anki 的默认值 0.9 估算
+const SM2_RETENTION: f64 = 0.9;
+
+/// cards 表中调度相关的列
+pub struct AnkiCardRow {
+    pub id: i64,
+    pub card_type: i64, // 0-新卡, 1-学习中, 2-复习, 3-重学
+    pub queue: i64,
+    pub due: i64,
+    pub ivl: i64,
+    pub factor: i64, // ease，千分制
+    pub reps: i32,
+    pub lapses: i32,
+    pub left: i64, // 学习中的卡片剩余的步数
+    pub data: String,
+}
+
+const CARD_COLUMNS: &str = "id, type, queue, due, ivl, factor, reps, lapses, left, data";
+
+fn read_card_row(row: &rusqlite::Row) -> rusqlite::Result<AnkiCardRow> {
+    Ok(AnkiCardRow {
+        id: row.get(0)?,
+        card_type: row.get(1)?,
+        queue: row.get(2)?,
+        due: row.get(3)?,
+        ivl: row.get(4)?,
+        factor: row.get(5)?,
+        reps: row.get(6)?,
+        lapses: row.get(7)?,
+        left: row.get(8)?,
+        data: row.get::<_, Option<String>>(9)?.unwrap_or_default(),
+    })
+}
+
+fn timestamp_to_utc(ts: i64) -> DateTime<Utc> {
+    Utc.timestamp_opt(ts, 0).single().unwrap_or_else(Utc::now)
+}
+
+/// 按 SM-2 的 ease 和间隔估算 FSRS 稳定性和难度：
+/// 认为卡片在当前间隔末尾的记忆保留率正好是 sm2_retention，反推稳定性，再由一次“良好”的增长倍数反推难度
+pub fn memory_state_from_sm2(w: &[f64], ease_factor: f64, interval: f64, sm2_retention: f64) -> (f64, f64) {
+    let stability = interval.max(S_MIN) * Parameters::FACTOR / (sm2_retention.powf(1.0 / Parameters::DECAY) - 1.0);
+    let growth = w[8].exp() * stability.powf(-w[9]) * (((1.0 - sm2_retention) * w[10]).exp() - 1.0);
+    let difficulty = (11.0 - (ease_factor - 1.0) / growth).clamp(1.0, 10.0);
+    (stability.max(S_MIN), difficulty)
+}
+
+fn replay_revlog(fsrs: &FSRS, reviews: &[(i64, Rating)]) -> Option<Card> {
+    let (first_time, _) = reviews.first()?;
+    let start = timestamp_to_utc(*first_time);
+    let mut card = Card { due: start, last_review: start, ..Default::default() };
+    for (time, rating) in reviews {
+        card = fsrs.next(card, timestamp_to_utc(*time), *rating).card;
+    }
+    Some(card)
+}
+
+// 学习中的卡片 due 是时间戳，复习卡和跨天学习的卡片 due 是学习日，取那天开始的时刻
+fn due_timestamp(row: &AnkiCardRow, timing: &Timing, now: i64) -> i64 {
+    match row.card_type {
+        0 => now,
+        _ if row.queue == 1 || row.queue == 4 || row.due > 1_000_000_000 => row.due,
+        _ => timing.day_start(row.due),
+    }
+}
+
+fn memory_from_data(data: &Value) -> Option<(f64, f64)> {
+    Some((data.get("s")?.as_f64()?, data.get("d")?.as_f64()?))
+}
+
+/// 由 cards 表的一行（和可选的复习记录）得到完整的记忆状态
+pub fn card_memory_state(
+    fsrs: &FSRS,
+    config: &SchedulerConfig,
+    row: &AnkiCardRow,
+    reviews: &[(i64, Rating)],
+    timing: &Timing,
+    now: i64,
+) -> CardMemoryState {
+    let state = match row.card_type {
+        1 => CardState::Learning,
+        2 => CardState::Review,
+        3 => CardState::Relearning,
+        _ => CardState::New,
+    };
+    let due = due_timestamp(row, timing, now);
+    let mut memory = CardMemoryState {
+        state,
+        due,
+        stability: 0.0,
+        difficulty: 0.0,
+        elapsed_days: 0,
+        scheduled_days: row.ivl.max(0),
+        reps: row.reps,
+        lapses: row.lapses,
+        step: match state {
+            CardState::Learning => step_from_left(&config.learn_steps, row.left),
+            CardState::Relearning => step_from_left(&config.relearn_steps, row.left),
+            _ => 0,
+        },
+        last_review: None,
+        ease_factor: if row.factor > 0 { row.factor as f64 / 1000.0 } else { 0.0 },
+    };
+    if state == CardState::New {
+        return memory;
+    }
+    let data: Value = serde_json::from_str(&row.data).unwrap_or(Value::Null);
+    let replayed = if reviews.is_empty() { None } else { replay_revlog(fsrs, reviews) };
+    if let Some((s, d)) = memory_from_data(&data) {
+        memory.stability = s;
+        memory.difficulty = d;
+    } else if let Some(card) = &replayed {
+        memory.stability = card.stability;
+        memory.difficulty = card.difficulty;
+    } else {
+        let ease = if row.factor > 0 { row.factor as f64 / 1000.0 } else { 2.5 };
+        let (s, d) = memory_state_from_sm2(&config.weights, ease, row.ivl.max(1) as f64, SM2_RETENTION);
+        memory.stability = s;
+        memory.difficulty = d;
+    }
+    memory.last_review = reviews
+        .last()
+        .map(|(time, _)| *time)
+        .or_else(|| data.get("lrt").and_then(|v| v.as_i64()))
+        .or_else(|| (row.ivl > 0).then(|| due - row.ivl * SECONDS_PER_DAY));
+    memory.elapsed_days = memory.last_review.map(|last| ((now - last) / SECONDS_PER_DAY).max(0)).unwrap_or(0);
+    memory
+}
+
+// 按卡片分组读取 revlog（deck_id 不为空时只读这个牌组的卡片）
+fn load_revlog(conn: &Connection, deck_id: Option<i64>) -> Result<HashMap<i64, Vec<(i64, Rating)>>, AnkiError> {
+    query_revlog(conn, "?1 IS NULL OR cid IN (SELECT id FROM cards WHERE did = ?1)", deck_id)
+}
+
+// 遇到“重置卡片”（手动记录且 ease 为 0）时丢弃之前的记录
+fn query_revlog(conn: &Connection, filter: &str, param: Option<i64>) -> Result<HashMap<i64, Vec<(i64, Rating)>>, AnkiError> {
+    let mut stmt = conn
+        .prepare_cached(&format!("SELECT cid, id, ease, type FROM revlog WHERE {filter} ORDER BY cid, id"))
+        .context("准备SQL失败")?;
+    let mut rows = stmt.query([param]).context("查询SQL失败")?;
+    let mut by_card: HashMap<i64, Vec<(i64, Rating)>> = HashMap::new();
+    while let Some(row) = rows.next().context("遍历SQL失败")? {
+        let cid: i64 = row.get(0).context("读取cid失败")?;
+        let id: i64 = row.get(1).context("读取id失败")?;
+        let ease: i64 = row.get(2).context("读取ease失败")?;
+        let kind: i64 = row.get(3).context("读取type失败")?;
+        let list = by_card.entry(cid).or_default();
+        if kind == 4 && ease == 0 {
+            list.clear();
+        } else if (1..=4).contains(&ease) && kind <= 3 {
+            list.push((id / 1000, rating_from_u8((ease - 1) as u8)?));
+        }
+    }
+    Ok(by_card)
+}
+
+/// 把 FSRS 记忆状态写进 cards.data，没有 FSRS 状态（如 SM-2 新学的卡片）时只更新复习时间
+pub fn write_memory_to_data(data: &str, memory: &CardMemoryState, desired_retention: f64) -> String {
+    let mut value: Value = serde_json::from_str(data).unwrap_or_else(|_| json!({}));
+    if !value.is_object() {
+        value = json!({});
+    }
+    if let Some(obj) = value.as_object_mut() {
+        if memory.stability > 0.0 {
+            obj.insert("s".to_string(), json!(memory.stability));
+            obj.insert("d".to_string(), json!(memory.difficulty));
+            obj.insert("dr".to_string(), json!(desired_retention));
+        }
+        if let Some(lrt) = memory.last_review {
+            obj.insert("lrt".to_string(), json!(lrt));
+        }
+    }
+    value.to_string()
+}
+
+/// 为集合中所有学过的卡片（deck_id 不为空时只处理这个牌组的）计算记忆状态并写回 cards.data，返回处理的卡片数
+pub fn bootstrap_collection(conn: &mut Connection, config: &SchedulerConfig, deck_id: Option<i64>) -> Result<u32, AnkiError> {
+    let fsrs = build_fsrs(config)?;
+    let timing = timing::load(conn)?;
+    let now = Utc::now().timestamp();
+    let revlog = load_revlog(conn, deck_id)?;
+    let rows: Vec<AnkiCardRow> = {
+        let mut stmt = conn
+            .prepare(&format!("SELECT {CARD_COLUMNS} FROM cards WHERE type != 0 AND (?1 IS NULL OR did = ?1)"))
+            .context("准备SQL失败")?;
+        let rows = stmt.query_map([deck_id], read_card_row).context("查询SQL失败")?;
+        rows.collect::<Result<_, _>>().context("读取card失败")?
+    };
+    let tx = conn.transaction().context("开启事务失败")?;
+    for row in &rows {
+        let reviews = revlog.get(&row.id).map(|r| r.as_slice()).unwrap_or(&[]);
+        let memory = card_memory_state(&fsrs, config, row, reviews, &timing, now);
+        let data = write_memory_to_data(&row.data, &memory, config.request_retention);
+        tx.execute("UPDATE cards SET data = ? WHERE id = ?", rusqlite::params![data, row.id])
+            .context("更新card失败")?;
+    }
+    tx.commit().context("提交事务失败")?;
+    Ok(rows.len() as u32)
+}
+
+/// 读取单张卡片的记忆状态，cards.data 里没有 FSRS 状态时按复习记录重放，也没有复习记录时按 SM-2 参数估算
+pub fn load_card_memory_state(conn: &Connection, card_id: i64, config: &SchedulerConfig, now: i64) -> Result<CardMemoryState, AnkiError> {
+    let fsrs = build_fsrs(config)?;
+    let timing = timing::load(conn)?;
+    let row = conn
+        .query_row(&format!("SELECT {CARD_COLUMNS} FROM cards WHERE id = ?"), [card_id], read_card_row)
+        .context("未找到指定id的card")?;
+    let reviews = query_revlog(conn, "cid = ?1", Some(card_id))?.remove(&card_id).unwrap_or_default();
+    Ok(card_memory_state(&fsrs, config, &row, &reviews, &timing, now))
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::params;
+    use crate::collection::{add_test_card, memory_collection};
+    use super::*;
+
+    const DAY: i64 = SECONDS_PER_DAY;
+
+    // 一张 20 天前学过、10 天前复习过、今天到期的复习卡，data 里没有 FSRS 状态
+    fn review_card(conn: &Connection, now: i64) -> i64 {
+        add_test_card(conn, "deck", 1, 100, 1000, 0);
+        let day = timing::load(conn).unwrap().day(now);
+        conn.execute("UPDATE cards SET type = 2, queue = 2, due = ?, ivl = 10, factor = 2300, reps = 2 WHERE id = 1000", [day])
+            .unwrap();
+        day
+    }
+
+    fn add_revlog(conn: &Connection, time: i64, ease: i64, kind: i64) {
+        conn.execute(
+            "INSERT INTO revlog (id, cid, usn, ease, ivl, lastIvl, factor, time, type) VALUES (?, 1000, 0, ?, 0, 0, 0, 0, ?)",
+            params![time * 1000, ease, kind],
+        )
+        .unwrap();
+    }
+
+    fn noon(conn: &Connection) -> i64 {
+        timing::collection_crt(conn).unwrap() + 30 * DAY + 12 * 3600
+    }
+
+    #[test]
+    fn card_data_state_wins_over_revlog() {
+        let conn = memory_collection();
+        let now = noon(&conn);
+        review_card(&conn, now);
+        add_revlog(&conn, now - 20 * DAY, 3, 0);
+        add_revlog(&conn, now - 10 * DAY, 3, 1);
+        let data = format!(r#"{{"s":12.5,"d":4.5,"lrt":{}}}"#, now - 7 * DAY);
+        conn.execute("UPDATE cards SET data = ? WHERE id = 1000", [data]).unwrap();
+
+        let memory = load_card_memory_state(&conn, 1000, &SchedulerConfig::default(), now).unwrap();
+        assert_eq!((memory.stability, memory.difficulty), (12.5, 4.5));
+        // 有复习记录时复习时间以最后一条记录为准
+        assert_eq!(memory.last_review, Some(now - 10 * DAY));
+        assert_eq!(memory.elapsed_days, 10);
+    }
+
+    #[test]
+    fn revlog_replay_wins_over_sm2_estimate() {
+        let conn = memory_collection();
+        let now = noon(&conn);
+        review_card(&conn, now);
+        // 重置之前的记录不参与重放
+        add_revlog(&conn, now - 25 * DAY, 1, 0);
+        add_revlog(&conn, now - 24 * DAY, 0, 4);
+        add_revlog(&conn, now - 20 * DAY, 3, 0);
+        add_revlog(&conn, now - 10 * DAY, 3, 1);
+        let config = SchedulerConfig::default();
+
+        let memory = load_card_memory_state(&conn, 1000, &config, now).unwrap();
+        let fsrs = build_fsrs(&config).unwrap();
+        let replayed = replay_revlog(&fsrs, &[(now - 20 * DAY, Rating::Good), (now - 10 * DAY, Rating::Good)]).unwrap();
+        assert_eq!((memory.stability, memory.difficulty), (replayed.stability, replayed.difficulty));
+        assert_eq!(memory.last_review, Some(now - 10 * DAY));
+        assert_eq!(memory.elapsed_days, 10);
+    }
+
+    #[test]
+    fn sm2_estimate_without_state_or_revlog() {
+        let conn = memory_collection();
+        let now = noon(&conn);
+        let day = review_card(&conn, now);
+        let config = SchedulerConfig::default();
+
+        let memory = load_card_memory_state(&conn, 1000, &config, now).unwrap();
+        let (s, d) = memory_state_from_sm2(&config.weights, 2.3, 10.0, SM2_RETENTION);
+        assert_eq!((memory.stability, memory.difficulty), (s, d));
+        // 复习时间按到期日往前推一个间隔
+        let due = timing::load(&conn).unwrap().day_start(day);
+        assert_eq!(memory.last_review, Some(due - 10 * DAY));
+        assert_eq!(memory.elapsed_days, (now - (due - 10 * DAY)) / DAY);
+        assert_eq!(memory.ease_factor, 2.3);
+    }
+}
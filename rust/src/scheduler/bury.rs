@@ -0,0 +1,161 @@
+// 搁置：回答一张卡片后，按牌组选项把同一笔记的其他卡片搁置到明天（queue -2），
+// 构建队列时同一笔记只出现一张卡片。搁置的卡片在每个牌组当天第一次构建队列时恢复。
+// 手动搁置（queue -3）也一起恢复，和 Anki 一样。
+
+use rusqlite::{params, Connection, OptionalExtension};
+use crate::api::simple::{rust_log, QueueKind, SchedulerConfig};
+use crate::collection::card_state::{QUEUE_MANUALLY_BURIED, QUEUE_SIBLING_BURIED, RESTORED_QUEUE};
+use crate::collection::IN_DECK;
+use crate::error::{AnkiError, ResultExt};
+
+// 上次恢复搁置卡片的日期（集合创建后的天数）存在 open_anki_config 表里，每个牌组一行
+const UNBURIED_KEY: &str = "unburied";
+
+fn unburied_key(deck_key: &str) -> String {
+    format!("{UNBURIED_KEY}:{deck_key}")
+}
+
+/// 配置里这一类卡片是否要让位给已经出现过的兄弟卡片
+pub fn buries(config: &SchedulerConfig, kind: QueueKind) -> bool {
+    match kind {
+        QueueKind::New => config.bury_new,
+        QueueKind::Review => config.bury_reviews,
+        QueueKind::Learning => config.bury_interday_learning,
+    }
+}
+
+/// 恢复牌组里搁置的卡片，返回恢复的卡片数
+pub fn unbury(conn: &Connection, deck_key: &str) -> Result<usize, AnkiError> {
+    conn.execute(
+        &format!("UPDATE cards SET queue = {RESTORED_QUEUE}, usn = -1 WHERE queue IN (?, ?) AND {IN_DECK}"),
+        params![QUEUE_SIBLING_BURIED, QUEUE_MANUALLY_BURIED, deck_key],
+    )
+    .context("恢复搁置的卡片失败")
+}
+
+/// 换天后第一次构建队列时恢复前一天搁置的卡片
+pub fn unbury_on_new_day(conn: &Connection, deck_key: &str, day: i64) -> Result<(), AnkiError> {
+    let last: Option<String> = conn
+        .prepare_cached("SELECT value FROM open_anki_config WHERE key = ?")
+        .context("准备SQL失败")?
+        .query_row([unburied_key(deck_key)], |row| row.get(0))
+        .optional()
+        .context("读取搁置状态失败")?;
+    if last.and_then(|v| v.parse::<i64>().ok()) == Some(day) {
+        return Ok(());
+    }
+    let count = unbury(conn, deck_key)?;
+    conn.execute(
+        "INSERT OR REPLACE INTO open_anki_config (key, value) VALUES (?, ?)",
+        params![unburied_key(deck_key), day.to_string()],
+    )
+    .context("保存搁置状态失败")?;
+    if count > 0 {
+        rust_log(&format!("[调度] 已恢复 {} 张搁置的卡片, deck_key={}", count, deck_key));
+    }
+    Ok(())
+}
+
+/// 回答一张卡片后按配置搁置同一笔记的其他卡片：新卡、今天到期的复习卡、今天到期的跨天学习卡，返回搁置的卡片数
+pub fn bury_siblings(conn: &Connection, card_id: i64, config: &SchedulerConfig, day: i64, now: i64) -> Result<usize, AnkiError> {
+    if !config.bury_new && !config.bury_reviews && !config.bury_interday_learning {
+        return Ok(0);
+    }
+    conn.execute(
+        "UPDATE cards SET queue = ?, mod = ?, usn = -1 WHERE nid = (SELECT nid FROM cards WHERE id = ?) AND id != ? \
+         AND ((? AND queue = 0) OR (? AND queue = 2 AND due <= ?) OR (? AND queue = 3 AND due <= ?))",
+        params![
+            QUEUE_SIBLING_BURIED,
+            now,
+            card_id,
+            card_id,
+            config.bury_new,
+            config.bury_reviews,
+            day,
+            config.bury_interday_learning,
+            day
+        ],
+    )
+    .context("搁置兄弟卡片失败")
+}
+
+pub fn remove(conn: &Connection, deck_key: &str) -> Result<(), AnkiError> {
+    conn.execute("DELETE FROM open_anki_config WHERE key = ?", [unburied_key(deck_key)])
+        .context("删除搁置状态失败")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collection::{add_test_card, memory_collection};
+    use super::*;
+
+    const DECK: &str = "deck";
+    const DAY: i64 = 10;
+    const NOW: i64 = 1_700_000_000;
+
+    // 一条笔记的五张卡片：回答的 1、新卡 2、今天到期的复习卡 3、今天到期的跨天学习卡 4、明天到期的复习卡 5
+    fn siblings() -> Connection {
+        let conn = memory_collection();
+        for cid in 1..=5 {
+            add_test_card(&conn, DECK, 1, 100, cid, cid - 1);
+        }
+        conn.execute("UPDATE cards SET type = 1, queue = 1, due = ? WHERE id = 1", [NOW]).unwrap();
+        conn.execute("UPDATE cards SET type = 2, queue = 2, due = ? WHERE id = 3", [DAY]).unwrap();
+        conn.execute("UPDATE cards SET type = 3, queue = 3, due = ? WHERE id = 4", [DAY]).unwrap();
+        conn.execute("UPDATE cards SET type = 2, queue = 2, due = ? WHERE id = 5", [DAY + 1]).unwrap();
+        conn
+    }
+
+    fn queues(conn: &Connection) -> Vec<i64> {
+        let mut stmt = conn.prepare("SELECT queue FROM cards ORDER BY id").unwrap();
+        let rows = stmt.query_map([], |row| row.get(0)).unwrap();
+        rows.collect::<Result<_, _>>().unwrap()
+    }
+
+    fn bury_with(bury_new: bool, bury_reviews: bool, bury_interday_learning: bool) -> (usize, Vec<i64>) {
+        let conn = siblings();
+        let config = SchedulerConfig { bury_new, bury_reviews, bury_interday_learning, ..Default::default() };
+        let count = bury_siblings(&conn, 1, &config, DAY, NOW).unwrap();
+        (count, queues(&conn))
+    }
+
+    #[test]
+    fn buries_siblings_by_config() {
+        let b = QUEUE_SIBLING_BURIED;
+        assert_eq!(bury_with(false, false, false), (0, vec![1, 0, 2, 3, 2]));
+        assert_eq!(bury_with(true, false, false), (1, vec![1, b, 2, 3, 2]));
+        assert_eq!(bury_with(false, true, false), (1, vec![1, 0, b, 3, 2]));
+        assert_eq!(bury_with(false, false, true), (1, vec![1, 0, 2, b, 2]));
+        // 回答的卡片和明天才到期的卡片不搁置
+        assert_eq!(bury_with(true, true, true), (3, vec![1, b, b, b, 2]));
+    }
+
+    #[test]
+    fn unburies_once_per_day() {
+        let conn = siblings();
+        let config = SchedulerConfig { bury_new: true, bury_reviews: true, bury_interday_learning: true, ..Default::default() };
+        bury_siblings(&conn, 1, &config, DAY, NOW).unwrap();
+        conn.execute("UPDATE cards SET queue = ? WHERE id = 5", [QUEUE_MANUALLY_BURIED]).unwrap();
+
+        // 当天第一次构建队列时恢复，queue 按 type 放回原来的队列
+        unbury_on_new_day(&conn, DECK, DAY).unwrap();
+        assert_eq!(queues(&conn), [1, 0, 2, 3, 2]);
+
+        // 同一天再搁置的卡片要等到第二天
+        bury_siblings(&conn, 1, &config, DAY, NOW).unwrap();
+        unbury_on_new_day(&conn, DECK, DAY).unwrap();
+        assert_eq!(queues(&conn)[1..4], [QUEUE_SIBLING_BURIED; 3]);
+        unbury_on_new_day(&conn, DECK, DAY + 1).unwrap();
+        assert_eq!(queues(&conn), [1, 0, 2, 3, 2]);
+    }
+
+    #[test]
+    fn unbury_only_touches_the_deck() {
+        let conn = siblings();
+        add_test_card(&conn, "other", 2, 200, 6, 0);
+        conn.execute("UPDATE cards SET queue = ?", [QUEUE_SIBLING_BURIED]).unwrap();
+        assert_eq!(unbury(&conn, DECK).unwrap(), 5);
+        assert_eq!(queues(&conn)[5], QUEUE_SIBLING_BURIED);
+    }
+}
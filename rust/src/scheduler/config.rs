@@ -0,0 +1,470 @@
+// 每个牌组的调度配置：校验、转换成 rs_fsrs 参数，在集合库中的读写，以及从导入集合的牌组选项生成默认配置
+
+use rs_fsrs::{Parameters, FSRS};
+use rusqlite::{Connection, OptionalExtension};
+use serde_json::Value;
+use crate::anki_proto::{decode_deck_config, decode_deck_kind, DeckConfigConfig};
+use crate::api::simple::{rust_log, LeechAction, NewCardGatherPriority, NewCardSortOrder, NewReviewMix, ReviewSortOrder, SchedulerConfig};
+use crate::collection::has_table;
+use crate::error::{AnkiError, ResultExt};
+
+// 配置存放在集合库自己建的 open_anki_config 表里，不动 Anki 原有的表，每个牌组一行
+const SCHEDULER_CONFIG_KEY: &str = "scheduler";
+
+// FSRS-5 的参数个数，调度和优化器用的都是这 19 个
+pub const WEIGHT_COUNT: usize = 19;
+// FSRS-6 的参数个数。rs_fsrs 只实现了 FSRS-5，FSRS-6 的参数原样保存（导出、以后换用支持 FSRS-6 的实现时不丢），
+// 调度时只用前 19 个，最后两个（短期稳定性的指数和遗忘曲线的衰减）被忽略，间隔会和 Anki 的 FSRS-6 有出入
+pub const FSRS6_WEIGHT_COUNT: usize = 21;
+
+// 各权重的取值范围，和 FSRS 优化器里的约束一致
+pub const WEIGHT_RANGES: [(f64, f64); WEIGHT_COUNT] = [
+    (0.001, 100.0),
+    (0.001, 100.0),
+    (0.001, 100.0),
+    (0.001, 100.0),
+    (1.0, 10.0),
+    (0.001, 4.0),
+    (0.001, 4.0),
+    (0.001, 0.75),
+    (0.0, 4.5),
+    (0.0, 0.8),
+    (0.001, 3.5),
+    (0.001, 5.0),
+    (0.001, 0.25),
+    (0.001, 0.9),
+    (0.0, 4.0),
+    (0.0, 1.0),
+    (1.0, 6.0),
+    (0.0, 2.0),
+    (0.0, 2.0),
+];
+
+// FSRS-6 多出的 w[19]、w[20] 的取值范围
+const FSRS6_EXTRA_RANGES: [(f64, f64); FSRS6_WEIGHT_COUNT - WEIGHT_COUNT] = [(0.0, 0.8), (0.1, 0.8)];
+
+fn weight_range(i: usize) -> (f64, f64) {
+    WEIGHT_RANGES.get(i).copied().unwrap_or_else(|| FSRS6_EXTRA_RANGES[i - WEIGHT_COUNT])
+}
+
+// 参数个数是 19（FSRS-5）或 21（FSRS-6），且每个参数都在范围内
+fn check_weights(weights: &[f64]) -> Result<(), AnkiError> {
+    if weights.len() != WEIGHT_COUNT && weights.len() != FSRS6_WEIGHT_COUNT {
+        return Err(AnkiError::invalid_argument(format!(
+            "FSRS参数个数必须是{WEIGHT_COUNT}（FSRS-5）或{FSRS6_WEIGHT_COUNT}（FSRS-6），实际为{}",
+            weights.len()
+        )));
+    }
+    for (i, w) in weights.iter().enumerate() {
+        let (min, max) = weight_range(i);
+        if !w.is_finite() || *w < min || *w > max {
+            return Err(AnkiError::invalid_argument(format!("FSRS参数w[{i}]={w}超出范围[{min}, {max}]")));
+        }
+    }
+    Ok(())
+}
+
+pub fn default_weights() -> Vec<f64> {
+    Parameters::default().w.to_vec()
+}
+
+pub fn validate(config: &SchedulerConfig) -> Result<(), AnkiError> {
+    check_weights(&config.weights)?;
+    // 和 Anki 设置界面允许的范围一致
+    if !(0.7..=0.99).contains(&config.request_retention) {
+        return Err(AnkiError::invalid_argument(format!("期望记忆保留率{}超出范围[0.7, 0.99]", config.request_retention)));
+    }
+    if !(1..=36500).contains(&config.maximum_interval) {
+        return Err(AnkiError::invalid_argument(format!("最大间隔{}天超出范围[1, 36500]", config.maximum_interval)));
+    }
+    for (name, steps) in [("学习步骤", &config.learn_steps), ("重学步骤", &config.relearn_steps)] {
+        if let Some(step) = steps.iter().find(|s| !s.is_finite() || **s <= 0.0) {
+            return Err(AnkiError::invalid_argument(format!("{name}中的{step}分钟无效，必须大于0")));
+        }
+    }
+    if config.graduating_interval_good < 1 || config.graduating_interval_easy < 1 {
+        return Err(AnkiError::invalid_argument(format!("毕业间隔{}天和简单间隔{}天必须至少为1天", config.graduating_interval_good, config.graduating_interval_easy)));
+    }
+    if !(0..=86400).contains(&config.learn_ahead_secs) {
+        return Err(AnkiError::invalid_argument(format!("提前学习时限{}秒超出范围[0, 86400]", config.learn_ahead_secs)));
+    }
+    if config.new_per_day > 9999 {
+        return Err(AnkiError::invalid_argument(format!("每天新卡上限{}超出范围[0, 9999]", config.new_per_day)));
+    }
+    if config.reviews_per_day > 9999 {
+        return Err(AnkiError::invalid_argument(format!("每天复习上限{}超出范围[0, 9999]", config.reviews_per_day)));
+    }
+    if !(1..=9999).contains(&config.leech_threshold) {
+        return Err(AnkiError::invalid_argument(format!("难记卡片阈值{}超出范围[1, 9999]", config.leech_threshold)));
+    }
+    if config.minimum_lapse_interval < 1 {
+        return Err(AnkiError::invalid_argument(format!("遗忘后的最小间隔{}天必须至少为1天", config.minimum_lapse_interval)));
+    }
+    let ranges = [
+        ("初始ease", config.initial_ease, 1.31, 5.0),
+        ("简单奖励", config.easy_multiplier, 1.0, 5.0),
+        ("困难间隔", config.hard_multiplier, 0.5, 1.3),
+        ("遗忘后的新间隔", config.lapse_multiplier, 0.0, 1.0),
+        ("间隔修饰", config.interval_multiplier, 0.5, 2.0),
+    ];
+    for (name, value, min, max) in ranges {
+        if !(min..=max).contains(&value) {
+            return Err(AnkiError::invalid_argument(format!("{name}{value}超出范围[{min}, {max}]")));
+        }
+    }
+    Ok(())
+}
+
+/// 按配置构造 rs_fsrs（FSRS-5）参数，调用前需要先校验配置。FSRS-6 的参数只取前 19 个
+pub fn build_parameters(config: &SchedulerConfig) -> Parameters {
+    let mut w = [0.0; WEIGHT_COUNT];
+    w.copy_from_slice(&config.weights[..WEIGHT_COUNT]);
+    Parameters {
+        request_retention: config.request_retention,
+        maximum_interval: config.maximum_interval,
+        w,
+        enable_fuzz: config.enable_fuzz,
+        enable_short_term: config.enable_short_term,
+        ..Default::default()
+    }
+}
+
+pub fn build_fsrs(config: &SchedulerConfig) -> Result<FSRS, AnkiError> {
+    validate(config)?;
+    Ok(FSRS::new(build_parameters(config)))
+}
+
+// 卡片最多的牌组，一个 apkg 里通常只有一个主牌组
+fn main_deck_id(conn: &Connection) -> Result<Option<i64>, AnkiError> {
+    conn.query_row("SELECT did FROM cards GROUP BY did ORDER BY COUNT(*) DESC LIMIT 1", [], |row| row.get(0))
+        .optional()
+        .context("查询主牌组失败")
+}
+
+// FSRS 参数只在个数和范围都合法时才采用，返回是否采用
+fn apply_fsrs_weights(config: &mut SchedulerConfig, weights: &[f32]) -> bool {
+    let weights: Vec<f64> = weights.iter().map(|w| *w as f64).collect();
+    if check_weights(&weights).is_err() {
+        return false;
+    }
+    config.weights = weights;
+    true
+}
+
+// 枚举值和 Anki deck_config.proto 里的一致，不认识的值保持默认
+fn gather_priority_from_proto(value: i32) -> Option<NewCardGatherPriority> {
+    match value {
+        0 => Some(NewCardGatherPriority::Deck),
+        1 => Some(NewCardGatherPriority::LowestPosition),
+        2 => Some(NewCardGatherPriority::HighestPosition),
+        3 => Some(NewCardGatherPriority::RandomNotes),
+        4 => Some(NewCardGatherPriority::RandomCards),
+        5 => Some(NewCardGatherPriority::DeckThenRandomNotes),
+        _ => None,
+    }
+}
+
+fn sort_order_from_proto(value: i32) -> Option<NewCardSortOrder> {
+    match value {
+        0 => Some(NewCardSortOrder::Template),
+        1 => Some(NewCardSortOrder::NoSort),
+        2 => Some(NewCardSortOrder::TemplateThenRandom),
+        3 => Some(NewCardSortOrder::RandomNoteThenTemplate),
+        4 => Some(NewCardSortOrder::RandomCard),
+        _ => None,
+    }
+}
+
+// 按到期日的几种排法都当作 Due；Anki 新版把相对过期程度换成了可提取性
+fn review_order_from_proto(value: i32) -> Option<ReviewSortOrder> {
+    match value {
+        0..=2 => Some(ReviewSortOrder::Due),
+        7 => Some(ReviewSortOrder::RetrievabilityAscending),
+        8 => Some(ReviewSortOrder::Random),
+        _ => None,
+    }
+}
+
+fn new_mix_from_proto(value: i32) -> Option<NewReviewMix> {
+    match value {
+        0 => Some(NewReviewMix::MixWithReviews),
+        1 => Some(NewReviewMix::ReviewsFirst),
+        2 => Some(NewReviewMix::NewFirst),
+        _ => None,
+    }
+}
+
+fn leech_action_from_proto(value: i32) -> Option<LeechAction> {
+    match value {
+        0 => Some(LeechAction::Suspend),
+        1 => Some(LeechAction::TagOnly),
+        _ => None,
+    }
+}
+
+fn apply_deck_config_proto(config: &mut SchedulerConfig, dc: &DeckConfigConfig) {
+    config.learn_steps = dc.learn_steps.iter().map(|s| *s as f64).collect();
+    config.relearn_steps = dc.relearn_steps.iter().map(|s| *s as f64).collect();
+    config.initial_ease = dc.initial_ease as f64;
+    config.easy_multiplier = dc.easy_multiplier as f64;
+    config.hard_multiplier = dc.hard_multiplier as f64;
+    config.lapse_multiplier = dc.lapse_multiplier as f64;
+    config.interval_multiplier = dc.interval_multiplier as f64;
+    config.maximum_interval = dc.maximum_review_interval as i32;
+    config.minimum_lapse_interval = dc.minimum_lapse_interval as i32;
+    config.graduating_interval_good = dc.graduating_interval_good as i32;
+    config.graduating_interval_easy = dc.graduating_interval_easy as i32;
+    config.new_per_day = dc.new_per_day;
+    if let Some(priority) = gather_priority_from_proto(dc.new_card_gather_priority) {
+        config.new_card_gather_priority = priority;
+    }
+    if let Some(order) = sort_order_from_proto(dc.new_card_sort_order) {
+        config.new_card_sort_order = order;
+    }
+    config.reviews_per_day = dc.reviews_per_day;
+    if let Some(order) = review_order_from_proto(dc.review_order) {
+        config.review_sort_order = order;
+    }
+    if let Some(mix) = new_mix_from_proto(dc.new_mix) {
+        config.new_mix = mix;
+    }
+    config.bury_new = dc.bury_new;
+    config.bury_reviews = dc.bury_reviews;
+    config.bury_interday_learning = dc.bury_interday_learning;
+    if dc.leech_threshold > 0 {
+        config.leech_threshold = dc.leech_threshold;
+    }
+    if let Some(action) = leech_action_from_proto(dc.leech_action) {
+        config.leech_action = action;
+    }
+    if dc.desired_retention > 0.0 {
+        config.request_retention = dc.desired_retention as f64;
+    }
+    // 用 FSRS-6 优化过的牌组优先用 FSRS-6 的参数，没有或不合法时用 FSRS-5 的
+    if !apply_fsrs_weights(config, &dc.fsrs_params_6) {
+        apply_fsrs_weights(config, &dc.fsrs_params_5);
+    }
+}
+
+// anki21b：decks.kind 里记录选项 id，deck_config.config 是 protobuf
+fn load_deck_config_proto(conn: &Connection, deck_id: Option<i64>) -> Result<Option<DeckConfigConfig>, AnkiError> {
+    let kind: Option<Vec<u8>> = match deck_id {
+        Some(did) => conn
+            .query_row("SELECT kind FROM decks WHERE id = ?", [did], |row| row.get(0))
+            .optional()
+            .context("读取deck失败")?,
+        None => None,
+    };
+    let config_id = match kind {
+        Some(bytes) => decode_deck_kind(&bytes)?.normal.map(|n| n.config_id).unwrap_or(1),
+        None => 1,
+    };
+    // 选项被删掉时 Anki 会回退到默认选项（id 为 1）
+    let bytes: Option<Vec<u8>> = conn
+        .query_row(
+            "SELECT config FROM deck_config WHERE id IN (?, 1) ORDER BY id = ? DESC LIMIT 1",
+            [config_id, config_id],
+            |row| row.get(0),
+        )
+        .optional()
+        .context("读取deck_config失败")?;
+    bytes.map(|b| decode_deck_config(&b)).transpose()
+}
+
+// 提前学习时限是集合级的设置（collapseTime，秒），存在 config 表，值是 JSON
+fn load_learn_ahead_secs(conn: &Connection) -> Result<Option<i64>, AnkiError> {
+    let value: Option<Vec<u8>> = conn
+        .query_row("SELECT val FROM config WHERE KEY = 'collapseTime'", [], |row| row.get(0))
+        .optional()
+        .context("读取config失败")?;
+    Ok(value.and_then(|bytes| serde_json::from_slice::<Value>(&bytes).ok()).and_then(|v| v.as_i64()))
+}
+
+/// 按导入集合（已转换成 anki21b 的表）中牌组 deck_id（为空时取主牌组）的选项和集合设置生成调度配置，
+/// 集合里都没有或选项不合法时返回 None。算法保持默认的 FSRS，SM-2 需要用户在牌组设置里切换
+pub fn imported_config(conn: &Connection, deck_id: Option<i64>) -> Result<Option<SchedulerConfig>, AnkiError> {
+    let deck_id = match deck_id {
+        Some(did) => Some(did),
+        None => main_deck_id(conn)?,
+    };
+    let mut config = SchedulerConfig::default();
+    let mut found = false;
+    if let Some(dc) = load_deck_config_proto(conn, deck_id)? {
+        apply_deck_config_proto(&mut config, &dc);
+        found = true;
+    }
+    if let Some(secs) = load_learn_ahead_secs(conn)? {
+        config.learn_ahead_secs = secs as i32;
+        found = true;
+    }
+    if !found {
+        return Ok(None);
+    }
+    match validate(&config) {
+        Ok(()) => Ok(Some(config)),
+        Err(e) => {
+            rust_log(&format!("[调度] 导入的牌组选项无效，使用默认配置: {e}"));
+            Ok(None)
+        }
+    }
+}
+
+fn config_key(deck_key: &str) -> String {
+    format!("{SCHEDULER_CONFIG_KEY}:{deck_key}")
+}
+
+fn load_saved(conn: &Connection, key: &str) -> Result<Option<SchedulerConfig>, AnkiError> {
+    let value: Option<String> = conn
+        .prepare_cached("SELECT value FROM open_anki_config WHERE key = ?")
+        .context("准备SQL失败")?
+        .query_row([key], |row| row.get(0))
+        .optional()
+        .context("读取调度配置失败")?;
+    value.map(|json| serde_json::from_str(&json).context("解析调度配置失败")).transpose()
+}
+
+/// 读取牌组的调度配置，没有保存过时返回默认配置；导入时已经按集合里的牌组选项保存过一份
+pub fn load(conn: &Connection, deck_key: &str) -> Result<SchedulerConfig, AnkiError> {
+    Ok(load_saved(conn, &config_key(deck_key))?.unwrap_or_default())
+}
+
+pub fn has_saved(conn: &Connection, deck_key: &str) -> Result<bool, AnkiError> {
+    Ok(load_saved(conn, &config_key(deck_key))?.is_some())
+}
+
+/// 以前每个牌组一个 sqlite，配置存在牌组自己的 open_anki_config 表里
+pub fn load_deck_dir_config(conn: &Connection) -> Result<Option<SchedulerConfig>, AnkiError> {
+    if !has_table(conn, "main", "open_anki_config") {
+        return Ok(None);
+    }
+    load_saved(conn, SCHEDULER_CONFIG_KEY)
+}
+
+pub fn save(conn: &Connection, deck_key: &str, config: &SchedulerConfig) -> Result<(), AnkiError> {
+    validate(config)?;
+    let json = serde_json::to_string(config).context("序列化调度配置失败")?;
+    conn.execute(
+        "INSERT OR REPLACE INTO open_anki_config (key, value) VALUES (?, ?)",
+        [config_key(deck_key), json],
+    )
+    .context("保存调度配置失败")?;
+    Ok(())
+}
+
+pub fn remove(conn: &Connection, deck_key: &str) -> Result<(), AnkiError> {
+    conn.execute("DELETE FROM open_anki_config WHERE key = ?", [config_key(deck_key)])
+        .context("删除调度配置失败")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use crate::api::error::ErrorKind;
+    use crate::collection::memory_collection;
+    use super::*;
+
+    // Anki 的 FSRS-6 默认参数
+    const FSRS6_DEFAULT: [f32; FSRS6_WEIGHT_COUNT] = [
+        0.212, 1.2931, 2.3065, 8.2956, 6.4133, 0.8334, 3.0194, 0.001, 1.8722, 0.1666, 0.796, 1.4835, 0.0614, 0.2629,
+        1.6483, 0.6014, 1.8729, 0.5425, 0.0912, 0.0658, 0.1542,
+    ];
+
+    fn with_weights(weights: Vec<f64>) -> SchedulerConfig {
+        SchedulerConfig { weights, ..SchedulerConfig::default() }
+    }
+
+    fn fsrs6_weights() -> Vec<f64> {
+        FSRS6_DEFAULT.iter().map(|w| *w as f64).collect()
+    }
+
+    fn invalid_message(config: &SchedulerConfig) -> String {
+        let err = validate(config).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidArgument);
+        err.context
+    }
+
+    #[test]
+    fn accepts_fsrs5_and_fsrs6_weights() {
+        validate(&SchedulerConfig::default()).unwrap();
+        let config = with_weights(fsrs6_weights());
+        validate(&config).unwrap();
+        // 调度时只用前 19 个
+        assert_eq!(build_parameters(&config).w.to_vec(), config.weights[..WEIGHT_COUNT].to_vec());
+        build_fsrs(&config).unwrap();
+    }
+
+    #[test]
+    fn rejects_other_weight_counts() {
+        for count in [0, 17, 20, 22] {
+            let mut weights = fsrs6_weights();
+            weights.resize(count, 0.1);
+            let message = invalid_message(&with_weights(weights));
+            assert!(message.contains(&format!("实际为{count}")), "{message}");
+        }
+    }
+
+    #[test]
+    fn rejects_weights_out_of_range() {
+        let mut weights = default_weights();
+        weights[4] = 0.5;
+        assert_eq!(invalid_message(&with_weights(weights)), "FSRS参数w[4]=0.5超出范围[1, 10]");
+        let mut weights = default_weights();
+        weights[0] = f64::NAN;
+        assert!(invalid_message(&with_weights(weights)).starts_with("FSRS参数w[0]=NaN"));
+        // FSRS-6 多出的两个参数也要检查
+        let mut weights = fsrs6_weights();
+        weights[20] = 0.05;
+        assert_eq!(invalid_message(&with_weights(weights)), "FSRS参数w[20]=0.05超出范围[0.1, 0.8]");
+        let mut weights = fsrs6_weights();
+        weights[19] = 0.9;
+        assert_eq!(invalid_message(&with_weights(weights)), "FSRS参数w[19]=0.9超出范围[0, 0.8]");
+    }
+
+    fn import_deck_config(dc: DeckConfigConfig) -> SchedulerConfig {
+        let conn = memory_collection();
+        conn.execute(
+            "INSERT INTO deck_config (id, name, mtime_secs, usn, config) VALUES (1, 'Default', 0, 0, ?)",
+            [dc.encode_to_vec()],
+        )
+        .unwrap();
+        imported_config(&conn, None).unwrap().unwrap()
+    }
+
+    fn deck_config(fsrs_params_5: Vec<f32>, fsrs_params_6: Vec<f32>) -> DeckConfigConfig {
+        DeckConfigConfig {
+            learn_steps: vec![1.0, 10.0],
+            relearn_steps: vec![10.0],
+            fsrs_params_5,
+            fsrs_params_6,
+            new_per_day: 20,
+            reviews_per_day: 200,
+            initial_ease: 2.5,
+            easy_multiplier: 1.3,
+            hard_multiplier: 1.2,
+            interval_multiplier: 1.0,
+            maximum_review_interval: 36500,
+            minimum_lapse_interval: 1,
+            graduating_interval_good: 1,
+            graduating_interval_easy: 4,
+            leech_threshold: 8,
+            desired_retention: 0.9,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn import_prefers_fsrs6_params() {
+        let fsrs5: Vec<f32> = default_weights().iter().map(|w| *w as f32 + 0.01).collect();
+        let config = import_deck_config(deck_config(fsrs5.clone(), FSRS6_DEFAULT.to_vec()));
+        assert_eq!(config.weights, fsrs6_weights());
+        // FSRS-6 的参数不合法时退回 FSRS-5 的
+        let mut bad = FSRS6_DEFAULT.to_vec();
+        bad[20] = 2.0;
+        let config = import_deck_config(deck_config(fsrs5.clone(), bad));
+        assert_eq!(config.weights, fsrs5.iter().map(|w| *w as f64).collect::<Vec<_>>());
+        // 都没有时用默认参数
+        let config = import_deck_config(deck_config(vec![], vec![]));
+        assert_eq!(config.weights, default_weights());
+    }
+}
@@ -0,0 +1,290 @@
+// FSRS 调度：在 CardMemoryState 和 rs_fsrs::Card 之间转换，记忆状态的计算交给 rs_fsrs
+
+use chrono::{DateTime, TimeZone, Utc};
+use rs_fsrs::{Card, Parameters, Rating, State, FSRS};
+use crate::api::simple::{CardMemoryState, CardState, SchedulerConfig};
+use crate::error::AnkiError;
+use super::config::{build_fsrs, build_parameters};
+use super::steps::{answer_step, SECONDS_PER_DAY};
+use super::Scheduler;
+
+fn timestamp_to_utc(ts: i64) -> DateTime<Utc> {
+    Utc.timestamp_opt(ts, 0).single().unwrap_or_else(Utc::now)
+}
+
+fn to_fsrs_state(state: CardState) -> State {
+    match state {
+        CardState::New => State::New,
+        CardState::Learning => State::Learning,
+        CardState::Review => State::Review,
+        CardState::Relearning => State::Relearning,
+    }
+}
+
+fn from_fsrs_state(state: State) -> CardState {
+    match state {
+        State::New => CardState::New,
+        State::Learning => CardState::Learning,
+        State::Review => CardState::Review,
+        State::Relearning => CardState::Relearning,
+    }
+}
+
+pub fn to_fsrs_card(card: &CardMemoryState, now: DateTime<Utc>) -> Card {
+    Card {
+        due: timestamp_to_utc(card.due),
+        stability: card.stability,
+        difficulty: card.difficulty,
+        elapsed_days: card.elapsed_days,
+        scheduled_days: card.scheduled_days,
+        reps: card.reps,
+        lapses: card.lapses,
+        state: to_fsrs_state(card.state),
+        // 新卡没有复习时间，rs_fsrs 要求有值，用当前时间代替
+        last_review: card.last_review.map(timestamp_to_utc).unwrap_or(now),
+    }
+}
+
+pub fn from_fsrs_card(prev: &CardMemoryState, card: &Card, now: DateTime<Utc>) -> CardMemoryState {
+    CardMemoryState {
+        state: from_fsrs_state(card.state),
+        due: card.due.timestamp(),
+        stability: card.stability,
+        difficulty: card.difficulty,
+        elapsed_days: card.elapsed_days,
+        scheduled_days: card.scheduled_days,
+        reps: card.reps,
+        lapses: card.lapses,
+        step: 0,
+        last_review: Some(now.timestamp()),
+        ease_factor: prev.ease_factor,
+    }
+}
+
+/// FSRS 调度：记忆状态（稳定性、难度）交给 rs_fsrs 计算，
+/// 学习和重学阶段的间隔按牌组配置的步骤走，步骤走完后按稳定性算出复习间隔
+pub struct FsrsScheduler {
+    fsrs: FSRS,
+    parameters: Parameters,
+    learn_steps: Vec<f64>,
+    relearn_steps: Vec<f64>,
+}
+
+impl FsrsScheduler {
+    pub fn new(config: &SchedulerConfig) -> Result<Self, AnkiError> {
+        Ok(FsrsScheduler {
+            fsrs: build_fsrs(config)?,
+            parameters: build_parameters(config),
+            learn_steps: config.learn_steps.clone(),
+            relearn_steps: config.relearn_steps.clone(),
+        })
+    }
+
+    fn graduate(&self, next: &mut CardMemoryState, now: i64) {
+        let days = self.parameters.next_interval(next.stability, next.elapsed_days) as i64;
+        next.state = CardState::Review;
+        next.step = 0;
+        next.scheduled_days = days;
+        next.due = now + days * SECONDS_PER_DAY;
+    }
+}
+
+impl FsrsScheduler {
+    // rs_fsrs 算出的记忆状态再按学习步骤调整：复习卡按“良好”等通过时直接用 rs_fsrs 的结果，其余情况按步骤调度
+    fn apply_steps(&self, card: &CardMemoryState, rating: Rating, fsrs_card: &Card, now: DateTime<Utc>) -> CardMemoryState {
+        let mut next = from_fsrs_card(card, fsrs_card, now);
+        let (steps, learning_state, step) = match card.state {
+            CardState::New | CardState::Learning => (&self.learn_steps, CardState::Learning, card.step),
+            CardState::Relearning => (&self.relearn_steps, CardState::Relearning, card.step),
+            CardState::Review if rating == Rating::Again => (&self.relearn_steps, CardState::Relearning, 0),
+            CardState::Review => return next,
+        };
+        let now = now.timestamp();
+        match answer_step(steps, step, rating) {
+            Some((step, delay)) => {
+                next.state = learning_state;
+                next.step = step;
+                next.scheduled_days = 0;
+                next.due = now + delay;
+            }
+            None => self.graduate(&mut next, now),
+        }
+        next
+    }
+}
+
+impl Scheduler for FsrsScheduler {
+    fn next_state(&self, card: &CardMemoryState, rating: Rating, now: DateTime<Utc>) -> CardMemoryState {
+        let info = self.fsrs.next(to_fsrs_card(card, now), now, rating);
+        self.apply_steps(card, rating, &info.card, now)
+    }
+
+    // FSRS::repeat 一次算出四个评分的记忆状态（RecordLog），再分别按学习步骤调整
+    fn preview_states(&self, card: &CardMemoryState, now: DateTime<Utc>) -> Vec<(Rating, CardMemoryState)> {
+        let log = self.fsrs.repeat(to_fsrs_card(card, now), now);
+        Rating::iter()
+            .filter_map(|rating| log.get(rating).map(|info| (*rating, self.apply_steps(card, *rating, &info.card, now))))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::simple::new_card_memory_state;
+    use super::*;
+
+    const NOW: i64 = 1_767_225_600; // 2026-01-01T00:00:00Z
+
+    fn now() -> DateTime<Utc> {
+        timestamp_to_utc(NOW)
+    }
+
+    fn config() -> SchedulerConfig {
+        SchedulerConfig::default()
+    }
+
+    fn answer(config: &SchedulerConfig, card: &CardMemoryState, rating: Rating) -> CardMemoryState {
+        FsrsScheduler::new(config).unwrap().next_state(card, rating, now())
+    }
+
+    // 直接用 rs_fsrs 算出的结果
+    fn reference(config: &SchedulerConfig, card: &CardMemoryState, rating: Rating) -> Card {
+        FSRS::new(build_parameters(config)).next(to_fsrs_card(card, now()), now(), rating).card
+    }
+
+    fn interval_days(config: &SchedulerConfig, stability: f64) -> i64 {
+        build_parameters(config).next_interval(stability, 0) as i64
+    }
+
+    fn assert_memory_matches(next: &CardMemoryState, expected: &Card) {
+        assert_eq!((next.stability, next.difficulty), (expected.stability, expected.difficulty));
+        assert_eq!((next.reps, next.lapses), (expected.reps, expected.lapses));
+        assert_eq!(next.last_review, Some(NOW));
+    }
+
+    fn learning(config: &SchedulerConfig, step: i32) -> CardMemoryState {
+        let mut card = answer(config, &new_card_memory_state(NOW - 600), Rating::Good);
+        card.step = step;
+        card.due = NOW;
+        card.last_review = Some(NOW - 600);
+        card
+    }
+
+    // 稳定性 10 天、难度 5、间隔 10 天并且按时复习的复习卡
+    fn review() -> CardMemoryState {
+        CardMemoryState {
+            state: CardState::Review,
+            due: NOW,
+            stability: 10.0,
+            difficulty: 5.0,
+            elapsed_days: 0,
+            scheduled_days: 10,
+            reps: 4,
+            lapses: 0,
+            last_review: Some(NOW - 10 * SECONDS_PER_DAY),
+            ..new_card_memory_state(NOW)
+        }
+    }
+
+    #[test]
+    fn new_card_uses_rs_fsrs_memory_and_learn_steps() {
+        let config = config();
+        let card = new_card_memory_state(NOW);
+        for (rating, step, delay) in [(Rating::Again, 0, 60), (Rating::Hard, 0, 330), (Rating::Good, 1, 600)] {
+            let next = answer(&config, &card, rating);
+            assert_memory_matches(&next, &reference(&config, &card, rating));
+            assert_eq!((next.state, next.step, next.due - NOW, next.scheduled_days), (CardState::Learning, step, delay, 0));
+        }
+        // 简单直接毕业，间隔和 rs_fsrs 一样按稳定性算
+        let expected = reference(&config, &card, Rating::Easy);
+        let next = answer(&config, &card, Rating::Easy);
+        assert_memory_matches(&next, &expected);
+        assert_eq!((next.state, next.scheduled_days), (CardState::Review, expected.scheduled_days));
+        assert_eq!(next.due, expected.due.timestamp());
+    }
+
+    #[test]
+    fn deck_steps_override_rs_fsrs_intervals() {
+        let config = SchedulerConfig { learn_steps: vec![5.0, 30.0, 120.0], ..config() };
+        let card = new_card_memory_state(NOW);
+        let rs_due = reference(&config, &card, Rating::Good).due.timestamp();
+        let next = answer(&config, &card, Rating::Good);
+        assert_eq!((next.step, next.due - NOW), (1, 1800));
+        assert_ne!(next.due, rs_due);
+        // 没有学习步骤时第一次评分就毕业
+        let config = SchedulerConfig { learn_steps: vec![], ..config };
+        let next = answer(&config, &card, Rating::Again);
+        assert_eq!(next.state, CardState::Review);
+        assert_eq!(next.scheduled_days, interval_days(&config, next.stability));
+    }
+
+    #[test]
+    fn learning_card_graduates_after_last_step() {
+        let config = config();
+        let card = learning(&config, 1);
+        let again = answer(&config, &card, Rating::Again);
+        assert_memory_matches(&again, &reference(&config, &card, Rating::Again));
+        assert_eq!((again.state, again.step, again.due - NOW), (CardState::Learning, 0, 60));
+        let hard = answer(&config, &card, Rating::Hard);
+        assert_eq!((hard.state, hard.step, hard.due - NOW), (CardState::Learning, 1, 600));
+        for rating in [Rating::Good, Rating::Easy] {
+            let expected = reference(&config, &card, rating);
+            let next = answer(&config, &card, rating);
+            assert_memory_matches(&next, &expected);
+            assert_eq!(next.state, CardState::Review);
+            assert_eq!(next.scheduled_days, interval_days(&config, expected.stability));
+            assert_eq!(next.due, NOW + next.scheduled_days * SECONDS_PER_DAY);
+        }
+    }
+
+    #[test]
+    fn review_card_matches_rs_fsrs() {
+        let config = config();
+        let card = review();
+        // 记住时的结果和 rs_fsrs 完全一样
+        for rating in [Rating::Hard, Rating::Good, Rating::Easy] {
+            let expected = reference(&config, &card, rating);
+            let next = answer(&config, &card, rating);
+            assert_memory_matches(&next, &expected);
+            assert_eq!(next.state, CardState::Review);
+            assert_eq!((next.scheduled_days, next.due), (expected.scheduled_days, expected.due.timestamp()));
+            assert_eq!(next.elapsed_days, 10);
+        }
+        // 忘记时稳定性和 rs_fsrs 一样，间隔按重学步骤
+        let expected = reference(&config, &card, Rating::Again);
+        let next = answer(&config, &card, Rating::Again);
+        assert_memory_matches(&next, &expected);
+        assert_eq!((next.lapses, next.state, next.step, next.due - NOW), (1, CardState::Relearning, 0, 600));
+    }
+
+    #[test]
+    fn relearning_card_returns_to_review() {
+        let config = SchedulerConfig { relearn_steps: vec![10.0, 60.0], ..config() };
+        let mut card = answer(&config, &review(), Rating::Again);
+        card.due = NOW;
+        card.last_review = Some(NOW - 600);
+        let good = answer(&config, &card, Rating::Good);
+        assert_memory_matches(&good, &reference(&config, &card, Rating::Good));
+        assert_eq!((good.state, good.step, good.due - NOW), (CardState::Relearning, 1, 3600));
+        let again = answer(&config, &good, Rating::Again);
+        assert_eq!((again.state, again.step, again.lapses), (CardState::Relearning, 0, 1));
+        let easy = answer(&config, &card, Rating::Easy);
+        assert_eq!(easy.state, CardState::Review);
+        assert_eq!(easy.scheduled_days, interval_days(&config, easy.stability));
+    }
+
+    #[test]
+    fn preview_matches_next_state() {
+        let config = config();
+        let scheduler = FsrsScheduler::new(&config).unwrap();
+        for card in [new_card_memory_state(NOW), learning(&config, 1), review()] {
+            let preview = scheduler.preview_states(&card, now());
+            assert_eq!(preview.len(), 4);
+            for (rating, state) in preview {
+                let next = scheduler.next_state(&card, rating, now());
+                assert_eq!((state.state, state.due, state.step), (next.state, next.due, next.step));
+                assert_eq!((state.stability, state.difficulty), (next.stability, next.difficulty));
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+// 难记卡片（leech）：复习卡回答“重来”后遗忘次数达到阈值时，给笔记加上 leech 标签，按牌组选项决定是否暂停。
+// 和 Anki 一样，达到阈值后每再遗忘阈值的一半次数再触发一次。
+
+use rusqlite::Connection;
+use crate::api::simple::{LeechAction, SchedulerConfig};
+use crate::collection::card_state;
+use crate::error::{AnkiError, ResultExt};
+
+const LEECH_TAG: &str = "leech";
+
+/// 遗忘次数为 lapses 时是否触发难记卡片
+pub fn is_leech(lapses: i32, threshold: u32) -> bool {
+    let (lapses, threshold) = (lapses as i64, threshold as i64);
+    if threshold == 0 || lapses < threshold {
+        return false;
+    }
+    // 阈值的一半向上取整，至少为 1
+    let half = ((threshold + 1) / 2).max(1);
+    (lapses - threshold) % half == 0
+}
+
+/// 给牌组里这张卡片所在的笔记加上 leech 标签，配置为暂停时暂停这张卡片，返回笔记 id 和是否暂停
+pub fn handle(conn: &Connection, deck_key: &str, card_id: i64, config: &SchedulerConfig, now: i64) -> Result<(i64, bool), AnkiError> {
+    let note_id: i64 = conn
+        .query_row("SELECT nid FROM cards WHERE id = ?", [card_id], |row| row.get(0))
+        .context("未找到指定id的card")?;
+    card_state::add_tag(conn, note_id, LEECH_TAG, now)?;
+    let suspended = config.leech_action == LeechAction::Suspend && card_state::suspend(conn, deck_key, &[card_id], now)? > 0;
+    Ok((note_id, suspended))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collection::card_state::QUEUE_SUSPENDED;
+    use crate::collection::{add_test_card, memory_collection};
+    use super::*;
+
+    const DECK: &str = "deck";
+    const NOW: i64 = 1_700_000_000;
+
+    fn leech_lapses(threshold: u32) -> Vec<i32> {
+        (0..=20).filter(|lapses| is_leech(*lapses, threshold)).collect()
+    }
+
+    #[test]
+    fn triggers_at_threshold_then_every_half_threshold() {
+        assert_eq!(leech_lapses(8), [8, 12, 16, 20]);
+        // 一半向上取整
+        assert_eq!(leech_lapses(5), [5, 8, 11, 14, 17, 20]);
+        assert_eq!(leech_lapses(1), (1..=20).collect::<Vec<_>>());
+        assert!(leech_lapses(0).is_empty());
+    }
+
+    fn handle_with(action: LeechAction) -> (Connection, (i64, bool)) {
+        let conn = memory_collection();
+        add_test_card(&conn, DECK, 1, 100, 1000, 0);
+        conn.execute("UPDATE cards SET type = 2, queue = 2 WHERE id = 1000", []).unwrap();
+        let config = SchedulerConfig { leech_action: action, ..Default::default() };
+        let result = handle(&conn, DECK, 1000, &config, NOW).unwrap();
+        (conn, result)
+    }
+
+    fn queue_and_tags(conn: &Connection) -> (i64, String) {
+        conn.query_row("SELECT c.queue, n.tags FROM cards c JOIN notes n ON n.id = c.nid WHERE c.id = 1000", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn tag_only_keeps_the_card_in_the_queue() {
+        let (conn, result) = handle_with(LeechAction::TagOnly);
+        assert_eq!(result, (100, false));
+        assert_eq!(queue_and_tags(&conn), (2, " leech ".to_string()));
+    }
+
+    #[test]
+    fn suspend_action_suspends_the_card() {
+        let (conn, result) = handle_with(LeechAction::Suspend);
+        assert_eq!(result, (100, true));
+        assert_eq!(queue_and_tags(&conn), (QUEUE_SUSPENDED, " leech ".to_string()));
+        // 再次触发时标签不重复，已经暂停的卡片不算新暂停
+        let config = SchedulerConfig { leech_action: LeechAction::Suspend, ..Default::default() };
+        assert_eq!(handle(&conn, DECK, 1000, &config, NOW).unwrap(), (100, false));
+        assert_eq!(queue_and_tags(&conn).1, " leech ");
+    }
+}
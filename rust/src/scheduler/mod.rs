@@ -0,0 +1,127 @@
+// 卡片调度：rating 转换、卡片状态机等各算法共用的部分
+pub mod bootstrap;
+pub mod bury;
+pub mod config;
+pub mod fsrs;
+pub mod leech;
+pub mod optimizer;
+pub mod queue;
+pub mod sm2;
+pub mod steps;
+pub mod storage;
+pub mod timing;
+
+use chrono::{DateTime, Utc};
+use rs_fsrs::Rating;
+use crate::api::simple::{CardMemoryState, SchedulerAlgorithm, SchedulerConfig};
+use crate::error::AnkiError;
+
+/// 调度算法的公共接口，FSRS 和 SM-2 各自实现
+pub trait Scheduler {
+    /// 按一次评分推进卡片状态
+    fn next_state(&self, card: &CardMemoryState, rating: Rating, now: DateTime<Utc>) -> CardMemoryState;
+
+    /// 一次算出四个评分各自的结果，按 Again/Hard/Good/Easy 排序。
+    /// 默认逐个评分调用 next_state；FSRS 用 FSRS::repeat 返回的 RecordLog 一次算出
+    fn preview_states(&self, card: &CardMemoryState, now: DateTime<Utc>) -> Vec<(Rating, CardMemoryState)> {
+        Rating::iter().map(|rating| (*rating, self.next_state(card, *rating, now))).collect()
+    }
+}
+
+/// 按牌组配置选择调度算法，配置无效时返回错误
+pub fn build_scheduler(config: &SchedulerConfig) -> Result<Box<dyn Scheduler>, AnkiError> {
+    match config.algorithm {
+        SchedulerAlgorithm::Fsrs => Ok(Box::new(fsrs::FsrsScheduler::new(config)?)),
+        SchedulerAlgorithm::Sm2 => {
+            config::validate(config)?;
+            Ok(Box::new(sm2::Sm2Scheduler::new(config)))
+        }
+    }
+}
+
+/// 评分按钮从 0 开始：0-重来, 1-困难, 2-良好, 3-简单
+pub fn rating_from_u8(rating: u8) -> Result<Rating, AnkiError> {
+    match rating {
+        0 => Ok(Rating::Again),
+        1 => Ok(Rating::Hard),
+        2 => Ok(Rating::Good),
+        3 => Ok(Rating::Easy),
+        _ => Err(AnkiError::invalid_argument("无效的rating，必须是0-3之间的值")),
+    }
+}
+
+pub fn rating_to_u8(rating: Rating) -> u8 {
+    match rating {
+        Rating::Again => 0,
+        Rating::Hard => 1,
+        Rating::Good => 2,
+        Rating::Easy => 3,
+    }
+}
+
+/// 间隔显示成按钮上的短文本，和 Anki 一样用 s/m/h/d/mo/y
+pub fn format_interval(seconds: i64) -> String {
+    const MINUTE: f64 = 60.0;
+    const HOUR: f64 = 3600.0;
+    const DAY: f64 = 86400.0;
+    const MONTH: f64 = 30.0 * DAY;
+    const YEAR: f64 = 365.0 * DAY;
+    let secs = seconds.max(0) as f64;
+    // 分钟以下取整，月和年保留一位小数
+    let trim = |v: f64| {
+        let s = format!("{:.1}", v);
+        s.strip_suffix(".0").map(|t| t.to_string()).unwrap_or(s)
+    };
+    if secs < MINUTE {
+        format!("{}s", secs as i64)
+    } else if secs < HOUR {
+        format!("{}m", (secs / MINUTE).round() as i64)
+    } else if secs < DAY {
+        format!("{}h", (secs / HOUR).round() as i64)
+    } else if secs < MONTH {
+        format!("{}d", (secs / DAY).round() as i64)
+    } else if secs < YEAR {
+        format!("{}mo", trim(secs / MONTH))
+    } else {
+        format!("{}y", trim(secs / YEAR))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86400;
+
+    #[test]
+    fn interval_text_units() {
+        assert_eq!(format_interval(-5), "0s");
+        assert_eq!(format_interval(45), "45s");
+        assert_eq!(format_interval(60), "1m");
+        assert_eq!(format_interval(330), "6m");
+        assert_eq!(format_interval(3599), "60m");
+        assert_eq!(format_interval(3600), "1h");
+        assert_eq!(format_interval(5 * 3600 + 1799), "5h");
+        assert_eq!(format_interval(DAY), "1d");
+        assert_eq!(format_interval(29 * DAY), "29d");
+    }
+
+    #[test]
+    fn interval_text_months_and_years() {
+        assert_eq!(format_interval(30 * DAY), "1mo");
+        assert_eq!(format_interval(45 * DAY), "1.5mo");
+        assert_eq!(format_interval(102 * DAY), "3.4mo");
+        assert_eq!(format_interval(364 * DAY), "12.1mo");
+        assert_eq!(format_interval(365 * DAY), "1y");
+        assert_eq!(format_interval(500 * DAY), "1.4y");
+        assert_eq!(format_interval(36500 * DAY), "100y");
+    }
+
+    #[test]
+    fn rating_round_trip() {
+        for value in 0..4 {
+            assert_eq!(rating_to_u8(rating_from_u8(value).unwrap()), value);
+        }
+        assert!(rating_from_u8(4).is_err());
+    }
+}
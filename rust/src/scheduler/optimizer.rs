@@ -0,0 +1,535 @@
+// FSRS 参数优化：用复习记录训练 FSRS-5 的 19 个参数。
+// 模型公式和 rs_fsrs 一致，梯度用前向自动微分（对偶数）精确计算，优化器用 Adam，
+// 每步之后把参数限制在 config::WEIGHT_RANGES 内。
+
+use std::collections::HashMap;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use chrono::TimeZone;
+use rs_fsrs::Parameters;
+use super::config::{WEIGHT_COUNT, WEIGHT_RANGES};
+use super::timing::Timing;
+use crate::error::AnkiError;
+
+const N: usize = WEIGHT_COUNT;
+const EPOCHS: usize = 5;
+const BATCH_SIZE: usize = 512;
+const LEARNING_RATE: f64 = 4e-2;
+const S_MIN: f64 = 0.01;
+const S_MAX: f64 = 36500.0;
+// 参与训练的最少复习条数，太少时优化结果没有意义
+const MIN_TRAIN_ITEMS: usize = 8;
+
+/// 带 19 个偏导数的对偶数
+#[derive(Clone, Copy)]
+struct Dual {
+    v: f64,
+    d: [f64; N],
+}
+
+impl Dual {
+    fn constant(v: f64) -> Self {
+        Dual { v, d: [0.0; N] }
+    }
+
+    fn variable(v: f64, i: usize) -> Self {
+        let mut d = [0.0; N];
+        d[i] = 1.0;
+        Dual { v, d }
+    }
+
+    fn map(self, v: f64, dv: f64) -> Self {
+        let mut d = self.d;
+        for x in d.iter_mut() {
+            *x *= dv;
+        }
+        Dual { v, d }
+    }
+
+    fn exp(self) -> Self {
+        let v = self.v.exp();
+        self.map(v, v)
+    }
+
+    fn ln(self) -> Self {
+        self.map(self.v.ln(), 1.0 / self.v)
+    }
+
+    fn powf(self, p: f64) -> Self {
+        self.map(self.v.powf(p), p * self.v.powf(p - 1.0))
+    }
+
+    fn pow(self, p: Dual) -> Self {
+        (p * self.ln()).exp()
+    }
+
+    fn min(self, other: Dual) -> Self {
+        if self.v <= other.v { self } else { other }
+    }
+
+    // 被截断时导数为 0
+    fn clamp(self, lo: f64, hi: f64) -> Self {
+        if self.v < lo {
+            Dual::constant(lo)
+        } else if self.v > hi {
+            Dual::constant(hi)
+        } else {
+            self
+        }
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+    fn add(self, o: Dual) -> Dual {
+        let mut d = self.d;
+        for (x, y) in d.iter_mut().zip(o.d.iter()) {
+            *x += y;
+        }
+        Dual { v: self.v + o.v, d }
+    }
+}
+
+impl Sub for Dual {
+    type Output = Dual;
+    fn sub(self, o: Dual) -> Dual {
+        self + (-o)
+    }
+}
+
+impl Neg for Dual {
+    type Output = Dual;
+    fn neg(self) -> Dual {
+        self.map(-self.v, -1.0)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+    fn mul(self, o: Dual) -> Dual {
+        let mut d = [0.0; N];
+        for (i, x) in d.iter_mut().enumerate() {
+            *x = self.d[i] * o.v + o.d[i] * self.v;
+        }
+        Dual { v: self.v * o.v, d }
+    }
+}
+
+impl Div for Dual {
+    type Output = Dual;
+    fn div(self, o: Dual) -> Dual {
+        let mut d = [0.0; N];
+        for (i, x) in d.iter_mut().enumerate() {
+            *x = (self.d[i] * o.v - o.d[i] * self.v) / (o.v * o.v);
+        }
+        Dual { v: self.v / o.v, d }
+    }
+}
+
+impl Add<f64> for Dual {
+    type Output = Dual;
+    fn add(self, o: f64) -> Dual {
+        Dual { v: self.v + o, d: self.d }
+    }
+}
+
+impl Mul<f64> for Dual {
+    type Output = Dual;
+    fn mul(self, o: f64) -> Dual {
+        self.map(self.v * o, o)
+    }
+}
+
+/// 一次复习：rating 为 1-4（Again..Easy），delta_days 为距上次复习的天数
+#[derive(Clone, Copy)]
+pub struct Review {
+    pub rating: u8,
+    pub delta_days: i64,
+}
+
+/// 每训练完一批回调一次，参数为 (已完成步数, 总步数)
+pub type ProgressFn<'a> = dyn FnMut(usize, usize) + 'a;
+
+pub struct OptimizeOutput {
+    pub weights: Vec<f64>,
+    pub log_loss_before: f64,
+    pub log_loss_after: f64,
+    pub rmse_before: f64,
+    pub rmse_after: f64,
+    pub item_count: usize,
+    pub total_steps: usize,
+}
+
+fn init_stability(w: &[Dual; N], rating: u8) -> Dual {
+    w[(rating - 1) as usize].clamp(0.1, S_MAX)
+}
+
+fn init_difficulty(w: &[Dual; N], rating: u8) -> Dual {
+    (w[4] - (w[5] * (rating as f64 - 1.0)).exp() + 1.0).clamp(1.0, 10.0)
+}
+
+fn forgetting_curve(delta_days: i64, s: Dual) -> Dual {
+    ((Dual::constant(delta_days as f64 * Parameters::FACTOR) / s) + 1.0).powf(Parameters::DECAY)
+}
+
+fn next_difficulty(w: &[Dual; N], d: Dual, rating: u8) -> Dual {
+    let next = d - w[6] * (rating as f64 - 3.0);
+    // 向“简单”初始难度做均值回归
+    (w[7] * init_difficulty(w, 4) + (Dual::constant(1.0) - w[7]) * next).clamp(1.0, 10.0)
+}
+
+fn next_recall_stability(w: &[Dual; N], d: Dual, s: Dual, r: Dual, rating: u8) -> Dual {
+    let modifier = match rating {
+        2 => w[15],
+        4 => w[16],
+        _ => Dual::constant(1.0),
+    };
+    s * (w[8].exp()
+        * (Dual::constant(11.0) - d)
+        * s.pow(-w[9])
+        * (((Dual::constant(1.0) - r) * w[10]).exp() + -1.0)
+        * modifier
+        + 1.0)
+}
+
+fn next_forget_stability(w: &[Dual; N], d: Dual, s: Dual, r: Dual) -> Dual {
+    let forget = w[11] * d.pow(-w[12]) * ((s + 1.0).pow(w[13]) + -1.0) * ((Dual::constant(1.0) - r) * w[14]).exp();
+    forget.min(s)
+}
+
+fn short_term_stability(w: &[Dual; N], s: Dual, rating: u8) -> Dual {
+    s * (w[17] * (w[18] + (rating as f64 - 3.0))).exp()
+}
+
+// 沿一张卡的复习记录推进记忆状态，每次隔天复习前的预测记忆率交给 on_predict
+fn replay_card(w: &[Dual; N], reviews: &[Review], on_predict: &mut dyn FnMut(Dual, bool)) {
+    let Some(first) = reviews.first() else {
+        return;
+    };
+    let mut s = init_stability(w, first.rating);
+    let mut d = init_difficulty(w, first.rating);
+    for review in &reviews[1..] {
+        if review.delta_days > 0 {
+            let r = forgetting_curve(review.delta_days, s);
+            on_predict(r, review.rating > 1);
+            s = if review.rating == 1 {
+                next_forget_stability(w, d, s, r)
+            } else {
+                next_recall_stability(w, d, s, r, review.rating)
+            };
+        } else {
+            // 同一天内的复习按短期记忆处理
+            s = short_term_stability(w, s, review.rating);
+        }
+        s = s.clamp(S_MIN, S_MAX);
+        d = next_difficulty(w, d, review.rating);
+    }
+}
+
+fn to_duals(weights: &[f64]) -> [Dual; N] {
+    let mut w = [Dual::constant(0.0); N];
+    for (i, x) in w.iter_mut().enumerate() {
+        *x = Dual::variable(weights[i], i);
+    }
+    w
+}
+
+fn binary_cross_entropy(r: f64, recalled: bool) -> f64 {
+    let r = r.clamp(1e-4, 1.0 - 1e-4);
+    if recalled { -r.ln() } else { -(1.0 - r).ln() }
+}
+
+// 返回 (平均 log loss, 分箱 RMSE)
+fn evaluate(weights: &[f64], cards: &[Vec<Review>]) -> (f64, f64) {
+    let w = to_duals(weights);
+    let mut loss = 0.0;
+    let mut count = 0usize;
+    // 按预测记忆率分 20 个箱：(预测之和, 实际之和, 条数)
+    let mut bins = [(0.0f64, 0.0f64, 0usize); 20];
+    for card in cards {
+        replay_card(&w, card, &mut |r, recalled| {
+            loss += binary_cross_entropy(r.v, recalled);
+            count += 1;
+            let bin = &mut bins[((r.v * 20.0) as usize).min(19)];
+            bin.0 += r.v;
+            bin.1 += if recalled { 1.0 } else { 0.0 };
+            bin.2 += 1;
+        });
+    }
+    if count == 0 {
+        return (0.0, 0.0);
+    }
+    let mse: f64 = bins
+        .iter()
+        .filter(|b| b.2 > 0)
+        .map(|(pred, actual, n)| {
+            let n = *n as f64;
+            n * (pred / n - actual / n).powi(2)
+        })
+        .sum::<f64>()
+        / count as f64;
+    (loss / count as f64, mse.sqrt())
+}
+
+// 一批卡片的平均 loss 对各参数的梯度
+fn batch_gradient(weights: &[f64], cards: &[&Vec<Review>]) -> [f64; N] {
+    let w = to_duals(weights);
+    let mut grad = [0.0; N];
+    let mut count = 0usize;
+    for card in cards {
+        replay_card(&w, card, &mut |r, recalled| {
+            let r = r.clamp(1e-4, 1.0 - 1e-4);
+            let loss = if recalled { -r.ln() } else { -(Dual::constant(1.0) - r).ln() };
+            for (g, d) in grad.iter_mut().zip(loss.d.iter()) {
+                *g += d;
+            }
+            count += 1;
+        });
+    }
+    if count > 0 {
+        for g in grad.iter_mut() {
+            *g /= count as f64;
+        }
+    }
+    grad
+}
+
+// 固定种子的线性同余随机数，保证同样的数据得到同样的结果
+fn shuffle<T>(items: &mut [T], seed: &mut u64) {
+    for i in (1..items.len()).rev() {
+        *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let j = ((*seed >> 33) as usize) % (i + 1);
+        items.swap(i, j);
+    }
+}
+
+/// 按卡片分组、按时间排序后的复习记录，时间为秒，rating 为 1-4。
+/// 天数按学习日（和调度一样在换天时刻换天）的差计算，同一学习日内多次复习记为 0 天。
+pub fn build_card_histories<Tz: TimeZone>(reviews: &[(i64, i64, u8)], timing: &Timing<Tz>) -> Vec<Vec<Review>> {
+    let mut by_card: HashMap<i64, Vec<(i64, u8)>> = HashMap::new();
+    for &(card_id, time, rating) in reviews {
+        if (1..=4).contains(&rating) {
+            by_card.entry(card_id).or_default().push((time, rating));
+        }
+    }
+    let mut card_ids: Vec<i64> = by_card.keys().copied().collect();
+    card_ids.sort();
+    card_ids
+        .into_iter()
+        .filter_map(|id| by_card.remove(&id))
+        .map(|mut list| {
+            list.sort_by_key(|(time, _)| *time);
+            let mut prev_day: Option<i64> = None;
+            list.into_iter()
+                .map(|(time, rating)| {
+                    let day = timing.day(time);
+                    let delta_days = prev_day.map(|p| day - p).unwrap_or(0);
+                    prev_day = Some(day);
+                    Review { rating, delta_days }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// 一张卡参与训练的预测条数：第一次之后的隔天复习，和 replay_card 里调用 on_predict 的次数一致
+fn card_items(card: &[Review]) -> usize {
+    card.iter().skip(1).filter(|r| r.delta_days > 0).count()
+}
+
+// 按卡片切分成每批约 BATCH_SIZE 条预测
+fn split_batches<'a>(order: &[&'a Vec<Review>]) -> Vec<Vec<&'a Vec<Review>>> {
+    let mut batches = vec![];
+    let mut batch = vec![];
+    let mut n = 0;
+    for card in order {
+        batch.push(*card);
+        n += card_items(card);
+        if n >= BATCH_SIZE {
+            batches.push(std::mem::take(&mut batch));
+            n = 0;
+        }
+    }
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    batches
+}
+
+pub fn optimize(cards: &[Vec<Review>], initial: &[f64], progress: &mut ProgressFn) -> Result<OptimizeOutput, AnkiError> {
+    if initial.len() < N {
+        return Err(AnkiError::invalid_argument(format!("FSRS参数个数必须至少为{N}")));
+    }
+    let initial: Vec<f64> = initial[..N].to_vec();
+    let item_count = cards.iter().map(|c| card_items(c)).sum::<usize>();
+    if item_count < MIN_TRAIN_ITEMS {
+        return Err(AnkiError::invalid_argument(format!("可用于训练的复习记录太少: {item_count}，至少需要{MIN_TRAIN_ITEMS}条")));
+    }
+    let (log_loss_before, rmse_before) = evaluate(&initial, cards);
+
+    // 每轮打乱后重新分批，先分好所有轮次，推送的进度总数就是实际的步数
+    let mut order: Vec<&Vec<Review>> = cards.iter().filter(|c| card_items(c) > 0).collect();
+    let mut seed = 42u64;
+    let epochs: Vec<Vec<Vec<&Vec<Review>>>> = (0..EPOCHS)
+        .map(|_| {
+            shuffle(&mut order, &mut seed);
+            split_batches(&order)
+        })
+        .collect();
+    let total_steps = epochs.iter().map(Vec::len).sum::<usize>();
+    let mut weights = initial.clone();
+    let (beta1, beta2, eps) = (0.9, 0.999, 1e-8);
+    let mut m = [0.0; N];
+    let mut v = [0.0; N];
+    let mut step = 0usize;
+    for batch in epochs.iter().flatten() {
+        let grad = batch_gradient(&weights, batch);
+        step += 1;
+        // 学习率按余弦退火
+        let lr = LEARNING_RATE * 0.5 * (1.0 + (std::f64::consts::PI * step as f64 / total_steps.max(1) as f64).cos());
+        for i in 0..N {
+            if !grad[i].is_finite() {
+                continue;
+            }
+            m[i] = beta1 * m[i] + (1.0 - beta1) * grad[i];
+            v[i] = beta2 * v[i] + (1.0 - beta2) * grad[i] * grad[i];
+            let m_hat = m[i] / (1.0 - beta1.powi(step as i32));
+            let v_hat = v[i] / (1.0 - beta2.powi(step as i32));
+            let (lo, hi) = WEIGHT_RANGES[i];
+            weights[i] = (weights[i] - lr * m_hat / (v_hat.sqrt() + eps)).clamp(lo, hi);
+        }
+        progress(step, total_steps);
+    }
+
+    let (log_loss_after, rmse_after) = evaluate(&weights, cards);
+    // 和 Anki 一样，优化后反而变差时保留原参数
+    if !log_loss_after.is_finite() || log_loss_after > log_loss_before {
+        return Ok(OptimizeOutput {
+            weights: initial,
+            log_loss_before,
+            log_loss_after: log_loss_before,
+            rmse_before,
+            rmse_after: rmse_before,
+            item_count,
+            total_steps,
+        });
+    }
+    Ok(OptimizeOutput { weights, log_loss_before, log_loss_after, rmse_before, rmse_after, item_count, total_steps })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, FixedOffset};
+    use crate::api::error::ErrorKind;
+    use super::*;
+
+    fn review(rating: u8, delta_days: i64) -> Review {
+        Review { rating, delta_days }
+    }
+
+    // 覆盖四种首次评分、隔天的记住和遗忘、同一天的短期复习，让每个参数都参与计算
+    fn small_histories() -> Vec<Vec<Review>> {
+        vec![
+            vec![review(1, 0), review(3, 0), review(3, 1), review(2, 3), review(3, 7)],
+            vec![review(2, 0), review(3, 2), review(1, 6), review(3, 0), review(3, 2)],
+            vec![review(3, 0), review(4, 3), review(3, 10), review(1, 25), review(2, 1)],
+            vec![review(4, 0), review(3, 8), review(4, 20), review(3, 45)],
+        ]
+    }
+
+    fn default_weights() -> Vec<f64> {
+        Parameters::default().w.to_vec()
+    }
+
+    fn mean_loss(weights: &[f64], cards: &[Vec<Review>]) -> f64 {
+        evaluate(weights, cards).0
+    }
+
+    #[test]
+    fn gradient_matches_finite_differences() {
+        let cards = small_histories();
+        let weights = default_weights();
+        let grad = batch_gradient(&weights, &cards.iter().collect::<Vec<_>>());
+        let h = 1e-6;
+        for i in 0..N {
+            let mut plus = weights.clone();
+            plus[i] += h;
+            let mut minus = weights.clone();
+            minus[i] -= h;
+            let numeric = (mean_loss(&plus, &cards) - mean_loss(&minus, &cards)) / (2.0 * h);
+            assert!(
+                (grad[i] - numeric).abs() <= 1e-5 + 1e-4 * numeric.abs(),
+                "w[{i}]: 自动微分 {} 和有限差分 {} 不一致",
+                grad[i],
+                numeric
+            );
+        }
+        // 每个参数都应该影响 loss
+        assert!(grad.iter().all(|g| *g != 0.0), "{grad:?}");
+    }
+
+    // 记得比默认参数预测的更牢：间隔越来越长仍然大多记住
+    fn synthetic_histories() -> Vec<Vec<Review>> {
+        (0..200)
+            .map(|i| {
+                let last = if i % 10 == 0 { 1 } else { 3 };
+                vec![review(3, 0), review(3, 4), review(3, 15), review(3, 40), review(last, 100)]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn loss_decreases_on_synthetic_data() {
+        let cards = synthetic_histories();
+        let mut calls = vec![];
+        let output = optimize(&cards, &default_weights(), &mut |current, total| calls.push((current, total))).unwrap();
+        assert_eq!(output.item_count, 800);
+        assert!(output.log_loss_after < output.log_loss_before, "{} -> {}", output.log_loss_before, output.log_loss_after);
+        assert_ne!(output.weights, default_weights());
+        for (i, w) in output.weights.iter().enumerate() {
+            let (lo, hi) = WEIGHT_RANGES[i];
+            assert!((lo..=hi).contains(w), "w[{i}]={w}");
+        }
+        // 每步推送一次进度，总数就是实际的步数
+        assert_eq!(calls.len(), output.total_steps);
+        assert_eq!(calls.last(), Some(&(output.total_steps, output.total_steps)));
+        // 同样的数据得到同样的结果
+        let again = optimize(&cards, &default_weights(), &mut |_, _| {}).unwrap();
+        assert_eq!(again.weights, output.weights);
+    }
+
+    #[test]
+    fn too_few_items_is_an_error() {
+        // 6 次隔天复习，同一天内的复习和第一次复习不算
+        let cards = vec![
+            vec![review(3, 0), review(3, 0), review(3, 1), review(3, 3), review(3, 7)],
+            vec![review(3, 0), review(3, 2), review(3, 5), review(2, 0), review(3, 9)],
+        ];
+        let err = optimize(&cards, &default_weights(), &mut |_, _| {}).err().unwrap();
+        assert_eq!(err.kind, ErrorKind::InvalidArgument);
+        assert!(err.context.contains(": 6，"), "{}", err.context);
+        let mut cards = cards;
+        cards.push(vec![review(3, 0), review(3, 4), review(1, 0), review(3, 1)]);
+        assert_eq!(optimize(&cards, &default_weights(), &mut |_, _| {}).unwrap().item_count, MIN_TRAIN_ITEMS);
+        let err = optimize(&cards, &default_weights()[..N - 1], &mut |_, _| {}).err().unwrap();
+        assert_eq!(err.kind, ErrorKind::InvalidArgument);
+    }
+
+    #[test]
+    fn histories_split_days_at_rollover() {
+        let ts = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().timestamp();
+        let timing = Timing::new(ts("2026-01-01T00:00:00Z"), 4, FixedOffset::east_opt(8 * 3600).unwrap());
+        let reviews = [
+            (2, ts("2026-01-03T10:00:00+08:00"), 3),
+            (1, ts("2026-01-02T23:00:00+08:00"), 3),
+            // 过了零点但还没到换天时刻，仍是同一个学习日
+            (1, ts("2026-01-03T01:00:00+08:00"), 1),
+            // 同一个自然日里跨过了换天时刻
+            (1, ts("2026-01-03T05:00:00+08:00"), 3),
+            (1, ts("2026-01-06T03:59:00+08:00"), 4),
+            (2, ts("2026-01-03T03:00:00+08:00"), 5),
+        ];
+        let cards = build_card_histories(&reviews, &timing);
+        let summary: Vec<Vec<(u8, i64)>> = cards.iter().map(|c| c.iter().map(|r| (r.rating, r.delta_days)).collect()).collect();
+        assert_eq!(summary, vec![vec![(3, 0), (1, 0), (3, 1), (4, 2)], vec![(3, 0)]]);
+    }
+}
@@ -0,0 +1,551 @@
+// 学习队列：按牌组选项从集合里取出今天要学的卡片。
+// 新卡片先按收集顺序取到每天的上限，再按排序方式排列；复习卡按排序方式取到每天的上限，
+// 再和新卡穿插，和 Anki v3 调度器的做法一致。
+// 随机顺序用卡片/笔记 id 和当天的天数算哈希，同一天里多次构建得到的队列不变。
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use rs_fsrs::Parameters;
+use rusqlite::{params, Connection};
+use serde_json::Value;
+use crate::api::simple::{
+    NewCardGatherPriority, NewCardQueue, NewCardSortOrder, NewReviewMix, QueueKind, QueueLimits, QueuedCard, ReviewQueue,
+    ReviewSortOrder, SchedulerConfig,
+};
+use crate::collection::IN_DECK;
+use crate::error::{AnkiError, ResultExt};
+use super::bury::{buries, unbury_on_new_day};
+use super::steps::SECONDS_PER_DAY;
+use super::timing;
+
+struct NewCard {
+    id: i64,
+    nid: i64,
+    ord: i64,
+    due: i64, // 新卡的 due 是位置
+    deck_name: String,
+}
+
+// 到期的复习卡和跨天学习的卡片，due 是天数
+struct DueCard {
+    id: i64,
+    nid: i64,
+    queue: i64,
+    due: i64,
+    ivl: i64,
+    data: String,
+}
+
+/// 今天是集合创建后的第几个学习日，以及今天开始的时间戳（秒），按本地时间的换天时刻划分
+pub fn today(conn: &Connection, now: i64) -> Result<(i64, i64), AnkiError> {
+    Ok(timing::load(conn)?.today(now))
+}
+
+// FNV-1a，只用来打乱顺序
+fn shuffle_key(id: i64, salt: i64) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in id.to_le_bytes().into_iter().chain(salt.to_le_bytes()) {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// 今天已经学过的新卡数。回答新卡时 revlog 记为学习（type 0），评分前的间隔 lastIvl 为 0；
+/// 重置过的卡片以前的复习记录不影响
+fn introduced_today(conn: &Connection, deck_key: &str, day_start: i64) -> Result<u32, AnkiError> {
+    conn.prepare_cached(&format!(
+        "SELECT COUNT(DISTINCT cid) FROM revlog WHERE type = 0 AND lastIvl = 0 AND ease BETWEEN 1 AND 4 \
+         AND cid IN (SELECT id FROM cards WHERE {IN_DECK}) AND id >= ?"
+    ))
+    .context("准备SQL失败")?
+    .query_row(params![deck_key, day_start * 1000], |row| row.get(0))
+    .context("统计今天的新卡失败")
+}
+
+/// 今天已经复习过的复习卡数，评分前是复习卡的记录 type 为 1
+fn reviewed_today(conn: &Connection, deck_key: &str, day_start: i64) -> Result<u32, AnkiError> {
+    conn.prepare_cached(&format!(
+        "SELECT COUNT(DISTINCT cid) FROM revlog WHERE type = 1 AND ease BETWEEN 1 AND 4 \
+         AND cid IN (SELECT id FROM cards WHERE {IN_DECK}) AND id >= ?"
+    ))
+    .context("准备SQL失败")?
+    .query_row(params![deck_key, day_start * 1000], |row| row.get(0))
+    .context("统计今天的复习失败")
+}
+
+// 可以学的新卡：type 0 且 queue 0，暂停（-1）和搁置（-2/-3）的不算
+fn load_new_cards(conn: &Connection, deck_key: &str) -> Result<Vec<NewCard>, AnkiError> {
+    let mut stmt = conn
+        .prepare_cached(&format!(
+            "SELECT id, nid, ord, due, (SELECT name FROM decks WHERE decks.id = cards.did) FROM cards \
+             WHERE type = 0 AND queue = 0 AND {IN_DECK}"
+        ))
+        .context("准备SQL失败")?;
+    let rows = stmt
+        .query_map([deck_key], |row| {
+            Ok(NewCard {
+                id: row.get(0)?,
+                nid: row.get(1)?,
+                ord: row.get(2)?,
+                due: row.get(3)?,
+                deck_name: row.get::<_, Option<String>>(4)?.unwrap_or_default(),
+            })
+        })
+        .context("查询SQL失败")?;
+    rows.collect::<Result<Vec<_>, _>>().context("读取card失败")
+}
+
+// 牌组名用 \x1f 分隔层级，直接按名字排序时子牌组紧跟在父牌组后面
+fn gather(cards: &mut [NewCard], priority: NewCardGatherPriority, salt: i64) {
+    match priority {
+        NewCardGatherPriority::Deck => cards.sort_by(|a, b| (&a.deck_name, a.due, a.ord).cmp(&(&b.deck_name, b.due, b.ord))),
+        NewCardGatherPriority::DeckThenRandomNotes => cards.sort_by(|a, b| {
+            (&a.deck_name, shuffle_key(a.nid, salt), a.ord).cmp(&(&b.deck_name, shuffle_key(b.nid, salt), b.ord))
+        }),
+        NewCardGatherPriority::LowestPosition => cards.sort_by_key(|c| (c.due, c.ord)),
+        NewCardGatherPriority::HighestPosition => cards.sort_by_key(|c| (Reverse(c.due), c.ord)),
+        NewCardGatherPriority::RandomNotes => cards.sort_by_key(|c| (shuffle_key(c.nid, salt), c.ord)),
+        NewCardGatherPriority::RandomCards => cards.sort_by_key(|c| shuffle_key(c.id, salt)),
+    }
+}
+
+// 排序都是稳定的，键相同时保持收集顺序
+fn sort(cards: &mut [NewCard], order: NewCardSortOrder, salt: i64) {
+    match order {
+        NewCardSortOrder::Template => cards.sort_by_key(|c| c.ord),
+        NewCardSortOrder::NoSort => {}
+        NewCardSortOrder::TemplateThenRandom => cards.sort_by_key(|c| (c.ord, shuffle_key(c.id, salt))),
+        NewCardSortOrder::RandomNoteThenTemplate => cards.sort_by_key(|c| (shuffle_key(c.nid, salt), c.ord)),
+        NewCardSortOrder::RandomCard => cards.sort_by_key(|c| shuffle_key(c.id, salt)),
+    }
+}
+
+// 按收集顺序取前 limit 张新卡再排序，同时返回能学的新卡总数。
+// 搁置新卡时，seen 里的笔记和已经取到的笔记的其他新卡跳过，不占上限
+fn new_cards(
+    conn: &Connection,
+    deck_key: &str,
+    config: &SchedulerConfig,
+    day: i64,
+    limit: u32,
+    seen: &mut HashSet<i64>,
+) -> Result<(Vec<QueuedCard>, u32), AnkiError> {
+    let mut cards = load_new_cards(conn, deck_key)?;
+    let available = cards.len() as u32;
+    gather(&mut cards, config.new_card_gather_priority, day);
+    if buries(config, QueueKind::New) {
+        cards.retain(|c| seen.insert(c.nid));
+    }
+    cards.truncate(limit as usize);
+    sort(&mut cards, config.new_card_sort_order, day);
+    let cards = cards.into_iter().map(|c| QueuedCard { card_id: c.id, note_id: c.nid, kind: QueueKind::New }).collect();
+    Ok((cards, available))
+}
+
+/// 今天的新卡队列：扣掉今天已经学过的新卡后按每天上限取卡
+pub fn build_new_queue(conn: &Connection, deck_key: &str, config: &SchedulerConfig, now: i64) -> Result<NewCardQueue, AnkiError> {
+    let (day, day_start) = today(conn, now)?;
+    unbury_on_new_day(conn, deck_key, day)?;
+    let introduced_today = introduced_today(conn, deck_key, day_start)?;
+    let limit = config.new_per_day.saturating_sub(introduced_today);
+    let (cards, available) = new_cards(conn, deck_key, config, day, limit, &mut HashSet::new())?;
+    Ok(NewCardQueue { cards, introduced_today, available })
+}
+
+// 到今天为止到期的复习卡（queue 2）和跨天学习的卡片（queue 3）
+fn load_due_cards(conn: &Connection, deck_key: &str, day: i64) -> Result<Vec<DueCard>, AnkiError> {
+    let mut stmt = conn
+        .prepare_cached(&format!("SELECT id, nid, queue, due, ivl, data FROM cards WHERE queue IN (2, 3) AND due <= ? AND {IN_DECK}"))
+        .context("准备SQL失败")?;
+    let rows = stmt
+        .query_map(params![day, deck_key], |row| {
+            Ok(DueCard {
+                id: row.get(0)?,
+                nid: row.get(1)?,
+                queue: row.get(2)?,
+                due: row.get(3)?,
+                ivl: row.get(4)?,
+                data: row.get::<_, Option<String>>(5)?.unwrap_or_default(),
+            })
+        })
+        .context("查询SQL失败")?;
+    rows.collect::<Result<Vec<_>, _>>().context("读取card失败")
+}
+
+// 按 FSRS 的遗忘曲线估算现在的可提取性；没有记忆状态的 SM-2 卡片把间隔当作稳定性
+fn retrievability(card: &DueCard, day: i64, now: i64) -> f64 {
+    let data: Value = serde_json::from_str(&card.data).unwrap_or(Value::Null);
+    let stability = data.get("s").and_then(|v| v.as_f64()).unwrap_or(card.ivl.max(1) as f64).max(0.01);
+    let elapsed_days = data
+        .get("lrt")
+        .and_then(|v| v.as_i64())
+        .map(|lrt| (now - lrt) as f64 / SECONDS_PER_DAY as f64)
+        .unwrap_or((day - (card.due - card.ivl)) as f64)
+        .max(0.0);
+    (1.0 + Parameters::FACTOR * elapsed_days / stability).powf(Parameters::DECAY)
+}
+
+fn sort_due(cards: &mut [DueCard], order: ReviewSortOrder, day: i64, now: i64) {
+    match order {
+        ReviewSortOrder::Due => cards.sort_by_key(|c| (c.due, shuffle_key(c.id, day))),
+        ReviewSortOrder::RetrievabilityAscending => {
+            cards.sort_by(|a, b| retrievability(a, day, now).total_cmp(&retrievability(b, day, now)))
+        }
+        ReviewSortOrder::Random => cards.sort_by_key(|c| shuffle_key(c.id, day)),
+        ReviewSortOrder::RelativeOverdueness => {
+            let overdueness = |c: &DueCard| (day - c.due) as f64 / c.ivl.max(1) as f64;
+            cards.sort_by(|a, b| overdueness(b).total_cmp(&overdueness(a)))
+        }
+    }
+}
+
+// 新卡按比例均匀穿插：每次取已取比例更小的一边
+fn mix(reviews: Vec<QueuedCard>, new: Vec<QueuedCard>, mode: NewReviewMix) -> Vec<QueuedCard> {
+    match mode {
+        NewReviewMix::ReviewsFirst => reviews.into_iter().chain(new).collect(),
+        NewReviewMix::NewFirst => new.into_iter().chain(reviews).collect(),
+        NewReviewMix::MixWithReviews => {
+            let (review_total, new_total) = (reviews.len() as f64, new.len() as f64);
+            let mut reviews = reviews.into_iter().peekable();
+            let mut new = new.into_iter().peekable();
+            let (mut review_taken, mut new_taken) = (0.0, 0.0);
+            let mut mixed = vec![];
+            while reviews.peek().is_some() || new.peek().is_some() {
+                let take_review = new.peek().is_none()
+                    || (reviews.peek().is_some() && (review_taken + 0.5) / review_total <= (new_taken + 0.5) / new_total);
+                if take_review {
+                    mixed.extend(reviews.next());
+                    review_taken += 1.0;
+                } else {
+                    mixed.extend(new.next());
+                    new_taken += 1.0;
+                }
+            }
+            mixed
+        }
+    }
+}
+
+// 当天学习队列（queue 1）里的卡片，按到期时间排序，due 是时间戳
+fn learning_cards(conn: &Connection, deck_key: &str) -> Result<Vec<(QueuedCard, i64)>, AnkiError> {
+    let mut stmt = conn
+        .prepare_cached(&format!("SELECT id, nid, due FROM cards WHERE queue = 1 AND {IN_DECK} ORDER BY due, id"))
+        .context("准备SQL失败")?;
+    let rows = stmt
+        .query_map([deck_key], |row| {
+            Ok((QueuedCard { card_id: row.get(0)?, note_id: row.get(1)?, kind: QueueKind::Learning }, row.get(2)?))
+        })
+        .context("查询SQL失败")?;
+    rows.collect::<Result<Vec<_>, _>>().context("读取card失败")
+}
+
+/// 现在的学习队列，limits 里没有给出的上限用牌组配置。
+/// 和 Anki 一样按当天学习、复习、新卡的顺序收集，按配置跳过已经收集到的笔记的其他卡片
+pub fn build_queue(conn: &Connection, deck_key: &str, config: &SchedulerConfig, limits: &QueueLimits, now: i64) -> Result<ReviewQueue, AnkiError> {
+    let (day, day_start) = today(conn, now)?;
+    unbury_on_new_day(conn, deck_key, day)?;
+
+    let learning = learning_cards(conn, deck_key)?;
+    let mut seen: HashSet<i64> = learning.iter().map(|(card, _)| card.note_id).collect();
+
+    // 跨天学习的卡片和复习卡一起排序，一起计入复习上限
+    let review_limit = limits.reviews.unwrap_or(config.reviews_per_day).saturating_sub(reviewed_today(conn, deck_key, day_start)?);
+    let mut due = load_due_cards(conn, deck_key, day)?;
+    sort_due(&mut due, config.review_sort_order, day, now);
+    let reviews: Vec<QueuedCard> = due
+        .into_iter()
+        .map(|c| QueuedCard {
+            card_id: c.id,
+            note_id: c.nid,
+            kind: if c.queue == 3 { QueueKind::Learning } else { QueueKind::Review },
+        })
+        .filter(|c| seen.insert(c.note_id) || !buries(config, c.kind))
+        .take(review_limit as usize)
+        .collect();
+
+    let new_limit = limits.new_cards.unwrap_or(config.new_per_day).saturating_sub(introduced_today(conn, deck_key, day_start)?);
+    let (new, _) = new_cards(conn, deck_key, config, day, new_limit, &mut seen)?;
+
+    let cutoff = now + config.learn_ahead_secs.max(0) as i64;
+    let next_learning_due = learning.iter().map(|(_, due)| *due).find(|due| *due > cutoff);
+    let (learning_due, learn_ahead): (Vec<_>, Vec<_>) =
+        learning.into_iter().filter(|(_, due)| *due <= cutoff).partition(|(_, due)| *due <= now);
+
+    let cards: Vec<QueuedCard> = learning_due
+        .into_iter()
+        .map(|(card, _)| card)
+        .chain(mix(reviews, new, config.new_mix))
+        .chain(learn_ahead.into_iter().map(|(card, _)| card))
+        .collect();
+    let count = |kind: QueueKind| cards.iter().filter(|c| c.kind == kind).count() as u32;
+    Ok(ReviewQueue {
+        new_count: count(QueueKind::New),
+        learning_count: count(QueueKind::Learning),
+        review_count: count(QueueKind::Review),
+        cards,
+        next_learning_due,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collection::card_state::{QUEUE_MANUALLY_BURIED, QUEUE_SIBLING_BURIED, QUEUE_SUSPENDED};
+    use crate::collection::{add_test_card, memory_collection};
+    use super::*;
+
+    const DECK: &str = "deck";
+
+    // 集合创建后第 3 天的中午
+    fn noon(conn: &Connection) -> i64 {
+        timing::collection_crt(conn).unwrap() + 3 * SECONDS_PER_DAY + 12 * 3600
+    }
+
+    fn add_deck(conn: &Connection, did: i64, name: &str) {
+        conn.execute("INSERT INTO decks (id, name, mtime_secs, usn, common, kind) VALUES (?, ?, 0, 0, x'', x'')", params![did, name])
+            .unwrap();
+    }
+
+    fn config(priority: NewCardGatherPriority, order: NewCardSortOrder) -> SchedulerConfig {
+        SchedulerConfig { new_card_gather_priority: priority, new_card_sort_order: order, ..Default::default() }
+    }
+
+    fn new_ids(conn: &Connection, config: &SchedulerConfig) -> Vec<i64> {
+        build_new_queue(conn, DECK, config, noon(conn)).unwrap().cards.iter().map(|c| c.card_id).collect()
+    }
+
+    // 新卡的位置就是卡片 id：A::B 里是 10，B 里是 20，A 里是 30
+    fn decks_collection() -> Connection {
+        let conn = memory_collection();
+        add_deck(&conn, 2, "A");
+        add_deck(&conn, 3, "A\x1fB");
+        add_deck(&conn, 4, "B");
+        add_test_card(&conn, DECK, 3, 1, 10, 0);
+        add_test_card(&conn, DECK, 4, 2, 20, 0);
+        add_test_card(&conn, DECK, 2, 3, 30, 0);
+        conn
+    }
+
+    #[test]
+    fn gathers_by_deck_or_position() {
+        let conn = decks_collection();
+        // 按牌组收集时子牌组紧跟在父牌组后面
+        assert_eq!(new_ids(&conn, &config(NewCardGatherPriority::Deck, NewCardSortOrder::NoSort)), [30, 10, 20]);
+        assert_eq!(new_ids(&conn, &config(NewCardGatherPriority::LowestPosition, NewCardSortOrder::NoSort)), [10, 20, 30]);
+        assert_eq!(new_ids(&conn, &config(NewCardGatherPriority::HighestPosition, NewCardSortOrder::NoSort)), [30, 20, 10]);
+    }
+
+    // 三条笔记，每条两张卡片（模板 0 和 1）
+    fn notes_collection() -> Connection {
+        let conn = memory_collection();
+        for nid in 1..=3 {
+            add_test_card(&conn, DECK, 1, nid, nid * 10, 0);
+            add_test_card(&conn, DECK, 1, nid, nid * 10 + 1, 1);
+        }
+        conn
+    }
+
+    #[test]
+    fn random_gather_is_stable_within_a_day_and_keeps_notes_together() {
+        let conn = notes_collection();
+        let random_cards = config(NewCardGatherPriority::RandomCards, NewCardSortOrder::NoSort);
+        let first = new_ids(&conn, &random_cards);
+        assert_eq!(first, new_ids(&conn, &random_cards));
+        let mut sorted = first.clone();
+        sorted.sort();
+        assert_eq!(sorted, [10, 11, 20, 21, 30, 31]);
+
+        // 随机笔记：同一笔记的卡片挨在一起，按模板排
+        let ids = new_ids(&conn, &config(NewCardGatherPriority::RandomNotes, NewCardSortOrder::NoSort));
+        for pair in ids.chunks(2) {
+            assert_eq!(pair[1], pair[0] + 1);
+        }
+    }
+
+    #[test]
+    fn sorts_by_template_after_gathering() {
+        let conn = notes_collection();
+        let ids = new_ids(&conn, &config(NewCardGatherPriority::LowestPosition, NewCardSortOrder::Template));
+        assert_eq!(ids, [10, 20, 30, 11, 21, 31]);
+        // 先按上限取卡，再排序：只取到前两条笔记的卡片
+        let limited = SchedulerConfig { new_per_day: 4, ..config(NewCardGatherPriority::LowestPosition, NewCardSortOrder::Template) };
+        assert_eq!(new_ids(&conn, &limited), [10, 20, 11, 21]);
+    }
+
+    // 记一条回答新卡的复习记录，卡片进入学习队列
+    fn introduce(conn: &Connection, card_id: i64, time: i64) {
+        conn.execute("UPDATE cards SET type = 1, queue = 1, due = ? WHERE id = ?", [time + 600, card_id]).unwrap();
+        conn.execute("INSERT INTO revlog VALUES (?, ?, 0, 3, -600, 0, 0, 1000, 0)", [time * 1000, card_id]).unwrap();
+    }
+
+    #[test]
+    fn daily_limit_subtracts_cards_introduced_today() {
+        let conn = notes_collection();
+        let now = noon(&conn);
+        introduce(&conn, 10, now - 3600);
+        introduce(&conn, 11, now - 1800);
+        // 昨天学的不算
+        introduce(&conn, 20, now - SECONDS_PER_DAY);
+        let config = SchedulerConfig { new_per_day: 3, ..config(NewCardGatherPriority::LowestPosition, NewCardSortOrder::NoSort) };
+        let queue = build_new_queue(&conn, DECK, &config, now).unwrap();
+        assert_eq!(queue.introduced_today, 2);
+        assert_eq!(queue.available, 3);
+        assert_eq!(queue.cards.iter().map(|c| c.card_id).collect::<Vec<_>>(), [21]);
+    }
+
+    #[test]
+    fn skips_suspended_and_buried_cards() {
+        let conn = notes_collection();
+        let config = config(NewCardGatherPriority::LowestPosition, NewCardSortOrder::NoSort);
+        // 先构建一次，今天的搁置已经恢复过，之后搁置的卡片要到明天才恢复
+        new_ids(&conn, &config);
+        for (card_id, queue) in [(10, QUEUE_SUSPENDED), (20, QUEUE_SIBLING_BURIED), (30, QUEUE_MANUALLY_BURIED)] {
+            conn.execute("UPDATE cards SET queue = ? WHERE id = ?", [queue, card_id]).unwrap();
+        }
+        let queue = build_new_queue(&conn, DECK, &config, noon(&conn)).unwrap();
+        assert_eq!(queue.available, 3);
+        assert_eq!(queue.cards.iter().map(|c| c.card_id).collect::<Vec<_>>(), [11, 21, 31]);
+    }
+
+    #[test]
+    fn bury_new_takes_one_card_per_note_without_using_the_limit() {
+        let conn = notes_collection();
+        let config = SchedulerConfig {
+            new_per_day: 3,
+            bury_new: true,
+            ..config(NewCardGatherPriority::LowestPosition, NewCardSortOrder::NoSort)
+        };
+        assert_eq!(new_ids(&conn, &config), [10, 20, 30]);
+    }
+
+    #[test]
+    fn cards_of_other_decks_are_not_gathered() {
+        let conn = notes_collection();
+        add_test_card(&conn, "other", 5, 9, 90, 0);
+        assert!(!new_ids(&conn, &SchedulerConfig::default()).contains(&90));
+    }
+
+
+    // 把卡片改成复习卡，due 是相对今天的天数
+    fn review(conn: &Connection, card_id: i64, days_from_today: i64, ivl: i64, data: &str) {
+        let (day, _) = today(conn, noon(conn)).unwrap();
+        conn.execute(
+            "UPDATE cards SET type = 2, queue = 2, due = ?, ivl = ?, data = ? WHERE id = ?",
+            params![day + days_from_today, ivl, data, card_id],
+        )
+        .unwrap();
+    }
+
+    fn build(conn: &Connection, config: &SchedulerConfig, limits: &QueueLimits) -> ReviewQueue {
+        build_queue(conn, DECK, config, limits, noon(conn)).unwrap()
+    }
+
+    fn ids(queue: &ReviewQueue) -> Vec<i64> {
+        queue.cards.iter().map(|c| c.card_id).collect()
+    }
+
+    fn kinds(queue: &ReviewQueue) -> Vec<QueueKind> {
+        queue.cards.iter().map(|c| c.kind).collect()
+    }
+
+    const NO_LIMITS: QueueLimits = QueueLimits { new_cards: None, reviews: None };
+
+    // 三张到期的复习卡：10 过期 1 天（间隔 1），20 过期 5 天（间隔 20），30 今天到期（间隔 5，稳定性只有 0.5）
+    fn reviews_collection() -> Connection {
+        let conn = memory_collection();
+        for (nid, cid) in [(1, 10), (2, 20), (3, 30)] {
+            add_test_card(&conn, DECK, 1, nid, cid, 0);
+        }
+        review(&conn, 10, -1, 1, "");
+        review(&conn, 20, -5, 20, "");
+        review(&conn, 30, 0, 5, r#"{"s":0.5}"#);
+        conn
+    }
+
+    fn sorted_by(conn: &Connection, order: ReviewSortOrder) -> Vec<i64> {
+        ids(&build(conn, &SchedulerConfig { review_sort_order: order, ..Default::default() }, &NO_LIMITS))
+    }
+
+    #[test]
+    fn sorts_reviews_by_config() {
+        let conn = reviews_collection();
+        assert_eq!(sorted_by(&conn, ReviewSortOrder::Due), [20, 10, 30]);
+        assert_eq!(sorted_by(&conn, ReviewSortOrder::RelativeOverdueness), [10, 20, 30]);
+        assert_eq!(sorted_by(&conn, ReviewSortOrder::RetrievabilityAscending), [30, 10, 20]);
+        let random = sorted_by(&conn, ReviewSortOrder::Random);
+        assert_eq!(random, sorted_by(&conn, ReviewSortOrder::Random));
+        let mut sorted = random.clone();
+        sorted.sort();
+        assert_eq!(sorted, [10, 20, 30]);
+    }
+
+    #[test]
+    fn skips_reviews_not_due_yet() {
+        let conn = reviews_collection();
+        review(&conn, 30, 1, 5, "");
+        assert_eq!(sorted_by(&conn, ReviewSortOrder::Due), [20, 10]);
+    }
+
+    #[test]
+    fn review_limit_subtracts_reviews_done_today() {
+        let conn = reviews_collection();
+        // 今天复习过的一张卡片已经排到以后
+        add_test_card(&conn, DECK, 1, 4, 40, 0);
+        review(&conn, 40, 10, 10, "");
+        conn.execute("INSERT INTO revlog VALUES (?, 40, 0, 3, 10, 5, 2500, 1000, 1)", [(noon(&conn) - 60) * 1000]).unwrap();
+
+        let config = SchedulerConfig { reviews_per_day: 3, ..Default::default() };
+        let queue = build(&conn, &config, &NO_LIMITS);
+        assert_eq!(ids(&queue), [20, 10]);
+        assert_eq!(queue.review_count, 2);
+        // 调用方给出的上限优先于牌组配置
+        let queue = build(&conn, &config, &QueueLimits { new_cards: None, reviews: Some(2) });
+        assert_eq!(ids(&queue), [20]);
+    }
+
+    // 两张复习卡和两张新卡
+    fn mixed_collection() -> Connection {
+        let conn = memory_collection();
+        for (nid, cid) in [(1, 10), (2, 20), (3, 30), (4, 40)] {
+            add_test_card(&conn, DECK, 1, nid, cid, 0);
+        }
+        review(&conn, 10, 0, 1, "");
+        review(&conn, 20, 0, 2, "");
+        conn
+    }
+
+    #[test]
+    fn mixes_new_cards_with_reviews() {
+        use QueueKind::{New, Review};
+        let conn = mixed_collection();
+        let mixed = |mix: NewReviewMix| kinds(&build(&conn, &SchedulerConfig { new_mix: mix, ..Default::default() }, &NO_LIMITS));
+        assert_eq!(mixed(NewReviewMix::ReviewsFirst), [Review, Review, New, New]);
+        assert_eq!(mixed(NewReviewMix::NewFirst), [New, New, Review, Review]);
+        assert_eq!(mixed(NewReviewMix::MixWithReviews), [Review, New, Review, New]);
+
+        let queue = build(&conn, &SchedulerConfig::default(), &QueueLimits { new_cards: Some(1), reviews: None });
+        assert_eq!(queue.new_count, 1);
+        assert_eq!(queue.review_count, 2);
+    }
+
+    #[test]
+    fn learning_cards_come_first_and_learn_ahead_last() {
+        let conn = mixed_collection();
+        let now = noon(&conn);
+        // 已到期、提前学习时限内、时限外各一张
+        for (nid, cid, due) in [(5, 50, now - 60), (6, 60, now + 600), (7, 70, now + 3600)] {
+            add_test_card(&conn, DECK, 1, nid, cid, 0);
+            conn.execute("UPDATE cards SET type = 1, queue = 1, due = ? WHERE id = ?", [due, cid]).unwrap();
+        }
+        // 跨天学习的卡片和复习卡一起排序
+        let (day, _) = today(&conn, now).unwrap();
+        conn.execute("UPDATE cards SET type = 3, queue = 3, due = ? WHERE id = 30", [day - 1]).unwrap();
+
+        let config = SchedulerConfig { learn_ahead_secs: 1200, new_mix: NewReviewMix::ReviewsFirst, ..Default::default() };
+        let queue = build(&conn, &config, &NO_LIMITS);
+        assert_eq!(ids(&queue), [50, 30, 10, 20, 40, 60]);
+        assert_eq!(queue.cards[1].kind, QueueKind::Learning);
+        assert_eq!((queue.learning_count, queue.review_count, queue.new_count), (3, 2, 1));
+        assert_eq!(queue.next_learning_due, Some(now + 3600));
+    }
+}
@@ -0,0 +1,319 @@
+// SM-2 调度：按 Anki 传统算法计算间隔，学习/重学阶段按步骤推进，复习阶段按 ease 和各项倍数增长。
+// 具体规则参照 Anki v3 调度器的 SM-2 部分：
+// - 学习中：按学习步骤推进（见 steps.rs），走完或按简单时毕业
+// - 复习中：重来扣 0.2 ease 并进入重学，困难扣 0.15，简单加 0.15，ease 最低 1.3
+// - 重学中：步骤走完后按遗忘时算出的新间隔回到复习
+
+use chrono::{DateTime, Utc};
+use rs_fsrs::Rating;
+use crate::api::simple::{CardMemoryState, CardState, SchedulerConfig};
+use super::steps::{answer_step, SECONDS_PER_DAY};
+use super::Scheduler;
+
+const MINIMUM_EASE: f64 = 1.3;
+
+pub struct Sm2Scheduler {
+    config: SchedulerConfig,
+}
+
+// Anki 用千分制整数保存 ease，这里同样保留三位小数
+fn round_ease(ease: f64) -> f64 {
+    (ease.max(MINIMUM_EASE) * 1000.0).round() / 1000.0
+}
+
+// 和 Anki 一样按间隔长度分段计算浮动范围，2.5 天以下不浮动
+fn fuzz_range(interval: f64) -> (f64, f64) {
+    if interval < 2.5 {
+        return (interval, interval);
+    }
+    let mut delta = 1.0;
+    for (start, end, factor) in [(2.5, 7.0, 0.15), (7.0, 20.0, 0.1), (20.0, f64::MAX, 0.05)] {
+        delta += factor * (interval.min(end) - start).max(0.0);
+    }
+    (interval - delta, interval + delta)
+}
+
+// 用卡片自身的状态做种子，预览和实际评分得到同样的浮动结果
+fn fuzz_factor(card: &CardMemoryState) -> f64 {
+    let seed = (card.due as u64).wrapping_add(card.reps as u64);
+    let x = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    (x >> 33) as f64 / (1u64 << 31) as f64
+}
+
+impl Sm2Scheduler {
+    pub fn new(config: &SchedulerConfig) -> Self {
+        Sm2Scheduler { config: config.clone() }
+    }
+
+    // 乘上间隔修饰、随机浮动后取整，限制在 [minimum, 最大间隔] 内
+    fn constrain_interval(&self, card: &CardMemoryState, interval: f64, minimum: i64, apply_multiplier: bool) -> i64 {
+        let maximum = self.config.maximum_interval.max(1) as i64;
+        let minimum = minimum.clamp(1, maximum);
+        let interval = if apply_multiplier { interval * self.config.interval_multiplier } else { interval };
+        let interval = interval.max(minimum as f64);
+        let interval = if self.config.enable_fuzz {
+            let (low, high) = fuzz_range(interval);
+            let (low, high) = (low.round(), high.round());
+            low + (fuzz_factor(card) * (high - low + 1.0)).floor().min(high - low)
+        } else {
+            interval.round()
+        };
+        (interval as i64).clamp(minimum, maximum)
+    }
+
+    fn graduate(&self, next: &mut CardMemoryState, days: i64, now: i64) {
+        next.state = CardState::Review;
+        next.step = 0;
+        next.scheduled_days = days;
+        next.due = now + days * SECONDS_PER_DAY;
+    }
+
+    fn answer_learning(&self, card: &CardMemoryState, next: &mut CardMemoryState, rating: Rating, now: i64) {
+        if card.state == CardState::New || next.ease_factor <= 0.0 {
+            next.ease_factor = self.config.initial_ease;
+        }
+        match answer_step(&self.config.learn_steps, card.step, rating) {
+            Some((step, delay)) => {
+                next.state = CardState::Learning;
+                next.step = step;
+                next.scheduled_days = 0;
+                next.due = now + delay;
+            }
+            None => {
+                let days = if rating == Rating::Easy {
+                    self.config.graduating_interval_easy
+                } else {
+                    self.config.graduating_interval_good
+                };
+                let days = self.constrain_interval(card, days as f64, 1, false);
+                self.graduate(next, days, now);
+            }
+        }
+    }
+
+    fn answer_review(&self, card: &CardMemoryState, next: &mut CardMemoryState, rating: Rating, now: i64) {
+        let config = &self.config;
+        let ease = if card.ease_factor > 0.0 { card.ease_factor } else { config.initial_ease };
+        let current = card.scheduled_days.max(1) as f64;
+        let days_late = (next.elapsed_days as f64 - current).max(0.0);
+        match rating {
+            Rating::Again => {
+                next.lapses += 1;
+                next.ease_factor = round_ease(ease - 0.2);
+                let days = self.constrain_interval(card, current * config.lapse_multiplier, config.minimum_lapse_interval as i64, false);
+                match answer_step(&config.relearn_steps, 0, Rating::Again) {
+                    Some((step, delay)) => {
+                        next.state = CardState::Relearning;
+                        next.step = step;
+                        next.scheduled_days = days;
+                        next.due = now + delay;
+                    }
+                    None => self.graduate(next, days, now),
+                }
+            }
+            _ => {
+                // 三个间隔依次至少比前一个多一天
+                let hard_minimum = if config.hard_multiplier > 1.0 { current as i64 + 1 } else { 1 };
+                let hard = self.constrain_interval(card, current * config.hard_multiplier, hard_minimum, true);
+                let good_minimum = if config.hard_multiplier > 1.0 { hard + 1 } else { current as i64 + 1 };
+                let good = self.constrain_interval(card, (current + days_late / 2.0) * ease, good_minimum, true);
+                let easy = self.constrain_interval(card, (current + days_late) * ease * config.easy_multiplier, good + 1, true);
+                let (days, ease) = match rating {
+                    Rating::Hard => (hard, ease - 0.15),
+                    Rating::Good => (good, ease),
+                    _ => (easy, ease + 0.15),
+                };
+                next.ease_factor = round_ease(ease);
+                self.graduate(next, days, now);
+            }
+        }
+    }
+
+    fn answer_relearning(&self, card: &CardMemoryState, next: &mut CardMemoryState, rating: Rating, now: i64) {
+        match answer_step(&self.config.relearn_steps, card.step, rating) {
+            Some((step, delay)) => {
+                next.step = step;
+                next.due = now + delay;
+            }
+            None => {
+                let lapse_interval = card.scheduled_days.max(1);
+                let days = if rating == Rating::Easy { lapse_interval + 1 } else { lapse_interval };
+                self.graduate(next, days.min(self.config.maximum_interval.max(1) as i64), now);
+            }
+        }
+    }
+}
+
+impl Scheduler for Sm2Scheduler {
+    fn next_state(&self, card: &CardMemoryState, rating: Rating, now: DateTime<Utc>) -> CardMemoryState {
+        let now = now.timestamp();
+        let mut next = card.clone();
+        next.reps += 1;
+        next.elapsed_days = card.last_review.map(|last| ((now - last) / SECONDS_PER_DAY).max(0)).unwrap_or(0);
+        next.last_review = Some(now);
+        match card.state {
+            CardState::New | CardState::Learning => self.answer_learning(card, &mut next, rating, now),
+            CardState::Review => self.answer_review(card, &mut next, rating, now),
+            CardState::Relearning => self.answer_relearning(card, &mut next, rating, now),
+        }
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use crate::api::simple::{new_card_memory_state, SchedulerAlgorithm};
+    use super::*;
+
+    const NOW: i64 = 1_767_225_600; // 2026-01-01T00:00:00Z
+
+    fn config() -> SchedulerConfig {
+        SchedulerConfig { algorithm: SchedulerAlgorithm::Sm2, ..Default::default() }
+    }
+
+    fn answer(config: &SchedulerConfig, card: &CardMemoryState, rating: Rating) -> CardMemoryState {
+        Sm2Scheduler::new(config).next_state(card, rating, Utc.timestamp_opt(NOW, 0).unwrap())
+    }
+
+    fn learning(step: i32) -> CardMemoryState {
+        CardMemoryState { state: CardState::Learning, step, ease_factor: 2.5, reps: 1, last_review: Some(NOW - 60), ..new_card_memory_state(NOW) }
+    }
+
+    // 间隔 ivl 天、上次复习在 elapsed 天前的复习卡
+    fn review(ivl: i64, elapsed: i64) -> CardMemoryState {
+        CardMemoryState {
+            state: CardState::Review,
+            scheduled_days: ivl,
+            ease_factor: 2.5,
+            reps: 5,
+            last_review: Some(NOW - elapsed * SECONDS_PER_DAY),
+            due: NOW - (elapsed - ivl) * SECONDS_PER_DAY,
+            ..new_card_memory_state(NOW)
+        }
+    }
+
+    // 学习阶段返回 (状态, 步骤, 延迟秒数)，复习阶段返回 (状态, 间隔天数, ease)
+    fn step_of(card: &CardMemoryState) -> (CardState, i32, i64) {
+        (card.state, card.step, card.due - NOW)
+    }
+
+    fn days_of(card: &CardMemoryState) -> (CardState, i64, f64) {
+        (card.state, card.scheduled_days, card.ease_factor)
+    }
+
+    #[test]
+    fn new_card_follows_learn_steps() {
+        let config = config();
+        let card = new_card_memory_state(NOW);
+        assert_eq!(step_of(&answer(&config, &card, Rating::Again)), (CardState::Learning, 0, 60));
+        // 困难取前两步的平均
+        assert_eq!(step_of(&answer(&config, &card, Rating::Hard)), (CardState::Learning, 0, 330));
+        assert_eq!(step_of(&answer(&config, &card, Rating::Good)), (CardState::Learning, 1, 600));
+        let easy = answer(&config, &card, Rating::Easy);
+        assert_eq!(days_of(&easy), (CardState::Review, 4, 2.5));
+        assert_eq!(easy.due, NOW + 4 * SECONDS_PER_DAY);
+    }
+
+    #[test]
+    fn learning_card_graduates() {
+        let config = config();
+        let card = learning(1);
+        assert_eq!(step_of(&answer(&config, &card, Rating::Again)), (CardState::Learning, 0, 60));
+        assert_eq!(step_of(&answer(&config, &card, Rating::Hard)), (CardState::Learning, 1, 600));
+        assert_eq!(days_of(&answer(&config, &card, Rating::Good)), (CardState::Review, 1, 2.5));
+        assert_eq!(days_of(&answer(&config, &card, Rating::Easy)), (CardState::Review, 4, 2.5));
+    }
+
+    #[test]
+    fn review_on_time() {
+        let config = config();
+        let card = review(10, 10);
+        assert_eq!(days_of(&answer(&config, &card, Rating::Hard)), (CardState::Review, 12, 2.35));
+        assert_eq!(days_of(&answer(&config, &card, Rating::Good)), (CardState::Review, 25, 2.5));
+        // 10 × 2.5 × 1.3 = 32.5
+        assert_eq!(days_of(&answer(&config, &card, Rating::Easy)), (CardState::Review, 33, 2.65));
+        let again = answer(&config, &card, Rating::Again);
+        assert_eq!(step_of(&again), (CardState::Relearning, 0, 600));
+        assert_eq!((again.scheduled_days, again.ease_factor, again.lapses), (1, 2.3, 1));
+    }
+
+    #[test]
+    fn late_review_counts_delay() {
+        let config = config();
+        let card = review(10, 14);
+        assert_eq!(answer(&config, &card, Rating::Hard).scheduled_days, 12);
+        // 良好算一半的延迟：(10 + 2) × 2.5
+        assert_eq!(answer(&config, &card, Rating::Good).scheduled_days, 30);
+        // 简单算全部延迟：14 × 2.5 × 1.3 = 45.5
+        assert_eq!(answer(&config, &card, Rating::Easy).scheduled_days, 46);
+    }
+
+    #[test]
+    fn hard_and_good_keep_minimum_gaps() {
+        // 间隔 1 天、ease 1.3 时 良好 至少比 困难 多一天，简单 至少比 良好 多一天
+        let config = SchedulerConfig { easy_multiplier: 1.0, ..config() };
+        let card = CardMemoryState { ease_factor: 1.3, ..review(1, 1) };
+        let days: Vec<i64> = [Rating::Hard, Rating::Good, Rating::Easy].iter().map(|r| answer(&config, &card, *r).scheduled_days).collect();
+        assert_eq!(days, vec![2, 3, 4]);
+        // ease 不低于 1.3
+        assert_eq!(answer(&config, &card, Rating::Hard).ease_factor, 1.3);
+        assert_eq!(answer(&config, &card, Rating::Again).ease_factor, 1.3);
+    }
+
+    #[test]
+    fn lapse_multiplier_and_minimum() {
+        let card = review(10, 10);
+        let half = SchedulerConfig { lapse_multiplier: 0.5, ..config() };
+        assert_eq!(answer(&half, &card, Rating::Again).scheduled_days, 5);
+        let minimum = SchedulerConfig { minimum_lapse_interval: 7, ..half.clone() };
+        assert_eq!(answer(&minimum, &card, Rating::Again).scheduled_days, 7);
+        // 没有重学步骤时直接回到复习
+        let no_steps = SchedulerConfig { relearn_steps: vec![], ..half };
+        let again = answer(&no_steps, &card, Rating::Again);
+        assert_eq!(days_of(&again), (CardState::Review, 5, 2.3));
+        assert_eq!(again.due, NOW + 5 * SECONDS_PER_DAY);
+    }
+
+    #[test]
+    fn relearning_returns_to_review() {
+        let config = SchedulerConfig { lapse_multiplier: 0.5, ..config() };
+        let card = CardMemoryState { state: CardState::Relearning, step: 0, scheduled_days: 5, ..review(5, 0) };
+        assert_eq!(step_of(&answer(&config, &card, Rating::Again)), (CardState::Relearning, 0, 600));
+        // 只有一步时困难是 1.5 倍
+        assert_eq!(step_of(&answer(&config, &card, Rating::Hard)), (CardState::Relearning, 0, 900));
+        assert_eq!(days_of(&answer(&config, &card, Rating::Good)), (CardState::Review, 5, 2.5));
+        assert_eq!(days_of(&answer(&config, &card, Rating::Easy)), (CardState::Review, 6, 2.5));
+    }
+
+    #[test]
+    fn maximum_interval_clamps() {
+        let config = SchedulerConfig { maximum_interval: 20, ..config() };
+        let card = review(10, 10);
+        assert_eq!(answer(&config, &card, Rating::Hard).scheduled_days, 12);
+        assert_eq!(answer(&config, &card, Rating::Good).scheduled_days, 20);
+        assert_eq!(answer(&config, &card, Rating::Easy).scheduled_days, 20);
+        let card = CardMemoryState { state: CardState::Relearning, scheduled_days: 20, ..review(20, 0) };
+        assert_eq!(answer(&config, &card, Rating::Easy).scheduled_days, 20);
+        let config = SchedulerConfig { maximum_interval: 3, ..config.clone() };
+        assert_eq!(answer(&config, &new_card_memory_state(NOW), Rating::Easy).scheduled_days, 3);
+    }
+
+    #[test]
+    fn fuzz_is_deterministic_and_in_range() {
+        let config = SchedulerConfig { enable_fuzz: true, ..config() };
+        let card = review(10, 10);
+        let good = answer(&config, &card, Rating::Good).scheduled_days;
+        assert_eq!(answer(&config, &card, Rating::Good).scheduled_days, good);
+        // 25 天的浮动范围是 ±3.225 天
+        assert!((22..=28).contains(&good), "{good}");
+        // 2.5 天以下不浮动
+        assert_eq!(fuzz_range(2.0), (2.0, 2.0));
+        let (low, high) = fuzz_range(25.0);
+        assert!((low - 21.775).abs() < 1e-9 && (high - 28.225).abs() < 1e-9);
+        let seeds: std::collections::HashSet<i64> = (0..20)
+            .map(|reps| answer(&config, &CardMemoryState { reps, ..card.clone() }, Rating::Good).scheduled_days)
+            .collect();
+        assert!(seeds.len() > 1);
+    }
+}
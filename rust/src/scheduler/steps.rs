@@ -0,0 +1,157 @@
+// 学习/重学步骤和当天学习队列，FSRS 和 SM-2 共用。
+// 步骤以分钟为单位，卡片当前走到第几步记在 CardMemoryState.step 里，
+// 存进 cards 表时和 Anki 一样把剩余步数写进 left 列。
+
+use chrono::TimeZone;
+use rs_fsrs::Rating;
+use super::timing::Timing;
+
+pub const SECONDS_PER_DAY: i64 = 86400;
+
+pub fn minutes_to_secs(minutes: f64) -> i64 {
+    (minutes * 60.0).round() as i64
+}
+
+// “困难”的延迟：第一步时取前两步的平均；只有一步时取 1.5 倍，但最多多出一天
+fn hard_delay_secs(steps: &[f64], step: usize) -> i64 {
+    let minutes = match (step, steps) {
+        (0, [first]) => (first * 1.5).min(first + 1440.0),
+        (0, [first, second, ..]) => (first + second) / 2.0,
+        _ => steps[step],
+    };
+    minutes_to_secs(minutes)
+}
+
+/// 在学习/重学步骤里回答一次：返回 (下一步, 延迟秒数)，返回 None 表示毕业。
+/// 重来回到第一步，困难停在当前步，良好进入下一步，简单直接毕业；没有步骤时直接毕业
+pub fn answer_step(steps: &[f64], step: i32, rating: Rating) -> Option<(i32, i64)> {
+    if steps.is_empty() {
+        return None;
+    }
+    // 步骤数可能在卡片学习途中被改小
+    let step = (step.max(0) as usize).min(steps.len() - 1);
+    match rating {
+        Rating::Again => Some((0, minutes_to_secs(steps[0]))),
+        Rating::Hard => Some((step as i32, hard_delay_secs(steps, step))),
+        Rating::Good => steps.get(step + 1).map(|minutes| (step as i32 + 1, minutes_to_secs(*minutes))),
+        Rating::Easy => None,
+    }
+}
+
+/// Anki 的 left 列：个位到百位是剩余步数
+pub fn remaining_steps(steps: &[f64], step: i32) -> i64 {
+    (steps.len() as i64 - step.max(0) as i64).max(1)
+}
+
+pub fn step_from_left(steps: &[f64], left: i64) -> i32 {
+    let remaining = (left % 1000).clamp(1, steps.len().max(1) as i64);
+    (steps.len() as i64 - remaining).max(0) as i32
+}
+
+/// 学习步骤保存时的 (queue, due)：到期时间落在以后的学习日（步骤达到一天，或者跨过了换天时刻）
+/// 时按天调度，放进跨天学习队列 queue 3，due 是学习日；否则留在当天的 queue 1，due 是时间戳
+pub fn learning_queue<Tz: TimeZone>(due: i64, now: i64, timing: &Timing<Tz>) -> (i64, i64) {
+    let day = timing.day(due);
+    if day > timing.day(now) {
+        (3, day)
+    } else {
+        (1, due)
+    }
+}
+
+/// 把当天学习队列里的卡片按到期时间分成已到期和可提前学习两部分，
+/// cards 是按到期时间排好序的 (card_id, due)
+pub fn split_learning_queue(cards: &[(i64, i64)], now: i64, learn_ahead_secs: i64) -> (Vec<i64>, Vec<i64>) {
+    let due = cards.iter().filter(|(_, due)| *due <= now).map(|(id, _)| *id).collect();
+    let cutoff = now + learn_ahead_secs.max(0);
+    let learn_ahead = cards.iter().filter(|(_, due)| *due > now && *due <= cutoff).map(|(id, _)| *id).collect();
+    (due, learn_ahead)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, FixedOffset};
+    use super::*;
+
+    const LEARN_STEPS: [f64; 3] = [1.0, 10.0, 60.0];
+
+    fn utc(s: &str) -> i64 {
+        DateTime::parse_from_rfc3339(s).unwrap().timestamp()
+    }
+
+    #[test]
+    fn again_resets_to_first_step() {
+        assert_eq!(answer_step(&LEARN_STEPS, 2, Rating::Again), Some((0, 60)));
+        assert_eq!(answer_step(&LEARN_STEPS, 0, Rating::Again), Some((0, 60)));
+    }
+
+    #[test]
+    fn hard_repeats_step() {
+        // 第一步取前两步的平均
+        assert_eq!(answer_step(&LEARN_STEPS, 0, Rating::Hard), Some((0, 330)));
+        assert_eq!(answer_step(&LEARN_STEPS, 1, Rating::Hard), Some((1, 600)));
+        // 只有一步时取 1.5 倍，最多多出一天
+        assert_eq!(answer_step(&[10.0], 0, Rating::Hard), Some((0, 900)));
+        assert_eq!(answer_step(&[2880.0], 0, Rating::Hard), Some((0, 4320 * 60)));
+        assert_eq!(answer_step(&[4320.0], 0, Rating::Hard), Some((0, 5760 * 60)));
+    }
+
+    #[test]
+    fn good_advances_and_graduates_after_last_step() {
+        assert_eq!(answer_step(&LEARN_STEPS, 0, Rating::Good), Some((1, 600)));
+        assert_eq!(answer_step(&LEARN_STEPS, 1, Rating::Good), Some((2, 3600)));
+        assert_eq!(answer_step(&LEARN_STEPS, 2, Rating::Good), None);
+        // 步骤被改少时按最后一步算
+        assert_eq!(answer_step(&[1.0, 10.0], 5, Rating::Good), None);
+        assert_eq!(answer_step(&[1.0, 10.0], 5, Rating::Hard), Some((1, 600)));
+    }
+
+    #[test]
+    fn easy_graduates() {
+        assert_eq!(answer_step(&LEARN_STEPS, 0, Rating::Easy), None);
+        assert_eq!(answer_step(&LEARN_STEPS, 1, Rating::Easy), None);
+        assert_eq!(answer_step(&[], 0, Rating::Again), None);
+    }
+
+    #[test]
+    fn left_counts_remaining_steps() {
+        assert_eq!(remaining_steps(&LEARN_STEPS, 0), 3);
+        assert_eq!(remaining_steps(&LEARN_STEPS, 2), 1);
+        // 至少剩一步
+        assert_eq!(remaining_steps(&LEARN_STEPS, 7), 1);
+        assert_eq!(remaining_steps(&[], 0), 1);
+        for step in 0..3 {
+            assert_eq!(step_from_left(&LEARN_STEPS, remaining_steps(&LEARN_STEPS, step)), step);
+        }
+        // Anki 在千位记今天剩余的步数，只看个位到百位
+        assert_eq!(step_from_left(&LEARN_STEPS, 2002), 1);
+        // 剩余步数超出现有步骤时从第一步开始
+        assert_eq!(step_from_left(&LEARN_STEPS, 5), 0);
+        assert_eq!(step_from_left(&LEARN_STEPS, 0), 2);
+    }
+
+    #[test]
+    fn step_crossing_rollover_is_interday() {
+        let timing = Timing::new(utc("2026-01-01T00:00:00Z"), 4, FixedOffset::east_opt(8 * 3600).unwrap());
+        let now = utc("2026-01-05T03:55:00+08:00");
+        assert_eq!(timing.day(now), 3);
+        // 还在换天时刻之前
+        let due = now + 240;
+        assert_eq!(learning_queue(due, now, &timing), (1, due));
+        // 10 分钟后已经是下一个学习日
+        assert_eq!(learning_queue(now + 600, now, &timing), (3, 4));
+        // 白天的一天步骤
+        let noon = utc("2026-01-05T12:00:00+08:00");
+        assert_eq!(learning_queue(noon + SECONDS_PER_DAY, noon, &timing), (3, 5));
+        assert_eq!(learning_queue(noon + 3600, noon, &timing), (1, noon + 3600));
+    }
+
+    #[test]
+    fn learning_queue_splits_due_and_learn_ahead() {
+        let now = 1_000_000;
+        let cards = [(1, now - 60), (2, now), (3, now + 600), (4, now + 1200), (5, now + 1201)];
+        assert_eq!(split_learning_queue(&cards, now, 1200), (vec![1, 2], vec![3, 4]));
+        assert_eq!(split_learning_queue(&cards, now, 0), (vec![1, 2], vec![]));
+        assert_eq!(split_learning_queue(&cards, now, -5), (vec![1, 2], vec![]));
+    }
+}
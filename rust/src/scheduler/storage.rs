@@ -0,0 +1,108 @@
+// 把复习结果写回集合：cards 表的调度列按 Anki 的格式保存，同时追加一条 revlog，
+// 这样导出或再次导入时 Anki 和本应用都能读懂卡片的状态。
+
+use rs_fsrs::Rating;
+use rusqlite::{params, Connection, OptionalExtension};
+use crate::api::simple::{CardMemoryState, CardState, SchedulerConfig};
+use crate::collection::IN_DECK;
+use crate::error::{AnkiError, ResultExt};
+use super::bootstrap::write_memory_to_data;
+use super::steps::{learning_queue, remaining_steps};
+use super::timing;
+use super::rating_to_u8;
+
+// revlog.ivl：复习卡是正的天数，学习中的卡片是负的秒数
+fn revlog_interval(card: &CardMemoryState, now: i64) -> i64 {
+    match card.state {
+        CardState::Review => card.scheduled_days,
+        CardState::New => 0,
+        CardState::Learning | CardState::Relearning => -(card.due - now).max(0),
+    }
+}
+
+// revlog.type 按评分前卡片所处的阶段：0-学习, 1-复习, 2-重学
+fn revlog_kind(card: &CardMemoryState) -> i64 {
+    match card.state {
+        CardState::New | CardState::Learning => 0,
+        CardState::Review => 1,
+        CardState::Relearning => 2,
+    }
+}
+
+fn insert_revlog(conn: &Connection, card_id: i64, prev: &CardMemoryState, next: &CardMemoryState, rating: Rating, now: i64) -> Result<(), AnkiError> {
+    // revlog.id 是毫秒时间戳，同一毫秒内有多条记录时顺延
+    let max_id: Option<i64> = conn
+        .query_row("SELECT MAX(id) FROM revlog", [], |row| row.get(0))
+        .optional()
+        .context("查询revlog失败")?
+        .flatten();
+    let id = (now * 1000).max(max_id.map(|m| m + 1).unwrap_or(0));
+    let factor = (next.ease_factor * 1000.0).round() as i64;
+    conn.execute(
+        "INSERT INTO revlog (id, cid, usn, ease, ivl, lastIvl, factor, time, type) VALUES (?, ?, -1, ?, ?, ?, ?, 0, ?)",
+        params![
+            id,
+            card_id,
+            rating_to_u8(rating) as i64 + 1,
+            revlog_interval(next, now),
+            revlog_interval(prev, prev.last_review.unwrap_or(now)),
+            factor,
+            revlog_kind(prev)
+        ],
+    )
+    .context("写入revlog失败")?;
+    Ok(())
+}
+
+/// 保存一次评分的结果：更新 cards 表的调度列和 data 里的 FSRS 状态，并记录 revlog。
+/// 由调用方开启事务，和回答后的其他改动（难记卡片、搁置兄弟卡片）一起提交
+pub fn save_answer(
+    conn: &Connection,
+    card_id: i64,
+    prev: &CardMemoryState,
+    next: &CardMemoryState,
+    rating: Rating,
+    config: &SchedulerConfig,
+    now: i64,
+) -> Result<(), AnkiError> {
+    let timing = timing::load(conn)?;
+    let data: String = conn
+        .query_row("SELECT data FROM cards WHERE id = ?", [card_id], |row| row.get::<_, Option<String>>(0))
+        .context("未找到指定id的card")?
+        .unwrap_or_default();
+    let day = |ts: i64| timing.day(ts);
+    // 学习中的卡片当天按秒调度（queue 1），到期落在以后学习日的按天调度（queue 3），复习卡按天调度（queue 2）
+    let (card_type, queue, due, left) = match next.state {
+        CardState::New => (0, 0, next.due, 0),
+        CardState::Learning | CardState::Relearning => {
+            let (card_type, steps) = if next.state == CardState::Learning {
+                (1, &config.learn_steps)
+            } else {
+                (3, &config.relearn_steps)
+            };
+            let (queue, due) = learning_queue(next.due, now, &timing);
+            (card_type, queue, due, remaining_steps(steps, next.step))
+        }
+        CardState::Review => (2, 2, day(next.due), 0),
+    };
+    let factor = (next.ease_factor > 0.0).then(|| (next.ease_factor * 1000.0).round() as i64);
+    let data = write_memory_to_data(&data, next, config.request_retention);
+    conn.execute(
+        "UPDATE cards SET type = ?, queue = ?, due = ?, ivl = ?, factor = COALESCE(?, factor), reps = ?, lapses = ?, \
+         left = ?, data = ?, mod = ?, usn = -1 WHERE id = ?",
+        params![card_type, queue, due, next.scheduled_days, factor, next.reps, next.lapses, left, data, now, card_id],
+    )
+    .context("更新card失败")?;
+    insert_revlog(conn, card_id, prev, next, rating, now)
+}
+
+/// 牌组当天学习队列（queue 1）里的卡片，按到期时间排序
+pub fn load_intraday_learning(conn: &Connection, deck_key: &str) -> Result<Vec<(i64, i64)>, AnkiError> {
+    let mut stmt = conn
+        .prepare(&format!("SELECT id, due FROM cards WHERE queue = 1 AND {IN_DECK} ORDER BY due, id"))
+        .context("准备SQL失败")?;
+    let rows = stmt
+        .query_map([deck_key], |row| Ok((row.get(0)?, row.get(1)?)))
+        .context("查询SQL失败")?;
+    rows.collect::<Result<Vec<_>, _>>().context("读取card失败")
+}
@@ -0,0 +1,129 @@
+// 学习日：和 Anki 一样按本地时间的换天时刻（默认凌晨 4 点）划分，复习卡的 due 是集合创建那个学习日之后的第几天。
+// 每天的新卡和复习上限、今天学过的卡片、搁置卡片的恢复都按这里的学习日算。
+// 换天时刻是集合级的设置，和 Anki 一样存在 config 表的 rollover 里，值是 JSON。
+
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveTime, TimeZone};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde_json::Value;
+use crate::collection::has_table;
+use crate::error::{AnkiError, ResultExt};
+
+pub const DEFAULT_ROLLOVER_HOUR: u8 = 4;
+const ROLLOVER_KEY: &str = "rollover";
+
+/// 集合创建时间（秒），复习卡的 due 是相对它的天数
+pub fn collection_crt(conn: &Connection) -> Result<i64, AnkiError> {
+    conn.query_row("SELECT crt FROM col LIMIT 1", [], |row| row.get(0))
+        .context("读取col.crt失败")
+}
+
+/// 集合的换天时刻（本地时间的小时），没有设置过或值不合法时用默认的 4 点
+pub fn rollover_hour(conn: &Connection) -> Result<u8, AnkiError> {
+    if !has_table(conn, "main", "config") {
+        return Ok(DEFAULT_ROLLOVER_HOUR);
+    }
+    let value: Option<Vec<u8>> = conn
+        .prepare_cached("SELECT val FROM config WHERE KEY = ?")
+        .context("准备SQL失败")?
+        .query_row([ROLLOVER_KEY], |row| row.get(0))
+        .optional()
+        .context("读取config失败")?;
+    let hour = value.and_then(|bytes| serde_json::from_slice::<Value>(&bytes).ok()).and_then(|v| v.as_u64());
+    Ok(hour.filter(|h| *h < 24).map(|h| h as u8).unwrap_or(DEFAULT_ROLLOVER_HOUR))
+}
+
+pub fn set_rollover_hour(conn: &Connection, hour: u8, now: i64) -> Result<(), AnkiError> {
+    if hour >= 24 {
+        return Err(AnkiError::invalid_argument(format!("换天时刻{hour}点超出范围[0, 23]")));
+    }
+    conn.execute(
+        "INSERT OR REPLACE INTO config (KEY, usn, mtime_secs, val) VALUES (?, -1, ?, ?)",
+        params![ROLLOVER_KEY, now, hour.to_string().into_bytes()],
+    )
+    .context("保存换天时刻失败")?;
+    Ok(())
+}
+
+/// 按集合创建时间、换天时刻和时区换算学习日
+pub struct Timing<Tz: TimeZone = Local> {
+    crt: i64,
+    rollover_hour: u8,
+    tz: Tz,
+}
+
+/// 集合的学习日换算，用设备的本地时区
+pub fn load(conn: &Connection) -> Result<Timing, AnkiError> {
+    Ok(Timing::new(collection_crt(conn)?, rollover_hour(conn)?, Local))
+}
+
+impl<Tz: TimeZone> Timing<Tz> {
+    pub fn new(crt: i64, rollover_hour: u8, tz: Tz) -> Self {
+        Timing { crt, rollover_hour: rollover_hour.min(23), tz }
+    }
+
+    // 时间戳所在学习日的本地日期，换天时刻之前算前一天
+    fn date(&self, ts: i64) -> NaiveDate {
+        let local = DateTime::from_timestamp(ts, 0).unwrap_or_default().with_timezone(&self.tz).naive_local();
+        (local - Duration::hours(self.rollover_hour as i64)).date()
+    }
+
+    /// 时间戳是集合创建后的第几个学习日
+    pub fn day(&self, ts: i64) -> i64 {
+        (self.date(ts) - self.date(self.crt)).num_days()
+    }
+
+    /// 第 day 个学习日开始的时间戳（秒）
+    pub fn day_start(&self, day: i64) -> i64 {
+        let time = NaiveTime::from_hms_opt(self.rollover_hour as u32, 0, 0).unwrap_or(NaiveTime::MIN);
+        let local = (self.date(self.crt) + Duration::days(day)).and_time(time);
+        // 夏令时跳过的时刻取一小时后
+        self.tz
+            .from_local_datetime(&local)
+            .earliest()
+            .or_else(|| self.tz.from_local_datetime(&(local + Duration::hours(1))).earliest())
+            .map(|t| t.timestamp())
+            .unwrap_or_else(|| local.and_utc().timestamp())
+    }
+
+    /// 今天是第几个学习日，以及今天开始的时间戳（秒）
+    pub fn today(&self, now: i64) -> (i64, i64) {
+        let day = self.day(now);
+        (day, self.day_start(day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::FixedOffset;
+    use super::*;
+
+    fn utc(s: &str) -> i64 {
+        DateTime::parse_from_rfc3339(s).unwrap().timestamp()
+    }
+
+    #[test]
+    fn day_changes_at_local_rollover() {
+        // crt 是 UTC 零点，东八区的早上 8 点
+        let timing = Timing::new(utc("2026-01-01T00:00:00Z"), 4, FixedOffset::east_opt(8 * 3600).unwrap());
+        assert_eq!(timing.today(utc("2026-01-02T03:59:59+08:00")), (0, utc("2026-01-01T04:00:00+08:00")));
+        assert_eq!(timing.today(utc("2026-01-02T04:00:00+08:00")), (1, utc("2026-01-02T04:00:00+08:00")));
+        // 按 UTC 零点换天时，这两个时刻都还是第 0 天
+        assert_eq!(timing.day(utc("2026-01-02T07:59:59+08:00")), 1);
+    }
+
+    #[test]
+    fn crt_before_rollover_belongs_to_previous_day() {
+        let timing = Timing::new(utc("2026-01-01T00:00:00Z"), 4, FixedOffset::west_opt(5 * 3600).unwrap());
+        // crt 是西五区 12-31 的 19 点
+        assert_eq!(timing.day(utc("2026-01-01T03:00:00-05:00")), 0);
+        assert_eq!(timing.day(utc("2026-01-01T04:00:00-05:00")), 1);
+        assert_eq!(timing.day_start(1), utc("2026-01-01T04:00:00-05:00"));
+    }
+
+    #[test]
+    fn rollover_at_midnight() {
+        let timing = Timing::new(utc("2026-03-10T00:00:00Z"), 0, FixedOffset::east_opt(0).unwrap());
+        assert_eq!(timing.today(utc("2026-03-12T00:00:00Z")), (2, utc("2026-03-12T00:00:00Z")));
+        assert_eq!(timing.day(utc("2026-03-11T23:59:59Z")), 1);
+    }
+}
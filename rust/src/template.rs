@@ -0,0 +1,414 @@
+// Anki 模板渲染：按 Anki 的模板语法解析 {{字段}}、{{#字段}}/{{^字段}} 条件块（可嵌套）、
+// {{FrontSide}} 以及 {{text:hint:字段}} 这种链式过滤器。
+// 这里只做纯文本处理，不碰数据库，字段内容（包括 Tags/Deck 等特殊字段）由调用方准备好。
+
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::collections::hash_map::DefaultHasher;
+use regex::{Captures, Regex};
+use lazy_static::lazy_static;
+use crate::cloze::{reveal_cloze_text, reveal_cloze_text_only};
+
+lazy_static! {
+    // 和 Anki 一致：只包含空白、<br>、<div></div> 的字段视为空
+    static ref EMPTY_FIELD: Regex = Regex::new(r"(?xsi)^(?:[[:space:]]|</?(?:br|div)\ ?/?>)*$").unwrap();
+    static ref HTML_COMMENT: Regex = Regex::new(r"(?s)<!--.*?-->").unwrap();
+    static ref HTML_TAG: Regex = Regex::new(r"(?s)<.*?>").unwrap();
+    static ref HTML_ENTITY: Regex = Regex::new(r"&(#[0-9]+|#[xX][0-9a-fA-F]+|[a-zA-Z]+);").unwrap();
+    static ref FURIGANA: Regex = Regex::new(r" ?([^ >]+?)\[(.+?)\]").unwrap();
+    static ref TYPE_ANSWER: Regex = Regex::new(r"\[\[type:(.+?)\]\]").unwrap();
+}
+
+#[derive(Debug)]
+enum Node {
+    Text(String),
+    // filters 已经是应用顺序：{{text:hint:F}} 先 hint 后 text
+    Replacement { key: String, filters: Vec<String> },
+    Conditional { key: String, negated: bool, children: Vec<Node> },
+}
+
+/// 正反面渲染结果，diagnostics 记录未知字段、条件块不匹配等问题
+pub struct RenderedSides {
+    pub question: String,
+    pub answer: String,
+    pub diagnostics: Vec<String>,
+}
+
+struct RenderContext<'a> {
+    fields: &'a HashMap<String, String>,
+    front_side: Option<&'a str>,
+    card_ord: u16,
+    question: bool,
+}
+
+fn parse(template: &str, diagnostics: &mut Vec<String>) -> Vec<Node> {
+    // 栈里保存外层的 (条件字段, 是否取反, 外层已解析的节点)
+    let mut stack: Vec<(String, bool, Vec<Node>)> = vec![];
+    let mut current: Vec<Node> = vec![];
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        if start > 0 {
+            current.push(Node::Text(rest[..start].to_string()));
+        }
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            // 没有闭合的 }}，剩下的按普通文本输出
+            current.push(Node::Text(rest[start..].to_string()));
+            rest = "";
+            break;
+        };
+        let tag = after[..end].trim();
+        rest = &after[end + 2..];
+        if let Some(key) = tag.strip_prefix('#') {
+            stack.push((key.trim().to_string(), false, std::mem::take(&mut current)));
+        } else if let Some(key) = tag.strip_prefix('^') {
+            stack.push((key.trim().to_string(), true, std::mem::take(&mut current)));
+        } else if let Some(key) = tag.strip_prefix('/') {
+            let key = key.trim();
+            match stack.pop() {
+                Some((open, negated, parent)) if open == key => {
+                    let children = std::mem::replace(&mut current, parent);
+                    current.push(Node::Conditional { key: open, negated, children });
+                }
+                Some(other) => {
+                    diagnostics.push(format!("{{{{/{key}}}}} 与 {{{{#{}}}}} 不匹配，已忽略", other.0));
+                    stack.push(other);
+                }
+                None => diagnostics.push(format!("多余的 {{{{/{key}}}}}，已忽略")),
+            }
+        } else {
+            let mut parts = tag.rsplit(':');
+            let key = parts.next().unwrap_or_default().trim().to_string();
+            let filters = parts.map(|f| f.trim().to_string()).collect();
+            current.push(Node::Replacement { key, filters });
+        }
+    }
+    if !rest.is_empty() {
+        current.push(Node::Text(rest.to_string()));
+    }
+    // 未闭合的条件块按模板末尾闭合处理
+    while let Some((key, negated, parent)) = stack.pop() {
+        diagnostics.push(format!("{{{{#{key}}}}} 缺少对应的 {{{{/{key}}}}}"));
+        let children = std::mem::replace(&mut current, parent);
+        current.push(Node::Conditional { key, negated, children });
+    }
+    current
+}
+
+fn render_nodes(nodes: &[Node], ctx: &RenderContext, out: &mut String, diagnostics: &mut Vec<String>) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Replacement { key, filters } => {
+                if key == "FrontSide" {
+                    out.push_str(ctx.front_side.unwrap_or(""));
+                    continue;
+                }
+                let text = match ctx.fields.get(key) {
+                    Some(text) => text.as_str(),
+                    None => {
+                        diagnostics.push(format!("模板引用了 {{{{{key}}}}}，但笔记类型中没有名为 '{key}' 的字段"));
+                        ""
+                    }
+                };
+                out.push_str(&apply_filters(text, key, filters, ctx, diagnostics));
+            }
+            Node::Conditional { key, negated, children } => {
+                let non_empty = match ctx.fields.get(key) {
+                    Some(text) => !field_is_empty(text),
+                    None => {
+                        diagnostics.push(format!("条件块引用了 {{{{#{key}}}}}，但笔记类型中没有名为 '{key}' 的字段"));
+                        false
+                    }
+                };
+                if non_empty != *negated {
+                    render_nodes(children, ctx, out, diagnostics);
+                }
+            }
+        }
+    }
+}
+
+fn apply_filters(text: &str, key: &str, filters: &[String], ctx: &RenderContext, diagnostics: &mut Vec<String>) -> String {
+    // type 必须是最外层的过滤器，先输出占位符，渲染完整面后再替换成输入框或答案
+    if filters.last().map(|f| f == "type").unwrap_or(false) {
+        let mut inner: Vec<&str> = filters[..filters.len() - 1].iter().rev().map(|f| f.as_str()).collect();
+        inner.push(key);
+        return format!("[[type:{}]]", inner.join(":"));
+    }
+    let mut text = text.to_string();
+    for filter in filters {
+        text = match filter.as_str() {
+            "text" => strip_html(&text),
+            // 填空序号从 1 开始，对应卡片 ord + 1
+            "cloze" => reveal_cloze_text(&text, ctx.card_ord + 1, ctx.question),
+            "cloze-only" => reveal_cloze_text_only(&text, ctx.card_ord + 1),
+            "hint" => hint_filter(&text, key),
+            "furigana" => furigana_filter(&text),
+            "kana" => kana_filter(&text),
+            "kanji" => kanji_filter(&text),
+            f if f == "tts" || f.starts_with("tts ") => tts_filter(&text, f),
+            other => {
+                diagnostics.push(format!("不支持的过滤器 '{other}'，已按原文输出"));
+                text
+            }
+        };
+    }
+    text
+}
+
+fn field_is_empty(text: &str) -> bool {
+    EMPTY_FIELD.is_match(text)
+}
+
+pub fn strip_html(html: &str) -> String {
+    let without_comments = HTML_COMMENT.replace_all(html, "");
+    let without_tags = HTML_TAG.replace_all(&without_comments, "");
+    decode_entities(&without_tags).trim().to_string()
+}
+
+fn decode_entities(text: &str) -> String {
+    HTML_ENTITY
+        .replace_all(text, |caps: &Captures| {
+            let entity = &caps[1];
+            let decoded = if let Some(hex) = entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+                u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+            } else if let Some(dec) = entity.strip_prefix('#') {
+                dec.parse::<u32>().ok().and_then(char::from_u32)
+            } else {
+                match entity {
+                    "nbsp" => Some(' '),
+                    "amp" => Some('&'),
+                    "lt" => Some('<'),
+                    "gt" => Some('>'),
+                    "quot" => Some('"'),
+                    "apos" => Some('\''),
+                    _ => None,
+                }
+            };
+            decoded.map(|c| c.to_string()).unwrap_or_else(|| caps[0].to_string())
+        })
+        .into_owned()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn hint_filter(text: &str, key: &str) -> String {
+    if text.trim().is_empty() {
+        return String::new();
+    }
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    text.hash(&mut hasher);
+    let id = hasher.finish();
+    format!(
+        r##"<a class=hint href="#" onclick="this.style.display='none';document.getElementById('hint{id}').style.display='block';return false;" draggable=false>{key}</a><div id="hint{id}" class=hint style="display: none">{text}</div>"##
+    )
+}
+
+// 振假名格式为 "漢字[かんじ]"，和 Anki 一样先把 &nbsp; 换成空格
+fn furigana_filter(text: &str) -> String {
+    FURIGANA
+        .replace_all(&text.replace("&nbsp;", " "), |caps: &Captures| {
+            if caps[2].starts_with("sound:") {
+                // [sound:xxx] 是音频标签，不是注音
+                caps[0].to_string()
+            } else {
+                format!("<ruby><rb>{}</rb><rt>{}</rt></ruby>", &caps[1], &caps[2])
+            }
+        })
+        .into_owned()
+}
+
+fn kana_filter(text: &str) -> String {
+    FURIGANA
+        .replace_all(&text.replace("&nbsp;", " "), |caps: &Captures| {
+            if caps[2].starts_with("sound:") { caps[0].to_string() } else { caps[2].to_string() }
+        })
+        .into_owned()
+}
+
+fn kanji_filter(text: &str) -> String {
+    FURIGANA
+        .replace_all(&text.replace("&nbsp;", " "), |caps: &Captures| {
+            if caps[2].starts_with("sound:") { caps[0].to_string() } else { caps[1].to_string() }
+        })
+        .into_owned()
+}
+
+// {{tts en_US voices=xxx:Field}} -> [anki:tts lang=en_US voices=xxx]内容[/anki:tts]
+fn tts_filter(text: &str, filter: &str) -> String {
+    let mut options = filter.split_whitespace().skip(1);
+    let lang = options.next().unwrap_or_default();
+    let mut attrs = format!("lang={lang}");
+    for opt in options {
+        attrs.push(' ');
+        attrs.push_str(opt);
+    }
+    format!("[anki:tts {attrs}]{text}[/anki:tts]")
+}
+
+fn render_template(template: &str, ctx: &RenderContext, diagnostics: &mut Vec<String>) -> String {
+    let nodes = parse(template, diagnostics);
+    let mut out = String::new();
+    render_nodes(&nodes, ctx, &mut out, diagnostics);
+    out
+}
+
+// [[type:nc:Field]]、[[type:cloze:Field]] 对应的标准答案
+fn type_answer_expected(spec: &str, fields: &HashMap<String, String>, card_ord: u16) -> String {
+    let field = spec.rsplit(':').next().unwrap_or(spec);
+    let text = fields.get(field).map(|t| t.as_str()).unwrap_or("");
+    if spec.starts_with("cloze:") {
+        strip_html(&reveal_cloze_text_only(text, card_ord + 1))
+    } else {
+        strip_html(text)
+    }
+}
+
+fn collect_cloze_fields(nodes: &[Node], out: &mut Vec<String>) {
+    for node in nodes {
+        match node {
+            Node::Replacement { key, filters } if filters.iter().any(|f| f == "cloze") && !out.contains(key) => {
+                out.push(key.clone());
+            }
+            Node::Conditional { children, .. } => collect_cloze_fields(children, out),
+            _ => {}
+        }
+    }
+}
+
+/// 模板里用 {{cloze:字段}} 引用的字段名，用于按填空序号生成卡片
+pub fn cloze_fields(qfmt: &str) -> Vec<String> {
+    let mut out = vec![];
+    collect_cloze_fields(&parse(qfmt, &mut vec![]), &mut out);
+    out
+}
+
+/// 按 qfmt/afmt 渲染一张卡片的正反面。
+/// fields 为字段名到内容的映射，需包含 Tags、Type、Deck、Card 等特殊字段；card_ord 为卡片的 ord，填空题用它确定当前填空。
+pub fn render_question_answer(qfmt: &str, afmt: &str, fields: &HashMap<String, String>, card_ord: u16) -> RenderedSides {
+    let mut diagnostics = vec![];
+    let question_ctx = RenderContext { fields, front_side: None, card_ord, question: true };
+    let question_raw = render_template(qfmt, &question_ctx, &mut diagnostics);
+    // 反面的 {{FrontSide}} 不再显示输入框，只在反面自己的 {{type:}} 位置显示答案
+    let front_side = TYPE_ANSWER.replace_all(&question_raw, "").into_owned();
+    let answer_ctx = RenderContext { fields, front_side: Some(&front_side), card_ord, question: false };
+    let answer_raw = render_template(afmt, &answer_ctx, &mut diagnostics);
+
+    // 和 Anki 一样一张卡只支持一个输入框，多余的占位符直接去掉
+    let question = TYPE_ANSWER.replace(&question_raw, r#"<center><input type="text" id="typeans" class="typeans"></center>"#);
+    let question = TYPE_ANSWER.replace_all(&question, "").into_owned();
+    let answer = TYPE_ANSWER
+        .replace_all(&answer_raw, |caps: &Captures| {
+            let expected = escape_html(&type_answer_expected(&caps[1], fields, card_ord));
+            format!(r#"<center><code id="typeans" class="typeans">{expected}</code></center>"#)
+        })
+        .into_owned();
+
+    let mut seen = std::collections::HashSet::new();
+    diagnostics.retain(|d| seen.insert(d.clone()));
+    RenderedSides { question, answer, diagnostics }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    fn render(template: &str, fields: &HashMap<String, String>) -> (String, Vec<String>) {
+        let sides = render_question_answer(template, "", fields, 0);
+        (sides.question, sides.diagnostics)
+    }
+
+    #[test]
+    fn nested_conditionals() {
+        let template = "{{#A}}a{{#B}}b{{/B}}{{^B}}!b{{/B}}{{/A}}{{^A}}!a{{/A}}";
+        let f = fields(&[("A", "1"), ("B", "1")]);
+        assert_eq!(render(template, &f).0, "ab");
+        let f = fields(&[("A", "1"), ("B", "<br> <div></div>")]);
+        assert_eq!(render(template, &f).0, "a!b");
+        let f = fields(&[("A", ""), ("B", "1")]);
+        assert_eq!(render(template, &f).0, "!a");
+    }
+
+    #[test]
+    fn mismatched_conditionals_are_reported() {
+        let f = fields(&[("A", "1"), ("B", "1")]);
+        let (out, diagnostics) = render("{{#A}}a{{/B}}{{/A}}{{/C}}", &f);
+        assert_eq!(out, "a");
+        assert_eq!(diagnostics.len(), 2);
+        let (out, diagnostics) = render("{{#A}}a", &f);
+        assert_eq!(out, "a");
+        assert_eq!(diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn filters_apply_right_to_left() {
+        let f = fields(&[("F", "<b>漢字[かんじ]</b>")]);
+        assert_eq!(render("{{text:F}}", &f).0, "漢字[かんじ]");
+        assert_eq!(render("{{kana:text:F}}", &f).0, "かんじ");
+        assert_eq!(render("{{text:furigana:F}}", &f).0, "漢字かんじ");
+        assert_eq!(render("{{furigana:F}}", &f).0, "<b><ruby><rb>漢字</rb><rt>かんじ</rt></ruby></b>");
+        assert_eq!(render("{{tts en_US voices=x:text:F}}", &f).0, "[anki:tts lang=en_US voices=x]漢字[かんじ][/anki:tts]");
+    }
+
+    #[test]
+    fn unknown_filter_keeps_text() {
+        let f = fields(&[("F", "x")]);
+        let (out, diagnostics) = render("{{nope:F}}", &f);
+        assert_eq!(out, "x");
+        assert_eq!(diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn unknown_fields_render_empty() {
+        let f = fields(&[("F", "x")]);
+        let (out, diagnostics) = render("[{{Missing}}]{{#Gone}}y{{/Gone}}{{^Gone}}z{{/Gone}}", &f);
+        assert_eq!(out, "[]z");
+        // 同一个字段重复出现只报一次
+        assert_eq!(diagnostics.len(), 2);
+    }
+
+    #[test]
+    fn hint_wraps_non_empty_field() {
+        let f = fields(&[("Extra", "more"), ("Empty", " ")]);
+        let (out, _) = render("{{hint:Extra}}", &f);
+        assert!(out.starts_with("<a class=hint"));
+        assert!(out.contains(">Extra</a>"));
+        assert!(out.contains(r#"style="display: none">more</div>"#));
+        assert_eq!(render("{{hint:Empty}}", &f).0, "");
+    }
+
+    #[test]
+    fn front_side_and_type_answer() {
+        let f = fields(&[("Front", "Q"), ("Back", "<i>A</i>")]);
+        let sides = render_question_answer("{{Front}}{{type:Back}}", "{{FrontSide}}<hr>{{type:Back}}", &f, 0);
+        assert_eq!(sides.question, r#"Q<center><input type="text" id="typeans" class="typeans"></center>"#);
+        assert_eq!(sides.answer, r#"Q<hr><center><code id="typeans" class="typeans">A</code></center>"#);
+    }
+
+    #[test]
+    fn cloze_filter_without_matching_ordinal_is_empty() {
+        let f = fields(&[("Text", "{{c1::a}} {{c2::b}}")]);
+        let sides = render_question_answer("{{cloze:Text}}", "{{cloze:Text}}", &f, 2);
+        assert_eq!(sides.question, "");
+        assert_eq!(sides.answer, "");
+        let sides = render_question_answer("{{cloze:Text}}", "{{cloze:Text}}", &f, 1);
+        assert_eq!(
+            sides.question,
+            r#"<span class="cloze-inactive" data-ordinal="1">a</span> <span class="cloze" data-cloze="b" data-ordinal="2">[...]</span>"#
+        );
+    }
+
+    #[test]
+    fn cloze_fields_inside_conditionals() {
+        assert_eq!(cloze_fields("{{cloze:Text}}{{#Extra}}{{cloze:Extra}}{{/Extra}}{{cloze:Text}}"), vec!["Text", "Extra"]);
+        assert!(cloze_fields("{{Front}}").is_empty());
+    }
+}